@@ -0,0 +1,168 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+
+//! Micro-benchmarks for individual lint rules.
+//!
+//! A large synthetic source is parsed once, and then each recommended rule is
+//! run over it on its own, so that the reported timings don't include the
+//! cost of parsing. The time taken by a linter without any rules (building
+//! the scope and the control flow) is reported as the baseline.
+//!
+//! Run with `cargo bench --bench rules`, optionally followed by `--` and
+//! substrings of the rule codes to benchmark, e.g.
+//! `cargo bench --bench rules -- no-self-compare`.
+
+use deno_ast::ParsedSource;
+use deno_lint::diagnostic::LintDiagnostic;
+use deno_lint::linter::LinterBuilder;
+use deno_lint::rules::{get_filtered_rules, get_recommended_rules, LintRule};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+const COPIES: usize = 200;
+const WARMUP_RUNS: usize = 2;
+const RUNS: usize = 10;
+
+/// A snippet exercising a wide range of syntax. `$N` is replaced with the
+/// index of the copy, so that every copy declares distinct bindings.
+const TEMPLATE: &str = r#"
+export interface Options$N {
+  name: string;
+  count?: number;
+  callback(value: string): void;
+}
+
+export class Service$N<T> {
+  #cache = new Map<string, T>();
+  private readonly options: Options$N;
+
+  constructor(options: Options$N) {
+    this.options = options;
+  }
+
+  get size(): number {
+    return this.#cache.size;
+  }
+
+  async load(key: string, fetcher: () => Promise<T>): Promise<T> {
+    const cached = this.#cache.get(key);
+    if (cached !== undefined) {
+      return cached;
+    }
+    try {
+      const value = await fetcher();
+      this.#cache.set(key, value);
+      return value;
+    } catch (err) {
+      throw new Error(`Failed to load ${key}: ${err}`);
+    }
+  }
+}
+
+export function compute$N(values: number[], limit = 10): number {
+  let total = 0;
+  for (let i = 0; i < values.length; i++) {
+    if (values[i] > limit && values[i] !== values[i - 1]) {
+      total += values[i] * 2;
+    } else if (values[i] < 0) {
+      continue;
+    }
+  }
+  switch (total % 3) {
+    case 0:
+      return total;
+    case 1:
+      return total + 1;
+    default:
+      return values.filter((v) => v % 2 === 0).reduce((a, b) => a + b, 0);
+  }
+}
+
+export const config$N = {
+  enabled: true,
+  retries: 3,
+  tags: ["a", "b", "c"],
+  nested: { deep: { value: compute$N([1, 2, 3]) } },
+};
+"#;
+
+fn synthetic_source() -> String {
+  (0..COPIES)
+    .map(|i| TEMPLATE.replace("$N", &i.to_string()))
+    .collect()
+}
+
+/// Runs the given rules over an already parsed source, so that only the cost
+/// of the rules themselves and of the analyses done for every lint run is
+/// measured.
+fn lint_with_rules(
+  rules: &Arc<Vec<Box<dyn LintRule>>>,
+  parsed_source: &ParsedSource,
+) -> Vec<LintDiagnostic> {
+  LinterBuilder::default()
+    .rules(rules.clone())
+    .build()
+    .lint_with_ast(parsed_source)
+}
+
+/// Returns the median duration of linting the source with the given rules.
+fn measure(
+  rules: &Arc<Vec<Box<dyn LintRule>>>,
+  parsed_source: &ParsedSource,
+) -> Duration {
+  for _ in 0..WARMUP_RUNS {
+    lint_with_rules(rules, parsed_source);
+  }
+  let mut durations: Vec<Duration> = (0..RUNS)
+    .map(|_| {
+      let start = Instant::now();
+      lint_with_rules(rules, parsed_source);
+      start.elapsed()
+    })
+    .collect();
+  durations.sort();
+  durations[RUNS / 2]
+}
+
+fn main() {
+  // `cargo bench` passes flags like `--bench` to the harness
+  let filters: Vec<String> = std::env::args()
+    .skip(1)
+    .filter(|arg| !arg.starts_with("--"))
+    .collect();
+
+  let source = synthetic_source();
+  let start = Instant::now();
+  let (parsed_source, _) = LinterBuilder::default()
+    .build()
+    .lint("bench.ts".to_string(), source)
+    .expect("Failed to parse the synthetic source");
+  println!("parse and lint without rules: {:?}", start.elapsed());
+
+  let baseline = measure(&Arc::new(vec![]), &parsed_source);
+  println!("baseline (no rules): {:?}", baseline);
+
+  let mut results: Vec<(&'static str, Duration)> = get_recommended_rules()
+    .iter()
+    .map(|rule| rule.code())
+    .filter(|code| {
+      filters.is_empty() || filters.iter().any(|f| code.contains(f.as_str()))
+    })
+    .map(|code| {
+      let rules =
+        get_filtered_rules(Some(vec![]), None, Some(vec![code.to_string()]));
+      (code, measure(&rules, &parsed_source))
+    })
+    .collect();
+  results.sort_by(|a, b| b.1.cmp(&a.1));
+
+  println!();
+  println!("{:<40} {:>12} {:>12}", "rule", "median", "over base");
+  for (code, duration) in results {
+    println!(
+      "{:<40} {:>12} {:>12}",
+      code,
+      format!("{:?}", duration),
+      format!("{:?}", duration.saturating_sub(baseline))
+    );
+  }
+}
@@ -0,0 +1,88 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use anyhow::Error as AnyError;
+use deno_ast::ParsedSource;
+use deno_lint::diagnostic::{LintDiagnostic, LintFix};
+use deno_lint::fix::apply_fixes;
+use std::collections::HashSet;
+
+pub const DEFAULT_MAX_PASSES: usize = 10;
+
+const BOM_CHAR: char = '\u{FEFF}';
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FixStatus {
+  /// Linting the source produces no more applicable fixes.
+  Stable,
+  /// The maximum number of passes was reached while fixes were still being
+  /// applied.
+  MaxPassesReached,
+  /// Applying the fixes produced a source seen in an earlier pass, e.g.
+  /// because the fixes of two rules undo each other.
+  Cycle,
+}
+
+#[derive(Debug)]
+pub struct FixOutcome {
+  pub source: String,
+  pub passes: usize,
+  pub applied: usize,
+  pub status: FixStatus,
+}
+
+/// Lints the source and applies the fixes of the diagnostics until no more
+/// fixes are applied, since the fixes of one pass may lead to new fixable
+/// problems, e.g. `var` fixed to `let` by `no-var` and then to `const` by
+/// `prefer-const`.
+pub fn fix_source<F>(
+  source: String,
+  max_passes: usize,
+  mut lint: F,
+) -> Result<FixOutcome, AnyError>
+where
+  F: FnMut(String) -> Result<(ParsedSource, Vec<LintDiagnostic>), AnyError>,
+{
+  let mut seen_sources = HashSet::new();
+  seen_sources.insert(source.clone());
+  let mut source = source;
+  let mut applied = 0;
+
+  for pass in 1..=max_passes {
+    let has_bom = source.starts_with(BOM_CHAR);
+    let (parsed_source, diagnostics) = lint(source.clone())?;
+    let fixes: Vec<LintFix> =
+      diagnostics.into_iter().filter_map(|d| d.fix).collect();
+    // The ranges of the fixes refer to the parsed text, which doesn't
+    // include the byte order mark
+    let result = apply_fixes(parsed_source.source().text_str(), &fixes);
+    if result.applied == 0 {
+      return Ok(FixOutcome {
+        source,
+        passes: pass,
+        applied,
+        status: FixStatus::Stable,
+      });
+    }
+
+    applied += result.applied;
+    source = if has_bom && !result.removes_bom {
+      format!("{}{}", BOM_CHAR, result.text)
+    } else {
+      result.text
+    };
+    if !seen_sources.insert(source.clone()) {
+      return Ok(FixOutcome {
+        source,
+        passes: pass,
+        applied,
+        status: FixStatus::Cycle,
+      });
+    }
+  }
+
+  Ok(FixOutcome {
+    source,
+    passes: max_passes,
+    applied,
+    status: FixStatus::MaxPassesReached,
+  })
+}
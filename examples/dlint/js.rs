@@ -38,6 +38,9 @@ struct Code {
 type Diagnostics = HashMap<String, Vec<InnerDiagnostics>>;
 type Codes = HashSet<String>;
 
+/// The file being linted, exposed to plugins via `op_get_file_name`.
+struct FileName(String);
+
 #[allow(clippy::unnecessary_wraps)]
 fn op_add_diagnostics(
   state: &mut OpState,
@@ -103,6 +106,19 @@ fn op_query_control_flow_by_span(
   .map_err(Into::into)
 }
 
+#[allow(clippy::unnecessary_wraps)]
+fn op_get_file_name(
+  state: &mut OpState,
+  _args: Value,
+  _maybe_buf: Option<ZeroCopyBuf>,
+) -> Result<Value, AnyError> {
+  let FileName(file_name) = state
+    .try_borrow::<FileName>()
+    .context("FileName is not set")?;
+
+  Ok(serde_json::json!({ "fileName": file_name }))
+}
+
 #[derive(Debug)]
 pub struct PluginRunner {
   plugin_path: String,
@@ -144,6 +160,8 @@ impl JsRunner {
       "op_query_control_flow_by_span",
       deno_core::op_sync(op_query_control_flow_by_span),
     );
+    runtime
+      .register_op("op_get_file_name", deno_core::op_sync(op_get_file_name));
     runtime.sync_ops_cache();
 
     let module_id =
@@ -164,11 +182,12 @@ impl Plugin for PluginRunner {
     program: ProgramRef,
   ) -> Result<(), AnyError> {
     let mut runner = JsRunner::new(&self.plugin_path);
-    runner
-      .runtime
-      .op_state()
-      .borrow_mut()
-      .put(context.control_flow().clone());
+    {
+      let op_state = runner.runtime.op_state();
+      let mut op_state = op_state.borrow_mut();
+      op_state.put(context.control_flow().clone());
+      op_state.put(FileName(context.file_name().to_string()));
+    }
 
     let _ = runner.runtime.mod_evaluate(runner.module_id);
     deno_core::futures::executor::block_on(
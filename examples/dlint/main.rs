@@ -21,6 +21,7 @@ use std::sync::{Arc, Mutex};
 mod color;
 mod config;
 mod diagnostics;
+mod fix;
 mod js;
 mod lexer;
 mod rules;
@@ -62,6 +63,18 @@ fn create_cli_app<'a, 'b>() -> App<'a, 'b> {
             .help("Specify plugin paths")
             .multiple(true)
             .takes_value(true),
+        )
+        .arg(
+          Arg::with_name("FIX")
+            .long("fix")
+            .help("Apply the fixes of the diagnostics to the files"),
+        )
+        .arg(
+          Arg::with_name("MAX_FIX_PASSES")
+            .long("max-fix-passes")
+            .help("Maximum number of times the files are fixed and linted again")
+            .takes_value(true)
+            .requires("FIX"),
         ),
     )
 }
@@ -71,6 +84,7 @@ fn run_linter(
   filter_rule_name: Option<&str>,
   maybe_config: Option<Arc<config::Config>>,
   plugin_paths: Vec<&str>,
+  max_fix_passes: Option<usize>,
 ) -> Result<(), AnyError> {
   let mut paths: Vec<PathBuf> = paths.iter().map(PathBuf::from).collect();
 
@@ -104,7 +118,7 @@ fn run_linter(
   paths
     .par_iter()
     .try_for_each(|file_path| -> Result<(), AnyError> {
-      let source_code = std::fs::read_to_string(&file_path)?;
+      let mut source_code = std::fs::read_to_string(&file_path)?;
 
       debug!("Configured rules: {}", rules.len());
 
@@ -112,15 +126,43 @@ fn run_linter(
         bail!("There's no rule to be run!");
       }
 
-      let linter_builder = LinterBuilder::default()
-        .rules(Arc::clone(&rules))
-        .plugins(Arc::clone(&plugins))
-        .syntax(determine_syntax(file_path));
+      let lint = |source_code: String| {
+        let linter = LinterBuilder::default()
+          .rules(Arc::clone(&rules))
+          .plugins(Arc::clone(&plugins))
+          .syntax(determine_syntax(file_path))
+          .build();
+        linter.lint(file_path.to_string_lossy().to_string(), source_code)
+      };
 
-      let linter = linter_builder.build();
+      if let Some(max_passes) = max_fix_passes {
+        let outcome =
+          fix::fix_source(source_code.clone(), max_passes, |s| Ok(lint(s)?))?;
+        debug!(
+          "Applied {} fixes to {} in {} passes",
+          outcome.applied,
+          file_path.display(),
+          outcome.passes
+        );
+        match outcome.status {
+          fix::FixStatus::Stable => {}
+          fix::FixStatus::MaxPassesReached => eprintln!(
+            "Warning: {} still has fixable problems after {} passes",
+            file_path.display(),
+            max_passes
+          ),
+          fix::FixStatus::Cycle => eprintln!(
+            "Warning: stopped fixing {}, since the fixes undo each other",
+            file_path.display()
+          ),
+        }
+        if outcome.source != source_code {
+          std::fs::write(&file_path, &outcome.source)?;
+          source_code = outcome.source;
+        }
+      }
 
-      let (parsed_source, diagnostics) =
-        linter.lint(file_path.to_string_lossy().to_string(), source_code)?;
+      let (parsed_source, diagnostics) = lint(source_code)?;
 
       error_counts.fetch_add(diagnostics.len(), Ordering::Relaxed);
 
@@ -219,11 +261,24 @@ fn main() -> Result<(), AnyError> {
         .unwrap_or_default()
         .map(|p| p.to_string())
         .collect();
+      let max_fix_passes = if run_matches.is_present("FIX") {
+        match run_matches.value_of("MAX_FIX_PASSES") {
+          Some(passes) => match passes.parse::<usize>() {
+            Ok(passes) if passes > 0 => Some(passes),
+            _ => bail!("Invalid number of fix passes: {}", passes),
+          },
+          None => Some(fix::DEFAULT_MAX_PASSES),
+        }
+      } else {
+        None
+      };
+
       run_linter(
         paths,
         run_matches.value_of("RULE_CODE"),
         maybe_config,
         plugins,
+        max_fix_passes,
       )?;
     }
     ("rules", Some(rules_matches)) => {
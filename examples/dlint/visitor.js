@@ -10,6 +10,10 @@ class Visitor {
   addDiagnostic(d) {
     this.diagnostics.push(d);
   }
+  // path or URL of the file being linted
+  getFileName() {
+    return Deno.core.opSync("op_get_file_name", {}).fileName;
+  }
   // must be overridden
   static ruleCode() {
     throw new Error("Rule code not provided!");
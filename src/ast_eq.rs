@@ -0,0 +1,162 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+
+//! Structural comparison of AST nodes, for rules which need to know whether
+//! two expressions are the same, like `a.b` in `a.b === a.b`. Nodes are
+//! compared directly instead of through their source text, so no substrings
+//! are allocated and a mismatch of the node kinds is detected immediately.
+
+use crate::swc_util::StringRepr;
+use deno_ast::swc::ast::{Expr, ExprOrSuper, Lit, MemberExpr};
+use deno_ast::swc::common::EqIgnoreSpan;
+
+/// Checks if two expressions are structurally equal, ignoring spans and
+/// comments. Parentheses are significant, so rules which don't care about
+/// them need to unwrap them first. Member accesses with a static key are equal
+/// regardless of whether they are written with dot or computed access, i.e.
+/// `a.b` equals `a["b"]`, but not `a[b]`.
+pub(crate) fn expr_eq(left: &Expr, right: &Expr) -> bool {
+  match (left, right) {
+    (Expr::Paren(left), Expr::Paren(right)) => expr_eq(&left.expr, &right.expr),
+    (Expr::Ident(left), Expr::Ident(right)) => left.sym == right.sym,
+    (Expr::This(_), Expr::This(_)) => true,
+    (Expr::Lit(left), Expr::Lit(right)) => lit_eq(left, right),
+    (Expr::Member(left), Expr::Member(right)) => member_eq(left, right),
+    (Expr::Unary(left), Expr::Unary(right)) => {
+      left.op == right.op && expr_eq(&left.arg, &right.arg)
+    }
+    (Expr::Bin(left), Expr::Bin(right)) => {
+      left.op == right.op
+        && expr_eq(&left.left, &right.left)
+        && expr_eq(&left.right, &right.right)
+    }
+    (Expr::TsNonNull(left), Expr::TsNonNull(right)) => {
+      expr_eq(&left.expr, &right.expr)
+    }
+    // The other kinds of expressions are rarely compared, so they are
+    // compared as they are
+    _ => left.eq_ignore_span(right),
+  }
+}
+
+fn lit_eq(left: &Lit, right: &Lit) -> bool {
+  match (left, right) {
+    (Lit::Str(left), Lit::Str(right)) => left.value == right.value,
+    (Lit::Num(left), Lit::Num(right)) => left.value == right.value,
+    (Lit::Bool(left), Lit::Bool(right)) => left.value == right.value,
+    (Lit::Null(_), Lit::Null(_)) => true,
+    (Lit::BigInt(left), Lit::BigInt(right)) => left.value == right.value,
+    (Lit::Regex(left), Lit::Regex(right)) => {
+      left.exp == right.exp && left.flags == right.flags
+    }
+    (Lit::JSXText(left), Lit::JSXText(right)) => left.value == right.value,
+    _ => false,
+  }
+}
+
+/// Returns the key of the member access if it is known statically, e.g. `b`
+/// for both `a.b` and `a["b"]`. Private names don't have a static key, since
+/// `a.#b` can't be written as a computed access.
+fn static_key(member: &MemberExpr) -> Option<String> {
+  match (&*member.prop, member.computed) {
+    (Expr::Ident(ident), false) => Some(ident.sym.to_string()),
+    (Expr::Lit(lit @ Lit::Str(_)), true)
+    | (Expr::Lit(lit @ Lit::Num(_)), true) => lit.string_repr(),
+    _ => None,
+  }
+}
+
+/// Checks if two member accesses are structurally equal, like [`expr_eq`]
+/// does for member expressions.
+pub(crate) fn member_eq(left: &MemberExpr, right: &MemberExpr) -> bool {
+  let is_same_prop = match (static_key(left), static_key(right)) {
+    (Some(left_key), Some(right_key)) => left_key == right_key,
+    (None, None) if left.computed == right.computed => {
+      expr_eq(&left.prop, &right.prop)
+    }
+    _ => false,
+  };
+  is_same_prop
+    && match (&left.obj, &right.obj) {
+      (ExprOrSuper::Expr(left), ExprOrSuper::Expr(right)) => {
+        expr_eq(left, right)
+      }
+      (ExprOrSuper::Super(_), ExprOrSuper::Super(_)) => true,
+      _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::parse;
+  use deno_ast::swc::ast::{ModuleItem, Stmt};
+
+  fn assert_expr_eq(left: &str, right: &str, expected: bool) {
+    let parsed_left = parse(left);
+    let parsed_right = parse(right);
+    let expr = |module_item: &ModuleItem| match module_item {
+      ModuleItem::Stmt(Stmt::Expr(expr_stmt)) => expr_stmt.expr.clone(),
+      _ => panic!("Expected an expression statement"),
+    };
+    let left_expr = expr(&parsed_left.module().body[0]);
+    let right_expr = expr(&parsed_right.module().body[0]);
+    assert_eq!(
+      expr_eq(&left_expr, &right_expr),
+      expected,
+      "expr_eq({}, {})",
+      left,
+      right
+    );
+  }
+
+  #[test]
+  fn member_chains() {
+    assert_expr_eq("a.b.c", "a.b.c", true);
+    assert_expr_eq("a.b.c", "a.b.d", false);
+    assert_expr_eq("a.b.c", "a.d.c", false);
+    assert_expr_eq("a.b.c", "a.b", false);
+    assert_expr_eq("this.a", "this.a", true);
+    assert_expr_eq("this.a", "self.a", false);
+    assert_expr_eq("a.b", "a . b", true);
+    assert_expr_eq("(a).b", "(a).b", true);
+    assert_expr_eq("a.b", "(a).b", false);
+    assert_expr_eq("a.b", "a.b /* comment */", true);
+  }
+
+  #[test]
+  fn computed_and_dot_access() {
+    assert_expr_eq("a.b", "a['b']", true);
+    assert_expr_eq("a[\"b\"]", "a['b']", true);
+    assert_expr_eq("a[0]", "a['0']", true);
+    assert_expr_eq("a.b", "a[b]", false);
+    assert_expr_eq("a[b]", "a[b]", true);
+    assert_expr_eq("a[b]", "a[c]", false);
+    assert_expr_eq("a[b.c]", "a[b.c]", true);
+    assert_expr_eq("a[0]", "a[1]", false);
+  }
+
+  #[test]
+  fn literals() {
+    assert_expr_eq("'a'", "\"a\"", true);
+    assert_expr_eq("'a'", "'b'", false);
+    assert_expr_eq("1", "1.0", true);
+    assert_expr_eq("1", "'1'", false);
+    assert_expr_eq("true", "true", true);
+    assert_expr_eq("true", "false", false);
+    assert_expr_eq("null", "null", true);
+    assert_expr_eq("/a/g", "/a/g", true);
+    assert_expr_eq("/a/g", "/a/i", false);
+  }
+
+  #[test]
+  fn other_expressions() {
+    assert_expr_eq("a === 1", "a===1", true);
+    assert_expr_eq("a === 1", "a !== 1", false);
+    assert_expr_eq("!a", "!a", true);
+    assert_expr_eq("!a", "-a", false);
+    assert_expr_eq("f(a)", "f(a)", true);
+    assert_expr_eq("f(a)", "f(b)", false);
+    assert_expr_eq("[a, b]", "[a, b]", true);
+    assert_expr_eq("a", "this", false);
+  }
+}
@@ -10,6 +10,7 @@ use deno_ast::MediaType;
 use deno_ast::ParsedSource;
 use std::error::Error;
 use std::fmt;
+use std::path::Path;
 
 #[allow(unused)]
 pub fn get_default_es_config() -> Syntax {
@@ -102,7 +103,7 @@ impl AstParser {
     deno_ast::parse_program_with_post_process(
       deno_ast::ParseParams {
         specifier: file_name.to_string(),
-        media_type: MediaType::Unknown,
+        media_type: MediaType::from(Path::new(file_name)),
         source: deno_ast::SourceTextInfo::from_string(source_code),
         capture_tokens: true,
         maybe_syntax: Some(syntax),
@@ -1,6 +1,6 @@
 // Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
 use crate::control_flow::ControlFlow;
-use crate::diagnostic::{LintDiagnostic, Position, Range};
+use crate::diagnostic::{LintDiagnostic, LintFix, Position, Range};
 use crate::ignore_directives::{
   CodeStatus, FileIgnoreDirective, LineIgnoreDirective,
 };
@@ -47,6 +47,10 @@ pub struct Context<'view> {
   /// The `SyntaxContext` of the top level
   top_level_ctxt: SyntaxContext,
 
+  /// Whether the source text started with a byte order mark, which is
+  /// stripped before parsing. `None` if the source text isn't known.
+  has_bom: Option<bool>,
+
   /// A value to control whether the node's children will be traversed or not.
   traverse_flow: TraverseFlow,
 }
@@ -62,6 +66,7 @@ impl<'view> Context<'view> {
     scope: Scope,
     control_flow: ControlFlow,
     top_level_ctxt: SyntaxContext,
+    has_bom: Option<bool>,
   ) -> Self {
     Self {
       file_name,
@@ -72,11 +77,14 @@ impl<'view> Context<'view> {
       scope,
       control_flow,
       top_level_ctxt,
+      has_bom,
       diagnostics: Vec::new(),
       plugin_codes: HashSet::new(),
       traverse_flow: TraverseFlow::default(),
     }
   }
+
+  /// The path or URL of the file being linted, as passed to the linter.
   pub fn file_name(&self) -> &str {
     &self.file_name
   }
@@ -109,6 +117,8 @@ impl<'view> Context<'view> {
     &self.line_ignore_directives
   }
 
+  /// Bindings of the program, which rules can use to resolve identifiers to
+  /// their declaration and references.
   pub fn scope(&self) -> &Scope {
     &self.scope
   }
@@ -121,6 +131,14 @@ impl<'view> Context<'view> {
     self.top_level_ctxt
   }
 
+  /// Whether the source text started with a byte order mark. The mark isn't
+  /// part of `source_file()`, and positions don't account for it. Returns
+  /// `None` when linting an already parsed source, since the mark has been
+  /// stripped by then.
+  pub fn has_bom(&self) -> Option<bool> {
+    self.has_bom
+  }
+
   pub(crate) fn assert_traverse_init(&self) {
     self.traverse_flow.assert_init();
   }
@@ -163,6 +181,83 @@ impl<'view> Context<'view> {
       .trailing_comments(hi)
   }
 
+  /// Counts the lines that the given span covers. Blank lines and lines that
+  /// contain nothing but comments can optionally be excluded from the count.
+  pub(crate) fn count_lines(
+    &self,
+    span: Span,
+    skip_blank_lines: bool,
+    skip_comments: bool,
+  ) -> usize {
+    let first_line = self.source_file.line_index(span.lo);
+    let last_line = self.source_file.line_index(span.hi);
+
+    let mut comment_spans: Vec<Span> = if skip_comments {
+      self
+        .all_comments()
+        .map(|comment| comment.span)
+        .filter(|comment| comment.lo < span.hi && span.lo < comment.hi)
+        .collect()
+    } else {
+      Vec::new()
+    };
+    // `all_comments` doesn't yield comments in source order
+    comment_spans.sort_by_key(|comment| comment.lo);
+    comment_spans.dedup();
+
+    (first_line..=last_line)
+      .filter(|&line_index| {
+        let line_span = Span::new(
+          self.source_file.line_start(line_index),
+          self.source_file.line_end(line_index),
+          Default::default(),
+        );
+
+        if skip_blank_lines
+          && self.file_text_substring(&line_span).trim().is_empty()
+        {
+          return false;
+        }
+
+        if skip_comments {
+          // Remove the parts of this line covered by comments and see if
+          // anything is left.
+          let mut code = String::new();
+          let mut pos = line_span.lo;
+          for comment in &comment_spans {
+            if comment.hi <= pos || line_span.hi <= comment.lo {
+              continue;
+            }
+            if pos < comment.lo {
+              code.push_str(self.file_text_substring(&Span::new(
+                pos,
+                comment.lo,
+                Default::default(),
+              )));
+            }
+            pos = comment.hi;
+          }
+          if pos < line_span.hi {
+            code.push_str(self.file_text_substring(&Span::new(
+              pos,
+              line_span.hi,
+              Default::default(),
+            )));
+          }
+
+          let has_comment = comment_spans.iter().any(|comment| {
+            comment.lo < line_span.hi && line_span.lo < comment.hi
+          });
+          if has_comment && code.trim().is_empty() {
+            return false;
+          }
+        }
+
+        true
+      })
+      .count()
+  }
+
   /// Mark ignore directives as used if that directive actually suppresses some
   /// diagnostic, and return a list of diagnostics that are not ignored.
   /// Make sure that this method is called after all lint rules have been
@@ -199,7 +294,7 @@ impl<'view> Context<'view> {
   /// works for diagnostics reported by other rules.
   pub(crate) fn ban_unused_ignore(
     &self,
-    specified_rules: &[Box<dyn LintRule>],
+    specified_rules: &[&dyn LintRule],
   ) -> Vec<LintDiagnostic> {
     const CODE: &str = "ban-unused-ignore";
 
@@ -326,6 +421,32 @@ impl<'view> Context<'view> {
     self.diagnostics.push(diagnostic);
   }
 
+  pub fn add_diagnostic_with_fix(
+    &mut self,
+    span: Span,
+    code: impl ToString,
+    message: impl ToString,
+    fix: LintFix,
+  ) {
+    let mut diagnostic = self.create_diagnostic(span, code, message, None);
+    diagnostic.fix = Some(fix);
+    self.diagnostics.push(diagnostic);
+  }
+
+  pub fn add_diagnostic_with_hint_and_fix(
+    &mut self,
+    span: Span,
+    code: impl ToString,
+    message: impl ToString,
+    hint: impl ToString,
+    fix: LintFix,
+  ) {
+    let mut diagnostic =
+      self.create_diagnostic(span, code, message, Some(hint.to_string()));
+    diagnostic.fix = Some(fix);
+    self.diagnostics.push(diagnostic);
+  }
+
   pub(crate) fn create_diagnostic(
     &self,
     span: Span,
@@ -334,21 +455,14 @@ impl<'view> Context<'view> {
     maybe_hint: Option<String>,
   ) -> LintDiagnostic {
     let time_start = Instant::now();
-    let start = Position::new(
-      span.lo(),
-      self.source_file.line_and_column_index(span.lo()),
-    );
-    let end = Position::new(
-      span.hi(),
-      self.source_file.line_and_column_index(span.hi()),
-    );
 
     let diagnostic = LintDiagnostic {
-      range: Range { start, end },
+      range: self.create_range(span),
       filename: self.file_name.clone(),
       message: message.to_string(),
       code: code.to_string(),
       hint: maybe_hint,
+      fix: None,
     };
 
     let time_end = Instant::now();
@@ -359,6 +473,33 @@ impl<'view> Context<'view> {
     diagnostic
   }
 
+  /// Creates a fix that replaces the text at `span` with `new_text`.
+  pub fn create_fix(
+    &self,
+    span: Span,
+    new_text: impl ToString,
+    description: impl ToString,
+  ) -> LintFix {
+    LintFix {
+      description: description.to_string(),
+      range: self.create_range(span),
+      new_text: new_text.to_string(),
+      removes_bom: false,
+    }
+  }
+
+  fn create_range(&self, span: Span) -> Range {
+    let start = Position::new(
+      span.lo(),
+      self.source_file.line_and_column_index(span.lo()),
+    );
+    let end = Position::new(
+      span.hi(),
+      self.source_file.line_and_column_index(span.hi()),
+    );
+    Range { start, end }
+  }
+
   pub fn set_plugin_codes(&mut self, codes: HashSet<String>) {
     self.plugin_codes = codes;
   }
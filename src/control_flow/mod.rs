@@ -2,6 +2,7 @@
 
 #[cfg(test)]
 mod analyze_test;
+pub(crate) mod super_calls;
 
 use deno_ast::swc::ast::*;
 use deno_ast::swc::common::{BytePos, Spanned, DUMMY_SP};
@@ -0,0 +1,366 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+//! Follows the code paths of a constructor body to find out whether `super()`
+//! has been called on each of them, for `constructor-super` and
+//! `no-this-before-super`.
+use super::ControlFlow;
+use deno_ast::swc::ast::{
+  ArrowExpr, BinExpr, BinaryOp, BlockStmt, BreakStmt, CallExpr, Class,
+  CondExpr, ContinueStmt, DoWhileStmt, Expr, ExprOrSuper, ForInStmt, ForOfStmt,
+  ForStmt, Function, GetterProp, Ident, IfStmt, Invalid, LabeledStmt,
+  ReturnStmt, SetterProp, Stmt, Super, SwitchStmt, ThisExpr, ThrowStmt,
+  TryStmt, WhileStmt,
+};
+use deno_ast::swc::atoms::JsWord;
+use deno_ast::swc::common::{Span, Spanned, DUMMY_SP};
+use deno_ast::swc::visit::{noop_visit_type, Node, Visit, VisitWith};
+use std::collections::BTreeSet;
+
+/// Where `super()` is called in a constructor body, and where it may be
+/// missing.
+pub(crate) struct SuperCalls {
+  /// The first call of `super()`, whether it can be reached or not.
+  pub first_call: Option<Span>,
+  /// Whether `super()` is called on some reachable path.
+  pub called_on_some_path: bool,
+  /// Whether some path reaches the end of the body, or returns from it,
+  /// without calling `super()` or returning a value.
+  pub missed_on_some_path: bool,
+  /// Calls of `super()` on paths where it may already have been called.
+  pub duplicate_calls: BTreeSet<Span>,
+  /// `this` and `super` accesses on paths where `super()` may not have been
+  /// called yet, in evaluation order.
+  pub accesses_before_call: Vec<Span>,
+}
+
+impl SuperCalls {
+  pub fn analyze(body: &BlockStmt, control_flow: &ControlFlow) -> Self {
+    let mut analyzer = Analyzer {
+      control_flow,
+      state: Some(Reach {
+        not_called: true,
+        called: false,
+      }),
+      break_targets: Vec::new(),
+      first_call: None,
+      called_on_some_path: false,
+      returns_without_super: false,
+      duplicate_calls: BTreeSet::new(),
+      accesses_before_call: Vec::new(),
+    };
+    body.visit_with(&Invalid { span: DUMMY_SP }, &mut analyzer);
+
+    SuperCalls {
+      first_call: analyzer.first_call,
+      called_on_some_path: analyzer.called_on_some_path,
+      missed_on_some_path: analyzer.returns_without_super
+        || analyzer.state.map_or(false, |state| state.not_called),
+      duplicate_calls: analyzer.duplicate_calls,
+      accesses_before_call: analyzer.accesses_before_call,
+    }
+  }
+}
+
+/// Which states of `super()` the code paths reaching a point can be in.
+/// `None` is used for points which can't be reached, like after `return`.
+#[derive(Clone, Copy)]
+struct Reach {
+  /// Some path reaches the point without calling `super()`.
+  not_called: bool,
+  /// Some path reaches the point after calling `super()`.
+  called: bool,
+}
+
+fn join(a: Option<Reach>, b: Option<Reach>) -> Option<Reach> {
+  match (a, b) {
+    (Some(a), Some(b)) => Some(Reach {
+      not_called: a.not_called || b.not_called,
+      called: a.called || b.called,
+    }),
+    (a, None) => a,
+    (None, b) => b,
+  }
+}
+
+/// A statement which can be exited with `break` or `continue`.
+struct BreakTarget {
+  label: Option<JsWord>,
+  /// Whether an unlabeled `break` exits this statement, as for loops and
+  /// `switch`.
+  is_breakable: bool,
+  /// The state of the paths exiting the statement through `break`.
+  exit: Option<Reach>,
+}
+
+struct Analyzer<'a> {
+  control_flow: &'a ControlFlow,
+  state: Option<Reach>,
+  break_targets: Vec<BreakTarget>,
+  first_call: Option<Span>,
+  called_on_some_path: bool,
+  returns_without_super: bool,
+  duplicate_calls: BTreeSet<Span>,
+  accesses_before_call: Vec<Span>,
+}
+
+impl<'a> Analyzer<'a> {
+  fn with_break_target(
+    &mut self,
+    label: Option<JsWord>,
+    is_breakable: bool,
+    op: impl FnOnce(&mut Self),
+  ) {
+    self.break_targets.push(BreakTarget {
+      label,
+      is_breakable,
+      exit: None,
+    });
+    op(self);
+    let target = self.break_targets.pop().unwrap();
+    self.state = join(self.state, target.exit);
+  }
+
+  /// Moves the current state to the target of `break` or `continue`. Since
+  /// the body of a loop may run any number of times, continuing a loop is
+  /// handled like breaking out of it.
+  fn jump(&mut self, label: &Option<Ident>) {
+    let target =
+      self
+        .break_targets
+        .iter_mut()
+        .rev()
+        .find(|target| match label {
+          Some(label) => target.label.as_ref() == Some(&label.sym),
+          None => target.is_breakable,
+        });
+    if let Some(target) = target {
+      target.exit = join(target.exit, self.state);
+    }
+    self.state = None;
+  }
+
+  /// Visits the body of a loop, which may not run at all or run several
+  /// times. The body is visited twice to find calls of `super()` repeated by
+  /// the loop.
+  fn visit_loop_body(&mut self, body: &Stmt, update: Option<&Expr>) {
+    let entry = self.state;
+    self.with_break_target(None, true, |a| {
+      body.visit_with(&Invalid { span: DUMMY_SP }, a);
+      if let Some(update) = update {
+        update.visit_with(&Invalid { span: DUMMY_SP }, a);
+      }
+      let after_first = a.state;
+      a.state = join(entry, after_first);
+      // Accesses are reported on the first visit already
+      let accesses = a.accesses_before_call.len();
+      body.visit_with(&Invalid { span: DUMMY_SP }, a);
+      if let Some(update) = update {
+        update.visit_with(&Invalid { span: DUMMY_SP }, a);
+      }
+      a.accesses_before_call.truncate(accesses);
+      a.state = join(join(entry, after_first), a.state);
+    });
+  }
+
+  fn access(&mut self, span: Span) {
+    if self.state.map_or(false, |state| state.not_called) {
+      self.accesses_before_call.push(span);
+    }
+  }
+}
+
+impl<'a> Visit for Analyzer<'a> {
+  noop_visit_type!();
+
+  fn visit_stmt(&mut self, stmt: &Stmt, _: &dyn Node) {
+    stmt.visit_children_with(self);
+    // The control flow analysis also knows about statements which never
+    // complete, like infinite loops
+    let stops_execution = self
+      .control_flow
+      .meta(stmt.span().lo)
+      .map_or(false, |meta| meta.stops_execution());
+    if stops_execution {
+      self.state = None;
+    }
+  }
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _: &dyn Node) {
+    if !matches!(call_expr.callee, ExprOrSuper::Super(_)) {
+      call_expr.visit_children_with(self);
+      return;
+    }
+
+    // arguments are evaluated before the callee
+    call_expr.args.visit_with(call_expr, self);
+    self.first_call.get_or_insert(call_expr.span);
+    if let Some(state) = self.state {
+      if state.called {
+        self.duplicate_calls.insert(call_expr.span);
+      }
+      self.called_on_some_path = true;
+      self.state = Some(Reach {
+        not_called: false,
+        called: true,
+      });
+    }
+  }
+
+  fn visit_this_expr(&mut self, this_expr: &ThisExpr, _: &dyn Node) {
+    self.access(this_expr.span);
+  }
+
+  fn visit_super(&mut self, super_: &Super, _: &dyn Node) {
+    self.access(super_.span);
+  }
+
+  fn visit_cond_expr(&mut self, cond_expr: &CondExpr, _: &dyn Node) {
+    cond_expr.test.visit_with(cond_expr, self);
+    let entry = self.state;
+    cond_expr.cons.visit_with(cond_expr, self);
+    let after_cons = self.state;
+    self.state = entry;
+    cond_expr.alt.visit_with(cond_expr, self);
+    self.state = join(after_cons, self.state);
+  }
+
+  fn visit_bin_expr(&mut self, bin_expr: &BinExpr, _: &dyn Node) {
+    bin_expr.left.visit_with(bin_expr, self);
+    let entry = self.state;
+    bin_expr.right.visit_with(bin_expr, self);
+    if matches!(
+      bin_expr.op,
+      BinaryOp::LogicalAnd | BinaryOp::LogicalOr | BinaryOp::NullishCoalescing
+    ) {
+      self.state = join(entry, self.state);
+    }
+  }
+
+  fn visit_if_stmt(&mut self, if_stmt: &IfStmt, _: &dyn Node) {
+    if_stmt.test.visit_with(if_stmt, self);
+    let entry = self.state;
+    if_stmt.cons.visit_with(if_stmt, self);
+    let after_cons = self.state;
+    self.state = entry;
+    if_stmt.alt.visit_with(if_stmt, self);
+    self.state = join(after_cons, self.state);
+  }
+
+  fn visit_while_stmt(&mut self, while_stmt: &WhileStmt, _: &dyn Node) {
+    while_stmt.test.visit_with(while_stmt, self);
+    self.visit_loop_body(&while_stmt.body, None);
+  }
+
+  fn visit_do_while_stmt(&mut self, do_while: &DoWhileStmt, _: &dyn Node) {
+    // The body runs at least once
+    self.with_break_target(None, true, |a| {
+      do_while.body.visit_with(do_while, a);
+      let after_first = a.state;
+      let accesses = a.accesses_before_call.len();
+      do_while.body.visit_with(do_while, a);
+      a.accesses_before_call.truncate(accesses);
+      a.state = join(after_first, a.state);
+    });
+    do_while.test.visit_with(do_while, self);
+  }
+
+  fn visit_for_stmt(&mut self, for_stmt: &ForStmt, _: &dyn Node) {
+    for_stmt.init.visit_with(for_stmt, self);
+    for_stmt.test.visit_with(for_stmt, self);
+    self.visit_loop_body(&for_stmt.body, for_stmt.update.as_deref());
+  }
+
+  fn visit_for_in_stmt(&mut self, for_in: &ForInStmt, _: &dyn Node) {
+    for_in.right.visit_with(for_in, self);
+    self.visit_loop_body(&for_in.body, None);
+  }
+
+  fn visit_for_of_stmt(&mut self, for_of: &ForOfStmt, _: &dyn Node) {
+    for_of.right.visit_with(for_of, self);
+    self.visit_loop_body(&for_of.body, None);
+  }
+
+  fn visit_switch_stmt(&mut self, switch_stmt: &SwitchStmt, _: &dyn Node) {
+    switch_stmt.discriminant.visit_with(switch_stmt, self);
+    let entry = self.state;
+    let has_default = switch_stmt.cases.iter().any(|case| case.test.is_none());
+    self.with_break_target(None, true, |a| {
+      // Each case can be entered directly or by falling through the previous
+      // one
+      let mut fallthrough = None;
+      for case in &switch_stmt.cases {
+        a.state = entry;
+        case.test.visit_with(case, a);
+        a.state = join(a.state, fallthrough);
+        case.cons.visit_with(case, a);
+        fallthrough = a.state;
+      }
+      if !has_default {
+        a.state = join(a.state, entry);
+      }
+    });
+  }
+
+  fn visit_try_stmt(&mut self, try_stmt: &TryStmt, _: &dyn Node) {
+    let entry = self.state;
+    try_stmt.block.visit_with(try_stmt, self);
+    let after_block = self.state;
+    if let Some(handler) = &try_stmt.handler {
+      // The catch clause only runs if the block throws, which it can do
+      // before or after calling `super()`
+      self.state = join(entry, after_block);
+      handler.visit_with(try_stmt, self);
+      self.state = join(after_block, self.state);
+    }
+    try_stmt.finalizer.visit_with(try_stmt, self);
+  }
+
+  fn visit_labeled_stmt(&mut self, labeled_stmt: &LabeledStmt, _: &dyn Node) {
+    let label = Some(labeled_stmt.label.sym.clone());
+    self.with_break_target(label, false, |a| {
+      labeled_stmt.body.visit_with(labeled_stmt, a);
+    });
+  }
+
+  fn visit_break_stmt(&mut self, break_stmt: &BreakStmt, _: &dyn Node) {
+    self.jump(&break_stmt.label);
+  }
+
+  fn visit_continue_stmt(
+    &mut self,
+    continue_stmt: &ContinueStmt,
+    _: &dyn Node,
+  ) {
+    self.jump(&continue_stmt.label);
+  }
+
+  fn visit_return_stmt(&mut self, return_stmt: &ReturnStmt, _: &dyn Node) {
+    return_stmt.arg.visit_with(return_stmt, self);
+    // returning value is a substitute of `super()`.
+    let not_called = self.state.map_or(false, |state| state.not_called);
+    if return_stmt.arg.is_none() && not_called {
+      self.returns_without_super = true;
+    }
+    self.state = None;
+  }
+
+  fn visit_throw_stmt(&mut self, throw_stmt: &ThrowStmt, _: &dyn Node) {
+    throw_stmt.arg.visit_with(throw_stmt, self);
+    self.state = None;
+  }
+
+  // `this` and `super` refer to something else in nested functions, and
+  // `super()` can't be called there
+  fn visit_function(&mut self, _: &Function, _: &dyn Node) {}
+
+  fn visit_arrow_expr(&mut self, _: &ArrowExpr, _: &dyn Node) {}
+
+  fn visit_getter_prop(&mut self, _: &GetterProp, _: &dyn Node) {}
+
+  fn visit_setter_prop(&mut self, _: &SetterProp, _: &dyn Node) {}
+
+  // The members of nested classes are checked on their own, but the super
+  // class is evaluated in the constructor
+  fn visit_class(&mut self, class: &Class, _: &dyn Node) {
+    class.super_class.visit_with(class, self);
+  }
+}
@@ -41,6 +41,20 @@ pub struct Range {
   pub end: Position,
 }
 
+/// A fix for a diagnostic, replacing the text in `range` with `new_text`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LintFix {
+  /// Short description of what the fix does, e.g. "Remove the semicolon".
+  pub description: String,
+  pub range: Range,
+  pub new_text: String,
+  /// Whether the fix also removes the byte order mark at the start of the
+  /// file. The mark is stripped before parsing, so no range can cover it.
+  #[serde(skip_serializing_if = "std::ops::Not::not")]
+  pub removes_bom: bool,
+}
+
 #[derive(Clone, Debug, Serialize)]
 pub struct LintDiagnostic {
   pub range: Range,
@@ -48,4 +62,6 @@ pub struct LintDiagnostic {
   pub message: String,
   pub code: String,
   pub hint: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub fix: Option<LintFix>,
 }
@@ -0,0 +1,188 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use crate::diagnostic::LintFix;
+
+/// The result of applying fixes to a source.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FixResult {
+  /// The source with the fixes applied.
+  pub text: String,
+  /// The number of fixes which were applied.
+  pub applied: usize,
+  /// The number of fixes which were skipped, because they overlap with
+  /// another fix or their range is invalid for the source.
+  pub skipped: usize,
+  /// Whether an applied fix removes the byte order mark, in which case it
+  /// must not be added back to `text`.
+  pub removes_bom: bool,
+}
+
+/// Applies the fixes to the source, which must be the source the fixes were
+/// created for.
+///
+/// The fixes are applied from the end of the source to the start. A fix which
+/// overlaps with an already applied one is skipped, as is a fix whose range
+/// isn't within the source or doesn't lie on character boundaries. Fixes which
+/// only touch each other, like replacing `a` and `b` in `ab`, are both applied.
+/// The skipped fixes can be applied by linting the new source again.
+pub fn apply_fixes(source: &str, fixes: &[LintFix]) -> FixResult {
+  let mut sorted: Vec<&LintFix> = fixes.iter().collect();
+  sorted.sort_by(|a, b| {
+    let a_range = (a.range.start.byte_pos, a.range.end.byte_pos);
+    let b_range = (b.range.start.byte_pos, b.range.end.byte_pos);
+    b_range.cmp(&a_range)
+  });
+
+  // The fixes to apply, from the last one in the source to the first one
+  let mut to_apply: Vec<&LintFix> = Vec::with_capacity(sorted.len());
+  for fix in sorted {
+    let start = fix.range.start.byte_pos;
+    let end = fix.range.end.byte_pos;
+    let is_valid = start <= end
+      && end <= source.len()
+      && source.is_char_boundary(start)
+      && source.is_char_boundary(end);
+    // Fixes starting at the same position overlap even if one of them is
+    // empty, since the order of their texts would be ambiguous
+    let overlaps = to_apply.last().map_or(false, |applied| {
+      let applied_start = applied.range.start.byte_pos;
+      end > applied_start || start == applied_start
+    });
+    if is_valid && !overlaps {
+      to_apply.push(fix);
+    }
+  }
+
+  let mut text = String::with_capacity(source.len());
+  let mut pos = 0;
+  for fix in to_apply.iter().rev() {
+    text.push_str(&source[pos..fix.range.start.byte_pos]);
+    text.push_str(&fix.new_text);
+    pos = fix.range.end.byte_pos;
+  }
+  text.push_str(&source[pos..]);
+
+  FixResult {
+    text,
+    applied: to_apply.len(),
+    skipped: fixes.len() - to_apply.len(),
+    removes_bom: to_apply.iter().any(|fix| fix.removes_bom),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::diagnostic::{Position, Range};
+
+  fn fix(start: usize, end: usize, new_text: &str) -> LintFix {
+    let position = |byte_pos| Position {
+      line_index: 0,
+      column_index: 0,
+      byte_pos,
+    };
+    LintFix {
+      description: "fix".to_string(),
+      range: Range {
+        start: position(start),
+        end: position(end),
+      },
+      new_text: new_text.to_string(),
+      removes_bom: false,
+    }
+  }
+
+  fn result(text: &str, applied: usize, skipped: usize) -> FixResult {
+    FixResult {
+      text: text.to_string(),
+      applied,
+      skipped,
+      removes_bom: false,
+    }
+  }
+
+  #[test]
+  fn no_fixes() {
+    assert_eq!(apply_fixes("let a = 1;", &[]), result("let a = 1;", 0, 0));
+  }
+
+  #[test]
+  fn applies_fixes_in_any_order() {
+    let fixes = [fix(0, 3, "const"), fix(8, 9, "2"), fix(9, 10, "")];
+    assert_eq!(
+      apply_fixes("let a = 1;", &fixes),
+      result("const a = 2", 3, 0)
+    );
+
+    let fixes = [fix(9, 10, ""), fix(0, 3, "const"), fix(8, 9, "2")];
+    assert_eq!(
+      apply_fixes("let a = 1;", &fixes),
+      result("const a = 2", 3, 0)
+    );
+  }
+
+  #[test]
+  fn applies_adjacent_fixes() {
+    let fixes = [fix(0, 1, "x"), fix(1, 2, "y")];
+    assert_eq!(apply_fixes("ab", &fixes), result("xy", 2, 0));
+
+    let fixes = [fix(0, 1, "x"), fix(1, 1, "y")];
+    assert_eq!(apply_fixes("ab", &fixes), result("xyb", 2, 0));
+  }
+
+  #[test]
+  fn skips_overlapping_fixes() {
+    // The fix closer to the end is applied first
+    let fixes = [fix(0, 3, "x"), fix(2, 4, "y")];
+    assert_eq!(apply_fixes("abcde", &fixes), result("abye", 1, 1));
+
+    let fixes = [fix(1, 2, "x"), fix(0, 4, "y")];
+    assert_eq!(apply_fixes("abcde", &fixes), result("axcde", 1, 1));
+
+    let fixes = [fix(1, 1, "x"), fix(1, 1, "y")];
+    assert_eq!(apply_fixes("ab", &fixes).skipped, 1);
+
+    let fixes = [fix(1, 3, "x"), fix(1, 3, "x")];
+    assert_eq!(apply_fixes("abcd", &fixes), result("axd", 1, 1));
+  }
+
+  #[test]
+  fn multi_byte_characters() {
+    // "ü" and "日" are 2 and 3 bytes long
+    let source = "'ü' + '日';";
+    let fixes = [fix(0, 4, "\"ü\""), fix(7, 12, "\"日\"")];
+    assert_eq!(apply_fixes(source, &fixes), result("\"ü\" + \"日\";", 2, 0));
+  }
+
+  #[test]
+  fn skips_invalid_ranges() {
+    // Inside of "ü"
+    let fixes = [fix(0, 2, "x")];
+    assert_eq!(apply_fixes("'ü'", &fixes), result("'ü'", 0, 1));
+
+    // Past the end of the source
+    let fixes = [fix(2, 10, "x"), fix(0, 1, "y")];
+    assert_eq!(apply_fixes("abc", &fixes), result("ybc", 1, 1));
+
+    // Reversed
+    let fixes = [fix(2, 1, "x")];
+    assert_eq!(apply_fixes("abc", &fixes), result("abc", 0, 1));
+  }
+
+  #[test]
+  fn removes_bom() {
+    let fixes = [
+      LintFix {
+        removes_bom: true,
+        ..fix(0, 0, "")
+      },
+      fix(0, 3, "const"),
+    ];
+    let result = apply_fixes("let a = 1;", &fixes);
+    assert_eq!(result.applied, 1);
+    assert!(!result.removes_bom);
+
+    let result = apply_fixes("let a = 1;", &fixes[..1]);
+    assert_eq!(result.text, "let a = 1;");
+    assert!(result.removes_bom);
+  }
+}
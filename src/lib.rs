@@ -7,12 +7,14 @@ extern crate log;
 #[macro_use]
 mod test_util;
 
+mod ast_eq;
 pub mod ast_parser;
 pub mod context;
 // TODO(magurotuna): Making control_flow public is just needed for implementing plugin prototype.
 // It will be likely possible to remove `pub` later.
 pub mod control_flow;
 pub mod diagnostic;
+pub mod fix;
 mod globals;
 mod handler;
 mod ignore_directives;
@@ -31,6 +33,8 @@ mod lint_tests {
   use crate::linter::*;
   use crate::rules::{get_recommended_rules, LintRule};
   use crate::test_util::{assert_diagnostic, parse};
+  use deno_ast::swc::common::DUMMY_SP;
+  use deno_ast::swc::parser::{Syntax, TsConfig};
   use deno_ast::ParsedSource;
   use std::sync::Arc;
 
@@ -219,4 +223,74 @@ const _foo = 42;
     let diagnostics = lint_recommended_rules_with_ast(&parsed_source);
     assert!(diagnostics.is_empty());
   }
+
+  #[test]
+  fn unicode_bom_with_ast() {
+    use crate::rules::unicode_bom::{
+      UnicodeBom, UnicodeBomMode, UnicodeBomOptions,
+    };
+    let rules = || -> Arc<Vec<Box<dyn LintRule>>> {
+      Arc::new(vec![UnicodeBom::with_options(UnicodeBomOptions {
+        mode: UnicodeBomMode::Always,
+      })])
+    };
+
+    // The byte order mark is stripped while parsing, so the rule can't tell
+    // whether the source had one
+    let parsed_source = parse("\u{FEFF}const a = 1;");
+    assert!(lint_with_ast(&parsed_source, rules()).is_empty());
+    let parsed_source = parse("const a = 1;");
+    assert!(lint_with_ast(&parsed_source, rules()).is_empty());
+
+    let diagnostics = lint("const a = 1;", rules());
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code, "unicode-bom");
+  }
+
+  #[derive(Debug)]
+  struct JsxOnlyRule;
+
+  impl LintRule for JsxOnlyRule {
+    fn new() -> Box<Self> {
+      Box::new(JsxOnlyRule)
+    }
+
+    fn lint_program<'view>(
+      &self,
+      context: &mut crate::context::Context<'view>,
+      _program: crate::ProgramRef<'view>,
+    ) {
+      context.add_diagnostic(DUMMY_SP, "jsx-only", "JSX file");
+    }
+
+    fn code(&self) -> &'static str {
+      "jsx-only"
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+      &["jsx"]
+    }
+  }
+
+  #[test]
+  fn jsx_rules_only_run_on_jsx_files() {
+    let diagnostics = lint("const a = 1;", Arc::new(vec![JsxOnlyRule::new()]));
+    assert!(diagnostics.is_empty());
+
+    let linter = LinterBuilder::default()
+      .syntax(Syntax::Typescript(TsConfig {
+        tsx: true,
+        ..Default::default()
+      }))
+      .rules(Arc::new(vec![JsxOnlyRule::new()]))
+      .build();
+    let (_, diagnostics) = linter
+      .lint(
+        "lint_test.tsx".to_string(),
+        "const a = <div />;".to_string(),
+      )
+      .expect("Failed to lint");
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code, "jsx-only");
+  }
 }
@@ -13,10 +13,12 @@ use crate::scopes::Scope;
 use deno_ast::swc::common::SyntaxContext;
 use deno_ast::swc::parser::Syntax;
 use deno_ast::view::ProgramRef;
-use deno_ast::ParsedSource;
+use deno_ast::{get_syntax, MediaType, ParsedSource};
 use std::sync::Arc;
 use std::time::Instant;
 
+const BOM_CHAR: char = '\u{FEFF}';
+
 pub use deno_ast::view::SourceFile;
 
 #[derive(Default)]
@@ -108,6 +110,9 @@ impl Linter {
   ) -> Result<(ParsedSource, Vec<LintDiagnostic>), SwcDiagnostic> {
     let start = Instant::now();
 
+    // The byte order mark is stripped by the parser, so its presence is
+    // recorded beforehand
+    let has_bom = source_code.starts_with(BOM_CHAR);
     let parse_result =
       self
         .ast_parser
@@ -118,31 +123,43 @@ impl Linter {
       end_parse_program - start
     );
     let parsed_source = parse_result?;
-    let diagnostics = self.lint_program(&parsed_source);
+    let diagnostics =
+      self.lint_program(&parsed_source, self.syntax, Some(has_bom));
 
     let end = Instant::now();
     debug!("Linter::lint took {:#?}", end - start);
     Ok((parsed_source, diagnostics))
   }
 
+  /// Lints an already parsed source. Since the byte order mark has been
+  /// stripped while parsing, whether the source had one isn't known.
   pub fn lint_with_ast(
     mut self,
     parsed_source: &ParsedSource,
   ) -> Vec<LintDiagnostic> {
     let start = Instant::now();
-    let diagnostics = self.lint_program(parsed_source);
+    // The syntax the source was parsed with is derived from its media type
+    let syntax = match parsed_source.media_type() {
+      MediaType::Unknown => self.syntax,
+      media_type => get_syntax(media_type),
+    };
+    let diagnostics = self.lint_program(parsed_source, syntax, None);
     let end = Instant::now();
     debug!("Linter::lint_with_ast took {:#?}", end - start);
 
     diagnostics
   }
 
-  fn filter_diagnostics(&self, mut context: Context) -> Vec<LintDiagnostic> {
+  fn filter_diagnostics(
+    &self,
+    mut context: Context,
+    executed_rules: &[&dyn LintRule],
+  ) -> Vec<LintDiagnostic> {
     let start = Instant::now();
 
     let mut filtered_diagnostics = context.check_ignore_directive_usage();
     // Run `ban-unused-ignore`
-    filtered_diagnostics.extend(context.ban_unused_ignore(&self.rules));
+    filtered_diagnostics.extend(context.ban_unused_ignore(executed_rules));
     // Run `ban-unknown-rule-code`
     filtered_diagnostics.extend(context.ban_unknown_rule_code());
     filtered_diagnostics.sort_by_key(|d| d.range.start.line_index);
@@ -156,6 +173,8 @@ impl Linter {
   fn lint_program(
     &mut self,
     parsed_source: &ParsedSource,
+    syntax: Syntax,
+    has_bom: Option<bool>,
   ) -> Vec<LintDiagnostic> {
     let start = Instant::now();
 
@@ -190,10 +209,18 @@ impl Linter {
         scope,
         control_flow,
         top_level_ctxt,
+        has_bom,
       );
 
-      // Run builtin rules
-      for rule in self.rules.iter() {
+      // Run builtin rules, skipping the ones which don't apply to the syntax
+      // of this file (e.g. JSX-only rules in a plain TypeScript file)
+      let executed_rules: Vec<&dyn LintRule> = self
+        .rules
+        .iter()
+        .map(|rule| &**rule)
+        .filter(|rule| rule.applies_to_syntax(syntax))
+        .collect();
+      for rule in &executed_rules {
         rule.lint_program_with_ast_view(&mut context, pg);
       }
 
@@ -203,7 +230,7 @@ impl Linter {
         let _ = plugin.run(&mut context, parsed_source.program_ref().into());
       }
 
-      self.filter_diagnostics(context)
+      self.filter_diagnostics(context, &executed_rules)
     });
 
     let end = Instant::now();
@@ -2,32 +2,51 @@
 use crate::context::Context;
 use crate::Program;
 use crate::ProgramRef;
+use deno_ast::swc::parser::Syntax;
 use std::collections::HashSet;
 use std::sync::Arc;
 
+pub mod accessor_pairs;
 pub mod adjacent_overload_signatures;
+pub mod array_callback_return;
 pub mod ban_ts_comment;
 pub mod ban_types;
 pub mod ban_unknown_rule_code;
 pub mod ban_untagged_ignore;
 pub mod ban_untagged_todo;
 pub mod ban_unused_ignore;
+pub mod block_scoped_var;
 pub mod camelcase;
+pub mod complexity;
 pub mod constructor_super;
 pub mod default_param_last;
+pub mod dot_notation;
 pub mod eqeqeq;
 pub mod explicit_function_return_type;
 pub mod explicit_module_boundary_types;
 pub mod for_direction;
 pub mod getter_return;
+pub mod guard_for_in;
+pub mod id_length;
+pub mod init_declarations;
+pub mod jsx_key;
+pub mod jsx_no_duplicate_props;
+pub mod jsx_no_useless_fragment;
+pub mod logical_assignment_operators;
+pub mod max_lines;
+pub mod max_lines_per_function;
+pub mod max_nested_callbacks;
+pub mod max_statements;
 pub mod no_array_constructor;
 pub mod no_async_promise_executor;
 pub mod no_await_in_loop;
+pub mod no_await_in_promise_methods;
 pub mod no_case_declarations;
 pub mod no_class_assign;
 pub mod no_compare_neg_zero;
 pub mod no_cond_assign;
 pub mod no_const_assign;
+pub mod no_constant_binary_expression;
 pub mod no_constant_condition;
 pub mod no_control_regex;
 pub mod no_debugger;
@@ -38,63 +57,114 @@ pub mod no_dupe_class_members;
 pub mod no_dupe_else_if;
 pub mod no_dupe_keys;
 pub mod no_duplicate_case;
+pub mod no_duplicate_string_literals;
+pub mod no_duplicate_type_constituents;
 pub mod no_empty;
 pub mod no_empty_character_class;
 pub mod no_empty_enum;
+pub mod no_empty_function;
 pub mod no_empty_interface;
 pub mod no_empty_pattern;
+pub mod no_empty_static_block;
 pub mod no_eval;
 pub mod no_ex_assign;
 pub mod no_explicit_any;
+pub mod no_extra_bind;
 pub mod no_extra_boolean_cast;
 pub mod no_extra_non_null_assertion;
 pub mod no_extra_semi;
 pub mod no_fallthrough;
+pub mod no_floating_decimal;
+pub mod no_floating_promises;
 pub mod no_func_assign;
 pub mod no_global_assign;
+pub mod no_implicit_globals;
+pub mod no_implied_eval;
 pub mod no_import_assign;
 pub mod no_inferrable_types;
 pub mod no_inner_declarations;
 pub mod no_invalid_regexp;
 pub mod no_invalid_triple_slash_reference;
 pub mod no_irregular_whitespace;
+pub mod no_label_var;
+pub mod no_lone_blocks;
 pub mod no_misused_new;
+pub mod no_mixed_operators;
+pub mod no_multi_assign;
 pub mod no_namespace;
+pub mod no_negated_condition;
+pub mod no_new_func;
+pub mod no_new_object;
 pub mod no_new_symbol;
 pub mod no_non_null_asserted_optional_chain;
 pub mod no_non_null_assertion;
+pub mod no_nonoctal_decimal_escape;
 pub mod no_obj_calls;
 pub mod no_octal;
+pub mod no_octal_escape;
+pub mod no_param_reassign;
 pub mod no_prototype_builtins;
 pub mod no_redeclare;
 pub mod no_regex_spaces;
+pub mod no_restricted_exports;
+pub mod no_restricted_globals;
+pub mod no_restricted_properties;
 pub mod no_self_assign;
+pub mod no_self_import;
 pub mod no_setter_return;
+pub mod no_shadow;
 pub mod no_shadow_restricted_names;
 pub mod no_sparse_arrays;
+pub mod no_ternary;
 pub mod no_this_alias;
 pub mod no_this_before_super;
 pub mod no_throw_literal;
 pub mod no_undef;
+pub mod no_undef_init;
+pub mod no_undefined;
+pub mod no_underscore_dangle;
+pub mod no_unmodified_loop_condition;
+pub mod no_unnecessary_boolean_literal_compare;
+pub mod no_unnecessary_condition;
 pub mod no_unreachable;
+pub mod no_unreachable_loop;
 pub mod no_unsafe_finally;
 pub mod no_unsafe_negation;
 pub mod no_unused_labels;
 pub mod no_unused_vars;
+pub mod no_use_before_define;
+pub mod no_useless_call;
+pub mod no_useless_escape;
+pub mod no_useless_spread;
 pub mod no_var;
 pub mod no_window_prefix;
 pub mod no_with;
+pub mod one_var;
 pub mod prefer_as_const;
 pub mod prefer_ascii;
+pub mod prefer_at;
 pub mod prefer_const;
+pub mod prefer_includes;
+pub mod prefer_named_capture_group;
 pub mod prefer_namespace_keyword;
+pub mod prefer_nullish_coalescing;
+pub mod prefer_object_has_own;
+pub mod prefer_optional_catch_binding;
+pub mod prefer_optional_chain;
 pub mod prefer_primordials;
+pub mod prefer_readonly;
+pub mod prefer_rest_params;
+pub mod prefer_spread;
+pub mod prefer_string_starts_ends_with;
+pub mod require_array_sort_compare;
 pub mod require_await;
 pub mod require_yield;
 pub mod single_var_declarator;
 pub mod triple_slash_reference;
+pub mod unicode_bom;
 pub mod use_isnan;
 pub mod valid_typeof;
+pub mod wrap_iife;
 
 const DUMMY_NODE: () = ();
 
@@ -135,6 +205,13 @@ pub trait LintRule: std::fmt::Debug + Send + Sync {
     &[]
   }
 
+  /// Returns whether this rule should be run on a file parsed with the given
+  /// syntax. By default, rules tagged with `jsx` are only run on files which
+  /// are parsed with JSX enabled, and all other rules are run on every file.
+  fn applies_to_syntax(&self, syntax: Syntax) -> bool {
+    !self.tags().contains(&"jsx") || syntax.jsx()
+  }
+
   /// Returns the documentation string for this rule, describing what this rule is for with several
   /// examples.
   #[cfg(feature = "docs")]
@@ -146,10 +223,16 @@ pub fn get_all_rules() -> Arc<Vec<Box<dyn LintRule>>> {
 }
 
 pub fn get_recommended_rules() -> Arc<Vec<Box<dyn LintRule>>> {
+  filter_rules_by_tag(&["recommended"])
+}
+
+/// Returns all rules which have at least one of the given tags, in the same
+/// order as `get_all_rules`.
+pub fn filter_rules_by_tag(tags: &[&str]) -> Arc<Vec<Box<dyn LintRule>>> {
   Arc::new(
     get_all_rules_raw()
       .into_iter()
-      .filter(|r| r.tags().contains(&"recommended"))
+      .filter(|rule| rule.tags().iter().any(|tag| tags.contains(tag)))
       .collect(),
   )
 }
@@ -212,29 +295,47 @@ pub fn get_filtered_rules(
 
 fn get_all_rules_raw() -> Vec<Box<dyn LintRule>> {
   vec![
+    accessor_pairs::AccessorPairs::new(),
     adjacent_overload_signatures::AdjacentOverloadSignatures::new(),
+    array_callback_return::ArrayCallbackReturn::new(),
     ban_ts_comment::BanTsComment::new(),
     ban_types::BanTypes::new(),
     ban_unknown_rule_code::BanUnknownRuleCode::new(),
     ban_untagged_ignore::BanUntaggedIgnore::new(),
     ban_untagged_todo::BanUntaggedTodo::new(),
     ban_unused_ignore::BanUnusedIgnore::new(),
+    block_scoped_var::BlockScopedVar::new(),
     camelcase::Camelcase::new(),
+    complexity::Complexity::new(),
     constructor_super::ConstructorSuper::new(),
     default_param_last::DefaultParamLast::new(),
+    dot_notation::DotNotation::new(),
     eqeqeq::Eqeqeq::new(),
     explicit_function_return_type::ExplicitFunctionReturnType::new(),
     explicit_module_boundary_types::ExplicitModuleBoundaryTypes::new(),
     for_direction::ForDirection::new(),
     getter_return::GetterReturn::new(),
+    guard_for_in::GuardForIn::new(),
+    id_length::IdLength::new(),
+    init_declarations::InitDeclarations::new(),
+    jsx_key::JsxKey::new(),
+    jsx_no_duplicate_props::JsxNoDuplicateProps::new(),
+    jsx_no_useless_fragment::JsxNoUselessFragment::new(),
+    logical_assignment_operators::LogicalAssignmentOperators::new(),
+    max_lines::MaxLines::new(),
+    max_lines_per_function::MaxLinesPerFunction::new(),
+    max_nested_callbacks::MaxNestedCallbacks::new(),
+    max_statements::MaxStatements::new(),
     no_array_constructor::NoArrayConstructor::new(),
     no_async_promise_executor::NoAsyncPromiseExecutor::new(),
     no_await_in_loop::NoAwaitInLoop::new(),
+    no_await_in_promise_methods::NoAwaitInPromiseMethods::new(),
     no_case_declarations::NoCaseDeclarations::new(),
     no_class_assign::NoClassAssign::new(),
     no_compare_neg_zero::NoCompareNegZero::new(),
     no_cond_assign::NoCondAssign::new(),
     no_const_assign::NoConstAssign::new(),
+    no_constant_binary_expression::NoConstantBinaryExpression::new(),
     no_constant_condition::NoConstantCondition::new(),
     no_control_regex::NoControlRegex::new(),
     no_debugger::NoDebugger::new(),
@@ -245,63 +346,114 @@ fn get_all_rules_raw() -> Vec<Box<dyn LintRule>> {
     no_dupe_else_if::NoDupeElseIf::new(),
     no_dupe_keys::NoDupeKeys::new(),
     no_duplicate_case::NoDuplicateCase::new(),
+    no_duplicate_string_literals::NoDuplicateStringLiterals::new(),
+    no_duplicate_type_constituents::NoDuplicateTypeConstituents::new(),
     no_empty::NoEmpty::new(),
     no_empty_character_class::NoEmptyCharacterClass::new(),
     no_empty_enum::NoEmptyEnum::new(),
+    no_empty_function::NoEmptyFunction::new(),
     no_empty_interface::NoEmptyInterface::new(),
     no_empty_pattern::NoEmptyPattern::new(),
+    no_empty_static_block::NoEmptyStaticBlock::new(),
     no_eval::NoEval::new(),
     no_ex_assign::NoExAssign::new(),
     no_explicit_any::NoExplicitAny::new(),
+    no_extra_bind::NoExtraBind::new(),
     no_extra_boolean_cast::NoExtraBooleanCast::new(),
     no_extra_non_null_assertion::NoExtraNonNullAssertion::new(),
     no_extra_semi::NoExtraSemi::new(),
     no_fallthrough::NoFallthrough::new(),
+    no_floating_decimal::NoFloatingDecimal::new(),
+    no_floating_promises::NoFloatingPromises::new(),
     no_func_assign::NoFuncAssign::new(),
     no_global_assign::NoGlobalAssign::new(),
+    no_implicit_globals::NoImplicitGlobals::new(),
+    no_implied_eval::NoImpliedEval::new(),
     no_import_assign::NoImportAssign::new(),
     no_inferrable_types::NoInferrableTypes::new(),
     no_inner_declarations::NoInnerDeclarations::new(),
     no_invalid_regexp::NoInvalidRegexp::new(),
     no_invalid_triple_slash_reference::NoInvalidTripleSlashReference::new(),
     no_irregular_whitespace::NoIrregularWhitespace::new(),
+    no_label_var::NoLabelVar::new(),
+    no_lone_blocks::NoLoneBlocks::new(),
     no_misused_new::NoMisusedNew::new(),
+    no_mixed_operators::NoMixedOperators::new(),
+    no_multi_assign::NoMultiAssign::new(),
     no_namespace::NoNamespace::new(),
+    no_negated_condition::NoNegatedCondition::new(),
+    no_new_func::NoNewFunc::new(),
+    no_new_object::NoNewObject::new(),
     no_new_symbol::NoNewSymbol::new(),
     no_non_null_asserted_optional_chain::NoNonNullAssertedOptionalChain::new(),
     no_non_null_assertion::NoNonNullAssertion::new(),
+    no_nonoctal_decimal_escape::NoNonoctalDecimalEscape::new(),
     no_obj_calls::NoObjCalls::new(),
     no_octal::NoOctal::new(),
+    no_octal_escape::NoOctalEscape::new(),
+    no_param_reassign::NoParamReassign::new(),
     no_prototype_builtins::NoPrototypeBuiltins::new(),
     no_redeclare::NoRedeclare::new(),
     no_regex_spaces::NoRegexSpaces::new(),
+    no_restricted_exports::NoRestrictedExports::new(),
+    no_restricted_globals::NoRestrictedGlobals::new(),
+    no_restricted_properties::NoRestrictedProperties::new(),
     no_self_assign::NoSelfAssign::new(),
+    no_self_import::NoSelfImport::new(),
     no_setter_return::NoSetterReturn::new(),
+    no_shadow::NoShadow::new(),
     no_shadow_restricted_names::NoShadowRestrictedNames::new(),
     no_sparse_arrays::NoSparseArrays::new(),
+    no_ternary::NoTernary::new(),
     no_this_alias::NoThisAlias::new(),
     no_this_before_super::NoThisBeforeSuper::new(),
     no_throw_literal::NoThrowLiteral::new(),
     no_undef::NoUndef::new(),
+    no_undef_init::NoUndefInit::new(),
+    no_undefined::NoUndefined::new(),
+    no_underscore_dangle::NoUnderscoreDangle::new(),
+    no_unmodified_loop_condition::NoUnmodifiedLoopCondition::new(),
+    no_unnecessary_boolean_literal_compare::NoUnnecessaryBooleanLiteralCompare::new(),
+    no_unnecessary_condition::NoUnnecessaryCondition::new(),
     no_unreachable::NoUnreachable::new(),
+    no_unreachable_loop::NoUnreachableLoop::new(),
     no_unsafe_finally::NoUnsafeFinally::new(),
     no_unsafe_negation::NoUnsafeNegation::new(),
     no_unused_labels::NoUnusedLabels::new(),
     no_unused_vars::NoUnusedVars::new(),
+    no_use_before_define::NoUseBeforeDefine::new(),
+    no_useless_call::NoUselessCall::new(),
+    no_useless_escape::NoUselessEscape::new(),
+    no_useless_spread::NoUselessSpread::new(),
     no_var::NoVar::new(),
     no_window_prefix::NoWindowPrefix::new(),
     no_with::NoWith::new(),
+    one_var::OneVar::new(),
     prefer_as_const::PreferAsConst::new(),
     prefer_ascii::PreferAscii::new(),
+    prefer_at::PreferAt::new(),
     prefer_const::PreferConst::new(),
+    prefer_includes::PreferIncludes::new(),
+    prefer_named_capture_group::PreferNamedCaptureGroup::new(),
     prefer_namespace_keyword::PreferNamespaceKeyword::new(),
+    prefer_nullish_coalescing::PreferNullishCoalescing::new(),
+    prefer_object_has_own::PreferObjectHasOwn::new(),
+    prefer_optional_catch_binding::PreferOptionalCatchBinding::new(),
+    prefer_optional_chain::PreferOptionalChain::new(),
     prefer_primordials::PreferPrimordials::new(),
+    prefer_readonly::PreferReadonly::new(),
+    prefer_rest_params::PreferRestParams::new(),
+    prefer_spread::PreferSpread::new(),
+    prefer_string_starts_ends_with::PreferStringStartsEndsWith::new(),
+    require_array_sort_compare::RequireArraySortCompare::new(),
     require_await::RequireAwait::new(),
     require_yield::RequireYield::new(),
     single_var_declarator::SingleVarDeclarator::new(),
     triple_slash_reference::TripleSlashReference::new(),
+    unicode_bom::UnicodeBom::new(),
     use_isnan::UseIsNaN::new(),
     valid_typeof::ValidTypeof::new(),
+    wrap_iife::WrapIife::new(),
   ]
 }
 
@@ -335,6 +487,53 @@ mod tests {
     }
   }
 
+  fn codes(rules: &[Box<dyn LintRule>]) -> Vec<&'static str> {
+    rules.iter().map(|r| r.code()).collect()
+  }
+
+  #[test]
+  fn test_filter_rules_by_tag() {
+    let jsx_rules = filter_rules_by_tag(&["jsx"]);
+    assert_eq!(
+      codes(&jsx_rules),
+      vec![
+        "jsx-key",
+        "jsx-no-duplicate-props",
+        "jsx-no-useless-fragment"
+      ]
+    );
+
+    let recommended_rules = filter_rules_by_tag(&["recommended"]);
+    assert!(recommended_rules
+      .iter()
+      .all(|r| r.tags().contains(&"recommended")));
+    assert!(codes(&recommended_rules).contains(&"no-debugger"));
+    assert!(!codes(&recommended_rules).contains(&"jsx-key"));
+
+    // Rules matching any of the tags are returned in alphabetical order,
+    // regardless of the order of the tags.
+    let rules = filter_rules_by_tag(&["recommended", "jsx"]);
+    assert_eq!(rules.len(), jsx_rules.len() + recommended_rules.len());
+    let mut sorted = codes(&rules);
+    sorted.sort_unstable();
+    assert_eq!(codes(&rules), sorted);
+    assert_eq!(
+      codes(&rules),
+      codes(&filter_rules_by_tag(&["jsx", "recommended"]))
+    );
+    assert!(codes(&rules).contains(&"jsx-key"));
+    assert!(codes(&rules).contains(&"no-debugger"));
+
+    let rules = filter_rules_by_tag(&[]);
+    assert!(rules.is_empty());
+
+    let rules = filter_rules_by_tag(&["unknown-tag"]);
+    assert!(rules.is_empty());
+
+    let rules = filter_rules_by_tag(&["jsx", "unknown-tag"]);
+    assert_eq!(codes(&rules), codes(&jsx_rules));
+  }
+
   #[test]
   fn test_get_filtered_rules() {
     let rules =
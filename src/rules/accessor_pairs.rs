@@ -0,0 +1,380 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::ProgramRef;
+use deno_ast::swc::ast::{
+  Class, ClassMember, Expr, Lit, MethodKind, ObjectLit, Prop, PropName,
+  PropOrSpread,
+};
+use deno_ast::swc::common::Span;
+use deno_ast::swc::visit::{noop_visit_type, Node, Visit, VisitWith};
+use derive_more::Display;
+use serde::Deserialize;
+
+#[derive(Debug, Default)]
+pub struct AccessorPairs {
+  options: AccessorPairsOptions,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct AccessorPairsOptions {
+  /// Whether a setter without a getter for the same property is reported.
+  pub set_without_get: bool,
+  /// Whether a getter without a setter for the same property is reported.
+  pub get_without_set: bool,
+  /// Whether the accessors of classes are checked, in addition to the ones of
+  /// object literals.
+  pub enforce_for_class_members: bool,
+}
+
+impl Default for AccessorPairsOptions {
+  fn default() -> Self {
+    Self {
+      set_without_get: true,
+      get_without_set: false,
+      enforce_for_class_members: true,
+    }
+  }
+}
+
+const CODE: &str = "accessor-pairs";
+
+#[derive(Display)]
+enum AccessorPairsMessage {
+  #[display(fmt = "Setter for '{}' is defined without a getter", _0)]
+  SetWithoutGet(String),
+  #[display(fmt = "Getter for '{}' is defined without a setter", _0)]
+  GetWithoutSet(String),
+}
+
+#[derive(Display)]
+enum AccessorPairsHint {
+  #[display(fmt = "Add a getter for the property, or remove the setter")]
+  AddGetter,
+  #[display(fmt = "Add a setter for the property, or remove the getter")]
+  AddSetter,
+}
+
+impl AccessorPairs {
+  pub fn with_options(options: AccessorPairsOptions) -> Box<Self> {
+    Box::new(AccessorPairs { options })
+  }
+}
+
+impl LintRule for AccessorPairs {
+  fn new() -> Box<Self> {
+    Box::new(AccessorPairs::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    let mut visitor = AccessorPairsVisitor::new(context, &self.options);
+    match program {
+      ProgramRef::Module(m) => visitor.visit_module(m, &DUMMY_NODE),
+      ProgramRef::Script(s) => visitor.visit_script(s, &DUMMY_NODE),
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/accessor_pairs.md")
+  }
+}
+
+/// Returns the name of a property key if it can be known statically. Computed
+/// keys are only supported when they are literals.
+fn static_prop_name(key: &PropName) -> Option<String> {
+  match key {
+    PropName::Ident(ident) => Some(ident.sym.to_string()),
+    PropName::Str(str) => Some(str.value.to_string()),
+    PropName::Num(num) => Some(num.value.to_string()),
+    PropName::BigInt(bigint) => Some(bigint.value.to_string()),
+    PropName::Computed(computed) => match &*computed.expr {
+      Expr::Lit(Lit::Str(str)) => Some(str.value.to_string()),
+      Expr::Lit(Lit::Num(num)) => Some(num.value.to_string()),
+      Expr::Tpl(tpl) if tpl.exprs.is_empty() => {
+        tpl.quasis.first().map(|quasi| quasi.raw.value.to_string())
+      }
+      _ => None,
+    },
+  }
+}
+
+#[derive(Clone, Copy)]
+enum AccessorKind {
+  Getter,
+  Setter,
+}
+
+/// The accessors defined for a property
+struct Accessors {
+  name: String,
+  is_static: bool,
+  getter: Option<Span>,
+  setter: Option<Span>,
+}
+
+/// Groups the accessors by property, keeping the order of their first
+/// definition.
+#[derive(Default)]
+struct AccessorGroups {
+  groups: Vec<Accessors>,
+}
+
+impl AccessorGroups {
+  fn add(
+    &mut self,
+    name: String,
+    is_static: bool,
+    kind: AccessorKind,
+    span: Span,
+  ) {
+    let index = match self
+      .groups
+      .iter()
+      .position(|group| group.name == name && group.is_static == is_static)
+    {
+      Some(index) => index,
+      None => {
+        self.groups.push(Accessors {
+          name,
+          is_static,
+          getter: None,
+          setter: None,
+        });
+        self.groups.len() - 1
+      }
+    };
+    let group = &mut self.groups[index];
+    let accessor = match kind {
+      AccessorKind::Getter => &mut group.getter,
+      AccessorKind::Setter => &mut group.setter,
+    };
+    accessor.get_or_insert(span);
+  }
+}
+
+struct AccessorPairsVisitor<'c, 'view, 'o> {
+  context: &'c mut Context<'view>,
+  options: &'o AccessorPairsOptions,
+}
+
+impl<'c, 'view, 'o> AccessorPairsVisitor<'c, 'view, 'o> {
+  fn new(
+    context: &'c mut Context<'view>,
+    options: &'o AccessorPairsOptions,
+  ) -> Self {
+    Self { context, options }
+  }
+
+  fn report_unpaired(&mut self, accessor_groups: AccessorGroups) {
+    for group in accessor_groups.groups {
+      match (group.getter, group.setter) {
+        (None, Some(setter)) if self.options.set_without_get => {
+          self.context.add_diagnostic_with_hint(
+            setter,
+            CODE,
+            AccessorPairsMessage::SetWithoutGet(group.name),
+            AccessorPairsHint::AddGetter,
+          );
+        }
+        (Some(getter), None) if self.options.get_without_set => {
+          self.context.add_diagnostic_with_hint(
+            getter,
+            CODE,
+            AccessorPairsMessage::GetWithoutSet(group.name),
+            AccessorPairsHint::AddSetter,
+          );
+        }
+        _ => {}
+      }
+    }
+  }
+}
+
+impl<'c, 'view, 'o> Visit for AccessorPairsVisitor<'c, 'view, 'o> {
+  noop_visit_type!();
+
+  fn visit_object_lit(&mut self, object_lit: &ObjectLit, _: &dyn Node) {
+    object_lit.visit_children_with(self);
+
+    let mut accessor_groups = AccessorGroups::default();
+    for prop in &object_lit.props {
+      let (key, kind, span) = match prop {
+        PropOrSpread::Prop(prop) => match &**prop {
+          Prop::Getter(getter) => {
+            (&getter.key, AccessorKind::Getter, getter.span)
+          }
+          Prop::Setter(setter) => {
+            (&setter.key, AccessorKind::Setter, setter.span)
+          }
+          _ => continue,
+        },
+        PropOrSpread::Spread(_) => continue,
+      };
+      if let Some(name) = static_prop_name(key) {
+        accessor_groups.add(name, false, kind, span);
+      }
+    }
+    self.report_unpaired(accessor_groups);
+  }
+
+  fn visit_class(&mut self, class: &Class, _: &dyn Node) {
+    class.visit_children_with(self);
+
+    if !self.options.enforce_for_class_members {
+      return;
+    }
+    let mut accessor_groups = AccessorGroups::default();
+    for member in &class.body {
+      let (name, is_static, kind, span) = match member {
+        ClassMember::Method(method) => {
+          let kind = match method.kind {
+            MethodKind::Getter => AccessorKind::Getter,
+            MethodKind::Setter => AccessorKind::Setter,
+            MethodKind::Method => continue,
+          };
+          match static_prop_name(&method.key) {
+            Some(name) => (name, method.is_static, kind, method.span),
+            None => continue,
+          }
+        }
+        ClassMember::PrivateMethod(method) => {
+          let kind = match method.kind {
+            MethodKind::Getter => AccessorKind::Getter,
+            MethodKind::Setter => AccessorKind::Setter,
+            MethodKind::Method => continue,
+          };
+          let name = format!("#{}", method.key.id.sym);
+          (name, method.is_static, kind, method.span)
+        }
+        _ => continue,
+      };
+      accessor_groups.add(name, is_static, kind, span);
+    }
+    self.report_unpaired(accessor_groups);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  #[test]
+  fn accessor_pairs_valid() {
+    assert_lint_ok! {
+      AccessorPairs,
+      "const o = { get a() { return 1; } };",
+      "const o = { get a() { return 1; }, set a(v) {} };",
+      "const o = { set a(v) {}, get a() { return 1; } };",
+      "const o = { set 'a'(v) {}, get a() { return 1; } };",
+      "const o = { set [1](v) {}, get 1() { return 1; } };",
+      "const o = { set [key](v) {} };",
+      "const o = { set [`a${b}`](v) {} };",
+      "class A { get a() { return 1; } set a(v) {} }",
+      "class A { static set a(v) {} static get a() { return 1; } }",
+      "class A { set #a(v) {} get #a() { return 1; } }",
+      "class A { set [key](v) {} }",
+    };
+
+    assert_lint_ok! {
+      AccessorPairs,
+      options: json!({ "setWithoutGet": false }),
+      "const o = { set a(v) {} };",
+    };
+
+    assert_lint_ok! {
+      AccessorPairs,
+      options: json!({ "enforceForClassMembers": false }),
+      "class A { set a(v) {} }",
+    };
+  }
+
+  #[test]
+  fn accessor_pairs_invalid() {
+    assert_lint_err! {
+      AccessorPairs,
+      "const o = { set a(v) {} };": [
+        {
+          col: 12,
+          message: AccessorPairsMessage::SetWithoutGet("a".to_string()),
+          hint: AccessorPairsHint::AddGetter,
+        },
+      ],
+      "const o = { get a() { return 1; }, set b(v) {} };": [
+        {
+          col: 35,
+          message: AccessorPairsMessage::SetWithoutGet("b".to_string()),
+          hint: AccessorPairsHint::AddGetter,
+        },
+      ],
+      "const o = { set ['a'](v) {}, ...{ get a() { return 1; } } };": [
+        {
+          col: 12,
+          message: AccessorPairsMessage::SetWithoutGet("a".to_string()),
+          hint: AccessorPairsHint::AddGetter,
+        },
+      ],
+      "class A { set a(v) {} }": [
+        {
+          col: 10,
+          message: AccessorPairsMessage::SetWithoutGet("a".to_string()),
+          hint: AccessorPairsHint::AddGetter,
+        },
+      ],
+      "class A { static get a() { return 1; } set a(v) {} }": [
+        {
+          col: 39,
+          message: AccessorPairsMessage::SetWithoutGet("a".to_string()),
+          hint: AccessorPairsHint::AddGetter,
+        },
+      ],
+      "class A { set #a(v) {} }": [
+        {
+          col: 10,
+          message: AccessorPairsMessage::SetWithoutGet("#a".to_string()),
+          hint: AccessorPairsHint::AddGetter,
+        },
+      ],
+      "const A = class { m() { return { set a(v) {} }; } };": [
+        {
+          col: 33,
+          message: AccessorPairsMessage::SetWithoutGet("a".to_string()),
+          hint: AccessorPairsHint::AddGetter,
+        },
+      ],
+    };
+
+    assert_lint_err! {
+      AccessorPairs,
+      options: json!({ "getWithoutSet": true }),
+      "const o = { get a() { return 1; } };": [
+        {
+          col: 12,
+          message: AccessorPairsMessage::GetWithoutSet("a".to_string()),
+          hint: AccessorPairsHint::AddSetter,
+        },
+      ],
+      "class A { get a() { return 1; } set b(v) {} }": [
+        {
+          col: 10,
+          message: AccessorPairsMessage::GetWithoutSet("a".to_string()),
+          hint: AccessorPairsHint::AddSetter,
+        },
+        {
+          col: 32,
+          message: AccessorPairsMessage::SetWithoutGet("b".to_string()),
+          hint: AccessorPairsHint::AddGetter,
+        },
+      ],
+    };
+  }
+}
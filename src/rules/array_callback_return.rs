@@ -0,0 +1,428 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::ProgramRef;
+use deno_ast::swc::ast::{
+  ArrowExpr, BlockStmt, BlockStmtOrExpr, CallExpr, Class, Expr, ExprOrSuper,
+  Function, ReturnStmt, UnaryOp,
+};
+use deno_ast::swc::common::{Span, Spanned};
+use deno_ast::swc::visit::{noop_visit_type, Node, Visit, VisitWith};
+use derive_more::Display;
+use serde::Deserialize;
+
+#[derive(Debug, Default)]
+pub struct ArrayCallbackReturn {
+  options: ArrayCallbackReturnOptions,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ArrayCallbackReturnOptions {
+  /// Whether `return;` without a value is allowed in callbacks.
+  pub allow_implicit: bool,
+  /// Whether callbacks of `forEach` are checked to not return a value.
+  pub check_for_each: bool,
+}
+
+const CODE: &str = "array-callback-return";
+
+/// Array methods whose callbacks are expected to return a value
+const ARRAY_METHODS: &[&str] = &[
+  "every",
+  "filter",
+  "find",
+  "findIndex",
+  "findLast",
+  "findLastIndex",
+  "flatMap",
+  "map",
+  "reduce",
+  "reduceRight",
+  "some",
+  "sort",
+  "toSorted",
+];
+
+#[derive(Display)]
+enum ArrayCallbackReturnMessage {
+  #[display(fmt = "Expected the callback of `{}()` to return a value", _0)]
+  Expected(String),
+  #[display(
+    fmt = "Expected the callback of `{}()` to return a value on all code paths",
+    _0
+  )]
+  ExpectedAlways(String),
+  #[display(fmt = "The callback of `forEach()` should not return a value")]
+  ForEachReturn,
+}
+
+#[derive(Display)]
+enum ArrayCallbackReturnHint {
+  #[display(fmt = "Return a value from the callback")]
+  Return,
+  #[display(fmt = "Remove the returned value, since `forEach()` ignores it")]
+  RemoveValue,
+}
+
+impl ArrayCallbackReturn {
+  pub fn with_options(options: ArrayCallbackReturnOptions) -> Box<Self> {
+    Box::new(ArrayCallbackReturn { options })
+  }
+}
+
+impl LintRule for ArrayCallbackReturn {
+  fn new() -> Box<Self> {
+    Box::new(ArrayCallbackReturn::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    let mut visitor = ArrayCallbackReturnVisitor::new(context, &self.options);
+    match program {
+      ProgramRef::Module(m) => visitor.visit_module(m, &DUMMY_NODE),
+      ProgramRef::Script(s) => visitor.visit_script(s, &DUMMY_NODE),
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/array_callback_return.md")
+  }
+}
+
+/// Returns the name of the method and the index of its callback argument if
+/// the call is like `array.map(callback)` or `Array.from(iterable, callback)`.
+fn callback_method(call_expr: &CallExpr) -> Option<(String, usize)> {
+  let member = match &call_expr.callee {
+    ExprOrSuper::Expr(callee) => match &**callee {
+      Expr::Member(member) if !member.computed => member,
+      _ => return None,
+    },
+    ExprOrSuper::Super(_) => return None,
+  };
+  let method = match &*member.prop {
+    Expr::Ident(prop) => prop.sym.to_string(),
+    _ => return None,
+  };
+  let is_array_from = method == "from"
+    && matches!(
+      &member.obj,
+      ExprOrSuper::Expr(obj)
+        if matches!(&**obj, Expr::Ident(ident) if ident.sym == *"Array")
+    );
+  if is_array_from {
+    Some(("Array.from".to_string(), 1))
+  } else if method == "forEach" || ARRAY_METHODS.contains(&method.as_str()) {
+    Some((method, 0))
+  } else {
+    None
+  }
+}
+
+/// Collects the return statements of a function, without those of nested
+/// functions.
+#[derive(Default)]
+struct ReturnCollector {
+  returns: Vec<Return>,
+}
+
+struct Return {
+  span: Span,
+  /// The span of the returned value, unless it is discarded with `void`
+  value: Option<Span>,
+  has_arg: bool,
+}
+
+impl Visit for ReturnCollector {
+  noop_visit_type!();
+
+  fn visit_return_stmt(&mut self, return_stmt: &ReturnStmt, _: &dyn Node) {
+    let arg = return_stmt.arg.as_deref();
+    self.returns.push(Return {
+      span: return_stmt.span,
+      value: arg.filter(|arg| !is_void(arg)).map(|arg| arg.span()),
+      has_arg: arg.is_some(),
+    });
+  }
+
+  fn visit_function(&mut self, _: &Function, _: &dyn Node) {}
+
+  fn visit_arrow_expr(&mut self, _: &ArrowExpr, _: &dyn Node) {}
+
+  fn visit_class(&mut self, _: &Class, _: &dyn Node) {}
+}
+
+/// Checks if the expression is like `void foo()`, which explicitly discards
+/// the value.
+fn is_void(expr: &Expr) -> bool {
+  matches!(expr, Expr::Unary(unary) if unary.op == UnaryOp::Void)
+}
+
+struct ArrayCallbackReturnVisitor<'c, 'view, 'o> {
+  context: &'c mut Context<'view>,
+  options: &'o ArrayCallbackReturnOptions,
+}
+
+impl<'c, 'view, 'o> ArrayCallbackReturnVisitor<'c, 'view, 'o> {
+  fn new(
+    context: &'c mut Context<'view>,
+    options: &'o ArrayCallbackReturnOptions,
+  ) -> Self {
+    Self { context, options }
+  }
+
+  fn check_callback(&mut self, method: &str, callback: &Expr) {
+    let (span, body) = match callback {
+      Expr::Paren(paren) => return self.check_callback(method, &paren.expr),
+      Expr::Fn(fn_expr) => {
+        let function = &fn_expr.function;
+        // Async functions and generators always return a value
+        if function.is_async || function.is_generator {
+          return;
+        }
+        match &function.body {
+          Some(body) => (fn_expr.function.span, body),
+          None => return,
+        }
+      }
+      Expr::Arrow(arrow_expr) => {
+        if arrow_expr.is_async {
+          return;
+        }
+        match &arrow_expr.body {
+          BlockStmtOrExpr::BlockStmt(body) => (arrow_expr.span, body),
+          BlockStmtOrExpr::Expr(expr) => {
+            if method == "forEach" && !is_void(expr) {
+              self.context.add_diagnostic_with_hint(
+                expr.span(),
+                CODE,
+                ArrayCallbackReturnMessage::ForEachReturn,
+                ArrayCallbackReturnHint::RemoveValue,
+              );
+            }
+            return;
+          }
+        }
+      }
+      _ => return,
+    };
+
+    if method == "forEach" {
+      self.check_for_each_body(body);
+    } else {
+      self.check_body(method, span, body);
+    }
+  }
+
+  fn check_body(&mut self, method: &str, span: Span, body: &BlockStmt) {
+    let mut collector = ReturnCollector::default();
+    body.visit_children_with(&mut collector);
+
+    if !self.options.allow_implicit {
+      for ret in &collector.returns {
+        if !ret.has_arg {
+          self.context.add_diagnostic_with_hint(
+            ret.span,
+            CODE,
+            ArrayCallbackReturnMessage::Expected(method.to_string()),
+            ArrayCallbackReturnHint::Return,
+          );
+        }
+      }
+    }
+
+    let can_fall_through = self
+      .context
+      .control_flow()
+      .meta(body.span.lo)
+      .map_or(true, |meta| meta.continues_execution());
+    if can_fall_through {
+      let message = if collector.returns.is_empty() {
+        ArrayCallbackReturnMessage::Expected(method.to_string())
+      } else {
+        ArrayCallbackReturnMessage::ExpectedAlways(method.to_string())
+      };
+      self.context.add_diagnostic_with_hint(
+        span,
+        CODE,
+        message,
+        ArrayCallbackReturnHint::Return,
+      );
+    }
+  }
+
+  fn check_for_each_body(&mut self, body: &BlockStmt) {
+    let mut collector = ReturnCollector::default();
+    body.visit_children_with(&mut collector);
+
+    for ret in collector.returns {
+      if let Some(value) = ret.value {
+        self.context.add_diagnostic_with_hint(
+          value,
+          CODE,
+          ArrayCallbackReturnMessage::ForEachReturn,
+          ArrayCallbackReturnHint::RemoveValue,
+        );
+      }
+    }
+  }
+}
+
+impl<'c, 'view, 'o> Visit for ArrayCallbackReturnVisitor<'c, 'view, 'o> {
+  noop_visit_type!();
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _: &dyn Node) {
+    call_expr.visit_children_with(self);
+
+    let (method, index) = match callback_method(call_expr) {
+      Some(method) => method,
+      None => return,
+    };
+    if method == "forEach" && !self.options.check_for_each {
+      return;
+    }
+    if let Some(callback) = call_expr.args.get(index) {
+      if callback.spread.is_none() {
+        self.check_callback(&method, &callback.expr);
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  #[test]
+  fn array_callback_return_valid() {
+    assert_lint_ok! {
+      ArrayCallbackReturn,
+      "arr.map(x => x * 2);",
+      "arr.map(function (x) { return x * 2; });",
+      "arr.filter(x => { if (x) { return true; } else { return false; } });",
+      "arr.find(x => { if (x) { return true; } throw new Error(); });",
+      "arr.reduce((acc, x) => { acc.push(x); return acc; }, []);",
+      "arr.sort((a, b) => { switch (a) { default: return b - a; } });",
+      "arr.map(async x => { await x; });",
+      "arr.map(function* (x) { yield x; });",
+      "arr.map(f);",
+      "arr.forEach(x => { return x; });",
+      "arr.forEach(x => x);",
+      "arr.unknown(x => {});",
+      "arr[map](x => {});",
+      "Array.from(xs, x => x * 2);",
+      "Array.from(xs);",
+      "arr.map(x => { function f() {} return f; });",
+      "arr.map(x => { const f = () => { return; }; return f; });",
+    };
+
+    assert_lint_ok! {
+      ArrayCallbackReturn,
+      options: json!({ "allowImplicit": true }),
+      "arr.map(x => { if (x) { return; } return x; });",
+    };
+
+    assert_lint_ok! {
+      ArrayCallbackReturn,
+      options: json!({ "checkForEach": true }),
+      "arr.forEach(x => { console.log(x); });",
+      "arr.forEach(x => { if (x) { return; } f(x); });",
+      "arr.forEach(x => void f(x));",
+      "arr.forEach(x => { return void f(x); });",
+    };
+  }
+
+  #[test]
+  fn array_callback_return_invalid() {
+    assert_lint_err! {
+      ArrayCallbackReturn,
+      "arr.map(x => {});": [
+        {
+          col: 8,
+          message: ArrayCallbackReturnMessage::Expected("map".to_string()),
+          hint: ArrayCallbackReturnHint::Return,
+        },
+      ],
+      "arr.filter(function (x) { x > 1; });": [
+        {
+          col: 11,
+          message: ArrayCallbackReturnMessage::Expected("filter".to_string()),
+          hint: ArrayCallbackReturnHint::Return,
+        },
+      ],
+      "arr.every(x => { if (x) { return true; } });": [
+        {
+          col: 10,
+          message: ArrayCallbackReturnMessage::ExpectedAlways(
+            "every".to_string()
+          ),
+          hint: ArrayCallbackReturnHint::Return,
+        },
+      ],
+      "arr.map(x => { if (x) { return; } return x; });": [
+        {
+          col: 24,
+          message: ArrayCallbackReturnMessage::Expected("map".to_string()),
+          hint: ArrayCallbackReturnHint::Return,
+        },
+      ],
+      "Array.from(xs, x => { x * 2; });": [
+        {
+          col: 15,
+          message: ArrayCallbackReturnMessage::Expected(
+            "Array.from".to_string()
+          ),
+          hint: ArrayCallbackReturnHint::Return,
+        },
+      ],
+      "arr.map(x => arr.some(y => { y; }));": [
+        {
+          col: 22,
+          message: ArrayCallbackReturnMessage::Expected("some".to_string()),
+          hint: ArrayCallbackReturnHint::Return,
+        },
+      ],
+    };
+
+    assert_lint_err! {
+      ArrayCallbackReturn,
+      options: json!({ "allowImplicit": true }),
+      "arr.map(x => { if (x) { return; } });": [
+        {
+          col: 8,
+          message: ArrayCallbackReturnMessage::ExpectedAlways(
+            "map".to_string()
+          ),
+          hint: ArrayCallbackReturnHint::Return,
+        },
+      ],
+    };
+
+    assert_lint_err! {
+      ArrayCallbackReturn,
+      options: json!({ "checkForEach": true }),
+      "arr.forEach(x => x);": [
+        {
+          col: 17,
+          message: ArrayCallbackReturnMessage::ForEachReturn,
+          hint: ArrayCallbackReturnHint::RemoveValue,
+        },
+      ],
+      "arr.forEach(function (x) { if (x) { return x; } });": [
+        {
+          col: 43,
+          message: ArrayCallbackReturnMessage::ForEachReturn,
+          hint: ArrayCallbackReturnHint::RemoveValue,
+        },
+      ],
+    };
+  }
+}
@@ -0,0 +1,237 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::ProgramRef;
+use deno_ast::swc::ast::{
+  BlockStmt, ForInStmt, ForOfStmt, ForStmt, Function, Ident, SwitchStmt,
+  VarDecl, VarDeclKind,
+};
+use deno_ast::swc::common::Span;
+use deno_ast::swc::utils::find_ids;
+use deno_ast::swc::utils::ident::IdentLike;
+use deno_ast::swc::utils::Id;
+use deno_ast::swc::visit::{noop_visit_type, Node, Visit, VisitWith};
+use derive_more::Display;
+use std::collections::{BTreeMap, HashMap};
+
+#[derive(Debug)]
+pub struct BlockScopedVar;
+
+const CODE: &str = "block-scoped-var";
+const HINT: &str =
+  "Declare the variable in the enclosing block or use `let` instead";
+
+#[derive(Display)]
+enum BlockScopedVarMessage {
+  #[display(fmt = "`{}` is used outside of the block it was declared in", _0)]
+  OutsideBlock(String),
+}
+
+impl LintRule for BlockScopedVar {
+  fn new() -> Box<Self> {
+    Box::new(BlockScopedVar)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    let mut visitor = BlockScopedVarVisitor::default();
+    match program {
+      ProgramRef::Module(m) => visitor.visit_module(m, &DUMMY_NODE),
+      ProgramRef::Script(s) => visitor.visit_script(s, &DUMMY_NODE),
+    }
+
+    let scope = context.scope();
+    // Keyed by position so that every use is reported once and in order
+    let mut uses = BTreeMap::new();
+    for (id, declarations) in &visitor.declarations {
+      let var = match scope.var(id) {
+        Some(var) => var,
+        None => continue,
+      };
+      let other_declarations = declarations.iter().map(|(ident, _)| *ident);
+      let spans: Vec<Span> = var
+        .references()
+        .iter()
+        .map(|reference| reference.span())
+        .chain(other_declarations)
+        .collect();
+
+      for (_, block) in declarations {
+        for span in &spans {
+          if !block.contains(*span) {
+            uses.insert(span.lo, (*span, id.0.to_string()));
+          }
+        }
+      }
+    }
+
+    for (span, name) in uses.into_values() {
+      context.add_diagnostic_with_hint(
+        span,
+        CODE,
+        BlockScopedVarMessage::OutsideBlock(name),
+        HINT,
+      );
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/block_scoped_var.md")
+  }
+}
+
+/// Collects `var` declarations along with the block they are declared in.
+/// Declarations at the top level of a function or a program aren't collected
+/// since they can't be used outside of it anyway.
+#[derive(Default)]
+struct BlockScopedVarVisitor {
+  blocks: Vec<Span>,
+  declarations: HashMap<Id, Vec<(Span, Span)>>,
+}
+
+impl BlockScopedVarVisitor {
+  fn with_block<F>(&mut self, span: Span, op: F)
+  where
+    F: FnOnce(&mut Self),
+  {
+    self.blocks.push(span);
+    op(self);
+    self.blocks.pop();
+  }
+}
+
+impl Visit for BlockScopedVarVisitor {
+  noop_visit_type!();
+
+  fn visit_var_decl(&mut self, var_decl: &VarDecl, _: &dyn Node) {
+    if var_decl.kind == VarDeclKind::Var {
+      if let Some(block) = self.blocks.last() {
+        for decl in &var_decl.decls {
+          let idents: Vec<Ident> = find_ids(&decl.name);
+          for ident in idents {
+            self
+              .declarations
+              .entry(ident.to_id())
+              .or_default()
+              .push((ident.span, *block));
+          }
+        }
+      }
+    }
+    var_decl.visit_children_with(self);
+  }
+
+  fn visit_function(&mut self, function: &Function, _: &dyn Node) {
+    let blocks = std::mem::take(&mut self.blocks);
+    function.decorators.visit_with(function, self);
+    function.params.visit_with(function, self);
+    if let Some(body) = &function.body {
+      body.stmts.visit_with(body, self);
+    }
+    self.blocks = blocks;
+  }
+
+  fn visit_block_stmt(&mut self, block_stmt: &BlockStmt, _: &dyn Node) {
+    self.with_block(block_stmt.span, |v| block_stmt.visit_children_with(v));
+  }
+
+  fn visit_for_stmt(&mut self, for_stmt: &ForStmt, _: &dyn Node) {
+    self.with_block(for_stmt.span, |v| for_stmt.visit_children_with(v));
+  }
+
+  fn visit_for_in_stmt(&mut self, for_in: &ForInStmt, _: &dyn Node) {
+    self.with_block(for_in.span, |v| for_in.visit_children_with(v));
+  }
+
+  fn visit_for_of_stmt(&mut self, for_of: &ForOfStmt, _: &dyn Node) {
+    self.with_block(for_of.span, |v| for_of.visit_children_with(v));
+  }
+
+  fn visit_switch_stmt(&mut self, switch_stmt: &SwitchStmt, _: &dyn Node) {
+    self.with_block(switch_stmt.span, |v| switch_stmt.visit_children_with(v));
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn block_scoped_var_valid() {
+    assert_lint_ok! {
+      BlockScopedVar,
+      "var a = 1; a;",
+      "function f() { var a; if (c) { a = 1; } else { a = 2; } return a; }",
+      "function f(c) { if (c) { var a = 1; a; } }",
+      "function f(c) { if (c) { var a = 1; g(() => a); } }",
+      "for (var i = 0; i < 3; i++) { i; }",
+      "for (var k in o) { k; }",
+      "for (const x of xs) { var y = x; y; }",
+      "switch (c) { case 0: var a = 1; break; case 1: a = 2; }",
+      "if (c) { function f() { var a = 1; } } a;",
+      "if (c) { let a = 1; } var a;",
+      "function f() { { var a = 1; a; } } a;",
+    };
+  }
+
+  #[test]
+  fn block_scoped_var_invalid() {
+    assert_lint_err! {
+      BlockScopedVar,
+      "function f(c) { if (c) { var a = 1; } return a; }": [
+        {
+          col: 45,
+          message: variant!(BlockScopedVarMessage, OutsideBlock, "a"),
+          hint: HINT,
+        },
+      ],
+      "function f(c) { if (c) { var a = 1; } else { var a = 2; } }": [
+        {
+          col: 29,
+          message: variant!(BlockScopedVarMessage, OutsideBlock, "a"),
+          hint: HINT,
+        },
+        {
+          col: 49,
+          message: variant!(BlockScopedVarMessage, OutsideBlock, "a"),
+          hint: HINT,
+        },
+      ],
+      "for (var i = 0; i < 3; i++) {} i;": [
+        {
+          col: 31,
+          message: variant!(BlockScopedVarMessage, OutsideBlock, "i"),
+          hint: HINT,
+        },
+      ],
+      "a = 0; { var a; }": [
+        {
+          col: 0,
+          message: variant!(BlockScopedVarMessage, OutsideBlock, "a"),
+          hint: HINT,
+        },
+      ],
+      "{ var { a, b: [c] } = o; } c;": [
+        {
+          col: 27,
+          message: variant!(BlockScopedVarMessage, OutsideBlock, "c"),
+          hint: HINT,
+        },
+      ],
+      "while (c) { var a = 1; } const f = () => a;": [
+        {
+          col: 41,
+          message: variant!(BlockScopedVarMessage, OutsideBlock, "a"),
+          hint: HINT,
+        },
+      ],
+    };
+  }
+}
@@ -8,16 +8,61 @@ use deno_ast::view as ast_view;
 use deno_ast::view::Spanned;
 use once_cell::sync::Lazy;
 use regex::{Captures, Regex};
+use serde::Deserialize;
 use std::collections::{BTreeMap, BTreeSet};
 
 #[derive(Debug)]
-pub struct Camelcase;
+pub struct Camelcase {
+  properties: PropertiesOption,
+  /// Compiled `allow` patterns
+  allow: Vec<Regex>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CamelcaseOptions {
+  /// Whether object property names are checked.
+  pub properties: PropertiesOption,
+  /// Regular expressions for identifier names that are always allowed, e.g.
+  /// `^UNSAFE_`. Invalid patterns are ignored.
+  pub allow: Vec<String>,
+}
+
+impl Default for CamelcaseOptions {
+  fn default() -> Self {
+    Self {
+      properties: PropertiesOption::Always,
+      allow: vec![],
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PropertiesOption {
+  Always,
+  Never,
+}
 
 const CODE: &str = "camelcase";
 
+impl Camelcase {
+  pub fn with_options(options: CamelcaseOptions) -> Box<Self> {
+    let allow = options
+      .allow
+      .iter()
+      .filter_map(|pattern| Regex::new(pattern).ok())
+      .collect();
+    Box::new(Camelcase {
+      properties: options.properties,
+      allow,
+    })
+  }
+}
+
 impl LintRule for Camelcase {
   fn new() -> Box<Self> {
-    Box::new(Camelcase)
+    Camelcase::with_options(CamelcaseOptions::default())
   }
 
   fn tags(&self) -> &'static [&'static str] {
@@ -41,7 +86,7 @@ impl LintRule for Camelcase {
     context: &mut Context,
     program: ast_view::Program,
   ) {
-    let mut handler = CamelcaseHandler::default();
+    let mut handler = CamelcaseHandler::new(self.properties, &self.allow);
     handler.traverse(program, context);
     handler.report_errors(context);
   }
@@ -338,15 +383,25 @@ impl IdentToCheck {
   }
 }
 
-#[derive(Default)]
-struct CamelcaseHandler {
+struct CamelcaseHandler<'a> {
+  properties: PropertiesOption,
+  allow: &'a [Regex],
   /// Accumulated errors to report
   errors: BTreeMap<Span, IdentToCheck>,
   /// Already visited identifiers
   visited: BTreeSet<Span>,
 }
 
-impl CamelcaseHandler {
+impl<'a> CamelcaseHandler<'a> {
+  fn new(properties: PropertiesOption, allow: &'a [Regex]) -> Self {
+    Self {
+      properties,
+      allow,
+      errors: BTreeMap::new(),
+      visited: BTreeSet::new(),
+    }
+  }
+
   /// Report accumulated errors, consuming `self`.
   fn report_errors(self, ctx: &mut Context) {
     for (span, error_ident) in self.errors {
@@ -362,7 +417,18 @@ impl CamelcaseHandler {
   /// Check if this ident is underscored only when it's not yet visited.
   fn check_ident<S: Spanned>(&mut self, span: &S, ident: IdentToCheck) {
     let span = span.span();
-    if self.visited.insert(span) && is_underscored(ident.get_ident_name()) {
+    if !self.visited.insert(span) {
+      return;
+    }
+
+    if self.properties == PropertiesOption::Never
+      && matches!(ident, IdentToCheck::ObjectKey { .. })
+    {
+      return;
+    }
+
+    let name = ident.get_ident_name();
+    if is_underscored(name) && !self.allow.iter().any(|re| re.is_match(name)) {
       self.errors.insert(span, ident);
     }
   }
@@ -498,7 +564,7 @@ impl CamelcaseHandler {
   }
 }
 
-impl Handler for CamelcaseHandler {
+impl<'a> Handler for CamelcaseHandler<'a> {
   fn fn_decl(&mut self, fn_decl: &ast_view::FnDecl, ctx: &mut Context) {
     if fn_decl.declare() {
       ctx.stop_traverse();
@@ -733,6 +799,7 @@ impl Handler for CamelcaseHandler {
 #[cfg(test)]
 mod tests {
   use super::*;
+  use serde_json::json;
 
   #[test]
   fn test_is_underscored() {
@@ -1252,4 +1319,65 @@ mod tests {
           ],
     };
   }
+
+  #[test]
+  fn camelcase_with_options_valid() {
+    assert_lint_ok! {
+      Camelcase,
+      options: json!({ "properties": "never" }),
+      r#"const obj = { snake_case: 1 };"#,
+      r#"const obj = { snake_case };"#,
+      r#"const obj = { get snake_case() { return 1; } };"#,
+      r#"interface Foo { some_property: number; }"#,
+    };
+
+    assert_lint_ok! {
+      Camelcase,
+      options: json!({ "allow": ["^UNSAFE_", "^legacy_"] }),
+      r#"function UNSAFE_componentWillMount() {}"#,
+      r#"const legacy_value = 42;"#,
+      r#"const obj = { UNSAFE_componentWillMount() {} };"#,
+    };
+  }
+
+  #[test]
+  fn camelcase_with_options_invalid() {
+    assert_lint_err! {
+      Camelcase,
+      options: json!({ "properties": "never" }),
+      r#"const snake_case = { some_key: 1 };"#: [
+        {
+          col: 6,
+          message: "Identifier 'snake_case' is not in camel case.",
+          hint: "Consider renaming `snake_case` to `snakeCase`",
+        }
+      ],
+      r#"const { some_key } = obj;"#: [
+        {
+          col: 8,
+          message: "Identifier 'some_key' is not in camel case.",
+          hint: "Consider replacing `{ some_key }` with `{ some_key: someKey }`",
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      Camelcase,
+      options: json!({ "allow": ["^UNSAFE_"] }),
+      r#"function unsafe_foo() {}"#: [
+        {
+          col: 9,
+          message: "Identifier 'unsafe_foo' is not in camel case.",
+          hint: "Consider renaming `unsafe_foo` to `unsafeFoo`",
+        }
+      ],
+      r#"const not_allowed = 1;"#: [
+        {
+          col: 6,
+          message: "Identifier 'not_allowed' is not in camel case.",
+          hint: "Consider renaming `not_allowed` to `notAllowed`",
+        }
+      ],
+    };
+  }
 }
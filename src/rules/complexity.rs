@@ -0,0 +1,337 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::swc_util::{describe_function, StringRepr};
+use crate::ProgramRef;
+use deno_ast::swc::ast::{
+  ArrowExpr, AssignExpr, AssignOp, BinExpr, BinaryOp, CatchClause, ClassMethod,
+  CondExpr, Constructor, DoWhileStmt, FnDecl, FnExpr, ForInStmt, ForOfStmt,
+  ForStmt, GetterProp, IfStmt, MethodProp, OptChainExpr, PrivateMethod,
+  SetterProp, SwitchCase, WhileStmt,
+};
+use deno_ast::swc::common::Span;
+use deno_ast::swc::visit::{noop_visit_type, Node, Visit, VisitWith};
+use serde::Deserialize;
+
+#[derive(Debug, Default)]
+pub struct Complexity {
+  options: ComplexityOptions,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ComplexityOptions {
+  /// Maximum cyclomatic complexity allowed per function. Defaults to `20`.
+  pub max: usize,
+}
+
+impl Default for ComplexityOptions {
+  fn default() -> Self {
+    Self { max: 20 }
+  }
+}
+
+const CODE: &str = "complexity";
+
+fn message(name: &str, complexity: usize, max: usize) -> String {
+  format!(
+    "{} has a complexity of {}. Maximum allowed is {}",
+    name, complexity, max
+  )
+}
+
+impl Complexity {
+  pub fn with_options(options: ComplexityOptions) -> Box<Self> {
+    Box::new(Complexity { options })
+  }
+}
+
+impl LintRule for Complexity {
+  fn new() -> Box<Self> {
+    Box::new(Complexity::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    let mut visitor = ComplexityVisitor::new(context, self.options.max);
+    match program {
+      ProgramRef::Module(m) => m.visit_with(&DUMMY_NODE, &mut visitor),
+      ProgramRef::Script(s) => s.visit_with(&DUMMY_NODE, &mut visitor),
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/complexity.md")
+  }
+}
+
+struct ComplexityVisitor<'c, 'view> {
+  context: &'c mut Context<'view>,
+  max: usize,
+  /// Complexity of each function enclosing the node currently visited. The
+  /// last one is the innermost function.
+  scores: Vec<usize>,
+}
+
+impl<'c, 'view> ComplexityVisitor<'c, 'view> {
+  fn new(context: &'c mut Context<'view>, max: usize) -> Self {
+    Self {
+      context,
+      max,
+      scores: Vec::new(),
+    }
+  }
+
+  /// Adds a decision point to the innermost function. Decision points outside
+  /// of functions are ignored.
+  fn increment(&mut self) {
+    if let Some(score) = self.scores.last_mut() {
+      *score += 1;
+    }
+  }
+
+  fn with_function<N: VisitWith<Self>>(
+    &mut self,
+    node: &N,
+    span: Span,
+    name: impl FnOnce() -> String,
+  ) {
+    self.scores.push(1);
+    node.visit_children_with(self);
+    let complexity = self.scores.pop().unwrap();
+
+    if complexity > self.max {
+      self.context.add_diagnostic(
+        span,
+        CODE,
+        message(&name(), complexity, self.max),
+      );
+    }
+  }
+}
+
+impl<'c, 'view> Visit for ComplexityVisitor<'c, 'view> {
+  noop_visit_type!();
+
+  fn visit_fn_decl(&mut self, fn_decl: &FnDecl, _: &dyn Node) {
+    self.with_function(fn_decl, fn_decl.function.span, || {
+      describe_function("Function", fn_decl.ident.string_repr())
+    });
+  }
+
+  fn visit_fn_expr(&mut self, fn_expr: &FnExpr, _: &dyn Node) {
+    self.with_function(fn_expr, fn_expr.function.span, || {
+      describe_function("Function", fn_expr.ident.string_repr())
+    });
+  }
+
+  fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr, _: &dyn Node) {
+    self.with_function(arrow_expr, arrow_expr.span, || {
+      "Arrow function".to_string()
+    });
+  }
+
+  fn visit_class_method(&mut self, class_method: &ClassMethod, _: &dyn Node) {
+    self.with_function(class_method, class_method.span, || {
+      describe_function("Method", class_method.key.string_repr())
+    });
+  }
+
+  fn visit_private_method(
+    &mut self,
+    private_method: &PrivateMethod,
+    _: &dyn Node,
+  ) {
+    self.with_function(private_method, private_method.span, || {
+      describe_function("Method", private_method.key.string_repr())
+    });
+  }
+
+  fn visit_constructor(&mut self, constructor: &Constructor, _: &dyn Node) {
+    self.with_function(constructor, constructor.span, || {
+      "Constructor".to_string()
+    });
+  }
+
+  fn visit_method_prop(&mut self, method_prop: &MethodProp, _: &dyn Node) {
+    self.with_function(method_prop, method_prop.function.span, || {
+      describe_function("Method", method_prop.key.string_repr())
+    });
+  }
+
+  fn visit_getter_prop(&mut self, getter_prop: &GetterProp, _: &dyn Node) {
+    self.with_function(getter_prop, getter_prop.span, || {
+      describe_function("Getter", getter_prop.key.string_repr())
+    });
+  }
+
+  fn visit_setter_prop(&mut self, setter_prop: &SetterProp, _: &dyn Node) {
+    self.with_function(setter_prop, setter_prop.span, || {
+      describe_function("Setter", setter_prop.key.string_repr())
+    });
+  }
+
+  fn visit_if_stmt(&mut self, if_stmt: &IfStmt, _: &dyn Node) {
+    self.increment();
+    if_stmt.visit_children_with(self);
+  }
+
+  fn visit_for_stmt(&mut self, for_stmt: &ForStmt, _: &dyn Node) {
+    self.increment();
+    for_stmt.visit_children_with(self);
+  }
+
+  fn visit_for_in_stmt(&mut self, for_in_stmt: &ForInStmt, _: &dyn Node) {
+    self.increment();
+    for_in_stmt.visit_children_with(self);
+  }
+
+  fn visit_for_of_stmt(&mut self, for_of_stmt: &ForOfStmt, _: &dyn Node) {
+    self.increment();
+    for_of_stmt.visit_children_with(self);
+  }
+
+  fn visit_while_stmt(&mut self, while_stmt: &WhileStmt, _: &dyn Node) {
+    self.increment();
+    while_stmt.visit_children_with(self);
+  }
+
+  fn visit_do_while_stmt(&mut self, do_while_stmt: &DoWhileStmt, _: &dyn Node) {
+    self.increment();
+    do_while_stmt.visit_children_with(self);
+  }
+
+  fn visit_switch_case(&mut self, switch_case: &SwitchCase, _: &dyn Node) {
+    // `default` is not a decision point
+    if switch_case.test.is_some() {
+      self.increment();
+    }
+    switch_case.visit_children_with(self);
+  }
+
+  fn visit_cond_expr(&mut self, cond_expr: &CondExpr, _: &dyn Node) {
+    self.increment();
+    cond_expr.visit_children_with(self);
+  }
+
+  fn visit_bin_expr(&mut self, bin_expr: &BinExpr, _: &dyn Node) {
+    if matches!(
+      bin_expr.op,
+      BinaryOp::LogicalAnd | BinaryOp::LogicalOr | BinaryOp::NullishCoalescing
+    ) {
+      self.increment();
+    }
+    bin_expr.visit_children_with(self);
+  }
+
+  fn visit_assign_expr(&mut self, assign_expr: &AssignExpr, _: &dyn Node) {
+    if matches!(
+      assign_expr.op,
+      AssignOp::AndAssign | AssignOp::OrAssign | AssignOp::NullishAssign
+    ) {
+      self.increment();
+    }
+    assign_expr.visit_children_with(self);
+  }
+
+  fn visit_catch_clause(&mut self, catch_clause: &CatchClause, _: &dyn Node) {
+    self.increment();
+    catch_clause.visit_children_with(self);
+  }
+
+  fn visit_opt_chain_expr(
+    &mut self,
+    opt_chain_expr: &OptChainExpr,
+    _: &dyn Node,
+  ) {
+    self.increment();
+    opt_chain_expr.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  #[test]
+  fn complexity_valid() {
+    assert_lint_ok! {
+      Complexity,
+      "function foo() { if (a) {} else if (b) {} }",
+      "if (a) { if (b) { if (c) {} } }",
+    };
+
+    assert_lint_ok! {
+      Complexity,
+      options: json!({ "max": 2 }),
+      "function foo() { if (a) {} }",
+      "function foo() { return a ? b : c; }",
+      "const foo = () => a ?? b;",
+      "const foo = () => { for (const x of xs) {} };",
+      // Nested functions get their own score
+      "function foo() { if (a) {} return () => { if (b) {} }; }",
+      "class Foo { bar() { if (a) {} } get baz() { return a && b; } }",
+      "switch (a) { case 1: break; case 2: break; default: break; }",
+      "function foo() { switch (a) { case 1: break; default: break; } }",
+    };
+  }
+
+  #[test]
+  fn complexity_invalid() {
+    assert_lint_err! {
+      Complexity,
+      options: json!({ "max": 2 }),
+      "function foo() { if (a) {} else if (b) {} }": [
+        { col: 0, message: message("Function `foo`", 3, 2) },
+      ],
+      "const foo = function() { return a || b || c; };": [
+        { col: 12, message: message("Function", 3, 2) },
+      ],
+      "const foo = () => { while (a) {} do {} while (b); };": [
+        { col: 12, message: message("Arrow function", 3, 2) },
+      ],
+      "function foo() { try {} catch { a?.b?.c; } }": [
+        { col: 0, message: message("Function `foo`", 4, 2) },
+      ],
+      "class Foo { bar() { for (;;) {} for (const x in y) {} } }": [
+        { col: 12, message: message("Method `bar`", 3, 2) },
+      ],
+      "class Foo { constructor() { a ||= b; a &&= c; } }": [
+        { col: 12, message: message("Constructor", 3, 2) },
+      ],
+      "const obj = { get foo() { return a ? b : c ? d : e; } };": [
+        { col: 14, message: message("Getter `foo`", 3, 2) },
+      ],
+      "function foo() { switch (a) { case 1: case 2: default: } }": [
+        { col: 0, message: message("Function `foo`", 3, 2) },
+      ],
+      "function foo() { if (a) {} return () => { if (b && c) {} }; }": [
+        { col: 34, message: message("Arrow function", 3, 2) },
+      ],
+    };
+
+    assert_lint_err! {
+      Complexity,
+      options: json!({ "max": 1 }),
+      r#"
+function outer() {
+  function inner() {
+    if (a) {}
+  }
+  if (b) {}
+}
+      "#: [
+        { line: 2, col: 0, message: message("Function `outer`", 2, 1) },
+        { line: 3, col: 2, message: message("Function `inner`", 2, 1) },
+      ],
+    };
+  }
+}
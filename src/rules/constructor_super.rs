@@ -1,18 +1,16 @@
 // Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
 use super::{Context, LintRule};
+use crate::control_flow::super_calls::SuperCalls;
 use crate::handler::{Handler, Traverse};
 use crate::{Program, ProgramRef};
 use deno_ast::view as ast_view;
 use deno_ast::view::{Span, Spanned};
-use if_chain::if_chain;
 
 #[derive(Debug)]
 pub struct ConstructorSuper;
 
 const CODE: &str = "constructor-super";
 
-// This rule currently differs from the ESlint implementation
-// as there is currently no way of handling code paths in dlint
 impl LintRule for ConstructorSuper {
   fn new() -> Box<Self> {
     Box::new(ConstructorSuper)
@@ -47,6 +45,7 @@ impl LintRule for ConstructorSuper {
 enum DiagnosticKind {
   TooManySuper,
   NoSuper,
+  NoSuperOnSomePaths,
   UnnecessaryConstructor,
   UnnecessarySuper,
 }
@@ -61,6 +60,7 @@ impl DiagnosticKind {
     match *self {
       DiagnosticKind::TooManySuper => "Constructors of derived classes must call super() only once",
       DiagnosticKind::NoSuper => "Constructors of derived classes must call super()",
+      DiagnosticKind::NoSuperOnSomePaths => "Constructors of derived classes must call super() on all code paths",
       DiagnosticKind::UnnecessaryConstructor => "Classes which inherit from a non constructor must not define a constructor",
       DiagnosticKind::UnnecessarySuper => "Constructors of non derived classes must not call super()",
     }
@@ -70,6 +70,9 @@ impl DiagnosticKind {
     match *self {
       DiagnosticKind::TooManySuper => "Remove extra calls to super()",
       DiagnosticKind::NoSuper => "Add call to super() in the constructor",
+      DiagnosticKind::NoSuperOnSomePaths => {
+        "Add call to super() to the code paths which lack it"
+      }
       DiagnosticKind::UnnecessaryConstructor => "Remove constructor",
       DiagnosticKind::UnnecessarySuper => "Remove call to super()",
     }
@@ -80,46 +83,8 @@ fn inherits_from_non_constructor(class: &ast_view::Class) -> bool {
   matches!(&class.super_class, Some(ast_view::Expr::Lit(_)))
 }
 
-fn super_call_spans(constructor: &ast_view::Constructor) -> Vec<Span> {
-  if let Some(block_stmt) = &constructor.body {
-    block_stmt
-      .stmts
-      .iter()
-      .filter_map(|stmt| extract_super_span(stmt))
-      .collect()
-  } else {
-    vec![]
-  }
-}
-
-fn extract_super_span(stmt: &ast_view::Stmt) -> Option<Span> {
-  if_chain! {
-    if let ast_view::Stmt::Expr(expr) = stmt;
-    if let ast_view::Expr::Call(call) = expr.expr;
-    if matches!(&call.callee, ast_view::ExprOrSuper::Super(_));
-    then {
-      Some(call.span())
-    } else {
-      None
-    }
-  }
-}
-
-fn return_before_super<'a, 'view>(
-  constructor: &'a ast_view::Constructor<'view>,
-) -> Option<&'a ast_view::ReturnStmt<'view>> {
-  if let Some(block_stmt) = &constructor.body {
-    for stmt in &block_stmt.stmts {
-      if extract_super_span(stmt).is_some() {
-        return None;
-      }
-
-      if let ast_view::Stmt::Return(ret) = stmt {
-        return Some(ret);
-      }
-    }
-  }
-  None
+fn add_diagnostic(kind: DiagnosticKind, span: Span, ctx: &mut Context) {
+  ctx.add_diagnostic_with_hint(span, CODE, kind.message(), kind.hint());
 }
 
 fn check_constructor(
@@ -128,68 +93,38 @@ fn check_constructor(
   ctx: &mut Context,
 ) {
   // Declarations shouldn't be linted
-  if cons.body.is_none() {
-    return;
-  }
+  let body = match &cons.inner.body {
+    Some(body) => body,
+    None => return,
+  };
+
+  let super_calls = SuperCalls::analyze(body, ctx.control_flow());
 
-  // returning value is a substitute of `super()`.
-  if let Some(ret) = return_before_super(cons) {
-    if ret.arg.is_none() && class.super_class.is_some() {
-      let kind = DiagnosticKind::NoSuper;
-      ctx.add_diagnostic_with_hint(
-        cons.span(),
-        CODE,
-        kind.message(),
-        kind.hint(),
-      );
+  if inherits_from_non_constructor(class) {
+    // Such a constructor must return a value on all code paths
+    if super_calls.first_call.is_some() || super_calls.missed_on_some_path {
+      add_diagnostic(DiagnosticKind::UnnecessaryConstructor, cons.span(), ctx);
     }
     return;
   }
 
-  if inherits_from_non_constructor(class) {
-    let kind = DiagnosticKind::UnnecessaryConstructor;
-    ctx.add_diagnostic_with_hint(
-      cons.span(),
-      CODE,
-      kind.message(),
-      kind.hint(),
-    );
+  if class.super_class.is_none() {
+    if let Some(span) = super_calls.first_call {
+      add_diagnostic(DiagnosticKind::UnnecessarySuper, span, ctx);
+    }
     return;
   }
 
-  let super_calls = super_call_spans(cons);
-
-  // in case where there are more than one `super()` calls.
-  for exceeded_super_span in super_calls.iter().skip(1) {
-    let kind = DiagnosticKind::TooManySuper;
-    ctx.add_diagnostic_with_hint(
-      *exceeded_super_span,
-      CODE,
-      kind.message(),
-      kind.hint(),
-    );
+  for span in &super_calls.duplicate_calls {
+    add_diagnostic(DiagnosticKind::TooManySuper, *span, ctx);
   }
-
-  match (super_calls.is_empty(), class.super_class.is_some()) {
-    (true, true) => {
-      let kind = DiagnosticKind::NoSuper;
-      ctx.add_diagnostic_with_hint(
-        cons.span(),
-        CODE,
-        kind.message(),
-        kind.hint(),
-      );
-    }
-    (false, false) => {
-      let kind = DiagnosticKind::UnnecessarySuper;
-      ctx.add_diagnostic_with_hint(
-        super_calls[0],
-        CODE,
-        kind.message(),
-        kind.hint(),
-      );
-    }
-    _ => {}
+  if super_calls.missed_on_some_path {
+    let kind = if super_calls.called_on_some_path {
+      DiagnosticKind::NoSuperOnSomePaths
+    } else {
+      DiagnosticKind::NoSuper
+    };
+    add_diagnostic(kind, cons.span(), ctx);
   }
 }
 
@@ -205,8 +140,7 @@ impl Handler for ConstructorSuperHandler {
   }
 }
 
-// most tests are taken from ESlint, commenting those
-// requiring code path support
+// most tests are taken from ESlint
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -227,13 +161,18 @@ mod tests {
       "class A extends B { }",
       "class A extends B { constructor() { super(); } }",
 
-      // TODO(magurotuna): control flow analysis is required to handle these cases
-      // "class A extends B { constructor() { if (true) { super(); } else { super(); } } }",
-      // "class A extends B { constructor() { a ? super() : super(); } }",
-      // "class A extends B { constructor() { if (a) super(); else super(); } }",
-      // "class A extends B { constructor() { switch (a) { case 0: super(); break; default: super(); } } }",
-      // "class A extends B { constructor() { try {} finally { super(); } } }",
-      // "class A extends B { constructor() { if (a) throw Error(); super(); } }",
+      // code paths.
+      "class A extends B { constructor() { if (true) { super(); } else { super(); } } }",
+      "class A extends B { constructor() { a ? super() : super(); } }",
+      "class A extends B { constructor() { if (a) super(); else super(); } }",
+      "class A extends B { constructor() { switch (a) { case 0: super(); break; default: super(); } } }",
+      "class A extends B { constructor() { switch (a) { case 0: case 1: super(); break; default: super(); } } }",
+      "class A extends B { constructor() { try {} finally { super(); } } }",
+      "class A extends B { constructor() { if (a) throw Error(); super(); } }",
+      "class A extends B { constructor() { try { super(); } catch (e) { throw e; } } }",
+      "class A extends B { constructor() { if (a) { super(); return; } super(); } }",
+      "class A extends B { constructor() { while (a) { if (b) return c; } super(); } }",
+      "class A extends B { constructor() { label: { if (a) { super(); break label; } super(); } } }",
 
       // derived classes.
       "class A extends (class B {}) { constructor() { super(); } }",
@@ -271,6 +210,8 @@ mod tests {
       DiagnosticKind::TooManySuper.message_and_hint();
     let (no_super_message, no_super_hint) =
       DiagnosticKind::NoSuper.message_and_hint();
+    let (no_super_on_some_paths_message, no_super_on_some_paths_hint) =
+      DiagnosticKind::NoSuperOnSomePaths.message_and_hint();
     let (unnecessary_constructor_message, unnecessary_constructor_hint) =
       DiagnosticKind::UnnecessaryConstructor.message_and_hint();
     let (unnecessary_super_message, unnecessary_super_hint) =
@@ -383,6 +324,74 @@ mod tests {
           hint: no_super_hint,
         }
       ],
+      "class A extends B { constructor() { if (a) super(); } }": [
+        {
+          col: 20,
+          message: no_super_on_some_paths_message,
+          hint: no_super_on_some_paths_hint,
+        }
+      ],
+      "class A extends B { constructor() { a && super(); } }": [
+        {
+          col: 20,
+          message: no_super_on_some_paths_message,
+          hint: no_super_on_some_paths_hint,
+        }
+      ],
+      "class A extends B { constructor() { if (a) return; super(); } }": [
+        {
+          col: 20,
+          message: no_super_on_some_paths_message,
+          hint: no_super_on_some_paths_hint,
+        }
+      ],
+      "class A extends B { constructor() { switch (a) { case 0: super(); break; } } }": [
+        {
+          col: 20,
+          message: no_super_on_some_paths_message,
+          hint: no_super_on_some_paths_hint,
+        }
+      ],
+      "class A extends B { constructor() { try { super(); } catch (e) {} } }": [
+        {
+          col: 20,
+          message: no_super_on_some_paths_message,
+          hint: no_super_on_some_paths_hint,
+        }
+      ],
+      "class A extends B { constructor() { if (a) super(); super(); } }": [
+        {
+          col: 52,
+          message: too_many_super_message,
+          hint: too_many_super_hint,
+        }
+      ],
+      "class A extends B { constructor() { do { super(); } while (a); } }": [
+        {
+          col: 41,
+          message: too_many_super_message,
+          hint: too_many_super_hint,
+        }
+      ],
+      "class A extends B { constructor() { for (const a of b) super(); } }": [
+        {
+          col: 55,
+          message: too_many_super_message,
+          hint: too_many_super_hint,
+        },
+        {
+          col: 20,
+          message: no_super_on_some_paths_message,
+          hint: no_super_on_some_paths_hint,
+        }
+      ],
+      "class A extends null { constructor() { if (a) return b; } }": [
+        {
+          col: 23,
+          message: unnecessary_constructor_message,
+          hint: unnecessary_constructor_hint,
+        }
+      ],
       r#"
 class A extends B {
   constructor() {
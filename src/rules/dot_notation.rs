@@ -0,0 +1,384 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{Expr, ExprOrSuper, Lit};
+use deno_ast::swc::common::{Span, Spanned};
+use deno_ast::view as ast_view;
+use derive_more::Display;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::Deserialize;
+
+#[derive(Debug)]
+pub struct DotNotation {
+  allow_keywords: bool,
+  /// Compiled `allow_pattern`
+  allow_pattern: Option<Regex>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct DotNotationOptions {
+  /// Whether keywords are allowed to be used as property names in dot
+  /// notation, e.g. `foo.class`.
+  pub allow_keywords: bool,
+  /// Regular expression for property names which are allowed to be accessed
+  /// with brackets, e.g. `^[a-z]+(_[a-z]+)+$` for snake case names. An invalid
+  /// pattern is ignored.
+  pub allow_pattern: Option<String>,
+}
+
+impl Default for DotNotationOptions {
+  fn default() -> Self {
+    Self {
+      allow_keywords: true,
+      allow_pattern: None,
+    }
+  }
+}
+
+const CODE: &str = "dot-notation";
+
+#[derive(Display)]
+enum DotNotationMessage {
+  #[display(fmt = "[\"{}\"] is better written in dot notation", _0)]
+  UseDot(String),
+  #[display(fmt = ".{} is a syntax keyword", _0)]
+  UseBrackets(String),
+}
+
+#[derive(Display)]
+enum DotNotationHint {
+  #[display(fmt = "Replace it with `.{}`", _0)]
+  UseDot(String),
+  #[display(fmt = "Replace it with `[\"{}\"]`", _0)]
+  UseBrackets(String),
+}
+
+impl DotNotation {
+  pub fn with_options(options: DotNotationOptions) -> Box<Self> {
+    let allow_pattern = options
+      .allow_pattern
+      .as_deref()
+      .and_then(|pattern| Regex::new(pattern).ok());
+    Box::new(DotNotation {
+      allow_keywords: options.allow_keywords,
+      allow_pattern,
+    })
+  }
+}
+
+impl LintRule for DotNotation {
+  fn new() -> Box<Self> {
+    DotNotation::with_options(DotNotationOptions::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    DotNotationHandler::new(self.allow_keywords, self.allow_pattern.as_ref())
+      .traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/dot_notation.md")
+  }
+}
+
+/// Keywords which were reserved in ECMAScript 3, where they were not allowed
+/// as property names in dot notation.
+const KEYWORDS: &[&str] = &[
+  "abstract",
+  "boolean",
+  "break",
+  "byte",
+  "case",
+  "catch",
+  "char",
+  "class",
+  "const",
+  "continue",
+  "debugger",
+  "default",
+  "delete",
+  "do",
+  "double",
+  "else",
+  "enum",
+  "export",
+  "extends",
+  "false",
+  "final",
+  "finally",
+  "float",
+  "for",
+  "function",
+  "goto",
+  "if",
+  "implements",
+  "import",
+  "in",
+  "instanceof",
+  "int",
+  "interface",
+  "long",
+  "native",
+  "new",
+  "null",
+  "package",
+  "private",
+  "protected",
+  "public",
+  "return",
+  "short",
+  "static",
+  "super",
+  "switch",
+  "synchronized",
+  "this",
+  "throw",
+  "throws",
+  "transient",
+  "true",
+  "try",
+  "typeof",
+  "var",
+  "void",
+  "volatile",
+  "while",
+  "with",
+];
+
+fn is_valid_identifier(name: &str) -> bool {
+  static IDENTIFIER: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^[a-zA-Z_$][a-zA-Z0-9_$]*$").unwrap());
+  IDENTIFIER.is_match(name)
+}
+
+/// Returns the value of a string literal or a template literal without
+/// substitutions.
+fn static_string(expr: &Expr) -> Option<String> {
+  match expr {
+    Expr::Lit(Lit::Str(str_lit)) => Some(str_lit.value.to_string()),
+    Expr::Tpl(tpl) if tpl.exprs.is_empty() => tpl
+      .quasis
+      .first()
+      .and_then(|quasi| quasi.cooked.as_ref())
+      .map(|cooked| cooked.value.to_string()),
+    _ => None,
+  }
+}
+
+struct DotNotationHandler<'a> {
+  allow_keywords: bool,
+  allow_pattern: Option<&'a Regex>,
+}
+
+impl<'a> DotNotationHandler<'a> {
+  fn new(allow_keywords: bool, allow_pattern: Option<&'a Regex>) -> Self {
+    Self {
+      allow_keywords,
+      allow_pattern,
+    }
+  }
+
+  fn is_allowed_in_brackets(&self, name: &str) -> bool {
+    !is_valid_identifier(name)
+      || (!self.allow_keywords && KEYWORDS.contains(&name))
+      || self
+        .allow_pattern
+        .map_or(false, |pattern| pattern.is_match(name))
+  }
+}
+
+impl<'a> Handler for DotNotationHandler<'a> {
+  fn member_expr(
+    &mut self,
+    member_expr: &ast_view::MemberExpr,
+    ctx: &mut Context,
+  ) {
+    let obj_span = match &member_expr.inner.obj {
+      ExprOrSuper::Expr(obj) => {
+        // `1.foo` would be parsed as a number
+        if matches!(&**obj, Expr::Lit(Lit::Num(_))) {
+          return;
+        }
+        obj.span()
+      }
+      ExprOrSuper::Super(super_) => super_.span,
+    };
+    let member_span = member_expr.inner.span;
+    // Text between the object and the property, e.g. `[`, `.` or `?.`
+    let accessor = ctx.file_text_substring(&Span::new(
+      obj_span.hi,
+      member_expr.inner.prop.span().lo,
+      Default::default(),
+    ));
+    let optional = accessor.trim_start().starts_with("?.");
+    let access_span =
+      Span::new(obj_span.hi, member_span.hi, Default::default());
+
+    if member_expr.inner.computed {
+      let name = match static_string(&member_expr.inner.prop) {
+        Some(name) => name,
+        None => return,
+      };
+      if self.is_allowed_in_brackets(&name) {
+        return;
+      }
+
+      let new_text = format!("{}{}", if optional { "?." } else { "." }, name);
+      let fix = ctx.create_fix(access_span, new_text, "Use dot notation");
+      ctx.add_diagnostic_with_hint_and_fix(
+        member_expr.inner.prop.span(),
+        CODE,
+        DotNotationMessage::UseDot(name.clone()),
+        DotNotationHint::UseDot(name),
+        fix,
+      );
+    } else if !self.allow_keywords {
+      let name = match &*member_expr.inner.prop {
+        Expr::Ident(ident) => ident.sym.to_string(),
+        _ => return,
+      };
+      if !KEYWORDS.contains(&name.as_str()) {
+        return;
+      }
+
+      let new_text =
+        format!("{}[\"{}\"]", if optional { "?." } else { "" }, name);
+      let fix = ctx.create_fix(access_span, new_text, "Use bracket notation");
+      ctx.add_diagnostic_with_hint_and_fix(
+        member_expr.inner.prop.span(),
+        CODE,
+        DotNotationMessage::UseBrackets(name.clone()),
+        DotNotationHint::UseBrackets(name),
+        fix,
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  #[test]
+  fn dot_notation_valid() {
+    assert_lint_ok! {
+      DotNotation,
+      "a.b;",
+      "a.b.c;",
+      "a['12'];",
+      "a[b];",
+      "a['foo-bar'];",
+      "a['foo bar'];",
+      "a[`${b}`];",
+      "a.class;",
+      "a[0];",
+      "1['foo'];",
+      "a['über'];",
+    };
+
+    assert_lint_ok! {
+      DotNotation,
+      options: json!({ "allowKeywords": false }),
+      "a['class'];",
+      "a['while'];",
+      "a.foo;",
+    };
+
+    assert_lint_ok! {
+      DotNotation,
+      options: json!({ "allowPattern": "^[a-z]+(_[a-z]+)+$" }),
+      "a['foo_bar'];",
+      "a['snake_case_name'];",
+    };
+  }
+
+  #[test]
+  fn dot_notation_invalid() {
+    assert_lint_err! {
+      DotNotation,
+      "a['b'];": [
+        {
+          col: 2,
+          message: DotNotationMessage::UseDot("b".to_string()),
+          hint: DotNotationHint::UseDot("b".to_string()),
+          fix: "a.b;",
+        },
+      ],
+      "a.b[\"c\"];": [
+        {
+          col: 4,
+          message: DotNotationMessage::UseDot("c".to_string()),
+          hint: DotNotationHint::UseDot("c".to_string()),
+          fix: "a.b.c;",
+        },
+      ],
+      "a[`b`] = 1;": [
+        {
+          col: 2,
+          message: DotNotationMessage::UseDot("b".to_string()),
+          hint: DotNotationHint::UseDot("b".to_string()),
+          fix: "a.b = 1;",
+        },
+      ],
+      "a?.['b'];": [
+        {
+          col: 4,
+          message: DotNotationMessage::UseDot("b".to_string()),
+          hint: DotNotationHint::UseDot("b".to_string()),
+          fix: "a?.b;",
+        },
+      ],
+      "a['class'];": [
+        {
+          col: 2,
+          message: DotNotationMessage::UseDot("class".to_string()),
+          hint: DotNotationHint::UseDot("class".to_string()),
+          fix: "a.class;",
+        },
+      ],
+    };
+
+    assert_lint_err! {
+      DotNotation,
+      options: json!({ "allowKeywords": false }),
+      "a.class;": [
+        {
+          col: 2,
+          message: DotNotationMessage::UseBrackets("class".to_string()),
+          hint: DotNotationHint::UseBrackets("class".to_string()),
+          fix: "a[\"class\"];",
+        },
+      ],
+    };
+
+    assert_lint_err! {
+      DotNotation,
+      options: json!({ "allowPattern": "^[a-z]+(_[a-z]+)+$" }),
+      "a['fooBar'];": [
+        {
+          col: 2,
+          message: DotNotationMessage::UseDot("fooBar".to_string()),
+          hint: DotNotationHint::UseDot("fooBar".to_string()),
+          fix: "a.fooBar;",
+        },
+      ],
+    };
+  }
+}
@@ -147,17 +147,21 @@ impl<'c, 'view> VisitAll for ForDirectionVisitor<'c, 'view> {
 
     if let Some(test) = &for_stmt.test {
       if let Expr::Bin(bin_expr) = &**test {
-        let counter_name = match &*bin_expr.left {
-          Expr::Ident(ident) => ident.sym.as_ref(),
-          _ => return,
-        };
-
         let wrong_direction = match &bin_expr.op {
           BinaryOp::Lt | BinaryOp::LtEq => -1,
           BinaryOp::Gt | BinaryOp::GtEq => 1,
           _ => return,
         };
 
+        // The counter may be on either side of the test, like `i < 10` or
+        // `10 > i`, in which case the direction is reversed.
+        let (counter_name, wrong_direction) =
+          match (&*bin_expr.left, &*bin_expr.right) {
+            (Expr::Ident(ident), _) => (ident.sym.as_ref(), wrong_direction),
+            (_, Expr::Ident(ident)) => (ident.sym.as_ref(), -wrong_direction),
+            _ => return,
+          };
+
         let update = for_stmt.update.as_ref().unwrap();
         let update_direction = match &**update {
           Expr::Update(update_expr) => {
@@ -221,6 +225,11 @@ mod tests {
       "for(let i = 0; i === 0; i++) {}",
       "for(let i = 0; i == 0; i++) {}",
       "for(let i = 0; i < 2; ++i) { for (let j = 0; j < 2; j++) {} }",
+      "for(let i = 0; 2 > i; i++) {}",
+      "for(let i = 0; 2 >= i; i += 1) {}",
+      "for(let i = 2; 0 < i; i--) {}",
+      "for(let i = 2; 0 <= i; i -= 1) {}",
+      "for(let i = 0; 2 > j; i--) {}",
     };
   }
 
@@ -345,6 +354,36 @@ mod tests {
         }
       ],
 
+      // counter on the right side
+      "for(let i = 0; 2 > i; i--) {}": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "for(let i = 0; 2 >= i; i -= 1) {}": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "for(let i = 2; 0 < i; i++) {}": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "for(let i = 2; 0 <= i; --i) {}": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+
       // nested
       r#"
 for (let i = 0; i < 2; i++) {
@@ -0,0 +1,188 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::ProgramRef;
+use deno_ast::swc::ast::{Expr, ForInStmt, IfStmt, MemberExpr, Stmt};
+use deno_ast::swc::visit::{noop_visit_type, Node, Visit, VisitWith};
+
+#[derive(Debug)]
+pub struct GuardForIn;
+
+const CODE: &str = "guard-for-in";
+const MESSAGE: &str =
+  "`for...in` loops also iterate over inherited enumerable properties";
+const HINT: &str =
+  "Check `Object.hasOwn(obj, key)` before using the property in the loop";
+
+impl LintRule for GuardForIn {
+  fn new() -> Box<Self> {
+    Box::new(GuardForIn)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    let mut visitor = GuardForInVisitor::new(context);
+    match program {
+      ProgramRef::Module(m) => visitor.visit_module(m, &DUMMY_NODE),
+      ProgramRef::Script(s) => visitor.visit_script(s, &DUMMY_NODE),
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/guard_for_in.md")
+  }
+}
+
+/// Checks if an expression refers to `hasOwnProperty` or `hasOwn`, like
+/// `obj.hasOwnProperty(key)` or `Object.hasOwn(obj, key)`.
+#[derive(Default)]
+struct OwnPropertyCheckFinder {
+  found: bool,
+}
+
+impl Visit for OwnPropertyCheckFinder {
+  noop_visit_type!();
+
+  fn visit_member_expr(&mut self, member_expr: &MemberExpr, _: &dyn Node) {
+    if let Expr::Ident(prop) = &*member_expr.prop {
+      if !member_expr.computed
+        && (prop.sym == *"hasOwnProperty" || prop.sym == *"hasOwn")
+      {
+        self.found = true;
+        return;
+      }
+    }
+    member_expr.visit_children_with(self);
+  }
+}
+
+fn is_own_property_check(if_stmt: &IfStmt) -> bool {
+  let mut finder = OwnPropertyCheckFinder::default();
+  if_stmt.test.visit_with(&DUMMY_NODE, &mut finder);
+  finder.found
+}
+
+/// Checks if the statement is `continue;` or `{ continue; }`.
+fn is_continue(stmt: &Stmt) -> bool {
+  match stmt {
+    Stmt::Continue(_) => true,
+    Stmt::Block(block) => matches!(&*block.stmts, [Stmt::Continue(_)]),
+    _ => false,
+  }
+}
+
+/// Checks if the body of a `for...in` loop filters the properties, either by
+/// being wrapped in an own property check, or by starting with
+/// `if (...) continue;`.
+fn is_guarded(body: &Stmt) -> bool {
+  let stmts = match body {
+    Stmt::Block(block) => &*block.stmts,
+    Stmt::Empty(_) => return true,
+    stmt => std::slice::from_ref(stmt),
+  };
+  match stmts {
+    [] => true,
+    [Stmt::If(if_stmt)] if is_own_property_check(if_stmt) => true,
+    [Stmt::If(if_stmt), ..] => {
+      if_stmt.alt.is_none() && is_continue(&if_stmt.cons)
+    }
+    _ => false,
+  }
+}
+
+struct GuardForInVisitor<'c, 'view> {
+  context: &'c mut Context<'view>,
+}
+
+impl<'c, 'view> GuardForInVisitor<'c, 'view> {
+  fn new(context: &'c mut Context<'view>) -> Self {
+    Self { context }
+  }
+}
+
+impl<'c, 'view> Visit for GuardForInVisitor<'c, 'view> {
+  noop_visit_type!();
+
+  fn visit_for_in_stmt(&mut self, for_in_stmt: &ForInStmt, _: &dyn Node) {
+    for_in_stmt.visit_children_with(self);
+
+    if !is_guarded(&for_in_stmt.body) {
+      self.context.add_diagnostic_with_hint(
+        for_in_stmt.span,
+        CODE,
+        MESSAGE,
+        HINT,
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn guard_for_in_valid() {
+    assert_lint_ok! {
+      GuardForIn,
+      "for (const key in obj);",
+      "for (const key in obj) {}",
+      "for (const key in obj) if (obj.hasOwnProperty(key)) f(key);",
+      "for (const key in obj) { if (Object.hasOwn(obj, key)) { f(key); } }",
+      r#"
+for (const key in obj) {
+  if (Object.prototype.hasOwnProperty.call(obj, key)) {
+    f(key);
+  }
+}
+      "#,
+      r#"
+for (const key in obj) {
+  if (!Object.hasOwn(obj, key)) continue;
+  f(key);
+}
+      "#,
+      r#"
+for (const key in obj) {
+  if (key.startsWith("_")) {
+    continue;
+  }
+  f(key);
+}
+      "#,
+      "for (const key of obj) { f(key); }",
+    };
+  }
+
+  #[test]
+  fn guard_for_in_invalid() {
+    assert_lint_err! {
+      GuardForIn,
+      "for (const key in obj) { f(key); }": [
+        { col: 0, message: MESSAGE, hint: HINT },
+      ],
+      "for (const key in obj) f(key);": [
+        { col: 0, message: MESSAGE, hint: HINT },
+      ],
+      "for (const key in obj) { if (key !== 'a') { f(key); } }": [
+        { col: 0, message: MESSAGE, hint: HINT },
+      ],
+      "for (const key in obj) { if (obj.hasOwnProperty(key)) {} f(key); }": [
+        { col: 0, message: MESSAGE, hint: HINT },
+      ],
+      "for (const key in obj) { if (!obj.hasOwn(key)) continue; else g(); }": [
+        { col: 0, message: MESSAGE, hint: HINT },
+      ],
+      "function f() { for (const a in x) { for (const b in y) {} g(); } }": [
+        { col: 15, message: MESSAGE, hint: HINT },
+      ],
+    };
+  }
+}
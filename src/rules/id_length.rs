@@ -0,0 +1,402 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{Expr, Ident, ObjectPatProp, Pat, PropName};
+use deno_ast::view as ast_view;
+use serde::Deserialize;
+
+#[derive(Debug, Default)]
+pub struct IdLength {
+  options: IdLengthOptions,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct IdLengthOptions {
+  /// Minimum length of identifiers. Defaults to `2`.
+  pub min: usize,
+  /// Maximum length of identifiers. No limit by default.
+  pub max: Option<usize>,
+  /// Whether object and class member names are checked.
+  pub properties: PropertiesOption,
+  /// Identifier names that are always allowed, e.g. `i` or `x`.
+  pub exceptions: Vec<String>,
+}
+
+impl Default for IdLengthOptions {
+  fn default() -> Self {
+    Self {
+      min: 2,
+      max: None,
+      properties: PropertiesOption::Always,
+      exceptions: vec![],
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PropertiesOption {
+  Always,
+  Never,
+}
+
+const CODE: &str = "id-length";
+
+fn too_short_message(name: &str, min: usize) -> String {
+  format!("Identifier name `{}` is too short (< {})", name, min)
+}
+
+fn too_long_message(name: &str, max: usize) -> String {
+  format!("Identifier name `{}` is too long (> {})", name, max)
+}
+
+impl IdLength {
+  pub fn with_options(options: IdLengthOptions) -> Box<Self> {
+    Box::new(IdLength { options })
+  }
+}
+
+impl LintRule for IdLength {
+  fn new() -> Box<Self> {
+    Box::new(IdLength::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    IdLengthHandler::new(&self.options).traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/id_length.md")
+  }
+}
+
+struct IdLengthHandler<'o> {
+  options: &'o IdLengthOptions,
+}
+
+impl<'o> IdLengthHandler<'o> {
+  fn new(options: &'o IdLengthOptions) -> Self {
+    Self { options }
+  }
+
+  fn check_ident(&self, ident: &Ident, ctx: &mut Context) {
+    let name = ident.sym.as_ref();
+    if self.options.exceptions.iter().any(|e| e == name) {
+      return;
+    }
+
+    let len = name.chars().count();
+    if len < self.options.min {
+      ctx.add_diagnostic(
+        ident.span,
+        CODE,
+        too_short_message(name, self.options.min),
+      );
+    } else if let Some(max) = self.options.max {
+      if len > max {
+        ctx.add_diagnostic(ident.span, CODE, too_long_message(name, max));
+      }
+    }
+  }
+
+  /// Checks the names bound by the given pattern. For destructuring with
+  /// renames, e.g. `const { foo: f } = obj;`, only the bound name `f` is
+  /// checked, not the source key `foo`.
+  fn check_pat(&self, pat: &Pat, ctx: &mut Context) {
+    match pat {
+      Pat::Ident(binding_ident) => self.check_ident(&binding_ident.id, ctx),
+      Pat::Array(array_pat) => {
+        for elem in array_pat.elems.iter().flatten() {
+          self.check_pat(elem, ctx);
+        }
+      }
+      Pat::Object(object_pat) => {
+        for prop in &object_pat.props {
+          match prop {
+            ObjectPatProp::KeyValue(kv) => self.check_pat(&kv.value, ctx),
+            ObjectPatProp::Assign(assign) => self.check_ident(&assign.key, ctx),
+            ObjectPatProp::Rest(rest) => self.check_pat(&rest.arg, ctx),
+          }
+        }
+      }
+      Pat::Rest(rest_pat) => self.check_pat(&rest_pat.arg, ctx),
+      Pat::Assign(assign_pat) => self.check_pat(&assign_pat.left, ctx),
+      Pat::Invalid(_) | Pat::Expr(_) => {}
+    }
+  }
+
+  fn check_prop_name(&self, prop_name: &PropName, ctx: &mut Context) {
+    if self.options.properties == PropertiesOption::Never {
+      return;
+    }
+
+    if let PropName::Ident(ident) = prop_name {
+      self.check_ident(ident, ctx);
+    }
+  }
+}
+
+impl<'o> Handler for IdLengthHandler<'o> {
+  fn var_declarator(
+    &mut self,
+    var_declarator: &ast_view::VarDeclarator,
+    ctx: &mut Context,
+  ) {
+    self.check_pat(&var_declarator.inner.name, ctx);
+  }
+
+  fn fn_decl(&mut self, fn_decl: &ast_view::FnDecl, ctx: &mut Context) {
+    self.check_ident(&fn_decl.inner.ident, ctx);
+  }
+
+  fn fn_expr(&mut self, fn_expr: &ast_view::FnExpr, ctx: &mut Context) {
+    if let Some(ident) = &fn_expr.inner.ident {
+      self.check_ident(ident, ctx);
+    }
+  }
+
+  fn class_decl(
+    &mut self,
+    class_decl: &ast_view::ClassDecl,
+    ctx: &mut Context,
+  ) {
+    self.check_ident(&class_decl.inner.ident, ctx);
+  }
+
+  fn class_expr(
+    &mut self,
+    class_expr: &ast_view::ClassExpr,
+    ctx: &mut Context,
+  ) {
+    if let Some(ident) = &class_expr.inner.ident {
+      self.check_ident(ident, ctx);
+    }
+  }
+
+  fn param(&mut self, param: &ast_view::Param, ctx: &mut Context) {
+    self.check_pat(&param.inner.pat, ctx);
+  }
+
+  fn arrow_expr(
+    &mut self,
+    arrow_expr: &ast_view::ArrowExpr,
+    ctx: &mut Context,
+  ) {
+    for param in &arrow_expr.inner.params {
+      self.check_pat(param, ctx);
+    }
+  }
+
+  fn catch_clause(
+    &mut self,
+    catch_clause: &ast_view::CatchClause,
+    ctx: &mut Context,
+  ) {
+    if let Some(param) = &catch_clause.inner.param {
+      self.check_pat(param, ctx);
+    }
+  }
+
+  fn key_value_prop(
+    &mut self,
+    key_value_prop: &ast_view::KeyValueProp,
+    ctx: &mut Context,
+  ) {
+    self.check_prop_name(&key_value_prop.inner.key, ctx);
+  }
+
+  fn method_prop(
+    &mut self,
+    method_prop: &ast_view::MethodProp,
+    ctx: &mut Context,
+  ) {
+    self.check_prop_name(&method_prop.inner.key, ctx);
+  }
+
+  fn getter_prop(
+    &mut self,
+    getter_prop: &ast_view::GetterProp,
+    ctx: &mut Context,
+  ) {
+    self.check_prop_name(&getter_prop.inner.key, ctx);
+  }
+
+  fn setter_prop(
+    &mut self,
+    setter_prop: &ast_view::SetterProp,
+    ctx: &mut Context,
+  ) {
+    self.check_prop_name(&setter_prop.inner.key, ctx);
+    self.check_pat(&setter_prop.inner.param, ctx);
+  }
+
+  fn class_method(
+    &mut self,
+    class_method: &ast_view::ClassMethod,
+    ctx: &mut Context,
+  ) {
+    self.check_prop_name(&class_method.inner.key, ctx);
+  }
+
+  fn class_prop(
+    &mut self,
+    class_prop: &ast_view::ClassProp,
+    ctx: &mut Context,
+  ) {
+    if self.options.properties == PropertiesOption::Never
+      || class_prop.inner.computed
+    {
+      return;
+    }
+
+    if let Expr::Ident(ident) = &*class_prop.inner.key {
+      self.check_ident(ident, ctx);
+    }
+  }
+
+  fn import_named_specifier(
+    &mut self,
+    import_named_specifier: &ast_view::ImportNamedSpecifier,
+    ctx: &mut Context,
+  ) {
+    self.check_ident(&import_named_specifier.inner.local, ctx);
+  }
+
+  fn import_default_specifier(
+    &mut self,
+    import_default_specifier: &ast_view::ImportDefaultSpecifier,
+    ctx: &mut Context,
+  ) {
+    self.check_ident(&import_default_specifier.inner.local, ctx);
+  }
+
+  fn import_star_as_specifier(
+    &mut self,
+    import_star_as_specifier: &ast_view::ImportStarAsSpecifier,
+    ctx: &mut Context,
+  ) {
+    self.check_ident(&import_star_as_specifier.inner.local, ctx);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  #[test]
+  fn id_length_valid() {
+    assert_lint_ok! {
+      IdLength,
+      "const xyz = 1;",
+      "function foo(bar, ...baz) {}",
+      "const fn = (ab, cd) => ab + cd;",
+      "try {} catch (err) {}",
+      "const { a: ab } = obj;",
+      "const [ab, { cd }] = arr;",
+      "const obj = { ab: 1, cd() {}, get ef() { return 1; } };",
+      "class Foo { ab = 1; cd() {} }",
+      "x = 1;",
+      "obj.a = 1;",
+      "const obj = { 'a': 1, [b]: 2 };",
+      "import { a as ab } from './mod.ts';",
+    };
+
+    assert_lint_ok! {
+      IdLength,
+      options: json!({ "properties": "never" }),
+      "const obj = { a: 1, b() {} };",
+      "class Foo { a = 1; b() {} }",
+    };
+
+    assert_lint_ok! {
+      IdLength,
+      options: json!({ "exceptions": ["i", "x"] }),
+      "for (let i = 0; i < 10; i++) {}",
+      "const x = 1;",
+    };
+
+    assert_lint_ok! {
+      IdLength,
+      options: json!({ "min": 1, "max": 5 }),
+      "const x = 1;",
+      "const abcde = 1;",
+    };
+  }
+
+  #[test]
+  fn id_length_invalid() {
+    assert_lint_err! {
+      IdLength,
+      "const x = 1;": [{ col: 6, message: too_short_message("x", 2) }],
+      "function f() {}": [{ col: 9, message: too_short_message("f", 2) }],
+      "function foo(a, ...b) {}": [
+        { col: 13, message: too_short_message("a", 2) },
+        { col: 19, message: too_short_message("b", 2) },
+      ],
+      "const fn = (a) => a;": [
+        { col: 12, message: too_short_message("a", 2) },
+      ],
+      "try {} catch (e) {}": [
+        { col: 14, message: too_short_message("e", 2) },
+      ],
+      "const { foo: f } = obj;": [
+        { col: 13, message: too_short_message("f", 2) },
+      ],
+      "const { a = 1 } = obj;": [
+        { col: 8, message: too_short_message("a", 2) },
+      ],
+      "const [a, ...b] = arr;": [
+        { col: 7, message: too_short_message("a", 2) },
+        { col: 13, message: too_short_message("b", 2) },
+      ],
+      "const obj = { a: 1 };": [
+        { col: 14, message: too_short_message("a", 2) },
+      ],
+      "const obj = { a() {} };": [
+        { col: 14, message: too_short_message("a", 2) },
+      ],
+      "class A { b = 1; c() {} }": [
+        { col: 6, message: too_short_message("A", 2) },
+        { col: 10, message: too_short_message("b", 2) },
+        { col: 17, message: too_short_message("c", 2) },
+      ],
+      "import a from './mod.ts';": [
+        { col: 7, message: too_short_message("a", 2) },
+      ],
+    };
+
+    assert_lint_err! {
+      IdLength,
+      options: json!({ "min": 3, "max": 5, "exceptions": ["i"] }),
+      "const ab = 1;": [{ col: 6, message: too_short_message("ab", 3) }],
+      "const abcdef = 1;": [{ col: 6, message: too_long_message("abcdef", 5) }],
+      "for (let i = 0, j = 0; ; ) {}": [
+        { col: 16, message: too_short_message("j", 3) },
+      ],
+    };
+
+    assert_lint_err! {
+      IdLength,
+      options: json!({ "properties": "never" }),
+      "const { a: b } = obj;": [
+        { col: 11, message: too_short_message("b", 2) },
+      ],
+    };
+  }
+}
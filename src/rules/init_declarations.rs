@@ -0,0 +1,225 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{Pat, VarDeclKind};
+use deno_ast::swc::common::Spanned;
+use deno_ast::view as ast_view;
+use derive_more::Display;
+use serde::Deserialize;
+
+#[derive(Debug, Default)]
+pub struct InitDeclarations {
+  options: InitDeclarationsOptions,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct InitDeclarationsOptions {
+  /// Whether variables must or must not be initialized on declaration.
+  pub mode: InitDeclarationsMode,
+  /// Whether declarations in the head of `for` loops are ignored.
+  pub ignore_for_loop_init: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum InitDeclarationsMode {
+  /// Variables must be initialized on declaration.
+  Always,
+  /// Variables must not be initialized on declaration, except for `const`.
+  Never,
+}
+
+impl Default for InitDeclarationsMode {
+  fn default() -> Self {
+    InitDeclarationsMode::Always
+  }
+}
+
+const CODE: &str = "init-declarations";
+
+#[derive(Display)]
+enum InitDeclarationsMessage {
+  #[display(fmt = "Variable `{}` should be initialized on declaration", _0)]
+  Always(String),
+  #[display(
+    fmt = "Variable `{}` should not be initialized on declaration",
+    _0
+  )]
+  Never(String),
+}
+
+impl InitDeclarations {
+  pub fn with_options(options: InitDeclarationsOptions) -> Box<Self> {
+    Box::new(InitDeclarations { options })
+  }
+}
+
+impl LintRule for InitDeclarations {
+  fn new() -> Box<Self> {
+    Box::new(InitDeclarations::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    InitDeclarationsHandler::new(&self.options).traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/init_declarations.md")
+  }
+}
+
+struct InitDeclarationsHandler<'o> {
+  options: &'o InitDeclarationsOptions,
+}
+
+impl<'o> InitDeclarationsHandler<'o> {
+  fn new(options: &'o InitDeclarationsOptions) -> Self {
+    Self { options }
+  }
+}
+
+impl<'o> Handler for InitDeclarationsHandler<'o> {
+  fn var_decl(&mut self, var_decl: &ast_view::VarDecl, ctx: &mut Context) {
+    if var_decl.inner.declare {
+      return;
+    }
+
+    let (is_for_loop_init, is_for_in_of) = match var_decl.parent() {
+      ast_view::Node::ForStmt(_) => (true, false),
+      ast_view::Node::ForInStmt(_) | ast_view::Node::ForOfStmt(_) => {
+        (true, true)
+      }
+      _ => (false, false),
+    };
+    if is_for_loop_init && self.options.ignore_for_loop_init {
+      return;
+    }
+
+    for decl in &var_decl.inner.decls {
+      // Variables declared in the head of `for-in` and `for-of` loops are
+      // initialized on each iteration
+      let is_initialized = decl.init.is_some() || is_for_in_of;
+      let name = || match &decl.name {
+        Pat::Ident(binding_ident) => binding_ident.id.sym.to_string(),
+        pat => ctx.file_text_substring(&pat.span()).to_string(),
+      };
+
+      let message = match self.options.mode {
+        InitDeclarationsMode::Always if !is_initialized => {
+          InitDeclarationsMessage::Always(name())
+        }
+        InitDeclarationsMode::Never
+          if is_initialized && var_decl.inner.kind != VarDeclKind::Const =>
+        {
+          InitDeclarationsMessage::Never(name())
+        }
+        _ => continue,
+      };
+      ctx.add_diagnostic(decl.span, CODE, message);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  #[test]
+  fn init_declarations_valid() {
+    assert_lint_ok! {
+      InitDeclarations,
+      "let foo = 1;",
+      "var foo = 1, bar = 2;",
+      "const foo = 1;",
+      "for (const x of xs) {}",
+      "for (const key in obj) {}",
+      "for (let i = 0; i < 10; i++) {}",
+      "declare let foo: number;",
+    };
+
+    assert_lint_ok! {
+      InitDeclarations,
+      options: json!({ "ignoreForLoopInit": true }),
+      "for (let i; i < 10; i++) {}",
+    };
+
+    assert_lint_ok! {
+      InitDeclarations,
+      options: json!({ "mode": "never" }),
+      "let foo;",
+      "var foo, bar;",
+      "const foo = 1;",
+      "for (const x of xs) {}",
+      "for (let i; i < 10; i++) {}",
+    };
+
+    assert_lint_ok! {
+      InitDeclarations,
+      options: json!({ "mode": "never", "ignoreForLoopInit": true }),
+      "for (let i = 0; i < 10; i++) {}",
+      "for (let x of xs) {}",
+      "for (var key in obj) {}",
+    };
+  }
+
+  #[test]
+  fn init_declarations_invalid() {
+    assert_lint_err! {
+      InitDeclarations,
+      "let foo;": [
+        { col: 4, message: InitDeclarationsMessage::Always("foo".to_string()) },
+      ],
+      "var foo = 1, bar;": [
+        {
+          col: 13,
+          message: InitDeclarationsMessage::Always("bar".to_string()),
+        },
+      ],
+      "for (let i; i < 10; i++) {}": [
+        { col: 9, message: InitDeclarationsMessage::Always("i".to_string()) },
+      ],
+      "function f() { let foo: number; }": [
+        {
+          col: 19,
+          message: InitDeclarationsMessage::Always("foo".to_string()),
+        },
+      ],
+    };
+
+    assert_lint_err! {
+      InitDeclarations,
+      options: json!({ "mode": "never" }),
+      "let foo = 1;": [
+        { col: 4, message: InitDeclarationsMessage::Never("foo".to_string()) },
+      ],
+      "var foo, bar = 1;": [
+        { col: 9, message: InitDeclarationsMessage::Never("bar".to_string()) },
+      ],
+      "let { a } = obj;": [
+        {
+          col: 4,
+          message: InitDeclarationsMessage::Never("{ a }".to_string()),
+        },
+      ],
+      "for (let x of xs) {}": [
+        { col: 9, message: InitDeclarationsMessage::Never("x".to_string()) },
+      ],
+    };
+  }
+}
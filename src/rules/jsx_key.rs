@@ -0,0 +1,283 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::ProgramRef;
+use deno_ast::swc::ast::{
+  ArrayLit, ArrowExpr, BinaryOp, BlockStmtOrExpr, CallExpr, Class, Expr,
+  ExprOrSuper, Function, JSXAttrName, JSXAttrOrSpread, ReturnStmt,
+};
+use deno_ast::swc::visit::{noop_visit_type, Node, Visit, VisitWith};
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct JsxKey;
+
+const CODE: &str = "jsx-key";
+
+#[derive(Clone, Copy, Display)]
+enum JsxKeyMessage {
+  #[display(fmt = "Missing `key` prop for element in array")]
+  InArray,
+  #[display(fmt = "Missing `key` prop for element in iterator")]
+  InIterator,
+}
+
+#[derive(Display)]
+enum JsxKeyHint {
+  #[display(fmt = "Add a `key` prop with a value unique among its siblings")]
+  AddKey,
+  #[display(
+    fmt = "Use `<Fragment key={{...}}>` instead of the `<>` shorthand"
+  )]
+  UseFragment,
+}
+
+impl LintRule for JsxKey {
+  fn new() -> Box<Self> {
+    Box::new(JsxKey)
+  }
+
+  fn tags(&self) -> &'static [&'static str] {
+    &["jsx"]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    let mut visitor = JsxKeyVisitor::new(context);
+    match program {
+      ProgramRef::Module(m) => visitor.visit_module(m, &DUMMY_NODE),
+      ProgramRef::Script(s) => visitor.visit_script(s, &DUMMY_NODE),
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/jsx_key.md")
+  }
+}
+
+/// Checks if the attributes contain a `key` prop. A spread attribute may not
+/// contain a key, so it doesn't count.
+fn has_key_prop(attrs: &[JSXAttrOrSpread]) -> bool {
+  attrs.iter().any(|attr| match attr {
+    JSXAttrOrSpread::JSXAttr(attr) => match &attr.name {
+      JSXAttrName::Ident(ident) => ident.sym == *"key",
+      JSXAttrName::JSXNamespacedName(_) => false,
+    },
+    JSXAttrOrSpread::SpreadElement(_) => false,
+  })
+}
+
+struct JsxKeyVisitor<'c, 'view> {
+  context: &'c mut Context<'view>,
+}
+
+impl<'c, 'view> JsxKeyVisitor<'c, 'view> {
+  fn new(context: &'c mut Context<'view>) -> Self {
+    Self { context }
+  }
+
+  /// Reports the expression if it is a JSX element without a `key` prop or a
+  /// shorthand fragment, which can't have one.
+  fn check_element(&mut self, expr: &Expr, message: JsxKeyMessage) {
+    match expr {
+      Expr::Paren(paren) => self.check_element(&paren.expr, message),
+      Expr::Cond(cond) => {
+        self.check_element(&cond.cons, message);
+        self.check_element(&cond.alt, message);
+      }
+      Expr::Bin(bin) if bin.op == BinaryOp::LogicalAnd => {
+        self.check_element(&bin.right, message);
+      }
+      Expr::JSXElement(element) => {
+        if !has_key_prop(&element.opening.attrs) {
+          self.context.add_diagnostic_with_hint(
+            element.span,
+            CODE,
+            message,
+            JsxKeyHint::AddKey,
+          );
+        }
+      }
+      Expr::JSXFragment(fragment) => {
+        self.context.add_diagnostic_with_hint(
+          fragment.span,
+          CODE,
+          message,
+          JsxKeyHint::UseFragment,
+        );
+      }
+      _ => {}
+    }
+  }
+}
+
+impl<'c, 'view> Visit for JsxKeyVisitor<'c, 'view> {
+  noop_visit_type!();
+
+  fn visit_array_lit(&mut self, array_lit: &ArrayLit, _: &dyn Node) {
+    array_lit.visit_children_with(self);
+
+    for elem in array_lit.elems.iter().flatten() {
+      if elem.spread.is_none() {
+        self.check_element(&elem.expr, JsxKeyMessage::InArray);
+      }
+    }
+  }
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _: &dyn Node) {
+    call_expr.visit_children_with(self);
+
+    let is_map_call = matches!(
+      &call_expr.callee,
+      ExprOrSuper::Expr(callee) if matches!(
+        &**callee,
+        Expr::Member(member)
+          if !member.computed
+            && matches!(&*member.prop, Expr::Ident(prop) if prop.sym == *"map")
+      )
+    );
+    if !is_map_call {
+      return;
+    }
+    let callback = match call_expr.args.first() {
+      Some(arg) if arg.spread.is_none() => &*arg.expr,
+      _ => return,
+    };
+
+    let body = match callback {
+      Expr::Arrow(arrow) => match &arrow.body {
+        BlockStmtOrExpr::Expr(expr) => {
+          self.check_element(expr, JsxKeyMessage::InIterator);
+          return;
+        }
+        BlockStmtOrExpr::BlockStmt(block) => block,
+      },
+      Expr::Fn(fn_expr) => match &fn_expr.function.body {
+        Some(body) => body,
+        None => return,
+      },
+      _ => return,
+    };
+    body.visit_with(&DUMMY_NODE, &mut ReturnedElementChecker { visitor: self });
+  }
+}
+
+/// Checks the elements returned from the callback of an iterator, without
+/// descending into nested functions and classes.
+struct ReturnedElementChecker<'a, 'c, 'view> {
+  visitor: &'a mut JsxKeyVisitor<'c, 'view>,
+}
+
+impl<'a, 'c, 'view> Visit for ReturnedElementChecker<'a, 'c, 'view> {
+  noop_visit_type!();
+
+  fn visit_return_stmt(&mut self, return_stmt: &ReturnStmt, _: &dyn Node) {
+    if let Some(arg) = &return_stmt.arg {
+      self.visitor.check_element(arg, JsxKeyMessage::InIterator);
+    }
+  }
+
+  fn visit_function(&mut self, _: &Function, _: &dyn Node) {}
+
+  fn visit_arrow_expr(&mut self, _: &ArrowExpr, _: &dyn Node) {}
+
+  fn visit_class(&mut self, _: &Class, _: &dyn Node) {}
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn jsx_key_valid() {
+    assert_lint_ok! {
+      JsxKey,
+      filename: "foo.tsx",
+      r#"[<div key="a" />, <div key="b" />];"#,
+      r#"items.map((item) => <li key={item.id}>{item.name}</li>);"#,
+      r#"items.map((item) => { return <li key={item.id} />; });"#,
+      r#"items.map(function (item) { return <li key={item.id} />; });"#,
+      r#"items.map((item) => <Fragment key={item.id}><li /></Fragment>);"#,
+      r#"items.forEach((item) => <li />);"#,
+      r#"items.map((item) => { const f = () => <li />; return f; });"#,
+      r#"const a = <div><span /></div>;"#,
+      r#"[...elements];"#,
+    };
+  }
+
+  #[test]
+  fn jsx_key_invalid() {
+    assert_lint_err! {
+      JsxKey,
+      filename: "foo.tsx",
+      r#"[<div />, <div key="b" />];"#: [
+        {
+          col: 1,
+          message: JsxKeyMessage::InArray,
+          hint: JsxKeyHint::AddKey,
+        },
+      ],
+      r#"[<></>];"#: [
+        {
+          col: 1,
+          message: JsxKeyMessage::InArray,
+          hint: JsxKeyHint::UseFragment,
+        },
+      ],
+      r#"items.map((item) => <li>{item}</li>);"#: [
+        {
+          col: 20,
+          message: JsxKeyMessage::InIterator,
+          hint: JsxKeyHint::AddKey,
+        },
+      ],
+      r#"items.map((item) => (<li {...item} />));"#: [
+        {
+          col: 21,
+          message: JsxKeyMessage::InIterator,
+          hint: JsxKeyHint::AddKey,
+        },
+      ],
+      r#"items.map(function (item) { return <li />; });"#: [
+        {
+          col: 35,
+          message: JsxKeyMessage::InIterator,
+          hint: JsxKeyHint::AddKey,
+        },
+      ],
+      r#"items.map((item) => { if (item) { return <a />; } return <b />; });"#: [
+        {
+          col: 41,
+          message: JsxKeyMessage::InIterator,
+          hint: JsxKeyHint::AddKey,
+        },
+        {
+          col: 57,
+          message: JsxKeyMessage::InIterator,
+          hint: JsxKeyHint::AddKey,
+        },
+      ],
+      r#"items.map((item) => item ? <a /> : <b key="b" />);"#: [
+        {
+          col: 27,
+          message: JsxKeyMessage::InIterator,
+          hint: JsxKeyHint::AddKey,
+        },
+      ],
+      r#"items.map((item) => <><li /></>);"#: [
+        {
+          col: 20,
+          message: JsxKeyMessage::InIterator,
+          hint: JsxKeyHint::UseFragment,
+        },
+      ],
+    };
+  }
+}
@@ -0,0 +1,169 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::ProgramRef;
+use deno_ast::swc::ast::{JSXAttrName, JSXAttrOrSpread, JSXOpeningElement};
+use deno_ast::swc::visit::{noop_visit_type, Node, Visit, VisitWith};
+use derive_more::Display;
+use std::collections::HashSet;
+
+#[derive(Debug)]
+pub struct JsxNoDuplicateProps;
+
+const CODE: &str = "jsx-no-duplicate-props";
+
+#[derive(Display)]
+enum JsxNoDuplicatePropsMessage {
+  #[display(fmt = "Duplicate JSX attribute `{}`", _0)]
+  Duplicate(String),
+}
+
+#[derive(Display)]
+enum JsxNoDuplicatePropsHint {
+  #[display(fmt = "Remove one of the attributes, only the last one is used")]
+  Remove,
+}
+
+impl LintRule for JsxNoDuplicateProps {
+  fn new() -> Box<Self> {
+    Box::new(JsxNoDuplicateProps)
+  }
+
+  fn tags(&self) -> &'static [&'static str] {
+    &["jsx"]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    let mut visitor = JsxNoDuplicatePropsVisitor::new(context);
+    match program {
+      ProgramRef::Module(m) => visitor.visit_module(m, &DUMMY_NODE),
+      ProgramRef::Script(s) => visitor.visit_script(s, &DUMMY_NODE),
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/jsx_no_duplicate_props.md")
+  }
+}
+
+/// Returns the name of the attribute as written, e.g. `xlink:href` for
+/// namespaced names. Attribute names are case-sensitive in JSX.
+fn attr_name(name: &JSXAttrName) -> String {
+  match name {
+    JSXAttrName::Ident(ident) => ident.sym.to_string(),
+    JSXAttrName::JSXNamespacedName(namespaced) => {
+      format!("{}:{}", namespaced.ns.sym, namespaced.name.sym)
+    }
+  }
+}
+
+struct JsxNoDuplicatePropsVisitor<'c, 'view> {
+  context: &'c mut Context<'view>,
+}
+
+impl<'c, 'view> JsxNoDuplicatePropsVisitor<'c, 'view> {
+  fn new(context: &'c mut Context<'view>) -> Self {
+    Self { context }
+  }
+}
+
+impl<'c, 'view> Visit for JsxNoDuplicatePropsVisitor<'c, 'view> {
+  noop_visit_type!();
+
+  fn visit_jsx_opening_element(
+    &mut self,
+    opening_element: &JSXOpeningElement,
+    _: &dyn Node,
+  ) {
+    opening_element.visit_children_with(self);
+
+    let mut seen = HashSet::new();
+    for attr in &opening_element.attrs {
+      // Spread attributes can't be checked statically
+      if let JSXAttrOrSpread::JSXAttr(attr) = attr {
+        let name = attr_name(&attr.name);
+        if !seen.insert(name.clone()) {
+          self.context.add_diagnostic_with_hint(
+            attr.span,
+            CODE,
+            JsxNoDuplicatePropsMessage::Duplicate(name),
+            JsxNoDuplicatePropsHint::Remove,
+          );
+        }
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn jsx_no_duplicate_props_valid() {
+    assert_lint_ok! {
+      JsxNoDuplicateProps,
+      filename: "foo.tsx",
+      r#"<div a="1" b="2" />"#,
+      r#"<div a="1" {...props} />"#,
+      r#"<div {...a} {...a} />"#,
+      r#"<div onClick={f} onclick={g} />"#,
+      r#"<svg xlink:href="a" href="b" />"#,
+      r#"<div a="1"><div a="2" /></div>"#,
+    };
+  }
+
+  #[test]
+  fn jsx_no_duplicate_props_invalid() {
+    assert_lint_err! {
+      JsxNoDuplicateProps,
+      filename: "foo.tsx",
+      r#"<div a="1" /*ERR*/a="2" />"#: [
+        {
+          message: JsxNoDuplicatePropsMessage::Duplicate("a".to_string()),
+          hint: JsxNoDuplicatePropsHint::Remove,
+        },
+      ],
+      r#"<div a {...props} /*ERR*/a="2" />"#: [
+        {
+          message: JsxNoDuplicatePropsMessage::Duplicate("a".to_string()),
+          hint: JsxNoDuplicatePropsHint::Remove,
+        },
+      ],
+      r#"<div a /*ERR*/a /*ERR*/a />"#: [
+        {
+          message: JsxNoDuplicatePropsMessage::Duplicate("a".to_string()),
+          hint: JsxNoDuplicatePropsHint::Remove,
+        },
+        {
+          message: JsxNoDuplicatePropsMessage::Duplicate("a".to_string()),
+          hint: JsxNoDuplicatePropsHint::Remove,
+        },
+      ],
+      r#"<svg xlink:href="a" /*ERR*/xlink:href="b" />"#: [
+        {
+          message: JsxNoDuplicatePropsMessage::Duplicate(
+            "xlink:href".to_string()
+          ),
+          hint: JsxNoDuplicatePropsHint::Remove,
+        },
+      ],
+      r#"<div>
+  <span b={1} /*ERR*/b={2}></span>
+</div>"#: [
+        {
+          message: JsxNoDuplicatePropsMessage::Duplicate("b".to_string()),
+          hint: JsxNoDuplicatePropsHint::Remove,
+        },
+      ],
+    };
+  }
+}
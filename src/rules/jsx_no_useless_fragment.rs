@@ -0,0 +1,363 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::ProgramRef;
+use deno_ast::swc::ast::{
+  Expr, JSXAttrName, JSXAttrOrSpread, JSXElement, JSXElementChild,
+  JSXElementName, JSXExpr, JSXFragment, JSXObject,
+};
+use deno_ast::swc::common::Span;
+use deno_ast::swc::visit::{noop_visit_type, Node, Visit, VisitWith};
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct JsxNoUselessFragment;
+
+const CODE: &str = "jsx-no-useless-fragment";
+const HINT: &str = "Remove the fragment and keep its children";
+const FIX_DESC: &str = "Unwrap the fragment";
+
+#[derive(Display)]
+enum JsxNoUselessFragmentMessage {
+  #[display(fmt = "Empty fragments are unnecessary")]
+  Empty,
+  #[display(fmt = "Fragments with a single child are unnecessary")]
+  SingleChild,
+  #[display(fmt = "Fragments inside of HTML elements are unnecessary")]
+  ChildOfHtmlElement,
+}
+
+impl LintRule for JsxNoUselessFragment {
+  fn new() -> Box<Self> {
+    Box::new(JsxNoUselessFragment)
+  }
+
+  fn tags(&self) -> &'static [&'static str] {
+    &["jsx"]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    let mut visitor = JsxNoUselessFragmentVisitor::new(context);
+    match program {
+      ProgramRef::Module(m) => visitor.visit_module(m, &DUMMY_NODE),
+      ProgramRef::Script(s) => visitor.visit_script(s, &DUMMY_NODE),
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/jsx_no_useless_fragment.md")
+  }
+}
+
+/// A fragment, written either as `<>...</>` or as `<Fragment>...</Fragment>`.
+struct Fragment<'a> {
+  span: Span,
+  children: &'a [JSXElementChild],
+  /// The span between the opening and the closing tag, which is `None` for
+  /// self-closing fragments.
+  inner_span: Option<Span>,
+}
+
+impl<'a> Fragment<'a> {
+  fn from_fragment(fragment: &'a JSXFragment) -> Self {
+    Fragment {
+      span: fragment.span,
+      children: &fragment.children,
+      inner_span: Some(
+        fragment
+          .opening
+          .span
+          .with_lo(fragment.opening.span.hi)
+          .with_hi(fragment.closing.span.lo),
+      ),
+    }
+  }
+
+  /// Returns `None` if the element isn't a fragment, or if it is a keyed
+  /// fragment, which is needed for rendering lists.
+  fn from_element(element: &'a JSXElement) -> Option<Self> {
+    if !is_fragment_name(&element.opening.name)
+      || has_key_prop(&element.opening.attrs)
+    {
+      return None;
+    }
+    Some(Fragment {
+      span: element.span,
+      children: &element.children,
+      inner_span: element.closing.as_ref().map(|closing| {
+        element
+          .opening
+          .span
+          .with_lo(element.opening.span.hi)
+          .with_hi(closing.span.lo)
+      }),
+    })
+  }
+
+  fn meaningful_children(&self) -> Vec<&'a JSXElementChild> {
+    self
+      .children
+      .iter()
+      .filter(|child| match child {
+        JSXElementChild::JSXText(text) => !text.value.trim().is_empty(),
+        JSXElementChild::JSXExprContainer(container) => {
+          !matches!(container.expr, JSXExpr::JSXEmptyExpr(_))
+        }
+        _ => true,
+      })
+      .collect()
+  }
+}
+
+/// Checks if the element name is `Fragment` or `React.Fragment`.
+fn is_fragment_name(name: &JSXElementName) -> bool {
+  match name {
+    JSXElementName::Ident(ident) => ident.sym == *"Fragment",
+    JSXElementName::JSXMemberExpr(member) => {
+      member.prop.sym == *"Fragment"
+        && matches!(&member.obj, JSXObject::Ident(obj) if obj.sym == *"React")
+    }
+    JSXElementName::JSXNamespacedName(_) => false,
+  }
+}
+
+/// Checks if the element name is an intrinsic element like `div`, whose
+/// children are flattened anyway.
+fn is_html_element_name(name: &JSXElementName) -> bool {
+  match name {
+    JSXElementName::Ident(ident) => ident
+      .sym
+      .chars()
+      .next()
+      .map_or(false, |c| c.is_ascii_lowercase()),
+    _ => false,
+  }
+}
+
+fn has_key_prop(attrs: &[JSXAttrOrSpread]) -> bool {
+  attrs.iter().any(|attr| match attr {
+    JSXAttrOrSpread::JSXAttr(attr) => match &attr.name {
+      JSXAttrName::Ident(ident) => ident.sym == *"key",
+      JSXAttrName::JSXNamespacedName(_) => false,
+    },
+    JSXAttrOrSpread::SpreadElement(_) => false,
+  })
+}
+
+struct JsxNoUselessFragmentVisitor<'c, 'view> {
+  context: &'c mut Context<'view>,
+}
+
+impl<'c, 'view> JsxNoUselessFragmentVisitor<'c, 'view> {
+  fn new(context: &'c mut Context<'view>) -> Self {
+    Self { context }
+  }
+
+  /// Checks a fragment used as an expression, e.g. `return <>...</>;`.
+  fn check_expr_fragment(&mut self, fragment: Fragment) {
+    let children = fragment.meaningful_children();
+    let message = match children.len() {
+      0 => JsxNoUselessFragmentMessage::Empty,
+      1 => JsxNoUselessFragmentMessage::SingleChild,
+      _ => return,
+    };
+    // Only an element can replace the fragment as an expression, since text
+    // and expression containers are only valid inside of JSX
+    let fix_text = match children.first() {
+      Some(JSXElementChild::JSXElement(element)) => {
+        Some(self.context.file_text_substring(&element.span).to_string())
+      }
+      Some(JSXElementChild::JSXFragment(inner)) => {
+        Some(self.context.file_text_substring(&inner.span).to_string())
+      }
+      _ => None,
+    };
+    self.report(fragment.span, message, fix_text);
+  }
+
+  /// Checks the fragments among the children of an element or fragment.
+  fn check_child_fragments(
+    &mut self,
+    children: &[JSXElementChild],
+    in_html_element: bool,
+  ) {
+    for child in children {
+      let fragment = match child {
+        JSXElementChild::JSXFragment(fragment) => {
+          Fragment::from_fragment(fragment)
+        }
+        JSXElementChild::JSXElement(element) => {
+          match Fragment::from_element(element) {
+            Some(fragment) => fragment,
+            None => continue,
+          }
+        }
+        _ => continue,
+      };
+      let message = match fragment.meaningful_children().len() {
+        0 => JsxNoUselessFragmentMessage::Empty,
+        1 => JsxNoUselessFragmentMessage::SingleChild,
+        _ if in_html_element => JsxNoUselessFragmentMessage::ChildOfHtmlElement,
+        _ => continue,
+      };
+      // Inside of JSX, the children can always take the place of the fragment
+      let fix_text = match fragment.inner_span {
+        Some(inner_span) => self.context.file_text_substring(&inner_span),
+        None => "",
+      }
+      .to_string();
+      self.report(fragment.span, message, Some(fix_text));
+    }
+  }
+
+  fn report(
+    &mut self,
+    span: Span,
+    message: JsxNoUselessFragmentMessage,
+    fix_text: Option<String>,
+  ) {
+    match fix_text {
+      Some(fix_text) => {
+        let fix = self.context.create_fix(span, fix_text, FIX_DESC);
+        self
+          .context
+          .add_diagnostic_with_hint_and_fix(span, CODE, message, HINT, fix);
+      }
+      None => {
+        self
+          .context
+          .add_diagnostic_with_hint(span, CODE, message, HINT);
+      }
+    }
+  }
+}
+
+impl<'c, 'view> Visit for JsxNoUselessFragmentVisitor<'c, 'view> {
+  noop_visit_type!();
+
+  fn visit_expr(&mut self, expr: &Expr, _: &dyn Node) {
+    expr.visit_children_with(self);
+
+    let fragment = match expr {
+      Expr::JSXFragment(fragment) => Fragment::from_fragment(fragment),
+      Expr::JSXElement(element) => match Fragment::from_element(element) {
+        Some(fragment) => fragment,
+        None => return,
+      },
+      _ => return,
+    };
+    self.check_expr_fragment(fragment);
+  }
+
+  fn visit_jsx_element(&mut self, element: &JSXElement, _: &dyn Node) {
+    element.visit_children_with(self);
+
+    let in_html_element = is_html_element_name(&element.opening.name);
+    self.check_child_fragments(&element.children, in_html_element);
+  }
+
+  fn visit_jsx_fragment(&mut self, fragment: &JSXFragment, _: &dyn Node) {
+    fragment.visit_children_with(self);
+    self.check_child_fragments(&fragment.children, false);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn jsx_no_useless_fragment_valid() {
+    assert_lint_ok! {
+      JsxNoUselessFragment,
+      filename: "foo.tsx",
+      r#"const a = <><Foo /><Bar /></>;"#,
+      r#"const a = <>foo <div /></>;"#,
+      r#"const a = <Fragment><Foo /><Bar /></Fragment>;"#,
+      r#"const a = <React.Fragment>foo <b>bar</b></React.Fragment>;"#,
+      r#"items.map((item) => <Fragment key={item.id}>{item.name}</Fragment>);"#,
+      r#"const a = <Foo><><a /><b /></></Foo>;"#,
+      r#"const a = <div><Foo /></div>;"#,
+    };
+  }
+
+  #[test]
+  fn jsx_no_useless_fragment_invalid() {
+    assert_lint_err! {
+      JsxNoUselessFragment,
+      filename: "foo.tsx",
+      r#"const a = <><Foo /></>;"#: [
+        {
+          col: 10,
+          message: JsxNoUselessFragmentMessage::SingleChild,
+          hint: HINT,
+          fix: "const a = <Foo />;",
+        },
+      ],
+      r#"const a = <Fragment>
+  <Foo />
+</Fragment>;"#: [
+        {
+          col: 10,
+          message: JsxNoUselessFragmentMessage::SingleChild,
+          hint: HINT,
+          fix: "const a = <Foo />;",
+        },
+      ],
+      r#"const a = <React.Fragment><Foo /></React.Fragment>;"#: [
+        {
+          col: 10,
+          message: JsxNoUselessFragmentMessage::SingleChild,
+          hint: HINT,
+          fix: "const a = <Foo />;",
+        },
+      ],
+      r#"const a = <>{foo}</>;"#: [
+        {
+          col: 10,
+          message: JsxNoUselessFragmentMessage::SingleChild,
+          hint: HINT,
+        },
+      ],
+      r#"const a = <></>;"#: [
+        {
+          col: 10,
+          message: JsxNoUselessFragmentMessage::Empty,
+          hint: HINT,
+        },
+      ],
+      r#"const a = <Foo><>{foo}</></Foo>;"#: [
+        {
+          col: 15,
+          message: JsxNoUselessFragmentMessage::SingleChild,
+          hint: HINT,
+          fix: "const a = <Foo>{foo}</Foo>;",
+        },
+      ],
+      r#"const a = <Foo>bar<Fragment /></Foo>;"#: [
+        {
+          col: 18,
+          message: JsxNoUselessFragmentMessage::Empty,
+          hint: HINT,
+          fix: "const a = <Foo>bar</Foo>;",
+        },
+      ],
+      r#"const a = <div><><a /><b /></></div>;"#: [
+        {
+          col: 15,
+          message: JsxNoUselessFragmentMessage::ChildOfHtmlElement,
+          hint: HINT,
+          fix: "const a = <div><a /><b /></div>;",
+        },
+      ],
+    };
+  }
+}
@@ -0,0 +1,237 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::prefer_at::is_simple;
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::ast_eq::expr_eq;
+use crate::ProgramRef;
+use deno_ast::swc::ast::{
+  AssignExpr, AssignOp, BinaryOp, Expr, Pat, PatOrExpr,
+};
+use deno_ast::swc::common::Spanned;
+use deno_ast::swc::visit::{noop_visit_type, Node, Visit, VisitWith};
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct LogicalAssignmentOperators;
+
+const CODE: &str = "logical-assignment-operators";
+
+#[derive(Display)]
+enum LogicalAssignmentOperatorsMessage {
+  #[display(fmt = "Assignment can be shortened with `{}=`", _0)]
+  Shorten(&'static str),
+}
+
+#[derive(Display)]
+enum LogicalAssignmentOperatorsHint {
+  #[display(fmt = "Use the logical assignment operator `{}=` instead", _0)]
+  UseOperator(&'static str),
+}
+
+impl LintRule for LogicalAssignmentOperators {
+  fn new() -> Box<Self> {
+    Box::new(LogicalAssignmentOperators)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    let mut visitor = LogicalAssignmentOperatorsVisitor::new(context);
+    match program {
+      ProgramRef::Module(m) => visitor.visit_module(m, &DUMMY_NODE),
+      ProgramRef::Script(s) => visitor.visit_script(s, &DUMMY_NODE),
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/logical_assignment_operators.md")
+  }
+}
+
+fn logical_operator(op: BinaryOp) -> Option<&'static str> {
+  match op {
+    BinaryOp::LogicalOr => Some("||"),
+    BinaryOp::LogicalAnd => Some("&&"),
+    BinaryOp::NullishCoalescing => Some("??"),
+    _ => None,
+  }
+}
+
+/// Checks if the assignment target is an identifier or a member expression
+/// which can be evaluated twice without side effects.
+fn is_simple_target(target: &PatOrExpr) -> bool {
+  match target {
+    PatOrExpr::Pat(pat) => match &**pat {
+      Pat::Ident(_) => true,
+      Pat::Expr(expr) => is_simple(expr),
+      _ => false,
+    },
+    PatOrExpr::Expr(expr) => is_simple(expr),
+  }
+}
+
+/// Checks if the expression refers to the same variable or property as the
+/// assignment target.
+fn is_same_target(target: &PatOrExpr, expr: &Expr) -> bool {
+  match target {
+    PatOrExpr::Pat(pat) => match &**pat {
+      Pat::Ident(ident) => {
+        matches!(expr, Expr::Ident(expr) if expr.sym == ident.id.sym)
+      }
+      Pat::Expr(target) => expr_eq(target, expr),
+      _ => false,
+    },
+    PatOrExpr::Expr(target) => expr_eq(target, expr),
+  }
+}
+
+struct LogicalAssignmentOperatorsVisitor<'c, 'view> {
+  context: &'c mut Context<'view>,
+}
+
+impl<'c, 'view> LogicalAssignmentOperatorsVisitor<'c, 'view> {
+  fn new(context: &'c mut Context<'view>) -> Self {
+    Self { context }
+  }
+}
+
+impl<'c, 'view> Visit for LogicalAssignmentOperatorsVisitor<'c, 'view> {
+  noop_visit_type!();
+
+  fn visit_assign_expr(&mut self, assign_expr: &AssignExpr, _: &dyn Node) {
+    assign_expr.visit_children_with(self);
+
+    if assign_expr.op != AssignOp::Assign
+      || !is_simple_target(&assign_expr.left)
+    {
+      return;
+    }
+    let mut right = &*assign_expr.right;
+    while let Expr::Paren(paren) = right {
+      right = &paren.expr;
+    }
+    let (bin, op) = match right {
+      Expr::Bin(bin) => match logical_operator(bin.op) {
+        Some(op) => (bin, op),
+        None => return,
+      },
+      _ => return,
+    };
+
+    // Only `a = a || b` can be shortened, `a = b || a` evaluates `b` first
+    if !is_simple(&bin.left) || !is_same_target(&assign_expr.left, &bin.left) {
+      return;
+    }
+    let target = self.context.file_text_substring(&assign_expr.left.span());
+
+    let hint = LogicalAssignmentOperatorsHint::UseOperator(op);
+    let fixed = format!(
+      "{} {}= {}",
+      target,
+      op,
+      self.context.file_text_substring(&bin.right.span())
+    );
+    let fix = self.context.create_fix(assign_expr.span, fixed, &hint);
+    self.context.add_diagnostic_with_hint_and_fix(
+      assign_expr.span,
+      CODE,
+      LogicalAssignmentOperatorsMessage::Shorten(op),
+      hint,
+      fix,
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn logical_assignment_operators_valid() {
+    assert_lint_ok! {
+      LogicalAssignmentOperators,
+      "a ||= b;",
+      "a &&= b;",
+      "a ??= b;",
+      "a = b || a;",
+      "a = b && c;",
+      "a = a + b;",
+      "a += a || b;",
+      "a = a || b || c;",
+      "a.b = a.c || d;",
+      "foo().a = foo().a || b;",
+      "a[i++] = a[i++] || b;",
+      "[a] = a || b;",
+      "const a = a || b;",
+    };
+  }
+
+  #[test]
+  fn logical_assignment_operators_invalid() {
+    assert_lint_err! {
+      LogicalAssignmentOperators,
+      "a = a || b;": [
+        {
+          col: 0,
+          message: LogicalAssignmentOperatorsMessage::Shorten("||"),
+          hint: LogicalAssignmentOperatorsHint::UseOperator("||"),
+          fix: "a ||= b;",
+        },
+      ],
+      "a = a && b;": [
+        {
+          col: 0,
+          message: LogicalAssignmentOperatorsMessage::Shorten("&&"),
+          hint: LogicalAssignmentOperatorsHint::UseOperator("&&"),
+          fix: "a &&= b;",
+        },
+      ],
+      "a = a ?? b;": [
+        {
+          col: 0,
+          message: LogicalAssignmentOperatorsMessage::Shorten("??"),
+          hint: LogicalAssignmentOperatorsHint::UseOperator("??"),
+          fix: "a ??= b;",
+        },
+      ],
+      "this.a = (this.a || {});": [
+        {
+          col: 0,
+          message: LogicalAssignmentOperatorsMessage::Shorten("||"),
+          hint: LogicalAssignmentOperatorsHint::UseOperator("||"),
+          fix: "this.a ||= {};",
+        },
+      ],
+      "obj.a.b = obj.a.b ?? (c, d);": [
+        {
+          col: 0,
+          message: LogicalAssignmentOperatorsMessage::Shorten("??"),
+          hint: LogicalAssignmentOperatorsHint::UseOperator("??"),
+          fix: "obj.a.b ??= (c, d);",
+        },
+      ],
+      "a[0] = a[0] || b && c;": [
+        {
+          col: 0,
+          message: LogicalAssignmentOperatorsMessage::Shorten("||"),
+          hint: LogicalAssignmentOperatorsHint::UseOperator("||"),
+          fix: "a[0] ||= b && c;",
+        },
+      ],
+      "function f() { x = x || 1; }": [
+        {
+          col: 15,
+          message: LogicalAssignmentOperatorsMessage::Shorten("||"),
+          hint: LogicalAssignmentOperatorsHint::UseOperator("||"),
+          fix: "function f() { x ||= 1; }",
+        },
+      ],
+    };
+  }
+}
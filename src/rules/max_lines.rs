@@ -0,0 +1,179 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::common::Span;
+use serde::Deserialize;
+
+#[derive(Debug, Default)]
+pub struct MaxLines {
+  options: MaxLinesOptions,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct MaxLinesOptions {
+  /// Maximum number of lines in a file. Defaults to `300`.
+  pub max: usize,
+  /// Whether blank lines are excluded from the count.
+  pub skip_blank_lines: bool,
+  /// Whether lines containing only comments are excluded from the count.
+  pub skip_comments: bool,
+}
+
+impl Default for MaxLinesOptions {
+  fn default() -> Self {
+    Self {
+      max: 300,
+      skip_blank_lines: false,
+      skip_comments: false,
+    }
+  }
+}
+
+const CODE: &str = "max-lines";
+
+fn message(lines: usize, max: usize) -> String {
+  format!(
+    "File has too many lines ({}). Maximum allowed is {}",
+    lines, max
+  )
+}
+
+impl MaxLines {
+  pub fn with_options(options: MaxLinesOptions) -> Box<Self> {
+    Box::new(MaxLines { options })
+  }
+}
+
+impl LintRule for MaxLines {
+  fn new() -> Box<Self> {
+    Box::new(MaxLines::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    _program: Program<'_>,
+  ) {
+    let source_file = context.source_file();
+
+    // The empty line after the final line break is not counted
+    let mut last_line = source_file.lines_count() - 1;
+    if last_line > 0
+      && source_file.line_start(last_line) == source_file.line_end(last_line)
+    {
+      last_line -= 1;
+    }
+
+    let file_span = Span::new(
+      source_file.span().lo,
+      source_file.line_end(last_line),
+      Default::default(),
+    );
+    let first_line_span = Span::new(
+      source_file.line_start(0),
+      source_file.line_end(0),
+      Default::default(),
+    );
+
+    let lines = context.count_lines(
+      file_span,
+      self.options.skip_blank_lines,
+      self.options.skip_comments,
+    );
+    if lines > self.options.max {
+      context.add_diagnostic(
+        first_line_span,
+        CODE,
+        message(lines, self.options.max),
+      );
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/max_lines.md")
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  #[test]
+  fn max_lines_valid() {
+    assert_lint_ok! {
+      MaxLines,
+      "",
+      "const a = 1;",
+    };
+
+    assert_lint_ok! {
+      MaxLines,
+      options: json!({ "max": 2 }),
+      "const a = 1;\nconst b = 2;",
+      "const a = 1;\nconst b = 2;\n",
+    };
+
+    assert_lint_ok! {
+      MaxLines,
+      options: json!({ "max": 2, "skipBlankLines": true }),
+      "const a = 1;\n\n   \nconst b = 2;\n",
+    };
+
+    assert_lint_ok! {
+      MaxLines,
+      options: json!({ "max": 2, "skipComments": true }),
+      r#"// comment
+const a = 1; // trailing comments don't make a line comment-only
+/*
+ * block comment
+ */
+const b = 2;"#,
+    };
+
+    assert_lint_ok! {
+      MaxLines,
+      options: json!({ "max": 1, "skipBlankLines": true, "skipComments": true }),
+      "// comment\n\nconst a = 1;\n\n/* comment */\n",
+    };
+  }
+
+  #[test]
+  fn max_lines_invalid() {
+    assert_lint_err! {
+      MaxLines,
+      options: json!({ "max": 2 }),
+      "const a = 1;\nconst b = 2;\nconst c = 3;": [
+        { col: 0, message: message(3, 2) },
+      ],
+      "const a = 1;\n\n\n": [{ col: 0, message: message(3, 2) }],
+      "// a\n// b\n// c\n": [{ col: 0, message: message(3, 2) }],
+    };
+
+    assert_lint_err! {
+      MaxLines,
+      options: json!({ "max": 2, "skipBlankLines": true }),
+      "const a = 1;\n\n// comment\nconst b = 2;": [
+        { col: 0, message: message(3, 2) },
+      ],
+    };
+
+    assert_lint_err! {
+      MaxLines,
+      options: json!({ "max": 2, "skipComments": true }),
+      "const a = 1;\n/* a */ const b = 2; /* b */\nconst c = 3; // c": [
+        { col: 0, message: message(3, 2) },
+      ],
+    };
+  }
+}
@@ -0,0 +1,348 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::{describe_function, StringRepr};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::common::{Span, Spanned};
+use deno_ast::view as ast_view;
+use deno_ast::view::NodeTrait;
+use serde::Deserialize;
+
+#[derive(Debug, Default)]
+pub struct MaxLinesPerFunction {
+  options: MaxLinesPerFunctionOptions,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct MaxLinesPerFunctionOptions {
+  /// Maximum number of lines in a function. Defaults to `50`.
+  pub max: usize,
+  /// Whether blank lines are excluded from the count.
+  pub skip_blank_lines: bool,
+  /// Whether lines containing only comments are excluded from the count.
+  pub skip_comments: bool,
+  /// Whether immediately invoked function expressions are checked.
+  #[serde(rename = "IIFEs")]
+  pub iifes: bool,
+}
+
+impl Default for MaxLinesPerFunctionOptions {
+  fn default() -> Self {
+    Self {
+      max: 50,
+      skip_blank_lines: false,
+      skip_comments: false,
+      iifes: false,
+    }
+  }
+}
+
+const CODE: &str = "max-lines-per-function";
+
+fn message(name: &str, lines: usize, max: usize) -> String {
+  format!(
+    "{} has too many lines ({}). Maximum allowed is {}",
+    name, lines, max
+  )
+}
+
+impl MaxLinesPerFunction {
+  pub fn with_options(options: MaxLinesPerFunctionOptions) -> Box<Self> {
+    Box::new(MaxLinesPerFunction { options })
+  }
+}
+
+impl LintRule for MaxLinesPerFunction {
+  fn new() -> Box<Self> {
+    Box::new(MaxLinesPerFunction::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    MaxLinesPerFunctionHandler::new(&self.options).traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/max_lines_per_function.md")
+  }
+}
+
+/// Checks if the function is immediately invoked, e.g. `(() => {})()`.
+fn is_iife(node: ast_view::Node) -> bool {
+  let mut current = node;
+  while let Some(parent) = current.parent() {
+    match parent {
+      ast_view::Node::ParenExpr(_) => current = parent,
+      ast_view::Node::CallExpr(call_expr) => {
+        return call_expr.callee.span() == current.span()
+      }
+      _ => return false,
+    }
+  }
+  false
+}
+
+struct MaxLinesPerFunctionHandler<'o> {
+  options: &'o MaxLinesPerFunctionOptions,
+}
+
+impl<'o> MaxLinesPerFunctionHandler<'o> {
+  fn new(options: &'o MaxLinesPerFunctionOptions) -> Self {
+    Self { options }
+  }
+
+  fn check(
+    &self,
+    span: Span,
+    name: impl FnOnce() -> String,
+    ctx: &mut Context,
+  ) {
+    let lines = ctx.count_lines(
+      span,
+      self.options.skip_blank_lines,
+      self.options.skip_comments,
+    );
+    if lines > self.options.max {
+      ctx.add_diagnostic(span, CODE, message(&name(), lines, self.options.max));
+    }
+  }
+}
+
+impl<'o> Handler for MaxLinesPerFunctionHandler<'o> {
+  fn fn_decl(&mut self, fn_decl: &ast_view::FnDecl, ctx: &mut Context) {
+    self.check(
+      fn_decl.span(),
+      || describe_function("Function", fn_decl.inner.ident.string_repr()),
+      ctx,
+    );
+  }
+
+  fn fn_expr(&mut self, fn_expr: &ast_view::FnExpr, ctx: &mut Context) {
+    if !self.options.iifes && is_iife(fn_expr.as_node()) {
+      return;
+    }
+
+    self.check(
+      fn_expr.span(),
+      || describe_function("Function", fn_expr.inner.ident.string_repr()),
+      ctx,
+    );
+  }
+
+  fn arrow_expr(
+    &mut self,
+    arrow_expr: &ast_view::ArrowExpr,
+    ctx: &mut Context,
+  ) {
+    if !self.options.iifes && is_iife(arrow_expr.as_node()) {
+      return;
+    }
+
+    self.check(arrow_expr.span(), || "Arrow function".to_string(), ctx);
+  }
+
+  fn class_method(
+    &mut self,
+    class_method: &ast_view::ClassMethod,
+    ctx: &mut Context,
+  ) {
+    self.check(
+      class_method.span(),
+      || describe_function("Method", class_method.inner.key.string_repr()),
+      ctx,
+    );
+  }
+
+  fn private_method(
+    &mut self,
+    private_method: &ast_view::PrivateMethod,
+    ctx: &mut Context,
+  ) {
+    self.check(
+      private_method.span(),
+      || describe_function("Method", private_method.inner.key.string_repr()),
+      ctx,
+    );
+  }
+
+  fn constructor(
+    &mut self,
+    constructor: &ast_view::Constructor,
+    ctx: &mut Context,
+  ) {
+    self.check(constructor.span(), || "Constructor".to_string(), ctx);
+  }
+
+  fn method_prop(
+    &mut self,
+    method_prop: &ast_view::MethodProp,
+    ctx: &mut Context,
+  ) {
+    self.check(
+      method_prop.span(),
+      || describe_function("Method", method_prop.inner.key.string_repr()),
+      ctx,
+    );
+  }
+
+  fn getter_prop(
+    &mut self,
+    getter_prop: &ast_view::GetterProp,
+    ctx: &mut Context,
+  ) {
+    self.check(
+      getter_prop.span(),
+      || describe_function("Getter", getter_prop.inner.key.string_repr()),
+      ctx,
+    );
+  }
+
+  fn setter_prop(
+    &mut self,
+    setter_prop: &ast_view::SetterProp,
+    ctx: &mut Context,
+  ) {
+    self.check(
+      setter_prop.span(),
+      || describe_function("Setter", setter_prop.inner.key.string_repr()),
+      ctx,
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  #[test]
+  fn max_lines_per_function_valid() {
+    assert_lint_ok! {
+      MaxLinesPerFunction,
+      "function foo() { return 1; }",
+    };
+
+    assert_lint_ok! {
+      MaxLinesPerFunction,
+      options: json!({ "max": 3 }),
+      r#"
+function foo() {
+  return 1;
+}
+      "#,
+      r#"
+const foo = () => {
+  return 1;
+};
+      "#,
+      r#"
+class Foo {
+  bar() {
+    return 1;
+  }
+}
+      "#,
+      r#"
+(function () {
+  const a = 1;
+  const b = 2;
+  const c = 3;
+})();
+      "#,
+      r#"
+(() => {
+  const a = 1;
+  const b = 2;
+  const c = 3;
+})();
+      "#,
+    };
+
+    assert_lint_ok! {
+      MaxLinesPerFunction,
+      options: json!({ "max": 3, "skipBlankLines": true, "skipComments": true }),
+      r#"
+function foo() {
+
+  // comment
+  /* comment */
+
+  return 1;
+}
+      "#,
+    };
+  }
+
+  #[test]
+  fn max_lines_per_function_invalid() {
+    assert_lint_err! {
+      MaxLinesPerFunction,
+      options: json!({ "max": 2 }),
+      r#"
+function foo() {
+  return 1;
+}
+      "#: [{ line: 2, col: 0, message: message("Function `foo`", 3, 2) }],
+      r#"
+const foo = () => {
+  return 1;
+};
+      "#: [{ line: 2, col: 12, message: message("Arrow function", 3, 2) }],
+      r#"
+const obj = {
+  get foo() {
+    return 1;
+  },
+};
+      "#: [{ line: 3, col: 2, message: message("Getter `foo`", 3, 2) }],
+      r#"
+class Foo {
+  constructor() {
+    this.a = 1;
+  }
+  #bar() {
+    return 1;
+  }
+}
+      "#: [
+        { line: 3, col: 2, message: message("Constructor", 3, 2) },
+        { line: 6, col: 2, message: message("Method `bar`", 3, 2) },
+      ],
+    };
+
+    assert_lint_err! {
+      MaxLinesPerFunction,
+      options: json!({ "max": 2, "skipBlankLines": true }),
+      r#"
+function foo() {
+
+  // comment
+  return 1;
+}
+      "#: [{ line: 2, col: 0, message: message("Function `foo`", 4, 2) }],
+    };
+
+    assert_lint_err! {
+      MaxLinesPerFunction,
+      options: json!({ "max": 2, "IIFEs": true }),
+      r#"
+(function () {
+  return 1;
+})();
+      "#: [{ line: 2, col: 1, message: message("Function", 3, 2) }],
+    };
+  }
+}
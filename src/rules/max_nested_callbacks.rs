@@ -0,0 +1,221 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::ProgramRef;
+use deno_ast::swc::ast::{CallExpr, Expr, FnDecl};
+use deno_ast::swc::common::{Span, Spanned};
+use deno_ast::swc::visit::{noop_visit_type, Node, Visit, VisitWith};
+use serde::Deserialize;
+
+#[derive(Debug, Default)]
+pub struct MaxNestedCallbacks {
+  options: MaxNestedCallbacksOptions,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct MaxNestedCallbacksOptions {
+  /// Maximum depth of nested callbacks. Defaults to `10`.
+  pub max: usize,
+}
+
+impl Default for MaxNestedCallbacksOptions {
+  fn default() -> Self {
+    Self { max: 10 }
+  }
+}
+
+const CODE: &str = "max-nested-callbacks";
+
+fn message(depth: usize, max: usize) -> String {
+  format!(
+    "Too many nested callbacks ({}). Maximum allowed is {}",
+    depth, max
+  )
+}
+
+impl MaxNestedCallbacks {
+  pub fn with_options(options: MaxNestedCallbacksOptions) -> Box<Self> {
+    Box::new(MaxNestedCallbacks { options })
+  }
+}
+
+impl LintRule for MaxNestedCallbacks {
+  fn new() -> Box<Self> {
+    Box::new(MaxNestedCallbacks::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    let mut visitor = MaxNestedCallbacksVisitor::new(context, self.options.max);
+    match program {
+      ProgramRef::Module(m) => m.visit_with(&DUMMY_NODE, &mut visitor),
+      ProgramRef::Script(s) => s.visit_with(&DUMMY_NODE, &mut visitor),
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/max_nested_callbacks.md")
+  }
+}
+
+struct MaxNestedCallbacksVisitor<'c, 'view> {
+  context: &'c mut Context<'view>,
+  max: usize,
+  /// Number of callbacks enclosing the node currently visited
+  depth: usize,
+  /// Whether a callback has been found inside the node currently visited
+  has_nested: bool,
+}
+
+impl<'c, 'view> MaxNestedCallbacksVisitor<'c, 'view> {
+  fn new(context: &'c mut Context<'view>, max: usize) -> Self {
+    Self {
+      context,
+      max,
+      depth: 0,
+      has_nested: false,
+    }
+  }
+
+  /// Only the innermost callback of a chain is reported, so that a chain
+  /// exceeding the limit is reported once.
+  fn check(&mut self, span: Span) {
+    if self.depth > self.max && !self.has_nested {
+      self
+        .context
+        .add_diagnostic(span, CODE, message(self.depth, self.max));
+    }
+  }
+}
+
+/// Unwraps parentheses and returns the expression if it's a function
+/// expression or an arrow function.
+fn as_callback(expr: &Expr) -> Option<&Expr> {
+  match expr {
+    Expr::Paren(paren) => as_callback(&paren.expr),
+    Expr::Fn(_) | Expr::Arrow(_) => Some(expr),
+    _ => None,
+  }
+}
+
+impl<'c, 'view> Visit for MaxNestedCallbacksVisitor<'c, 'view> {
+  noop_visit_type!();
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _: &dyn Node) {
+    call_expr.callee.visit_with(call_expr, self);
+
+    for arg in &call_expr.args {
+      if let Some(callback) = as_callback(&arg.expr) {
+        self.depth += 1;
+        self.has_nested = false;
+        callback.visit_with(call_expr, self);
+        self.check(callback.span());
+        self.depth -= 1;
+        self.has_nested = true;
+      } else {
+        arg.visit_with(call_expr, self);
+      }
+    }
+  }
+
+  fn visit_fn_decl(&mut self, fn_decl: &FnDecl, _: &dyn Node) {
+    // Named function declarations start a new chain of callbacks
+    let prev_depth = self.depth;
+    let prev_has_nested = self.has_nested;
+    self.depth = 0;
+    fn_decl.visit_children_with(self);
+    self.depth = prev_depth;
+    self.has_nested = prev_has_nested;
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  #[test]
+  fn max_nested_callbacks_valid() {
+    assert_lint_ok! {
+      MaxNestedCallbacks,
+      options: json!({ "max": 3 }),
+      "foo(function() { bar(function() { baz(function() {}); }); });",
+      "foo(() => { bar(() => { baz(() => {}); }); });",
+      r#"
+foo(() => {
+  bar(() => {
+    function qux() {
+      a(() => { b(() => { c(() => {}); }); });
+    }
+  });
+});
+      "#,
+      // Functions that are not passed as arguments are not callbacks
+      "foo(() => { const f = () => { const g = () => { bar(() => {}); }; }; });",
+      "foo(() => {}, () => {}, () => {}, () => {});",
+    };
+
+    assert_lint_ok! {
+      MaxNestedCallbacks,
+      "a(() => { b(() => { c(() => { d(() => { e(() => {}); }); }); }); });",
+    };
+  }
+
+  #[test]
+  fn max_nested_callbacks_invalid() {
+    assert_lint_err! {
+      MaxNestedCallbacks,
+      options: json!({ "max": 3 }),
+      "foo(function() { bar(function() { baz(function() { qux(function() {}); }); }); });": [
+        { col: 55, message: message(4, 3) },
+      ],
+      "a(() => { b(() => { c(() => { d((() => {})); }); }); });": [
+        { col: 33, message: message(4, 3) },
+      ],
+      "a(() => { b(() => { c(() => { d(() => { e(() => {}); }); }); }); });": [
+        { col: 42, message: message(5, 3) },
+      ],
+      "a(() => { b(() => { c(() => { d(() => { e(() => { f(() => {}); }); }); }); }); });": [
+        { col: 52, message: message(6, 3) },
+      ],
+      // Each chain exceeding the limit is reported once
+      "a(() => { b(() => { c(() => { d(() => { e(() => {}); }); f(() => {}); }); }); });": [
+        { col: 42, message: message(5, 3) },
+        { col: 59, message: message(4, 3) },
+      ],
+      r#"
+a(() => {
+  b(() => {
+    c(() => {
+      d(() => {
+        function g() {
+          e(() => {});
+        }
+      });
+    });
+  });
+});
+      "#: [{ line: 5, col: 8, message: message(4, 3) }],
+    };
+
+    assert_lint_err! {
+      MaxNestedCallbacks,
+      options: json!({ "max": 1 }),
+      r#"
+function f() {
+  a(() => {
+    b(() => {});
+  });
+}
+      "#: [{ line: 4, col: 6, message: message(2, 1) }],
+    };
+  }
+}
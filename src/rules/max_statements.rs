@@ -0,0 +1,255 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::swc_util::{describe_function, StringRepr};
+use crate::ProgramRef;
+use deno_ast::swc::ast::{
+  ArrowExpr, BlockStmt, ClassMethod, Constructor, FnDecl, FnExpr, GetterProp,
+  MethodProp, PrivateMethod, SetterProp,
+};
+use deno_ast::swc::common::Span;
+use deno_ast::swc::visit::{noop_visit_type, Node, Visit, VisitWith};
+use serde::Deserialize;
+
+#[derive(Debug, Default)]
+pub struct MaxStatements {
+  options: MaxStatementsOptions,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct MaxStatementsOptions {
+  /// Maximum number of statements allowed per function. Defaults to `10`.
+  pub max: usize,
+  /// Whether functions that are not nested in another function are ignored.
+  pub ignore_top_level_functions: bool,
+}
+
+impl Default for MaxStatementsOptions {
+  fn default() -> Self {
+    Self {
+      max: 10,
+      ignore_top_level_functions: false,
+    }
+  }
+}
+
+const CODE: &str = "max-statements";
+
+fn message(name: &str, statements: usize, max: usize) -> String {
+  format!(
+    "{} has too many statements ({}). Maximum allowed is {}",
+    name, statements, max
+  )
+}
+
+impl MaxStatements {
+  pub fn with_options(options: MaxStatementsOptions) -> Box<Self> {
+    Box::new(MaxStatements { options })
+  }
+}
+
+impl LintRule for MaxStatements {
+  fn new() -> Box<Self> {
+    Box::new(MaxStatements::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    let mut visitor = MaxStatementsVisitor::new(context, &self.options);
+    match program {
+      ProgramRef::Module(m) => m.visit_with(&DUMMY_NODE, &mut visitor),
+      ProgramRef::Script(s) => s.visit_with(&DUMMY_NODE, &mut visitor),
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/max_statements.md")
+  }
+}
+
+struct MaxStatementsVisitor<'c, 'view, 'o> {
+  context: &'c mut Context<'view>,
+  options: &'o MaxStatementsOptions,
+  /// Number of statements in each function enclosing the node currently
+  /// visited. The last one is the innermost function.
+  counts: Vec<usize>,
+}
+
+impl<'c, 'view, 'o> MaxStatementsVisitor<'c, 'view, 'o> {
+  fn new(
+    context: &'c mut Context<'view>,
+    options: &'o MaxStatementsOptions,
+  ) -> Self {
+    Self {
+      context,
+      options,
+      counts: Vec::new(),
+    }
+  }
+
+  fn with_function<N: VisitWith<Self>>(
+    &mut self,
+    node: &N,
+    span: Span,
+    name: impl FnOnce() -> String,
+  ) {
+    self.counts.push(0);
+    node.visit_children_with(self);
+    let statements = self.counts.pop().unwrap();
+
+    if self.options.ignore_top_level_functions && self.counts.is_empty() {
+      return;
+    }
+
+    if statements > self.options.max {
+      self.context.add_diagnostic(
+        span,
+        CODE,
+        message(&name(), statements, self.options.max),
+      );
+    }
+  }
+}
+
+impl<'c, 'view, 'o> Visit for MaxStatementsVisitor<'c, 'view, 'o> {
+  noop_visit_type!();
+
+  fn visit_fn_decl(&mut self, fn_decl: &FnDecl, _: &dyn Node) {
+    self.with_function(fn_decl, fn_decl.function.span, || {
+      describe_function("Function", fn_decl.ident.string_repr())
+    });
+  }
+
+  fn visit_fn_expr(&mut self, fn_expr: &FnExpr, _: &dyn Node) {
+    self.with_function(fn_expr, fn_expr.function.span, || {
+      describe_function("Function", fn_expr.ident.string_repr())
+    });
+  }
+
+  fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr, _: &dyn Node) {
+    self.with_function(arrow_expr, arrow_expr.span, || {
+      "Arrow function".to_string()
+    });
+  }
+
+  fn visit_class_method(&mut self, class_method: &ClassMethod, _: &dyn Node) {
+    self.with_function(class_method, class_method.span, || {
+      describe_function("Method", class_method.key.string_repr())
+    });
+  }
+
+  fn visit_private_method(
+    &mut self,
+    private_method: &PrivateMethod,
+    _: &dyn Node,
+  ) {
+    self.with_function(private_method, private_method.span, || {
+      describe_function("Method", private_method.key.string_repr())
+    });
+  }
+
+  fn visit_constructor(&mut self, constructor: &Constructor, _: &dyn Node) {
+    self.with_function(constructor, constructor.span, || {
+      "Constructor".to_string()
+    });
+  }
+
+  fn visit_method_prop(&mut self, method_prop: &MethodProp, _: &dyn Node) {
+    self.with_function(method_prop, method_prop.function.span, || {
+      describe_function("Method", method_prop.key.string_repr())
+    });
+  }
+
+  fn visit_getter_prop(&mut self, getter_prop: &GetterProp, _: &dyn Node) {
+    self.with_function(getter_prop, getter_prop.span, || {
+      describe_function("Getter", getter_prop.key.string_repr())
+    });
+  }
+
+  fn visit_setter_prop(&mut self, setter_prop: &SetterProp, _: &dyn Node) {
+    self.with_function(setter_prop, setter_prop.span, || {
+      describe_function("Setter", setter_prop.key.string_repr())
+    });
+  }
+
+  fn visit_block_stmt(&mut self, block_stmt: &BlockStmt, _: &dyn Node) {
+    // Statements in nested blocks count towards the enclosing function, while
+    // the block itself is counted as a statement of its parent block.
+    if let Some(count) = self.counts.last_mut() {
+      *count += block_stmt.stmts.len();
+    }
+    block_stmt.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  #[test]
+  fn max_statements_valid() {
+    assert_lint_ok! {
+      MaxStatements,
+      "function foo() { const a = 1; const b = 2; return a + b; }",
+      "const a = 1; const b = 2; const c = 3; const d = 4; const e = 5; const f = 6; const g = 7; const h = 8; const i = 9; const j = 10; const k = 11;",
+    };
+
+    assert_lint_ok! {
+      MaxStatements,
+      options: json!({ "max": 2 }),
+      "function foo() { const a = 1; return a; }",
+      "const foo = () => a + b;",
+      "function foo() { if (a) bar(); return 1; }",
+      // Nested functions are counted separately
+      "function foo() { const f = () => { a(); b(); }; return f; }",
+      "class Foo { bar() { a(); b(); } baz() { c(); d(); } }",
+    };
+
+    assert_lint_ok! {
+      MaxStatements,
+      options: json!({ "max": 1, "ignoreTopLevelFunctions": true }),
+      "function foo() { a(); b(); c(); }",
+      "const foo = function() { a(); b(); return () => { c(); }; };",
+    };
+  }
+
+  #[test]
+  fn max_statements_invalid() {
+    assert_lint_err! {
+      MaxStatements,
+      options: json!({ "max": 2 }),
+      "function foo() { a(); b(); c(); }": [
+        { col: 0, message: message("Function `foo`", 3, 2) },
+      ],
+      "function foo() { if (a) { b(); c(); } }": [
+        { col: 0, message: message("Function `foo`", 3, 2) },
+      ],
+      "const foo = () => { a(); { b(); } };": [
+        { col: 12, message: message("Arrow function", 3, 2) },
+      ],
+      "class Foo { bar() { a(); b(); c(); } }": [
+        { col: 12, message: message("Method `bar`", 3, 2) },
+      ],
+      "function foo() { a(); return function() { b(); c(); d(); }; }": [
+        { col: 29, message: message("Function", 3, 2) },
+      ],
+    };
+
+    assert_lint_err! {
+      MaxStatements,
+      options: json!({ "max": 1, "ignoreTopLevelFunctions": true }),
+      "function foo() { return () => { a(); b(); }; }": [
+        { col: 24, message: message("Arrow function", 2, 1) },
+      ],
+    };
+  }
+}
@@ -0,0 +1,199 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::swc_util::unwrap_parens;
+use crate::ProgramRef;
+use deno_ast::swc::ast::{CallExpr, Expr, ExprOrSuper};
+use deno_ast::swc::common::Spanned;
+use deno_ast::swc::utils::ident::IdentLike;
+use deno_ast::swc::visit::{noop_visit_type, Node, Visit, VisitWith};
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct NoAwaitInPromiseMethods;
+
+const CODE: &str = "no-await-in-promise-methods";
+const HINT: &str = "Remove `await` to let the promises run concurrently";
+const FIX_DESC: &str = "Remove `await`";
+
+/// Methods of `Promise` which take an iterable of promises to run
+/// concurrently
+const PROMISE_METHODS: &[&str] = &["all", "allSettled", "any", "race"];
+
+#[derive(Display)]
+enum NoAwaitInPromiseMethodsMessage {
+  #[display(
+    fmt = "Promises in `Promise.{}()` should not be awaited one by one",
+    _0
+  )]
+  Awaited(String),
+}
+
+impl LintRule for NoAwaitInPromiseMethods {
+  fn new() -> Box<Self> {
+    Box::new(NoAwaitInPromiseMethods)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    let mut visitor = NoAwaitInPromiseMethodsVisitor::new(context);
+    match program {
+      ProgramRef::Module(m) => visitor.visit_module(m, &DUMMY_NODE),
+      ProgramRef::Script(s) => visitor.visit_script(s, &DUMMY_NODE),
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_await_in_promise_methods.md")
+  }
+}
+
+struct NoAwaitInPromiseMethodsVisitor<'c, 'view> {
+  context: &'c mut Context<'view>,
+}
+
+impl<'c, 'view> NoAwaitInPromiseMethodsVisitor<'c, 'view> {
+  fn new(context: &'c mut Context<'view>) -> Self {
+    Self { context }
+  }
+
+  /// Returns the name of the method if the call is like `Promise.all(...)`.
+  fn promise_method(&self, call_expr: &CallExpr) -> Option<String> {
+    let member = match &call_expr.callee {
+      ExprOrSuper::Expr(callee) => match unwrap_parens(callee) {
+        Expr::Member(member) if !member.computed => member,
+        _ => return None,
+      },
+      ExprOrSuper::Super(_) => return None,
+    };
+    let is_promise = match &member.obj {
+      ExprOrSuper::Expr(obj) => matches!(
+        &**obj,
+        Expr::Ident(ident)
+          if ident.sym == *"Promise"
+            && self.context.scope().is_global(&ident.to_id())
+      ),
+      ExprOrSuper::Super(_) => false,
+    };
+    match &*member.prop {
+      Expr::Ident(prop)
+        if is_promise && PROMISE_METHODS.contains(&&*prop.sym) =>
+      {
+        Some(prop.sym.to_string())
+      }
+      _ => None,
+    }
+  }
+}
+
+impl<'c, 'view> Visit for NoAwaitInPromiseMethodsVisitor<'c, 'view> {
+  noop_visit_type!();
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _: &dyn Node) {
+    call_expr.visit_children_with(self);
+
+    let method = match self.promise_method(call_expr) {
+      Some(method) => method,
+      None => return,
+    };
+    let array_lit = match call_expr.args.first() {
+      Some(arg) if arg.spread.is_none() => match unwrap_parens(&arg.expr) {
+        Expr::Array(array_lit) => array_lit,
+        _ => return,
+      },
+      _ => return,
+    };
+
+    for elem in array_lit.elems.iter().flatten() {
+      if let Expr::Await(await_expr) = unwrap_parens(&elem.expr) {
+        let arg = self
+          .context
+          .file_text_substring(&await_expr.arg.span())
+          .to_string();
+        let fix = self.context.create_fix(await_expr.span, arg, FIX_DESC);
+        self.context.add_diagnostic_with_hint_and_fix(
+          await_expr.span,
+          CODE,
+          NoAwaitInPromiseMethodsMessage::Awaited(method.clone()),
+          HINT,
+          fix,
+        );
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_await_in_promise_methods_valid() {
+    assert_lint_ok! {
+      NoAwaitInPromiseMethods,
+      "await Promise.all([a(), b()]);",
+      "await Promise.allSettled([a, b]);",
+      "await Promise.all([a(), b()].map(async (x) => await x));",
+      "await Promise.all([async () => await a()]);",
+      "await Promise.all(promises);",
+      "await Promise.resolve(await a());",
+      "await Foo.all([await a()]);",
+      "await Promise[all]([await a()]);",
+      "class Promise {} await Promise.all([await a()]);",
+    };
+  }
+
+  #[test]
+  fn no_await_in_promise_methods_invalid() {
+    assert_lint_err! {
+      NoAwaitInPromiseMethods,
+      "await Promise.all([await a(), b()]);": [
+        {
+          col: 19,
+          message: NoAwaitInPromiseMethodsMessage::Awaited("all".to_string()),
+          hint: HINT,
+          fix: "await Promise.all([a(), b()]);",
+        },
+      ],
+      "await Promise.race([await a, await b]);": [
+        {
+          col: 20,
+          message: NoAwaitInPromiseMethodsMessage::Awaited("race".to_string()),
+          hint: HINT,
+          fix: "await Promise.race([a, await b]);",
+        },
+        {
+          col: 29,
+          message: NoAwaitInPromiseMethodsMessage::Awaited("race".to_string()),
+          hint: HINT,
+          fix: "await Promise.race([await a, b]);",
+        },
+      ],
+      "Promise.any([(await a())]);": [
+        {
+          col: 14,
+          message: NoAwaitInPromiseMethodsMessage::Awaited("any".to_string()),
+          hint: HINT,
+          fix: "Promise.any([(a())]);",
+        },
+      ],
+      "Promise.allSettled([...xs, await y]);": [
+        {
+          col: 27,
+          message: NoAwaitInPromiseMethodsMessage::Awaited(
+            "allSettled".to_string()
+          ),
+          hint: HINT,
+          fix: "Promise.allSettled([...xs, y]);",
+        },
+      ],
+    };
+  }
+}
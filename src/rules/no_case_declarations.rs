@@ -66,25 +66,25 @@ impl<'c, 'view> VisitAll for NoCaseDeclarationsVisitor<'c, 'view> {
     switch_case: &SwitchCase,
     _parent: &dyn Node,
   ) {
-    for stmt in &switch_case.cons {
-      let is_lexical_decl = match stmt {
-        Stmt::Decl(decl) => match &decl {
-          Decl::Fn(_) => true,
-          Decl::Class(_) => true,
-          Decl::Var(var_decl) => var_decl.kind != VarDeclKind::Var,
-          _ => false,
-        },
+    let has_lexical_decl = switch_case.cons.iter().any(|stmt| match stmt {
+      Stmt::Decl(decl) => match &decl {
+        Decl::Fn(_) => true,
+        Decl::Class(_) => true,
+        Decl::Var(var_decl) => var_decl.kind != VarDeclKind::Var,
         _ => false,
-      };
+      },
+      _ => false,
+    });
 
-      if is_lexical_decl {
-        self.context.add_diagnostic_with_hint(
-          switch_case.span,
-          CODE,
-          MESSAGE,
-          HINT,
-        );
-      }
+    // Wrapping the clause in a block fixes all of its declarations at once,
+    // so it's reported only once
+    if has_lexical_decl {
+      self.context.add_diagnostic_with_hint(
+        switch_case.span,
+        CODE,
+        MESSAGE,
+        HINT,
+      );
     }
   }
 }
@@ -123,6 +123,13 @@ switch (foo) {
     let b = "b";
     break;
   }
+}
+      "#,
+      r#"
+switch (foo) {
+  case 1:
+    var a = "a";
+    break;
 }
       "#,
     };
@@ -133,6 +140,22 @@ switch (foo) {
     assert_lint_err! {
       NoCaseDeclarations,
       r#"
+switch (foo) {
+  case 1:
+    let a = "a";
+    const b = "b";
+    class C {}
+    break;
+}
+    "#: [
+        {
+          line: 3,
+          col: 2,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      r#"
 switch (foo) {
   case 1:
     let a = "a";
@@ -0,0 +1,441 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::swc_util::is_nullish;
+use crate::ProgramRef;
+use deno_ast::swc::ast::{AssignOp, BinExpr, BinaryOp, Expr, Lit, UnaryOp};
+use deno_ast::swc::common::Spanned;
+use deno_ast::swc::visit::{noop_visit_type, Node, VisitAll, VisitAllWith};
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct NoConstantBinaryExpression;
+
+const CODE: &str = "no-constant-binary-expression";
+
+#[derive(Display)]
+enum NoConstantBinaryExpressionMessage {
+  #[display(
+    fmt = "Unexpected constant {} on the left-hand side of a `{}` expression",
+    _0,
+    _1
+  )]
+  ShortCircuit(&'static str, BinaryOp),
+  #[display(
+    fmt = "Unexpected constant binary expression. Compares constantly with the {}-hand side of the `{}`",
+    _0,
+    _1
+  )]
+  BinaryOperand(&'static str, BinaryOp),
+  #[display(
+    fmt = "Unexpected comparison to newly constructed object. These two values can never be equal"
+  )]
+  AlwaysNew,
+  #[display(
+    fmt = "Unexpected comparison of two newly constructed objects. These two values will always be considered different"
+  )]
+  BothAlwaysNew,
+}
+
+impl LintRule for NoConstantBinaryExpression {
+  fn new() -> Box<Self> {
+    Box::new(NoConstantBinaryExpression)
+  }
+
+  fn tags(&self) -> &'static [&'static str] {
+    &["recommended"]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    let mut visitor = NoConstantBinaryExpressionVisitor::new(context);
+    match program {
+      ProgramRef::Module(m) => m.visit_all_with(&DUMMY_NODE, &mut visitor),
+      ProgramRef::Script(s) => s.visit_all_with(&DUMMY_NODE, &mut visitor),
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_constant_binary_expression.md")
+  }
+}
+
+/// Checks if the expression is either always nullish or never nullish.
+fn has_constant_nullishness(expr: &Expr, ctx: &Context) -> bool {
+  match expr {
+    Expr::Object(_)
+    | Expr::Array(_)
+    | Expr::Arrow(_)
+    | Expr::Fn(_)
+    | Expr::Class(_)
+    | Expr::New(_)
+    | Expr::Lit(_)
+    | Expr::Tpl(_)
+    | Expr::Update(_)
+    | Expr::Unary(_)
+    | Expr::JSXElement(_)
+    | Expr::JSXFragment(_) => true,
+    Expr::Bin(bin) => !matches!(
+      bin.op,
+      BinaryOp::LogicalAnd | BinaryOp::LogicalOr | BinaryOp::NullishCoalescing
+    ),
+    Expr::Assign(assign) => match assign.op {
+      AssignOp::Assign => has_constant_nullishness(&assign.right, ctx),
+      AssignOp::AndAssign | AssignOp::OrAssign | AssignOp::NullishAssign => {
+        false
+      }
+      // Compound assignments always produce a number, a bigint or a string
+      _ => true,
+    },
+    Expr::Seq(seq) => seq
+      .exprs
+      .last()
+      .map_or(false, |last| has_constant_nullishness(last, ctx)),
+    Expr::Ident(_) => is_nullish(expr, ctx.scope()),
+    Expr::Paren(paren) => has_constant_nullishness(&paren.expr, ctx),
+    _ => false,
+  }
+}
+
+/// Checks if the expression is either always truthy or always falsy.
+/// `in_boolean_position` tells whether only the truthiness of the value
+/// matters, which makes e.g. `typeof x` constant.
+fn has_constant_truthiness(
+  expr: &Expr,
+  in_boolean_position: bool,
+  ctx: &Context,
+) -> bool {
+  match expr {
+    Expr::Object(_)
+    | Expr::Array(_)
+    | Expr::Arrow(_)
+    | Expr::Fn(_)
+    | Expr::Class(_)
+    | Expr::Lit(_)
+    | Expr::JSXElement(_)
+    | Expr::JSXFragment(_) => true,
+    Expr::New(_) => in_boolean_position,
+    Expr::Tpl(tpl) => {
+      (in_boolean_position
+        && tpl.quasis.iter().any(|quasi| !quasi.raw.value.is_empty()))
+        || tpl
+          .exprs
+          .iter()
+          .all(|expr| has_constant_truthiness(expr, false, ctx))
+    }
+    Expr::Unary(unary) => match unary.op {
+      UnaryOp::Void => true,
+      UnaryOp::TypeOf => in_boolean_position,
+      UnaryOp::Bang => has_constant_truthiness(&unary.arg, true, ctx),
+      UnaryOp::Delete => false,
+      _ => has_constant_truthiness(&unary.arg, false, ctx),
+    },
+    Expr::Bin(bin) => match bin.op {
+      BinaryOp::LogicalAnd | BinaryOp::LogicalOr => {
+        has_constant_truthiness(&bin.left, in_boolean_position, ctx)
+          && has_constant_truthiness(&bin.right, in_boolean_position, ctx)
+      }
+      BinaryOp::NullishCoalescing | BinaryOp::In | BinaryOp::InstanceOf => {
+        false
+      }
+      _ => {
+        has_constant_truthiness(&bin.left, false, ctx)
+          && has_constant_truthiness(&bin.right, false, ctx)
+      }
+    },
+    Expr::Assign(assign) if assign.op == AssignOp::Assign => {
+      has_constant_truthiness(&assign.right, in_boolean_position, ctx)
+    }
+    Expr::Seq(seq) => seq.exprs.last().map_or(false, |last| {
+      has_constant_truthiness(last, in_boolean_position, ctx)
+    }),
+    Expr::Ident(_) => is_nullish(expr, ctx.scope()),
+    Expr::Paren(paren) => {
+      has_constant_truthiness(&paren.expr, in_boolean_position, ctx)
+    }
+    _ => false,
+  }
+}
+
+/// Checks if the expression always evaluates to a newly constructed object,
+/// which can't be strictly equal to any other value.
+fn is_always_new(expr: &Expr) -> bool {
+  match expr {
+    Expr::Object(_)
+    | Expr::Array(_)
+    | Expr::Arrow(_)
+    | Expr::Fn(_)
+    | Expr::Class(_)
+    | Expr::New(_)
+    | Expr::Lit(Lit::Regex(_))
+    | Expr::JSXElement(_)
+    | Expr::JSXFragment(_) => true,
+    Expr::Seq(seq) => {
+      seq.exprs.last().map_or(false, |last| is_always_new(last))
+    }
+    Expr::Assign(assign) => {
+      assign.op == AssignOp::Assign && is_always_new(&assign.right)
+    }
+    Expr::Cond(cond) => is_always_new(&cond.cons) && is_always_new(&cond.alt),
+    Expr::Paren(paren) => is_always_new(&paren.expr),
+    _ => false,
+  }
+}
+
+struct NoConstantBinaryExpressionVisitor<'c, 'view> {
+  context: &'c mut Context<'view>,
+}
+
+impl<'c, 'view> NoConstantBinaryExpressionVisitor<'c, 'view> {
+  fn new(context: &'c mut Context<'view>) -> Self {
+    Self { context }
+  }
+
+  /// Finds the side of a comparison with `null` or `undefined` whose
+  /// nullishness is known ahead of time.
+  fn find_constant_nullish_operand(
+    &self,
+    bin_expr: &BinExpr,
+  ) -> Option<&'static str> {
+    let ctx = &*self.context;
+    if is_nullish(&bin_expr.right, ctx.scope())
+      && has_constant_nullishness(&bin_expr.left, ctx)
+    {
+      Some("left")
+    } else if is_nullish(&bin_expr.left, ctx.scope())
+      && has_constant_nullishness(&bin_expr.right, ctx)
+    {
+      Some("right")
+    } else {
+      None
+    }
+  }
+}
+
+impl<'c, 'view> VisitAll for NoConstantBinaryExpressionVisitor<'c, 'view> {
+  noop_visit_type!();
+
+  fn visit_bin_expr(&mut self, bin_expr: &BinExpr, _: &dyn Node) {
+    match bin_expr.op {
+      BinaryOp::LogicalAnd | BinaryOp::LogicalOr => {
+        if has_constant_truthiness(&bin_expr.left, true, self.context) {
+          self.context.add_diagnostic(
+            bin_expr.left.span(),
+            CODE,
+            NoConstantBinaryExpressionMessage::ShortCircuit(
+              "truthiness",
+              bin_expr.op,
+            ),
+          );
+        }
+      }
+      BinaryOp::NullishCoalescing => {
+        if has_constant_nullishness(&bin_expr.left, self.context) {
+          self.context.add_diagnostic(
+            bin_expr.left.span(),
+            CODE,
+            NoConstantBinaryExpressionMessage::ShortCircuit(
+              "nullishness",
+              bin_expr.op,
+            ),
+          );
+        }
+      }
+      BinaryOp::EqEq
+      | BinaryOp::NotEq
+      | BinaryOp::EqEqEq
+      | BinaryOp::NotEqEq => {
+        let strict =
+          matches!(bin_expr.op, BinaryOp::EqEqEq | BinaryOp::NotEqEq);
+        let left_new = is_always_new(&bin_expr.left);
+        let right_new = is_always_new(&bin_expr.right);
+
+        if let Some(side) = self.find_constant_nullish_operand(bin_expr) {
+          self.context.add_diagnostic(
+            bin_expr.span,
+            CODE,
+            NoConstantBinaryExpressionMessage::BinaryOperand(side, bin_expr.op),
+          );
+        } else if left_new && right_new {
+          self.context.add_diagnostic(
+            bin_expr.span,
+            CODE,
+            NoConstantBinaryExpressionMessage::BothAlwaysNew,
+          );
+        } else if strict && (left_new || right_new) {
+          // Loose equality may convert the object to a primitive, e.g.
+          // `[] == ""` is true, so only strict equality is checked here
+          self.context.add_diagnostic(
+            bin_expr.span,
+            CODE,
+            NoConstantBinaryExpressionMessage::AlwaysNew,
+          );
+        }
+      }
+      _ => {}
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v8.11.0/tests/lib/rules/no-constant-binary-expression.js
+  // MIT Licensed.
+
+  #[test]
+  fn no_constant_binary_expression_valid() {
+    assert_lint_ok! {
+      NoConstantBinaryExpression,
+      "bar && foo",
+      "bar || foo",
+      "bar ?? foo",
+      "foo || {}",
+      "foo ?? []",
+      "x === null",
+      "x == undefined",
+      "x === {}.foo",
+      "x === y",
+      "[] == x",
+      "({}) == x",
+      "new Foo() == x",
+      "function f(undefined) { return undefined ?? x; }",
+      "(a = b) ?? c",
+      "(a ||= b) ?? c",
+      "(typeof x) === 'string'",
+      "`${foo}` || bar",
+      "delete foo.bar || baz",
+      "!foo || bar",
+    };
+  }
+
+  #[test]
+  fn no_constant_binary_expression_invalid() {
+    assert_lint_err! {
+      NoConstantBinaryExpression,
+      "[] && greeting": [
+        {
+          col: 0,
+          message: NoConstantBinaryExpressionMessage::ShortCircuit(
+            "truthiness",
+            BinaryOp::LogicalAnd,
+          ),
+        },
+      ],
+      "true || greeting": [
+        {
+          col: 0,
+          message: NoConstantBinaryExpressionMessage::ShortCircuit(
+            "truthiness",
+            BinaryOp::LogicalOr,
+          ),
+        },
+      ],
+      "(() => {}) || foo": [
+        {
+          col: 0,
+          message: NoConstantBinaryExpressionMessage::ShortCircuit(
+            "truthiness",
+            BinaryOp::LogicalOr,
+          ),
+        },
+      ],
+      "!foo ?? bar": [
+        {
+          col: 0,
+          message: NoConstantBinaryExpressionMessage::ShortCircuit(
+            "nullishness",
+            BinaryOp::NullishCoalescing,
+          ),
+        },
+      ],
+      "x = typeof foo && bar": [
+        {
+          col: 4,
+          message: NoConstantBinaryExpressionMessage::ShortCircuit(
+            "truthiness",
+            BinaryOp::LogicalAnd,
+          ),
+        },
+      ],
+      "({}) ?? foo": [
+        {
+          col: 0,
+          message: NoConstantBinaryExpressionMessage::ShortCircuit(
+            "nullishness",
+            BinaryOp::NullishCoalescing,
+          ),
+        },
+      ],
+      "null ?? foo": [
+        {
+          col: 0,
+          message: NoConstantBinaryExpressionMessage::ShortCircuit(
+            "nullishness",
+            BinaryOp::NullishCoalescing,
+          ),
+        },
+      ],
+      "`${foo}` ?? bar": [
+        {
+          col: 0,
+          message: NoConstantBinaryExpressionMessage::ShortCircuit(
+            "nullishness",
+            BinaryOp::NullishCoalescing,
+          ),
+        },
+      ],
+      "!foo == null": [
+        {
+          col: 0,
+          message: NoConstantBinaryExpressionMessage::BinaryOperand(
+            "left",
+            BinaryOp::EqEq,
+          ),
+        },
+      ],
+      "undefined !== a + b": [
+        {
+          col: 0,
+          message: NoConstantBinaryExpressionMessage::BinaryOperand(
+            "right",
+            BinaryOp::NotEqEq,
+          ),
+        },
+      ],
+      "x === {}": [
+        { col: 0, message: NoConstantBinaryExpressionMessage::AlwaysNew },
+      ],
+      "x !== []": [
+        { col: 0, message: NoConstantBinaryExpressionMessage::AlwaysNew },
+      ],
+      "x === /foo/": [
+        { col: 0, message: NoConstantBinaryExpressionMessage::AlwaysNew },
+      ],
+      "new Foo() === x": [
+        { col: 0, message: NoConstantBinaryExpressionMessage::AlwaysNew },
+      ],
+      "x === (y, [])": [
+        { col: 0, message: NoConstantBinaryExpressionMessage::AlwaysNew },
+      ],
+      "x === (cond ? [] : {})": [
+        { col: 0, message: NoConstantBinaryExpressionMessage::AlwaysNew },
+      ],
+      "[] == []": [
+        { col: 0, message: NoConstantBinaryExpressionMessage::BothAlwaysNew },
+      ],
+      "({}) != function () {}": [
+        { col: 0, message: NoConstantBinaryExpressionMessage::BothAlwaysNew },
+      ],
+    };
+  }
+}
@@ -1,9 +1,9 @@
 // Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
 use super::{Context, LintRule, DUMMY_NODE};
+use crate::ast_eq::expr_eq;
 use crate::ProgramRef;
 use deno_ast::swc::ast::{BinExpr, BinaryOp, Expr, IfStmt, ParenExpr, Stmt};
 use deno_ast::swc::common::{Span, Spanned};
-use deno_ast::swc::utils::drop_span;
 use deno_ast::swc::visit::{noop_visit_type, Node, VisitAll, VisitAllWith};
 use derive_more::Display;
 use std::collections::HashSet;
@@ -86,9 +86,8 @@ impl<'c, 'view> VisitAll for NoDupeElseIfVisitor<'c, 'view> {
     // This check is necessary to avoid outputting the same errors multiple times.
     if !self.checked_span.contains(&span) {
       self.checked_span.insert(span);
-      let span_dropped_test = drop_span(if_stmt.test.clone());
-      let mut appeared_conditions: Vec<Vec<Vec<Expr>>> = Vec::new();
-      append_test(&mut appeared_conditions, *span_dropped_test);
+      let mut appeared_conditions: Vec<Vec<Vec<&Expr>>> = Vec::new();
+      append_test(&mut appeared_conditions, &if_stmt.test);
 
       let mut next = if_stmt.alt.as_ref();
       while let Some(cur) = next {
@@ -96,11 +95,9 @@ impl<'c, 'view> VisitAll for NoDupeElseIfVisitor<'c, 'view> {
           ref test, ref alt, ..
         }) = &**cur
         {
-          // preserve the span before dropping
           let span = test.span();
-          let span_dropped_test = drop_span(test.clone());
-          let mut current_condition_to_check: Vec<Vec<Vec<Expr>>> =
-            mk_condition_to_check(*span_dropped_test.clone())
+          let mut current_condition_to_check: Vec<Vec<Vec<&Expr>>> =
+            mk_condition_to_check(test)
               .into_iter()
               .map(split_by_or_then_and)
               .collect();
@@ -135,7 +132,7 @@ impl<'c, 'view> VisitAll for NoDupeElseIfVisitor<'c, 'view> {
           }
 
           self.checked_span.insert(span);
-          append_test(&mut appeared_conditions, *span_dropped_test);
+          append_test(&mut appeared_conditions, test);
           next = alt.as_ref();
         } else {
           break;
@@ -145,105 +142,73 @@ impl<'c, 'view> VisitAll for NoDupeElseIfVisitor<'c, 'view> {
   }
 }
 
-fn mk_condition_to_check(cond: Expr) -> Vec<Expr> {
+fn mk_condition_to_check(cond: &Expr) -> Vec<&Expr> {
   match cond {
-    Expr::Bin(BinExpr { op, .. }) if op == BinaryOp::LogicalAnd => {
-      let mut c = vec![cond.clone()];
+    Expr::Bin(BinExpr { op, .. }) if *op == BinaryOp::LogicalAnd => {
+      let mut c = vec![cond];
       c.append(&mut split_by_and(cond));
       c
     }
-    Expr::Paren(ParenExpr { expr, .. }) => mk_condition_to_check(*expr),
+    Expr::Paren(ParenExpr { expr, .. }) => mk_condition_to_check(expr),
     _ => vec![cond],
   }
 }
 
-fn split_by_bin_op(op_to_split: BinaryOp, expr: Expr) -> Vec<Expr> {
+fn split_by_bin_op(op_to_split: BinaryOp, expr: &Expr) -> Vec<&Expr> {
   match expr {
     Expr::Bin(BinExpr {
       op, left, right, ..
-    }) if op == op_to_split => {
-      let mut ret = split_by_bin_op(op_to_split, *left);
-      ret.append(&mut split_by_bin_op(op_to_split, *right));
+    }) if *op == op_to_split => {
+      let mut ret = split_by_bin_op(op_to_split, left);
+      ret.append(&mut split_by_bin_op(op_to_split, right));
       ret
     }
-    Expr::Paren(ParenExpr { expr, .. }) => split_by_bin_op(op_to_split, *expr),
+    Expr::Paren(ParenExpr { expr, .. }) => split_by_bin_op(op_to_split, expr),
     _ => vec![expr],
   }
 }
 
-fn split_by_or(expr: Expr) -> Vec<Expr> {
+fn split_by_or(expr: &Expr) -> Vec<&Expr> {
   split_by_bin_op(BinaryOp::LogicalOr, expr)
 }
 
-fn split_by_and(expr: Expr) -> Vec<Expr> {
+fn split_by_and(expr: &Expr) -> Vec<&Expr> {
   split_by_bin_op(BinaryOp::LogicalAnd, expr)
 }
 
-fn split_by_or_then_and(expr: Expr) -> Vec<Vec<Expr>> {
+fn split_by_or_then_and(expr: &Expr) -> Vec<Vec<&Expr>> {
   split_by_or(expr).into_iter().map(split_by_and).collect()
 }
 
-fn is_subset(arr_a: &[Expr], arr_b: &[Expr]) -> bool {
+fn is_subset(arr_a: &[&Expr], arr_b: &[&Expr]) -> bool {
   arr_a
     .iter()
     .all(|a| arr_b.iter().any(|b| equal_in_if_else(a, b)))
 }
 
-/// Determines whether the two given `Expr`s are considered to be equal in if-else condition
-/// context. Note that `expr1` and `expr2` must be span-dropped to be compared properly.
+/// Determines whether the two given `Expr`s are considered to be equal in
+/// if-else condition context, where `a || b` is the same as `b || a`.
 fn equal_in_if_else(expr1: &Expr, expr2: &Expr) -> bool {
-  use deno_ast::swc::ast::Expr::*;
   match (expr1, expr2) {
-    (Bin(ref bin1), Bin(ref bin2))
+    (Expr::Bin(bin1), Expr::Bin(bin2))
       if matches!(bin1.op, BinaryOp::LogicalOr | BinaryOp::LogicalAnd)
         && bin1.op == bin2.op =>
     {
-      equal_in_if_else(&*bin1.left, &*bin2.left)
-        && equal_in_if_else(&*bin1.right, &*bin2.right)
-        || equal_in_if_else(&*bin1.left, &*bin2.right)
-          && equal_in_if_else(&*bin1.right, &*bin2.left)
+      equal_in_if_else(&bin1.left, &bin2.left)
+        && equal_in_if_else(&bin1.right, &bin2.right)
+        || equal_in_if_else(&bin1.left, &bin2.right)
+          && equal_in_if_else(&bin1.right, &bin2.left)
     }
-    (Paren(ParenExpr { ref expr, .. }), _) => equal_in_if_else(&**expr, expr2),
-    (_, Paren(ParenExpr { ref expr, .. })) => equal_in_if_else(expr1, &**expr),
-    (This(_), This(_))
-    | (Array(_), Array(_))
-    | (Object(_), Object(_))
-    | (Fn(_), Fn(_))
-    | (Unary(_), Unary(_))
-    | (Update(_), Update(_))
-    | (Bin(_), Bin(_))
-    | (Assign(_), Assign(_))
-    | (Member(_), Member(_))
-    | (Cond(_), Cond(_))
-    | (Call(_), Call(_))
-    | (New(_), New(_))
-    | (Seq(_), Seq(_))
-    | (Ident(_), Ident(_))
-    | (Lit(_), Lit(_))
-    | (Tpl(_), Tpl(_))
-    | (TaggedTpl(_), TaggedTpl(_))
-    | (Arrow(_), Arrow(_))
-    | (Class(_), Class(_))
-    | (Yield(_), Yield(_))
-    | (MetaProp(_), MetaProp(_))
-    | (Await(_), Await(_))
-    | (JSXMember(_), JSXMember(_))
-    | (JSXNamespacedName(_), JSXNamespacedName(_))
-    | (JSXEmpty(_), JSXEmpty(_))
-    | (JSXElement(_), JSXElement(_))
-    | (JSXFragment(_), JSXFragment(_))
-    | (TsTypeAssertion(_), TsTypeAssertion(_))
-    | (TsConstAssertion(_), TsConstAssertion(_))
-    | (TsNonNull(_), TsNonNull(_))
-    | (TsAs(_), TsAs(_))
-    | (PrivateName(_), PrivateName(_))
-    | (OptChain(_), OptChain(_))
-    | (Invalid(_), Invalid(_)) => expr1 == expr2,
-    _ => false,
+    (Expr::Paren(ParenExpr { expr, .. }), _) => equal_in_if_else(expr, expr2),
+    (_, Expr::Paren(ParenExpr { expr, .. })) => equal_in_if_else(expr1, expr),
+    _ => expr_eq(expr1, expr2),
   }
 }
 
-fn append_test(appeared_conditions: &mut Vec<Vec<Vec<Expr>>>, expr: Expr) {
+fn append_test<'a>(
+  appeared_conditions: &mut Vec<Vec<Vec<&'a Expr>>>,
+  expr: &'a Expr,
+) {
   appeared_conditions.push(split_by_or_then_and(expr));
 }
 
@@ -501,6 +466,13 @@ if (a) {
           hint: NoDupeElseIfHint::RemoveOrRework,
         }
       ],
+      "if (a.b) {} else if (a['b']) {}": [
+        {
+          col: 21,
+          message: NoDupeElseIfMessage::Unexpected,
+          hint: NoDupeElseIfHint::RemoveOrRework,
+        }
+      ],
       "if (a ? 1 : 2) {} else if (a ? 1 : 2) {}": [
         {
           col: 27,
@@ -1,13 +1,12 @@
 // Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
 use super::{Context, LintRule, DUMMY_NODE};
+use crate::ast_eq::expr_eq;
 use crate::ProgramRef;
 use deno_ast::swc::ast::{Expr, SwitchStmt};
-use deno_ast::swc::utils::drop_span;
 use deno_ast::swc::visit::noop_visit_type;
 use deno_ast::swc::visit::Node;
 use deno_ast::swc::visit::{VisitAll, VisitAllWith};
 use derive_more::Display;
-use std::collections::HashSet;
 
 #[derive(Debug)]
 pub struct NoDuplicateCase;
@@ -71,19 +70,19 @@ impl<'c, 'view> VisitAll for NoDuplicateCaseVisitor<'c, 'view> {
   noop_visit_type!();
 
   fn visit_switch_stmt(&mut self, switch_stmt: &SwitchStmt, _: &dyn Node) {
-    // Check if there are duplicates by comparing span dropped expressions
-    let mut seen: HashSet<Box<Expr>> = HashSet::new();
+    let mut seen: Vec<&Expr> = Vec::new();
 
     for case in &switch_stmt.cases {
       if let Some(test) = &case.test {
-        let span_dropped_test = drop_span(test.clone());
-        if !seen.insert(span_dropped_test) {
+        if seen.iter().any(|seen_test| expr_eq(seen_test, test)) {
           self.context.add_diagnostic_with_hint(
             case.span,
             CODE,
             NoDuplicateCaseMessage::Unexpected,
             NoDuplicateCaseHint::RemoveOrRename,
           );
+        } else {
+          seen.push(test);
         }
       }
     }
@@ -186,6 +185,27 @@ switch (someText) {
           hint: NoDuplicateCaseHint::RemoveOrRename,
         }
       ],
+      "switch (a) {case p.q: break; case p['q']: break;}": [
+        {
+          col: 29,
+          message: NoDuplicateCaseMessage::Unexpected,
+          hint: NoDuplicateCaseHint::RemoveOrRename,
+        }
+      ],
+      "switch (a) {case 1: break; case 1.0: break;}": [
+        {
+          col: 27,
+          message: NoDuplicateCaseMessage::Unexpected,
+          hint: NoDuplicateCaseHint::RemoveOrRename,
+        }
+      ],
+      "switch (a) {case 'a': break; case \"a\": break;}": [
+        {
+          col: 29,
+          message: NoDuplicateCaseMessage::Unexpected,
+          hint: NoDuplicateCaseHint::RemoveOrRename,
+        }
+      ],
       "var a = 1, f = function(b) { return b ? { p1: 1 } : { p1: 2 }; }; switch (a) {case f(true).p1: break; case f(true).p1: break; default: break;}": [
         {
           col: 102,
@@ -0,0 +1,221 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::ProgramRef;
+use deno_ast::swc::ast::{CallExpr, Expr, ExprOrSuper, Lit};
+use deno_ast::swc::common::Span;
+use deno_ast::swc::visit::{noop_visit_type, Node, Visit, VisitWith};
+use derive_more::Display;
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug, Default)]
+pub struct NoDuplicateStringLiterals {
+  options: NoDuplicateStringLiteralsOptions,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoDuplicateStringLiteralsOptions {
+  /// The number of occurrences of a string which is allowed. Strings occurring
+  /// more often are reported.
+  pub threshold: usize,
+  /// The minimum length of the checked strings. Shorter strings are ignored.
+  pub min_length: usize,
+  /// Strings which are allowed to be duplicated.
+  pub ignore: Vec<String>,
+}
+
+impl Default for NoDuplicateStringLiteralsOptions {
+  fn default() -> Self {
+    Self {
+      threshold: 3,
+      min_length: 2,
+      ignore: Vec::new(),
+    }
+  }
+}
+
+const CODE: &str = "no-duplicate-string-literals";
+const HINT: &str = "Extract the string into a constant";
+
+#[derive(Display)]
+enum NoDuplicateStringLiteralsMessage {
+  #[display(fmt = "The string '{}' is duplicated {} times", _0, _1)]
+  Duplicated(String, usize),
+}
+
+impl NoDuplicateStringLiterals {
+  pub fn with_options(options: NoDuplicateStringLiteralsOptions) -> Box<Self> {
+    Box::new(NoDuplicateStringLiterals { options })
+  }
+}
+
+impl LintRule for NoDuplicateStringLiterals {
+  fn new() -> Box<Self> {
+    Box::new(NoDuplicateStringLiterals::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    let mut collector = StringCollector::default();
+    match program {
+      ProgramRef::Module(m) => m.visit_with(&DUMMY_NODE, &mut collector),
+      ProgramRef::Script(s) => s.visit_with(&DUMMY_NODE, &mut collector),
+    }
+
+    let options = &self.options;
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    let strings: Vec<&(String, Span)> = collector
+      .strings
+      .iter()
+      .filter(|(value, _)| {
+        value.chars().count() >= options.min_length
+          && !options.ignore.contains(value)
+      })
+      .collect();
+    for (value, _) in &strings {
+      *counts.entry(value.as_str()).or_default() += 1;
+    }
+
+    // The first occurrence is where the constant would be declared, so only
+    // the following ones are reported.
+    let mut seen = HashSet::new();
+    for (value, span) in strings {
+      let count = counts[value.as_str()];
+      if count <= options.threshold || seen.insert(value.as_str()) {
+        continue;
+      }
+      context.add_diagnostic_with_hint(
+        *span,
+        CODE,
+        NoDuplicateStringLiteralsMessage::Duplicated(value.clone(), count),
+        HINT,
+      );
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_duplicate_string_literals.md")
+  }
+}
+
+/// Collects the string literals of the program, in source order. The
+/// specifiers of imports and exports are not collected, since they can't be
+/// replaced by constants.
+#[derive(Default)]
+struct StringCollector {
+  strings: Vec<(String, Span)>,
+}
+
+impl Visit for StringCollector {
+  noop_visit_type!();
+
+  fn visit_lit(&mut self, lit: &Lit, _: &dyn Node) {
+    if let Lit::Str(str) = lit {
+      self.strings.push((str.value.to_string(), str.span));
+    }
+  }
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _: &dyn Node) {
+    if let ExprOrSuper::Expr(callee) = &call_expr.callee {
+      // The specifier of dynamic imports
+      if matches!(&**callee, Expr::Ident(ident) if ident.sym == *"import") {
+        return;
+      }
+    }
+    call_expr.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  #[test]
+  fn no_duplicate_string_literals_valid() {
+    assert_lint_ok! {
+      NoDuplicateStringLiterals,
+      "f('foo'); f('foo'); f('foo');",
+      "f('a'); f('a'); f('a'); f('a');",
+      "f('foo'); f('bar'); f('baz'); f('qux');",
+      r#"
+import a from "./mod.ts";
+import b from "./mod.ts";
+export * from "./mod.ts";
+export { c } from "./mod.ts";
+      "#,
+      "const a: 'foo' | 'foo' | 'foo' | 'foo' = 'foo';",
+      "const o = { 'foo': 1, 'foo': 2, 'foo': 3, 'foo': 4 };",
+      "import('foo'); import('foo'); import('foo'); import('foo');",
+    };
+
+    assert_lint_ok! {
+      NoDuplicateStringLiterals,
+      options: json!({ "threshold": 4 }),
+      "f('foo'); f('foo'); f('foo'); f('foo');",
+    };
+
+    assert_lint_ok! {
+      NoDuplicateStringLiterals,
+      options: json!({ "ignore": ["foo"] }),
+      "f('foo'); f('foo'); f('foo'); f('foo');",
+    };
+  }
+
+  #[test]
+  fn no_duplicate_string_literals_invalid() {
+    assert_lint_err! {
+      NoDuplicateStringLiterals,
+      "f('foo'); f('foo'); f(\"foo\"); f(`foo`, 'foo');": [
+        {
+          col: 12,
+          message: NoDuplicateStringLiteralsMessage::Duplicated(
+            "foo".to_string(),
+            4
+          ),
+          hint: HINT,
+        },
+        {
+          col: 22,
+          message: NoDuplicateStringLiteralsMessage::Duplicated(
+            "foo".to_string(),
+            4
+          ),
+          hint: HINT,
+        },
+        {
+          col: 39,
+          message: NoDuplicateStringLiteralsMessage::Duplicated(
+            "foo".to_string(),
+            4
+          ),
+          hint: HINT,
+        },
+      ],
+    };
+
+    assert_lint_err! {
+      NoDuplicateStringLiterals,
+      options: json!({ "threshold": 1, "minLength": 1 }),
+      "const a = 'x'; const b = { c: 'x' };": [
+        {
+          col: 30,
+          message: NoDuplicateStringLiteralsMessage::Duplicated(
+            "x".to_string(),
+            2
+          ),
+          hint: HINT,
+        },
+      ],
+    };
+  }
+}
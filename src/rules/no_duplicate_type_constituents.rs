@@ -0,0 +1,193 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::ProgramRef;
+use deno_ast::swc::ast::{TsType, TsUnionOrIntersectionType};
+use deno_ast::swc::common::{EqIgnoreSpan, Spanned};
+use deno_ast::swc::visit::{Node, Visit, VisitWith};
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct NoDuplicateTypeConstituents;
+
+const CODE: &str = "no-duplicate-type-constituents";
+const HINT: &str = "Remove the duplicated constituent";
+
+#[derive(Display)]
+enum NoDuplicateTypeConstituentsMessage {
+  #[display(fmt = "`{}` is duplicated in the union type", _0)]
+  Union(String),
+  #[display(fmt = "`{}` is duplicated in the intersection type", _0)]
+  Intersection(String),
+}
+
+impl LintRule for NoDuplicateTypeConstituents {
+  fn new() -> Box<Self> {
+    Box::new(NoDuplicateTypeConstituents)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    let mut visitor = NoDuplicateTypeConstituentsVisitor::new(context);
+    match program {
+      ProgramRef::Module(m) => visitor.visit_module(m, &DUMMY_NODE),
+      ProgramRef::Script(s) => visitor.visit_script(s, &DUMMY_NODE),
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_duplicate_type_constituents.md")
+  }
+}
+
+fn unwrap_parens(mut ts_type: &TsType) -> &TsType {
+  while let TsType::TsParenthesizedType(paren) = ts_type {
+    ts_type = &paren.type_ann;
+  }
+  ts_type
+}
+
+struct NoDuplicateTypeConstituentsVisitor<'c, 'view> {
+  context: &'c mut Context<'view>,
+}
+
+impl<'c, 'view> NoDuplicateTypeConstituentsVisitor<'c, 'view> {
+  fn new(context: &'c mut Context<'view>) -> Self {
+    Self { context }
+  }
+}
+
+impl<'c, 'view> Visit for NoDuplicateTypeConstituentsVisitor<'c, 'view> {
+  fn visit_ts_union_or_intersection_type(
+    &mut self,
+    ts_type: &TsUnionOrIntersectionType,
+    _: &dyn Node,
+  ) {
+    ts_type.visit_children_with(self);
+
+    let (types, is_union) = match ts_type {
+      TsUnionOrIntersectionType::TsUnionType(union) => (&union.types, true),
+      TsUnionOrIntersectionType::TsIntersectionType(intersection) => {
+        (&intersection.types, false)
+      }
+    };
+
+    for (i, constituent) in types.iter().enumerate().skip(1) {
+      let is_duplicate = types[..i].iter().any(|previous| {
+        unwrap_parens(previous).eq_ignore_span(unwrap_parens(constituent))
+      });
+      if !is_duplicate {
+        continue;
+      }
+
+      let text = self
+        .context
+        .file_text_substring(&constituent.span())
+        .to_string();
+      let message = if is_union {
+        NoDuplicateTypeConstituentsMessage::Union(text)
+      } else {
+        NoDuplicateTypeConstituentsMessage::Intersection(text)
+      };
+      // Removes the constituent along with the operator before it
+      let removed = constituent.span().with_lo(types[i - 1].span().hi);
+      let fix = self.context.create_fix(removed, "", HINT);
+      self.context.add_diagnostic_with_hint_and_fix(
+        constituent.span(),
+        CODE,
+        message,
+        HINT,
+        fix,
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_duplicate_type_constituents_valid() {
+    assert_lint_ok! {
+      NoDuplicateTypeConstituents,
+      "type T = A | B;",
+      "type T = A & B;",
+      "type T = 'a' | 'b' | 1 | 2;",
+      "type T = A<string> | A<number>;",
+      "type T = { a: string } | { a: number };",
+      "type T = (A | B) | (A & B);",
+      "let a: string | number;",
+      "function f(a: A | B): C & D {}",
+    };
+  }
+
+  #[test]
+  fn no_duplicate_type_constituents_invalid() {
+    assert_lint_err! {
+      NoDuplicateTypeConstituents,
+      "type T = A | A | B;": [
+        {
+          col: 13,
+          message: NoDuplicateTypeConstituentsMessage::Union("A".to_string()),
+          hint: HINT,
+          fix: "type T = A | B;",
+        },
+      ],
+      "type T = A & B & A;": [
+        {
+          col: 17,
+          message:
+            NoDuplicateTypeConstituentsMessage::Intersection("A".to_string()),
+          hint: HINT,
+          fix: "type T = A & B;",
+        },
+      ],
+      "type T = 'a' | \"a\";": [
+        {
+          col: 15,
+          message: NoDuplicateTypeConstituentsMessage::Union(
+            "\"a\"".to_string()
+          ),
+          hint: HINT,
+          fix: "type T = 'a';",
+        },
+      ],
+      "type T = { a: string } | {  a:  string  };": [
+        {
+          col: 25,
+          message: NoDuplicateTypeConstituentsMessage::Union(
+            "{  a:  string  }".to_string()
+          ),
+          hint: HINT,
+          fix: "type T = { a: string };",
+        },
+      ],
+      "let a: (string) | string | number;": [
+        {
+          col: 18,
+          message: NoDuplicateTypeConstituentsMessage::Union(
+            "string".to_string()
+          ),
+          hint: HINT,
+          fix: "let a: (string) | number;",
+        },
+      ],
+      "function f(a: A<B | B>) {}": [
+        {
+          col: 21,
+          message: NoDuplicateTypeConstituentsMessage::Union("B".to_string()),
+          hint: HINT,
+          fix: "function f(a: A<B>) {}",
+        },
+      ],
+    };
+  }
+}
@@ -2,18 +2,35 @@
 use super::{Context, LintRule, DUMMY_NODE};
 use crate::ProgramRef;
 use deno_ast::swc::ast::{
-  ArrowExpr, BlockStmt, BlockStmtOrExpr, Constructor, Function, SwitchStmt,
+  ArrowExpr, BlockStmt, BlockStmtOrExpr, CatchClause, Constructor, Function,
+  SwitchStmt,
 };
 use deno_ast::swc::visit::{noop_visit_type, Node, Visit, VisitWith};
+use serde::Deserialize;
 
-#[derive(Debug)]
-pub struct NoEmpty;
+#[derive(Debug, Default)]
+pub struct NoEmpty {
+  options: NoEmptyOptions,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoEmptyOptions {
+  /// Whether empty `catch` clauses are allowed.
+  pub allow_empty_catch: bool,
+}
 
 const CODE: &str = "no-empty";
 
+impl NoEmpty {
+  pub fn with_options(options: NoEmptyOptions) -> Box<Self> {
+    Box::new(NoEmpty { options })
+  }
+}
+
 impl LintRule for NoEmpty {
   fn new() -> Box<Self> {
-    Box::new(NoEmpty)
+    Box::new(NoEmpty::default())
   }
 
   fn tags(&self) -> &'static [&'static str] {
@@ -29,7 +46,8 @@ impl LintRule for NoEmpty {
     context: &mut Context<'view>,
     program: ProgramRef<'view>,
   ) {
-    let mut visitor = NoEmptyVisitor::new(context);
+    let mut visitor =
+      NoEmptyVisitor::new(context, self.options.allow_empty_catch);
     match program {
       ProgramRef::Module(m) => visitor.visit_module(m, &DUMMY_NODE),
       ProgramRef::Script(s) => visitor.visit_script(s, &DUMMY_NODE),
@@ -44,11 +62,15 @@ impl LintRule for NoEmpty {
 
 struct NoEmptyVisitor<'c, 'view> {
   context: &'c mut Context<'view>,
+  allow_empty_catch: bool,
 }
 
 impl<'c, 'view> NoEmptyVisitor<'c, 'view> {
-  fn new(context: &'c mut Context<'view>) -> Self {
-    Self { context }
+  fn new(context: &'c mut Context<'view>, allow_empty_catch: bool) -> Self {
+    Self {
+      context,
+      allow_empty_catch,
+    }
   }
 }
 
@@ -79,6 +101,14 @@ impl<'c, 'view> Visit for NoEmptyVisitor<'c, 'view> {
     }
   }
 
+  fn visit_catch_clause(&mut self, catch: &CatchClause, _parent: &dyn Node) {
+    if self.allow_empty_catch && catch.body.stmts.is_empty() {
+      catch.param.visit_with(catch, self);
+    } else {
+      catch.visit_children_with(self);
+    }
+  }
+
   fn visit_block_stmt(&mut self, block_stmt: &BlockStmt, _parent: &dyn Node) {
     if block_stmt.stmts.is_empty() {
       if !block_stmt.contains_comments(self.context) {
@@ -122,6 +152,7 @@ impl ContainsComments for BlockStmt {
 #[cfg(test)]
 mod tests {
   use super::*;
+  use serde_json::json;
 
   #[test]
   fn no_empty_valid() {
@@ -177,6 +208,13 @@ try {
 }
       "#,
     };
+
+    assert_lint_ok! {
+      NoEmpty,
+      options: json!({ "allowEmptyCatch": true }),
+      "try { foo(); } catch (e) {}",
+      "try { foo(); } catch {}",
+    };
   }
 
   #[test]
@@ -373,5 +411,31 @@ try {
         }
       ]
     };
+
+    assert_lint_err! {
+      NoEmpty,
+      options: json!({ "allowEmptyCatch": true }),
+      "try { foo(); } catch (e) { if (e) {} }": [
+        {
+          col: 34,
+          message: "Empty block statement",
+          hint: "Add code or comment to the empty block",
+        }
+      ],
+      "try {} catch {}": [
+        {
+          col: 4,
+          message: "Empty block statement",
+          hint: "Add code or comment to the empty block",
+        }
+      ],
+      "try { foo(); } catch {} finally {}": [
+        {
+          col: 32,
+          message: "Empty block statement",
+          hint: "Add code or comment to the empty block",
+        }
+      ]
+    };
   }
 }
@@ -0,0 +1,322 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::{describe_function, StringRepr};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{
+  BlockStmt, BlockStmtOrExpr, MethodKind, ParamOrTsParamProp,
+};
+use deno_ast::view as ast_view;
+use serde::Deserialize;
+
+#[derive(Debug, Default)]
+pub struct NoEmptyFunction {
+  options: NoEmptyFunctionOptions,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoEmptyFunctionOptions {
+  /// Kinds of functions which are allowed to be empty. Supported values are
+  /// `"functions"`, `"arrowFunctions"`, `"methods"`, `"getters"`, `"setters"`
+  /// and `"constructors"`.
+  pub allow: Vec<String>,
+}
+
+const CODE: &str = "no-empty-function";
+const HINT: &str =
+  "Add code, or a comment explaining why the function is intentionally empty";
+
+fn message(name: &str) -> String {
+  format!("Unexpected empty {}", name)
+}
+
+impl NoEmptyFunction {
+  pub fn with_options(options: NoEmptyFunctionOptions) -> Box<Self> {
+    Box::new(NoEmptyFunction { options })
+  }
+}
+
+impl LintRule for NoEmptyFunction {
+  fn new() -> Box<Self> {
+    Box::new(NoEmptyFunction::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoEmptyFunctionHandler::new(&self.options).traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_empty_function.md")
+  }
+}
+
+struct NoEmptyFunctionHandler<'o> {
+  options: &'o NoEmptyFunctionOptions,
+}
+
+impl<'o> NoEmptyFunctionHandler<'o> {
+  fn new(options: &'o NoEmptyFunctionOptions) -> Self {
+    Self { options }
+  }
+
+  /// Reports the body if it has neither statements nor comments. Functions
+  /// without a body, e.g. overload signatures, are ignored.
+  fn check(
+    &self,
+    allow_kind: &str,
+    body: Option<&BlockStmt>,
+    name: impl FnOnce() -> String,
+    ctx: &mut Context,
+  ) {
+    let body = match body {
+      Some(body) => body,
+      None => return,
+    };
+    if !body.stmts.is_empty()
+      || self.options.allow.iter().any(|allow| allow == allow_kind)
+    {
+      return;
+    }
+    if ctx
+      .all_comments()
+      .any(|comment| body.span.contains(comment.span))
+    {
+      return;
+    }
+
+    ctx.add_diagnostic_with_hint(body.span, CODE, message(&name()), HINT);
+  }
+
+  fn check_method(
+    &self,
+    kind: MethodKind,
+    body: Option<&BlockStmt>,
+    name: Option<String>,
+    ctx: &mut Context,
+  ) {
+    let (allow_kind, kind) = match kind {
+      MethodKind::Method => ("methods", "method"),
+      MethodKind::Getter => ("getters", "getter"),
+      MethodKind::Setter => ("setters", "setter"),
+    };
+    self.check(allow_kind, body, || describe_function(kind, name), ctx);
+  }
+}
+
+impl<'o> Handler for NoEmptyFunctionHandler<'o> {
+  fn fn_decl(&mut self, fn_decl: &ast_view::FnDecl, ctx: &mut Context) {
+    self.check(
+      "functions",
+      fn_decl.inner.function.body.as_ref(),
+      || describe_function("function", fn_decl.inner.ident.string_repr()),
+      ctx,
+    );
+  }
+
+  fn fn_expr(&mut self, fn_expr: &ast_view::FnExpr, ctx: &mut Context) {
+    self.check(
+      "functions",
+      fn_expr.inner.function.body.as_ref(),
+      || describe_function("function", fn_expr.inner.ident.string_repr()),
+      ctx,
+    );
+  }
+
+  fn arrow_expr(
+    &mut self,
+    arrow_expr: &ast_view::ArrowExpr,
+    ctx: &mut Context,
+  ) {
+    if let BlockStmtOrExpr::BlockStmt(body) = &arrow_expr.inner.body {
+      self.check(
+        "arrowFunctions",
+        Some(body),
+        || "arrow function".to_string(),
+        ctx,
+      );
+    }
+  }
+
+  fn class_method(
+    &mut self,
+    class_method: &ast_view::ClassMethod,
+    ctx: &mut Context,
+  ) {
+    self.check_method(
+      class_method.inner.kind,
+      class_method.inner.function.body.as_ref(),
+      class_method.inner.key.string_repr(),
+      ctx,
+    );
+  }
+
+  fn private_method(
+    &mut self,
+    private_method: &ast_view::PrivateMethod,
+    ctx: &mut Context,
+  ) {
+    self.check_method(
+      private_method.inner.kind,
+      private_method.inner.function.body.as_ref(),
+      private_method.inner.key.string_repr(),
+      ctx,
+    );
+  }
+
+  fn constructor(
+    &mut self,
+    constructor: &ast_view::Constructor,
+    ctx: &mut Context,
+  ) {
+    // Parameter properties, e.g. `constructor(private foo: string) {}`,
+    // assign the arguments to the instance implicitly
+    if constructor
+      .inner
+      .params
+      .iter()
+      .any(|param| matches!(param, ParamOrTsParamProp::TsParamProp(_)))
+    {
+      return;
+    }
+
+    self.check(
+      "constructors",
+      constructor.inner.body.as_ref(),
+      || "constructor".to_string(),
+      ctx,
+    );
+  }
+
+  fn method_prop(
+    &mut self,
+    method_prop: &ast_view::MethodProp,
+    ctx: &mut Context,
+  ) {
+    self.check_method(
+      MethodKind::Method,
+      method_prop.inner.function.body.as_ref(),
+      method_prop.inner.key.string_repr(),
+      ctx,
+    );
+  }
+
+  fn getter_prop(
+    &mut self,
+    getter_prop: &ast_view::GetterProp,
+    ctx: &mut Context,
+  ) {
+    self.check_method(
+      MethodKind::Getter,
+      getter_prop.inner.body.as_ref(),
+      getter_prop.inner.key.string_repr(),
+      ctx,
+    );
+  }
+
+  fn setter_prop(
+    &mut self,
+    setter_prop: &ast_view::SetterProp,
+    ctx: &mut Context,
+  ) {
+    self.check_method(
+      MethodKind::Setter,
+      setter_prop.inner.body.as_ref(),
+      setter_prop.inner.key.string_repr(),
+      ctx,
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  #[test]
+  fn no_empty_function_valid() {
+    assert_lint_ok! {
+      NoEmptyFunction,
+      "function foo() { bar(); }",
+      "function foo() { /* noop */ }",
+      "function foo() {\n  // intentionally empty\n}",
+      "const foo = () => { /* noop */ };",
+      "const foo = () => bar;",
+      "function foo(): void;",
+      "class A { constructor(private foo: string) {} }",
+      "class A { constructor(readonly foo: string) {} }",
+      "abstract class A { abstract foo(): void; }",
+      "declare function foo(): void;",
+    };
+
+    assert_lint_ok! {
+      NoEmptyFunction,
+      options: json!({
+        "allow": [
+          "functions",
+          "arrowFunctions",
+          "methods",
+          "getters",
+          "setters",
+          "constructors",
+        ],
+      }),
+      "function foo() {}",
+      "const foo = function () {};",
+      "const foo = () => {};",
+      "class A { constructor() {} foo() {} get bar() {} set bar(v) {} }",
+      "const obj = { foo() {}, get bar() {}, set bar(v) {} };",
+    };
+  }
+
+  #[test]
+  fn no_empty_function_invalid() {
+    assert_lint_err! {
+      NoEmptyFunction,
+      "function foo() {}": [
+        { col: 15, message: message("function `foo`"), hint: HINT },
+      ],
+      "const foo = function () {};": [
+        { col: 24, message: message("function"), hint: HINT },
+      ],
+      "const foo = () => {};": [
+        { col: 18, message: message("arrow function"), hint: HINT },
+      ],
+      "class A { constructor() {} }": [
+        { col: 24, message: message("constructor"), hint: HINT },
+      ],
+      "class A { foo() {} #bar() {} }": [
+        { col: 16, message: message("method `foo`"), hint: HINT },
+        { col: 26, message: message("method `bar`"), hint: HINT },
+      ],
+      "class A { get foo() { return 1; } set foo(v) {} }": [
+        { col: 45, message: message("setter `foo`"), hint: HINT },
+      ],
+      "const obj = { foo() {}, get bar() {} };": [
+        { col: 20, message: message("method `foo`"), hint: HINT },
+        { col: 34, message: message("getter `bar`"), hint: HINT },
+      ],
+    };
+
+    assert_lint_err! {
+      NoEmptyFunction,
+      options: json!({ "allow": ["arrowFunctions"] }),
+      "const foo = () => {}; function bar() {}": [
+        { col: 37, message: message("function `bar`"), hint: HINT },
+      ],
+    };
+  }
+}
@@ -0,0 +1,135 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::ProgramRef;
+use deno_ast::swc::ast::StaticBlock;
+use deno_ast::swc::visit::{noop_visit_type, Node, Visit, VisitWith};
+
+#[derive(Debug)]
+pub struct NoEmptyStaticBlock;
+
+const CODE: &str = "no-empty-static-block";
+const MESSAGE: &str = "Empty static blocks are not allowed";
+const HINT: &str = "Remove the empty static block";
+
+impl LintRule for NoEmptyStaticBlock {
+  fn new() -> Box<Self> {
+    Box::new(NoEmptyStaticBlock)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    let mut visitor = NoEmptyStaticBlockVisitor::new(context);
+    match program {
+      ProgramRef::Module(m) => visitor.visit_module(m, &DUMMY_NODE),
+      ProgramRef::Script(s) => visitor.visit_script(s, &DUMMY_NODE),
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_empty_static_block.md")
+  }
+}
+
+struct NoEmptyStaticBlockVisitor<'c, 'view> {
+  context: &'c mut Context<'view>,
+}
+
+impl<'c, 'view> NoEmptyStaticBlockVisitor<'c, 'view> {
+  fn new(context: &'c mut Context<'view>) -> Self {
+    Self { context }
+  }
+}
+
+impl<'c, 'view> Visit for NoEmptyStaticBlockVisitor<'c, 'view> {
+  noop_visit_type!();
+
+  fn visit_static_block(&mut self, static_block: &StaticBlock, _: &dyn Node) {
+    static_block.visit_children_with(self);
+
+    if !static_block.body.stmts.is_empty() {
+      return;
+    }
+    // A comment in the block documents that it is empty on purpose
+    let has_comments = self
+      .context
+      .all_comments()
+      .any(|comment| static_block.span.contains(comment.span));
+    if has_comments {
+      return;
+    }
+
+    let fix = self.context.create_fix(static_block.span, "", HINT);
+    self.context.add_diagnostic_with_hint_and_fix(
+      static_block.span,
+      CODE,
+      MESSAGE,
+      HINT,
+      fix,
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_empty_static_block_valid() {
+    assert_lint_ok! {
+      NoEmptyStaticBlock,
+      "class A { static { init(); } }",
+      "class A { static { /* empty */ } }",
+      "class A { static {\n  // empty\n} }",
+      "class A { static a = 1; }",
+      "class A { static a() {} }",
+      "class A {}",
+    };
+  }
+
+  #[test]
+  fn no_empty_static_block_invalid() {
+    assert_lint_err! {
+      NoEmptyStaticBlock,
+      "class A { static {} }": [
+        {
+          col: 10,
+          message: MESSAGE,
+          hint: HINT,
+          fix: "class A {  }",
+        },
+      ],
+      "class A { static { } a = 1; }": [
+        {
+          col: 10,
+          message: MESSAGE,
+          hint: HINT,
+          fix: "class A {  a = 1; }",
+        },
+      ],
+      "class A { static { init(); } static {} }": [
+        {
+          col: 29,
+          message: MESSAGE,
+          hint: HINT,
+          fix: "class A { static { init(); }  }",
+        },
+      ],
+      "const A = class { m() { class B { static {} } } };": [
+        {
+          col: 34,
+          message: MESSAGE,
+          hint: HINT,
+          fix: "const A = class { m() { class B {  } } };",
+        },
+      ],
+    };
+  }
+}
@@ -5,23 +5,49 @@ use crate::ProgramRef;
 use deno_ast::swc::ast::CallExpr;
 use deno_ast::swc::ast::Expr;
 use deno_ast::swc::ast::ExprOrSuper;
+use deno_ast::swc::ast::Ident;
+use deno_ast::swc::ast::MemberExpr;
 use deno_ast::swc::ast::ParenExpr;
 use deno_ast::swc::ast::VarDeclarator;
 use deno_ast::swc::common::Span;
+use deno_ast::swc::common::Spanned;
+use deno_ast::swc::utils::ident::IdentLike;
 use deno_ast::swc::visit::noop_visit_type;
 use deno_ast::swc::visit::Node;
 use deno_ast::swc::visit::Visit;
+use deno_ast::swc::visit::VisitWith;
+use serde::Deserialize;
 
-#[derive(Debug)]
-pub struct NoEval;
+#[derive(Debug, Default)]
+pub struct NoEval {
+  options: NoEvalOptions,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoEvalOptions {
+  /// Whether indirect calls of `eval`, like `(0, eval)('x')` or
+  /// `globalThis.eval('x')`, are allowed.
+  pub allow_indirect: bool,
+}
 
 const CODE: &str = "no-eval";
-const MESSAGE: &str = "`eval` call is not allowed";
+const MESSAGE: &str =
+  "`eval` runs arbitrary code, which is a security risk and is not allowed";
 const HINT: &str = "Remove the use of `eval`";
 
+/// Names of the global object, whose `eval` property is the global `eval`
+const GLOBAL_OBJECTS: &[&str] = &["globalThis", "window", "self"];
+
+impl NoEval {
+  pub fn with_options(options: NoEvalOptions) -> Box<Self> {
+    Box::new(NoEval { options })
+  }
+}
+
 impl LintRule for NoEval {
   fn new() -> Box<Self> {
-    Box::new(NoEval)
+    Box::new(NoEval::default())
   }
 
   fn code(&self) -> &'static str {
@@ -33,7 +59,7 @@ impl LintRule for NoEval {
     context: &mut Context<'view>,
     program: ProgramRef<'view>,
   ) {
-    let mut visitor = NoEvalVisitor::new(context);
+    let mut visitor = NoEvalVisitor::new(context, &self.options);
     match program {
       ProgramRef::Module(m) => visitor.visit_module(m, &DUMMY_NODE),
       ProgramRef::Script(s) => visitor.visit_script(s, &DUMMY_NODE),
@@ -46,18 +72,65 @@ impl LintRule for NoEval {
   }
 }
 
-struct NoEvalVisitor<'c, 'view> {
+struct NoEvalVisitor<'c, 'view, 'o> {
   context: &'c mut Context<'view>,
+  options: &'o NoEvalOptions,
 }
 
-impl<'c, 'view> NoEvalVisitor<'c, 'view> {
-  fn new(context: &'c mut Context<'view>) -> Self {
-    Self { context }
+impl<'c, 'view, 'o> NoEvalVisitor<'c, 'view, 'o> {
+  fn new(context: &'c mut Context<'view>, options: &'o NoEvalOptions) -> Self {
+    Self { context, options }
   }
 
-  fn maybe_add_diagnostic(&mut self, source: &dyn StringRepr, span: Span) {
-    if source.string_repr().as_deref() == Some("eval") {
-      self.add_diagnostic(span);
+  /// Checks whether `ident` refers to a global variable named `name`, i.e. it
+  /// isn't shadowed by a local binding.
+  fn is_global_ident(&self, ident: &Ident, name: &str) -> bool {
+    ident.sym == *name && self.context.scope().is_global(&ident.to_id())
+  }
+
+  /// Checks whether `expr` is the global object, like `window` or
+  /// `globalThis.globalThis`.
+  fn is_global_object(&self, expr: &Expr) -> bool {
+    match expr {
+      Expr::Ident(ident) => GLOBAL_OBJECTS
+        .iter()
+        .any(|name| self.is_global_ident(ident, name)),
+      Expr::Member(member) => {
+        let is_global_prop = member
+          .string_repr()
+          .map_or(false, |prop| GLOBAL_OBJECTS.contains(&prop.as_str()));
+        is_global_prop && self.is_global_member_obj(member)
+      }
+      Expr::Paren(paren) => self.is_global_object(&paren.expr),
+      _ => false,
+    }
+  }
+
+  fn is_global_member_obj(&self, member: &MemberExpr) -> bool {
+    match &member.obj {
+      ExprOrSuper::Expr(obj) => self.is_global_object(obj),
+      ExprOrSuper::Super(_) => false,
+    }
+  }
+
+  /// Checks whether `expr` refers to `eval` through the global object, like
+  /// `window.eval` or `globalThis['eval']`.
+  fn is_eval_member(&self, expr: &Expr) -> bool {
+    match expr {
+      Expr::Member(member) => {
+        member.string_repr().as_deref() == Some("eval")
+          && self.is_global_member_obj(member)
+      }
+      Expr::Paren(paren) => self.is_eval_member(&paren.expr),
+      _ => false,
+    }
+  }
+
+  /// Checks whether `expr` refers to the global `eval` in any way.
+  fn is_eval(&self, expr: &Expr) -> bool {
+    match expr {
+      Expr::Ident(ident) => self.is_global_ident(ident, "eval"),
+      _ => self.is_eval_member(expr),
     }
   }
 
@@ -67,57 +140,97 @@ impl<'c, 'view> NoEvalVisitor<'c, 'view> {
       .add_diagnostic_with_hint(span, CODE, MESSAGE, HINT);
   }
 
+  fn add_indirect_diagnostic(&mut self, span: Span) {
+    if !self.options.allow_indirect {
+      self.add_diagnostic(span);
+    }
+  }
+
   fn handle_paren_callee(&mut self, p: &ParenExpr) {
     match p.expr.as_ref() {
       // Nested paren callee ((eval))('var foo = 0;')
       Expr::Paren(paren) => self.handle_paren_callee(paren),
-      // Single argument callee: (eval)('var foo = 0;')
-      Expr::Ident(ident) => self.maybe_add_diagnostic(ident, ident.span),
+      // Single argument callee: (eval)('var foo = 0;'), which is still a
+      // direct call
+      Expr::Ident(ident) => {
+        if self.is_global_ident(ident, "eval") {
+          self.add_diagnostic(ident.span);
+        }
+      }
       // Multiple arguments callee: (0, eval)('var foo = 0;')
       Expr::Seq(seq) => {
         for expr in &seq.exprs {
-          if let Expr::Ident(ident) = expr.as_ref() {
-            self.maybe_add_diagnostic(ident, ident.span)
+          if self.is_eval(expr) {
+            self.add_indirect_diagnostic(expr.span());
           }
         }
       }
-      _ => {}
+      expr => {
+        if self.is_eval_member(expr) {
+          self.add_indirect_diagnostic(expr.span());
+        }
+      }
     }
   }
 }
 
-impl<'c, 'view> Visit for NoEvalVisitor<'c, 'view> {
+impl<'c, 'view, 'o> Visit for NoEvalVisitor<'c, 'view, 'o> {
   noop_visit_type!();
 
   fn visit_var_declarator(&mut self, v: &VarDeclarator, _: &dyn Node) {
     if let Some(expr) = &v.init {
-      if let Expr::Ident(ident) = expr.as_ref() {
-        self.maybe_add_diagnostic(ident, v.span);
+      if self.is_eval(expr) {
+        self.add_indirect_diagnostic(v.span);
       }
     }
+    v.visit_children_with(self);
   }
 
   fn visit_call_expr(&mut self, call_expr: &CallExpr, _parent: &dyn Node) {
     if let ExprOrSuper::Expr(expr) = &call_expr.callee {
       match expr.as_ref() {
-        Expr::Ident(ident) => self.maybe_add_diagnostic(ident, call_expr.span),
+        Expr::Ident(ident) => {
+          if self.is_global_ident(ident, "eval") {
+            self.add_diagnostic(call_expr.span);
+          }
+        }
         Expr::Paren(paren) => self.handle_paren_callee(paren),
-        _ => {}
+        expr => {
+          if self.is_eval_member(expr) {
+            self.add_indirect_diagnostic(call_expr.span);
+          }
+        }
       }
     }
+    call_expr.visit_children_with(self);
   }
 }
 
 #[cfg(test)]
 mod tests {
   use super::*;
+  use serde_json::json;
 
   #[test]
   fn no_eval_valid() {
     assert_lint_ok! {
       NoEval,
       "foo.eval('bar');",
-    }
+      "function f(eval) { eval('foo'); }",
+      "const eval = (s) => s; eval('foo');",
+      "function f(window) { window.eval('foo'); }",
+      "foo.window.eval('foo');",
+      "window.foo('eval');",
+    };
+
+    assert_lint_ok! {
+      NoEval,
+      options: json!({ "allowIndirect": true }),
+      "(0, eval)('foo');",
+      "window.eval('foo');",
+      "globalThis['eval']('foo');",
+      "var foo = eval;",
+    };
   }
 
   #[test]
@@ -128,25 +241,34 @@ mod tests {
       "(0, eval)('var a = 0');": [{col: 4, message: MESSAGE, hint: HINT}],
       "((eval))('var a = 0');": [{col: 2, message: MESSAGE, hint: HINT}],
       "var foo = eval;": [{col: 4, message: MESSAGE, hint: HINT}],
+      "var foo = eval('1');": [{col: 10, message: MESSAGE, hint: HINT}],
+      "foo(eval('1'));": [{col: 4, message: MESSAGE, hint: HINT}],
+      "window.eval('foo');": [{col: 0, message: MESSAGE, hint: HINT}],
+      "window.window['eval']('foo');": [{col: 0, message: MESSAGE, hint: HINT}],
+      "globalThis.eval('foo');": [{col: 0, message: MESSAGE, hint: HINT}],
+      "self.eval('foo');": [{col: 0, message: MESSAGE, hint: HINT}],
+      "(0, window.eval)('foo');": [{col: 4, message: MESSAGE, hint: HINT}],
+      "(0, globalThis['eval'])('foo');": [{col: 4, message: MESSAGE, hint: HINT}],
+      "(window.eval)('foo');": [{col: 1, message: MESSAGE, hint: HINT}],
+      "var foo = globalThis.eval;": [{col: 4, message: MESSAGE, hint: HINT}],
+      "var foo = window.eval;": [{col: 4, message: MESSAGE, hint: HINT}],
+      "window.window.eval('foo');": [{col: 0, message: MESSAGE, hint: HINT}],
+      "(0, window['eval'])('foo');": [{col: 4, message: MESSAGE, hint: HINT}],
+      "globalThis.globalThis.eval('foo');": [{col: 0, message: MESSAGE, hint: HINT}],
+      "globalThis.globalThis['eval']('foo');": [{col: 0, message: MESSAGE, hint: HINT}],
+      "(0, globalThis.eval)('foo');": [{col: 4, message: MESSAGE, hint: HINT}],
 
       // TODO (see: https://github.com/denoland/deno_lint/pull/490)
       // "this.eval("123");": [{col: 0, message: MESSAGE, hint: HINT}],
       // "var foo = this.eval;": [{col: 0, message: MESSAGE, hint: HINT}],
       // "(function(exe){ exe('foo') })(eval);": [{col: 0, message: MESSAGE, hint: HINT}],
-      //
-      // "(0, window.eval)('foo');": [{col: 0, message: MESSAGE, hint: HINT}],
-      // "(0, window['eval'])('foo');": [{col: 0, message: MESSAGE, hint: HINT}],
-      // "var foo = window.eval;": [{col: 0, message: MESSAGE, hint: HINT}],
-      // "window.eval('foo');": [{col: 0, message: MESSAGE, hint: HINT}],
-      // "window.window.eval('foo');": [{col: 0, message: MESSAGE, hint: HINT}],
-      // "window.window['eval']('foo');": [{col: 0, message: MESSAGE, hint: HINT}],
-      //
-      // "var foo = globalThis.eval;": [{col: 0, message: MESSAGE, hint: HINT}],
-      // "globalThis.eval('foo')": [{col: 0, message: MESSAGE, hint: HINT}],
-      // "globalThis.globalThis.eval('foo')": [{col: 0, message: MESSAGE, hint: HINT}],
-      // "globalThis.globalThis['eval']('foo')": [{col: 0, message: MESSAGE, hint: HINT}],
-      // "(0, globalThis.eval)('foo')": [{col: 0, message: MESSAGE, hint: HINT}],
-      // "(0, globalThis['eval'])('foo')": [{col: 0, message: MESSAGE, hint: HINT}],
-    }
+    };
+
+    assert_lint_err! {
+      NoEval,
+      options: json!({ "allowIndirect": true }),
+      "eval('123');": [{col: 0, message: MESSAGE, hint: HINT}],
+      "((eval))('var a = 0');": [{col: 2, message: MESSAGE, hint: HINT}],
+    };
   }
 }
@@ -0,0 +1,175 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::swc_util::StringRepr;
+use crate::ProgramRef;
+use deno_ast::swc::ast::{
+  CallExpr, Class, Expr, ExprOrSuper, Function, ThisExpr,
+};
+use deno_ast::swc::visit::{noop_visit_type, Node, Visit, VisitWith};
+
+#[derive(Debug)]
+pub struct NoExtraBind;
+
+const CODE: &str = "no-extra-bind";
+const MESSAGE: &str =
+  "`bind` is unnecessary since the function doesn't use `this`";
+const HINT: &str = "Remove the `bind` call";
+
+impl LintRule for NoExtraBind {
+  fn new() -> Box<Self> {
+    Box::new(NoExtraBind)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    let mut visitor = NoExtraBindVisitor::new(context);
+    match program {
+      ProgramRef::Module(m) => visitor.visit_module(m, &DUMMY_NODE),
+      ProgramRef::Script(s) => visitor.visit_script(s, &DUMMY_NODE),
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_extra_bind.md")
+  }
+}
+
+/// Checks whether the function bound to `this` by a `bind` call uses `this`.
+/// Arrow functions never do, since they take `this` from the enclosing scope.
+fn uses_this(expr: &Expr) -> bool {
+  match expr {
+    Expr::Fn(fn_expr) => {
+      let mut finder = ThisFinder { found: false };
+      let function = &fn_expr.function;
+      function.params.visit_with(function, &mut finder);
+      function.body.visit_with(function, &mut finder);
+      finder.found
+    }
+    Expr::Arrow(_) => false,
+    Expr::Paren(paren) => uses_this(&paren.expr),
+    _ => true,
+  }
+}
+
+struct ThisFinder {
+  found: bool,
+}
+
+impl Visit for ThisFinder {
+  noop_visit_type!();
+
+  fn visit_this_expr(&mut self, _: &ThisExpr, _: &dyn Node) {
+    self.found = true;
+  }
+
+  // Nested functions and classes have their own `this`
+  fn visit_function(&mut self, _: &Function, _: &dyn Node) {}
+
+  fn visit_class(&mut self, class: &Class, _: &dyn Node) {
+    class.super_class.visit_with(class, self);
+  }
+}
+
+struct NoExtraBindVisitor<'c, 'view> {
+  context: &'c mut Context<'view>,
+}
+
+impl<'c, 'view> NoExtraBindVisitor<'c, 'view> {
+  fn new(context: &'c mut Context<'view>) -> Self {
+    Self { context }
+  }
+}
+
+impl<'c, 'view> Visit for NoExtraBindVisitor<'c, 'view> {
+  noop_visit_type!();
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _: &dyn Node) {
+    call_expr.visit_children_with(self);
+
+    let member = match &call_expr.callee {
+      ExprOrSuper::Expr(callee) => match callee.as_ref() {
+        Expr::Member(member) => member,
+        _ => return,
+      },
+      ExprOrSuper::Super(_) => return,
+    };
+    if member.string_repr().as_deref() != Some("bind") {
+      return;
+    }
+    // Additional arguments are bound as parameters, which is not useless
+    if call_expr.args.len() != 1 || call_expr.args[0].spread.is_some() {
+      return;
+    }
+
+    let bound = match &member.obj {
+      ExprOrSuper::Expr(obj) => obj,
+      ExprOrSuper::Super(_) => return,
+    };
+    if !uses_this(bound) {
+      self.context.add_diagnostic_with_hint(
+        call_expr.span,
+        CODE,
+        MESSAGE,
+        HINT,
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_extra_bind_valid() {
+    assert_lint_ok! {
+      NoExtraBind,
+      "const a = function () { this.foo(); }.bind(b);",
+      "const a = (function () { return this.foo; }).bind(b);",
+      "const a = function () { return () => this; }.bind(b);",
+      "const a = function (x = this.x) {}.bind(b);",
+      "const a = function (x) { return x; }.bind(b, 1);",
+      "const a = function () {}.bind(...args);",
+      "const a = f.bind(b);",
+      "const a = function () {}.call(b);",
+      "const a = function () {}.bind();",
+      "const a = function () { class A extends this.B {} }.bind(b);",
+    };
+  }
+
+  #[test]
+  fn no_extra_bind_invalid() {
+    assert_lint_err! {
+      NoExtraBind,
+      "const a = function () { foo(); }.bind(b);": [
+        { col: 10, message: MESSAGE, hint: HINT },
+      ],
+      "const a = (function () { return 1; }).bind(b);": [
+        { col: 10, message: MESSAGE, hint: HINT },
+      ],
+      "const a = (() => this).bind(b);": [
+        { col: 10, message: MESSAGE, hint: HINT },
+      ],
+      "const a = function () { function f() { this.foo(); } }.bind(b);": [
+        { col: 10, message: MESSAGE, hint: HINT },
+      ],
+      "const a = function () { class A { f() { this.x; } } }.bind(b);": [
+        { col: 10, message: MESSAGE, hint: HINT },
+      ],
+      "const a = function () {}['bind'](b);": [
+        { col: 10, message: MESSAGE, hint: HINT },
+      ],
+      "foo(function () {}.bind(this));": [
+        { col: 4, message: MESSAGE, hint: HINT },
+      ],
+    };
+  }
+}
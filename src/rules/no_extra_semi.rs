@@ -5,11 +5,31 @@ use deno_ast::swc::ast::{
   DoWhileStmt, EmptyStmt, ForInStmt, ForOfStmt, ForStmt, IfStmt, LabeledStmt,
   Stmt, WhileStmt, WithStmt,
 };
+use deno_ast::swc::common::Span;
 use deno_ast::swc::visit::{noop_visit_type, Node, Visit, VisitWith};
 use derive_more::Display;
+use serde::Deserialize;
 
-#[derive(Debug)]
-pub struct NoExtraSemi;
+#[derive(Debug, Default)]
+pub struct NoExtraSemi {
+  options: NoExtraSemiOptions,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoExtraSemiOptions {
+  /// Whether an empty statement is allowed as the body of a loop, like
+  /// `for (;;);`.
+  pub allow_empty_loop_body: bool,
+}
+
+impl Default for NoExtraSemiOptions {
+  fn default() -> Self {
+    Self {
+      allow_empty_loop_body: true,
+    }
+  }
+}
 
 const CODE: &str = "no-extra-semi";
 
@@ -17,17 +37,27 @@ const CODE: &str = "no-extra-semi";
 enum NoExtraSemiMessage {
   #[display(fmt = "Unnecessary semicolon.")]
   Unnecessary,
+  #[display(fmt = "Empty statement used as a loop body.")]
+  EmptyLoopBody,
 }
 
 #[derive(Display)]
 enum NoExtraSemiHint {
   #[display(fmt = "Remove the extra (and unnecessary) semi-colon")]
   Remove,
+  #[display(fmt = "Use an empty block `{{}}` as the loop body")]
+  EmptyBlock,
+}
+
+impl NoExtraSemi {
+  pub fn with_options(options: NoExtraSemiOptions) -> Box<Self> {
+    Box::new(NoExtraSemi { options })
+  }
 }
 
 impl LintRule for NoExtraSemi {
   fn new() -> Box<Self> {
-    Box::new(NoExtraSemi)
+    Box::new(NoExtraSemi::default())
   }
 
   fn tags(&self) -> &'static [&'static str] {
@@ -43,7 +73,7 @@ impl LintRule for NoExtraSemi {
     context: &mut Context<'view>,
     program: ProgramRef<'view>,
   ) {
-    let mut visitor = NoExtraSemiVisitor::new(context);
+    let mut visitor = NoExtraSemiVisitor::new(context, &self.options);
     match program {
       ProgramRef::Module(m) => m.visit_with(&DUMMY_NODE, &mut visitor),
       ProgramRef::Script(s) => s.visit_with(&DUMMY_NODE, &mut visitor),
@@ -56,58 +86,85 @@ impl LintRule for NoExtraSemi {
   }
 }
 
-struct NoExtraSemiVisitor<'c, 'view> {
+struct NoExtraSemiVisitor<'c, 'view, 'o> {
   context: &'c mut Context<'view>,
+  options: &'o NoExtraSemiOptions,
 }
 
-impl<'c, 'view> NoExtraSemiVisitor<'c, 'view> {
-  fn new(context: &'c mut Context<'view>) -> Self {
-    Self { context }
+impl<'c, 'view, 'o> NoExtraSemiVisitor<'c, 'view, 'o> {
+  fn new(
+    context: &'c mut Context<'view>,
+    options: &'o NoExtraSemiOptions,
+  ) -> Self {
+    Self { context, options }
+  }
+
+  fn handle_loop_body(&mut self, body: &Stmt, parent: &dyn Node) {
+    match body {
+      Stmt::Empty(empty_stmt) => {
+        if !self.options.allow_empty_loop_body {
+          self.report_empty_loop_body(empty_stmt.span);
+        }
+      }
+      body => body.visit_with(parent, self),
+    }
+  }
+
+  fn report_empty_loop_body(&mut self, span: Span) {
+    // The body can't be removed, so it's replaced with an empty block
+    let fix = self.context.create_fix(
+      span,
+      "{}",
+      "Replace the semicolon with an empty block",
+    );
+    self.context.add_diagnostic_with_hint_and_fix(
+      span,
+      CODE,
+      NoExtraSemiMessage::EmptyLoopBody,
+      NoExtraSemiHint::EmptyBlock,
+      fix,
+    );
   }
 }
 
-impl<'c, 'view> Visit for NoExtraSemiVisitor<'c, 'view> {
+impl<'c, 'view, 'o> Visit for NoExtraSemiVisitor<'c, 'view, 'o> {
   noop_visit_type!();
 
   fn visit_empty_stmt(&mut self, empty_stmt: &EmptyStmt, _parent: &dyn Node) {
-    self.context.add_diagnostic_with_hint(
+    let fix =
+      self
+        .context
+        .create_fix(empty_stmt.span, "", "Remove the semicolon");
+    self.context.add_diagnostic_with_hint_and_fix(
       empty_stmt.span,
       CODE,
       NoExtraSemiMessage::Unnecessary,
       NoExtraSemiHint::Remove,
+      fix,
     );
   }
 
   fn visit_for_stmt(&mut self, for_stmt: &ForStmt, _: &dyn Node) {
-    if matches!(&*for_stmt.body, Stmt::Empty(_)) {
-      if let Some(ref init) = for_stmt.init {
-        init.visit_with(for_stmt, self);
-      }
-      if let Some(ref test) = for_stmt.test {
-        test.visit_with(for_stmt, self);
-      }
-      if let Some(ref update) = for_stmt.update {
-        update.visit_with(for_stmt, self);
-      }
-    } else {
-      for_stmt.visit_children_with(self);
+    if let Some(ref init) = for_stmt.init {
+      init.visit_with(for_stmt, self);
+    }
+    if let Some(ref test) = for_stmt.test {
+      test.visit_with(for_stmt, self);
     }
+    if let Some(ref update) = for_stmt.update {
+      update.visit_with(for_stmt, self);
+    }
+    self.handle_loop_body(&for_stmt.body, for_stmt);
   }
 
   fn visit_while_stmt(&mut self, while_stmt: &WhileStmt, _: &dyn Node) {
-    if matches!(&*while_stmt.body, Stmt::Empty(_)) {
-      while_stmt.test.visit_with(while_stmt, self);
-    } else {
-      while_stmt.visit_children_with(self);
-    }
+    while_stmt.test.visit_with(while_stmt, self);
+    self.handle_loop_body(&while_stmt.body, while_stmt);
   }
 
   fn visit_do_while_stmt(&mut self, do_while_stmt: &DoWhileStmt, _: &dyn Node) {
-    if matches!(&*do_while_stmt.body, Stmt::Empty(_)) {
-      do_while_stmt.test.visit_with(do_while_stmt, self);
-    } else {
-      do_while_stmt.visit_children_with(self);
-    }
+    self.handle_loop_body(&do_while_stmt.body, do_while_stmt);
+    do_while_stmt.test.visit_with(do_while_stmt, self);
   }
 
   fn visit_with_stmt(&mut self, with_stmt: &WithStmt, _: &dyn Node) {
@@ -119,21 +176,15 @@ impl<'c, 'view> Visit for NoExtraSemiVisitor<'c, 'view> {
   }
 
   fn visit_for_of_stmt(&mut self, for_of_stmt: &ForOfStmt, _: &dyn Node) {
-    if matches!(&*for_of_stmt.body, Stmt::Empty(_)) {
-      for_of_stmt.left.visit_with(for_of_stmt, self);
-      for_of_stmt.right.visit_with(for_of_stmt, self);
-    } else {
-      for_of_stmt.visit_children_with(self);
-    }
+    for_of_stmt.left.visit_with(for_of_stmt, self);
+    for_of_stmt.right.visit_with(for_of_stmt, self);
+    self.handle_loop_body(&for_of_stmt.body, for_of_stmt);
   }
 
   fn visit_for_in_stmt(&mut self, for_in_stmt: &ForInStmt, _: &dyn Node) {
-    if matches!(&*for_in_stmt.body, Stmt::Empty(_)) {
-      for_in_stmt.left.visit_with(for_in_stmt, self);
-      for_in_stmt.right.visit_with(for_in_stmt, self);
-    } else {
-      for_in_stmt.visit_children_with(self);
-    }
+    for_in_stmt.left.visit_with(for_in_stmt, self);
+    for_in_stmt.right.visit_with(for_in_stmt, self);
+    self.handle_loop_body(&for_in_stmt.body, for_in_stmt);
   }
 
   fn visit_if_stmt(&mut self, if_stmt: &IfStmt, _: &dyn Node) {
@@ -166,6 +217,7 @@ impl<'c, 'view> Visit for NoExtraSemiVisitor<'c, 'view> {
 #[cfg(test)]
 mod tests {
   use super::*;
+  use serde_json::json;
 
   #[test]
   fn no_extra_semi_valid() {
@@ -200,6 +252,7 @@ mod tests {
           col: 10,
           message: NoExtraSemiMessage::Unnecessary,
           hint: NoExtraSemiHint::Remove,
+          fix: "var x = 5;",
         }
       ],
       "function foo(){};": [
@@ -296,6 +349,7 @@ mod tests {
           col: 10,
           message: NoExtraSemiMessage::Unnecessary,
           hint: NoExtraSemiHint::Remove,
+          fix: "class A {  }",
         }
       ],
       "class A { /*a*/; }": [
@@ -474,4 +528,66 @@ class A {
       ]
     };
   }
+  #[test]
+  fn no_extra_semi_empty_loop_body() {
+    assert_lint_ok! {
+      NoExtraSemi,
+      options: json!({ "allowEmptyLoopBody": false }),
+      "for(;;) {}",
+      "while(0) {}",
+      "if(true);",
+      "foo: ;",
+    };
+
+    assert_lint_err! {
+      NoExtraSemi,
+      options: json!({ "allowEmptyLoopBody": false }),
+      "for(;;);": [
+        {
+          col: 7,
+          message: NoExtraSemiMessage::EmptyLoopBody,
+          hint: NoExtraSemiHint::EmptyBlock,
+          fix: "for(;;){}",
+        }
+      ],
+      "while(0);": [
+        {
+          col: 8,
+          message: NoExtraSemiMessage::EmptyLoopBody,
+          hint: NoExtraSemiHint::EmptyBlock,
+          fix: "while(0){}",
+        }
+      ],
+      "do;while(0);": [
+        {
+          col: 2,
+          message: NoExtraSemiMessage::EmptyLoopBody,
+          hint: NoExtraSemiHint::EmptyBlock,
+          fix: "do{}while(0);",
+        }
+      ],
+      "for(a of b);;": [
+        {
+          col: 11,
+          message: NoExtraSemiMessage::EmptyLoopBody,
+          hint: NoExtraSemiHint::EmptyBlock,
+          fix: "for(a of b){};",
+        },
+        {
+          col: 12,
+          message: NoExtraSemiMessage::Unnecessary,
+          hint: NoExtraSemiHint::Remove,
+          fix: "for(a of b);",
+        }
+      ],
+      "for(a in b);": [
+        {
+          col: 11,
+          message: NoExtraSemiMessage::EmptyLoopBody,
+          hint: NoExtraSemiHint::EmptyBlock,
+          fix: "for(a in b){}",
+        }
+      ],
+    };
+  }
 }
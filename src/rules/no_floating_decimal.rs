@@ -0,0 +1,203 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::ProgramRef;
+use deno_ast::swc::ast::Number;
+use deno_ast::swc::visit::noop_visit_type;
+use deno_ast::swc::visit::Node;
+use deno_ast::swc::visit::Visit;
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct NoFloatingDecimal;
+
+const CODE: &str = "no-floating-decimal";
+
+#[derive(Display)]
+enum NoFloatingDecimalMessage {
+  #[display(fmt = "Numeric literals shouldn't begin with a decimal point")]
+  Leading,
+  #[display(fmt = "Numeric literals shouldn't end with a decimal point")]
+  Trailing,
+}
+
+#[derive(Display)]
+enum NoFloatingDecimalHint {
+  #[display(fmt = "Add a zero before the decimal point")]
+  Leading,
+  #[display(fmt = "Add a zero after the decimal point")]
+  Trailing,
+}
+
+impl LintRule for NoFloatingDecimal {
+  fn new() -> Box<Self> {
+    Box::new(NoFloatingDecimal)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    let mut visitor = NoFloatingDecimalVisitor::new(context);
+    match program {
+      ProgramRef::Module(m) => visitor.visit_module(m, &DUMMY_NODE),
+      ProgramRef::Script(s) => visitor.visit_script(s, &DUMMY_NODE),
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_floating_decimal.md")
+  }
+}
+
+struct NoFloatingDecimalVisitor<'c, 'view> {
+  context: &'c mut Context<'view>,
+}
+
+impl<'c, 'view> NoFloatingDecimalVisitor<'c, 'view> {
+  fn new(context: &'c mut Context<'view>) -> Self {
+    Self { context }
+  }
+}
+
+impl<'c, 'view> Visit for NoFloatingDecimalVisitor<'c, 'view> {
+  noop_visit_type!();
+
+  fn visit_number(&mut self, number: &Number, _: &dyn Node) {
+    // The parsed value doesn't keep the formatting, so the source text is
+    // checked instead
+    let raw = self.context.file_text_substring(&number.span);
+
+    let (message, hint, fixed) = if raw.starts_with('.') {
+      // `return.5` needs a space to become `return 0.5`
+      let text = self.context.source_file().text();
+      let preceded_by_word = text[..number.span.lo.0 as usize]
+        .chars()
+        .next_back()
+        .map_or(false, |c| c.is_alphanumeric() || c == '_' || c == '$');
+      let space = if preceded_by_word { " " } else { "" };
+      (
+        NoFloatingDecimalMessage::Leading,
+        NoFloatingDecimalHint::Leading,
+        format!("{}0{}", space, raw),
+      )
+    } else if raw.ends_with('.') {
+      (
+        NoFloatingDecimalMessage::Trailing,
+        NoFloatingDecimalHint::Trailing,
+        format!("{}0", raw),
+      )
+    } else {
+      return;
+    };
+
+    let fix = self
+      .context
+      .create_fix(number.span, fixed, hint.to_string());
+    self.context.add_diagnostic_with_hint_and_fix(
+      number.span,
+      CODE,
+      message,
+      hint,
+      fix,
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_floating_decimal_valid() {
+    assert_lint_ok! {
+      NoFloatingDecimal,
+      "const a = 0.5;",
+      "const a = 2.0;",
+      "const a = 2;",
+      "const a = -0.5;",
+      "const a = 1e5;",
+      "const a = 0x1F;",
+      "const a = 0b101;",
+      "const a = 0o17;",
+      "const a = 1_000.5;",
+      "const a = 2.0.toFixed();",
+      "const a = 2 .toFixed();",
+      "const a = 10n;",
+    };
+  }
+
+  #[test]
+  fn no_floating_decimal_invalid() {
+    assert_lint_err! {
+      NoFloatingDecimal,
+      "const a = .5;": [
+        {
+          col: 10,
+          message: NoFloatingDecimalMessage::Leading,
+          hint: NoFloatingDecimalHint::Leading,
+          fix: "const a = 0.5;",
+        }
+      ],
+      "const a = -.5;": [
+        {
+          col: 11,
+          message: NoFloatingDecimalMessage::Leading,
+          hint: NoFloatingDecimalHint::Leading,
+          fix: "const a = -0.5;",
+        }
+      ],
+      "const a = 2.;": [
+        {
+          col: 10,
+          message: NoFloatingDecimalMessage::Trailing,
+          hint: NoFloatingDecimalHint::Trailing,
+          fix: "const a = 2.0;",
+        }
+      ],
+      "const a = 2..toFixed();": [
+        {
+          col: 10,
+          message: NoFloatingDecimalMessage::Trailing,
+          hint: NoFloatingDecimalHint::Trailing,
+          fix: "const a = 2.0.toFixed();",
+        }
+      ],
+      "const a = .5e3;": [
+        {
+          col: 10,
+          message: NoFloatingDecimalMessage::Leading,
+          hint: NoFloatingDecimalHint::Leading,
+          fix: "const a = 0.5e3;",
+        }
+      ],
+      "function f() { return.5; }": [
+        {
+          col: 21,
+          message: NoFloatingDecimalMessage::Leading,
+          hint: NoFloatingDecimalHint::Leading,
+          fix: "function f() { return 0.5; }",
+        }
+      ],
+      "const a = [.5, 1.];": [
+        {
+          col: 11,
+          message: NoFloatingDecimalMessage::Leading,
+          hint: NoFloatingDecimalHint::Leading,
+          fix: "const a = [0.5, 1.];",
+        },
+        {
+          col: 15,
+          message: NoFloatingDecimalMessage::Trailing,
+          hint: NoFloatingDecimalHint::Trailing,
+          fix: "const a = [.5, 1.0];",
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,216 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::swc_util::unwrap_parens;
+use crate::ProgramRef;
+use deno_ast::swc::ast::{
+  CallExpr, Expr, ExprOrSuper, ExprStmt, FnDecl, Pat, VarDecl, VarDeclKind,
+};
+use deno_ast::swc::utils::ident::IdentLike;
+use deno_ast::swc::utils::Id;
+use deno_ast::swc::visit::{noop_visit_type, Node, Visit, VisitWith};
+use std::collections::HashSet;
+
+#[derive(Debug)]
+pub struct NoFloatingPromises;
+
+const CODE: &str = "no-floating-promises";
+const MESSAGE: &str = "The promise returned by this call is not handled";
+const HINT: &str =
+  "Await the promise, handle it with `.catch()`, or ignore it with `void`";
+
+/// Static methods of `Promise` which return a new promise
+const PROMISE_METHODS: &[&str] =
+  &["all", "allSettled", "any", "race", "reject", "resolve"];
+
+impl LintRule for NoFloatingPromises {
+  fn new() -> Box<Self> {
+    Box::new(NoFloatingPromises)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    let mut collector = AsyncFunctionCollector::default();
+    match program {
+      ProgramRef::Module(m) => m.visit_with(&DUMMY_NODE, &mut collector),
+      ProgramRef::Script(s) => s.visit_with(&DUMMY_NODE, &mut collector),
+    }
+
+    let mut visitor =
+      NoFloatingPromisesVisitor::new(context, collector.async_functions);
+    match program {
+      ProgramRef::Module(m) => visitor.visit_module(m, &DUMMY_NODE),
+      ProgramRef::Script(s) => visitor.visit_script(s, &DUMMY_NODE),
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_floating_promises.md")
+  }
+}
+
+/// Collects the functions which are known to be async: `async function`
+/// declarations, and `const` variables initialized with an async function or
+/// arrow function.
+#[derive(Default)]
+struct AsyncFunctionCollector {
+  async_functions: HashSet<Id>,
+}
+
+impl Visit for AsyncFunctionCollector {
+  noop_visit_type!();
+
+  fn visit_fn_decl(&mut self, fn_decl: &FnDecl, _: &dyn Node) {
+    if fn_decl.function.is_async && !fn_decl.function.is_generator {
+      self.async_functions.insert(fn_decl.ident.to_id());
+    }
+    fn_decl.visit_children_with(self);
+  }
+
+  fn visit_var_decl(&mut self, var_decl: &VarDecl, _: &dyn Node) {
+    if var_decl.kind == VarDeclKind::Const {
+      for decl in &var_decl.decls {
+        let ident = match &decl.name {
+          Pat::Ident(binding) => &binding.id,
+          _ => continue,
+        };
+        let is_async = match decl.init.as_deref().map(unwrap_parens) {
+          Some(Expr::Arrow(arrow)) => arrow.is_async,
+          Some(Expr::Fn(fn_expr)) => {
+            fn_expr.function.is_async && !fn_expr.function.is_generator
+          }
+          _ => false,
+        };
+        if is_async {
+          self.async_functions.insert(ident.to_id());
+        }
+      }
+    }
+    var_decl.visit_children_with(self);
+  }
+}
+
+struct NoFloatingPromisesVisitor<'c, 'view> {
+  context: &'c mut Context<'view>,
+  async_functions: HashSet<Id>,
+}
+
+impl<'c, 'view> NoFloatingPromisesVisitor<'c, 'view> {
+  fn new(
+    context: &'c mut Context<'view>,
+    async_functions: HashSet<Id>,
+  ) -> Self {
+    Self {
+      context,
+      async_functions,
+    }
+  }
+
+  /// Checks if the call returns a promise, which is the case for calls to
+  /// local async functions and to the static methods of `Promise`.
+  fn returns_promise(&self, call_expr: &CallExpr) -> bool {
+    let callee = match &call_expr.callee {
+      ExprOrSuper::Expr(callee) => unwrap_parens(callee),
+      ExprOrSuper::Super(_) => return false,
+    };
+    match callee {
+      Expr::Ident(ident) => self.async_functions.contains(&ident.to_id()),
+      Expr::Member(member) if !member.computed => {
+        let is_promise = match &member.obj {
+          ExprOrSuper::Expr(obj) => matches!(
+            &**obj,
+            Expr::Ident(ident)
+              if ident.sym == *"Promise"
+                && self.context.scope().is_global(&ident.to_id())
+          ),
+          ExprOrSuper::Super(_) => false,
+        };
+        is_promise
+          && matches!(
+            &*member.prop,
+            Expr::Ident(prop) if PROMISE_METHODS.contains(&&*prop.sym)
+          )
+      }
+      _ => false,
+    }
+  }
+}
+
+impl<'c, 'view> Visit for NoFloatingPromisesVisitor<'c, 'view> {
+  noop_visit_type!();
+
+  fn visit_expr_stmt(&mut self, expr_stmt: &ExprStmt, _: &dyn Node) {
+    expr_stmt.visit_children_with(self);
+
+    // `void promise()` is not a call, so it is allowed as an explicit way to
+    // ignore the promise.
+    if let Expr::Call(call_expr) = unwrap_parens(&expr_stmt.expr) {
+      if self.returns_promise(call_expr) {
+        self.context.add_diagnostic_with_hint(
+          expr_stmt.span,
+          CODE,
+          MESSAGE,
+          HINT,
+        );
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_floating_promises_valid() {
+    assert_lint_ok! {
+      NoFloatingPromises,
+      "async function f() {} await f();",
+      "async function f() {} void f();",
+      "async function f() {} f().catch(console.error);",
+      "async function f() {} const p = f();",
+      "async function f() {} function g() { return f(); }",
+      "const f = async () => {}; await f();",
+      "function f() {} f();",
+      "function* f() {} f();",
+      "let f = async () => {}; f = () => {}; f();",
+      "async function f() {} function g(f) { f(); }",
+      "void Promise.resolve();",
+      "await Promise.all([a(), b()]);",
+      "class Promise { static resolve() {} } Promise.resolve();",
+      "unknown();",
+    };
+  }
+
+  #[test]
+  fn no_floating_promises_invalid() {
+    assert_lint_err! {
+      NoFloatingPromises,
+      "async function f() {} f();": [
+        { col: 22, message: MESSAGE, hint: HINT },
+      ],
+      "f(); async function f() {}": [
+        { col: 0, message: MESSAGE, hint: HINT },
+      ],
+      "const f = async () => {}; (f());": [
+        { col: 26, message: MESSAGE, hint: HINT },
+      ],
+      "const f = async function () {}; function g() { f(); }": [
+        { col: 47, message: MESSAGE, hint: HINT },
+      ],
+      "Promise.reject(new Error());": [
+        { col: 0, message: MESSAGE, hint: HINT },
+      ],
+      "Promise.all([a(), b()]);": [
+        { col: 0, message: MESSAGE, hint: HINT },
+      ],
+    };
+  }
+}
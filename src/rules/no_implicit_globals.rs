@@ -0,0 +1,227 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::swc_util::find_lhs_ids;
+use crate::{globals::GLOBALS, ProgramRef};
+use deno_ast::swc::ast::{
+  AssignExpr, AssignOp, Decl, Ident, Script, Stmt, VarDeclKind,
+};
+use deno_ast::swc::utils::ident::IdentLike;
+use deno_ast::swc::visit::{noop_visit_type, Node, Visit, VisitWith};
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct NoImplicitGlobals;
+
+const CODE: &str = "no-implicit-globals";
+
+#[derive(Display)]
+enum NoImplicitGlobalsMessage {
+  #[display(fmt = "Unexpected `var` declaration in the global scope")]
+  GlobalVar,
+  #[display(fmt = "Unexpected function declaration in the global scope")]
+  GlobalFunction,
+  #[display(fmt = "Unexpected `{}` declaration in the global scope", _0)]
+  GlobalLexical(&'static str),
+  #[display(
+    fmt = "Assignment to undeclared variable `{}` creates a global",
+    _0
+  )]
+  Leak(String),
+}
+
+#[derive(Display)]
+enum NoImplicitGlobalsHint {
+  #[display(
+    fmt = "Wrap it in an IIFE to keep it local, or assign it to `globalThis` explicitly"
+  )]
+  WrapOrAssign,
+  #[display(fmt = "Declare the variable before assigning to it")]
+  Declare,
+}
+
+impl LintRule for NoImplicitGlobals {
+  fn new() -> Box<Self> {
+    Box::new(NoImplicitGlobals)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    // Modules have their own scope, so nothing leaks into the global one
+    if let ProgramRef::Script(script) = program {
+      check_top_level_decls(script, context);
+
+      let mut visitor = NoImplicitGlobalsVisitor::new(context);
+      script.visit_with(&DUMMY_NODE, &mut visitor);
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_implicit_globals.md")
+  }
+}
+
+fn check_top_level_decls(script: &Script, context: &mut Context) {
+  for stmt in &script.body {
+    let (span, message) = match stmt {
+      Stmt::Decl(Decl::Var(var_decl)) if !var_decl.declare => {
+        let message = match var_decl.kind {
+          VarDeclKind::Var => NoImplicitGlobalsMessage::GlobalVar,
+          VarDeclKind::Let => NoImplicitGlobalsMessage::GlobalLexical("let"),
+          VarDeclKind::Const => {
+            NoImplicitGlobalsMessage::GlobalLexical("const")
+          }
+        };
+        (var_decl.span, message)
+      }
+      Stmt::Decl(Decl::Fn(fn_decl)) if !fn_decl.declare => (
+        fn_decl.function.span,
+        NoImplicitGlobalsMessage::GlobalFunction,
+      ),
+      Stmt::Decl(Decl::Class(class_decl)) if !class_decl.declare => (
+        class_decl.class.span,
+        NoImplicitGlobalsMessage::GlobalLexical("class"),
+      ),
+      _ => continue,
+    };
+
+    context.add_diagnostic_with_hint(
+      span,
+      CODE,
+      message,
+      NoImplicitGlobalsHint::WrapOrAssign,
+    );
+  }
+}
+
+struct NoImplicitGlobalsVisitor<'c, 'view> {
+  context: &'c mut Context<'view>,
+}
+
+impl<'c, 'view> NoImplicitGlobalsVisitor<'c, 'view> {
+  fn new(context: &'c mut Context<'view>) -> Self {
+    Self { context }
+  }
+
+  fn check_leak(&mut self, ident: &Ident) {
+    if ident.span.ctxt != self.context.top_level_ctxt() {
+      return;
+    }
+
+    if self.context.scope().var(&ident.to_id()).is_some() {
+      return;
+    }
+
+    // Assigning to known globals is handled by `no-global-assign`
+    if GLOBALS.iter().any(|(name, _)| name == &&*ident.sym) {
+      return;
+    }
+
+    self.context.add_diagnostic_with_hint(
+      ident.span,
+      CODE,
+      NoImplicitGlobalsMessage::Leak(ident.sym.to_string()),
+      NoImplicitGlobalsHint::Declare,
+    );
+  }
+}
+
+impl<'c, 'view> Visit for NoImplicitGlobalsVisitor<'c, 'view> {
+  noop_visit_type!();
+
+  fn visit_assign_expr(&mut self, assign_expr: &AssignExpr, _: &dyn Node) {
+    if assign_expr.op == AssignOp::Assign {
+      let idents: Vec<Ident> = find_lhs_ids(&assign_expr.left);
+      for ident in &idents {
+        self.check_leak(ident);
+      }
+    }
+
+    assign_expr.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_implicit_globals_valid() {
+    assert_lint_ok! {
+      NoImplicitGlobals,
+      "export var foo = 1;",
+      "var foo = 1; export {};",
+      "function foo() {} export {};",
+      "foo = 1; export {};",
+      "(function () { var foo = 1; function bar() {} })();",
+      "globalThis.foo = 1;",
+      "window.foo = 1;",
+      "declare var foo: number;",
+      "let foo; (function () { foo = 1; })(); export {};",
+      "(function () { let foo; foo = 1; })();",
+      "function f(foo) { foo = 1; } export {};",
+      "foo += 1;",
+    };
+  }
+
+  #[test]
+  fn no_implicit_globals_invalid() {
+    assert_lint_err! {
+      NoImplicitGlobals,
+      "var foo = 1;": [
+        {
+          col: 0,
+          message: NoImplicitGlobalsMessage::GlobalVar,
+          hint: NoImplicitGlobalsHint::WrapOrAssign,
+        },
+      ],
+      "function foo() {}": [
+        {
+          col: 0,
+          message: NoImplicitGlobalsMessage::GlobalFunction,
+          hint: NoImplicitGlobalsHint::WrapOrAssign,
+        },
+      ],
+      "class Foo {}": [
+        {
+          col: 0,
+          message: NoImplicitGlobalsMessage::GlobalLexical("class"),
+          hint: NoImplicitGlobalsHint::WrapOrAssign,
+        },
+      ],
+      "const foo = 1;": [
+        {
+          col: 0,
+          message: NoImplicitGlobalsMessage::GlobalLexical("const"),
+          hint: NoImplicitGlobalsHint::WrapOrAssign,
+        },
+      ],
+      "foo = 1;": [
+        {
+          col: 0,
+          message: NoImplicitGlobalsMessage::Leak("foo".to_string()),
+          hint: NoImplicitGlobalsHint::Declare,
+        },
+      ],
+      "(function () { [a, b] = [1, 2]; })();": [
+        {
+          col: 16,
+          message: NoImplicitGlobalsMessage::Leak("a".to_string()),
+          hint: NoImplicitGlobalsHint::Declare,
+        },
+        {
+          col: 19,
+          message: NoImplicitGlobalsMessage::Leak("b".to_string()),
+          hint: NoImplicitGlobalsHint::Declare,
+        },
+      ],
+    };
+  }
+}
@@ -0,0 +1,275 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::swc_util::StringRepr;
+use crate::ProgramRef;
+use deno_ast::swc::ast::{
+  BinaryOp, CallExpr, Expr, ExprOrSuper, Ident, Lit, Pat, VarDecl, VarDeclKind,
+};
+use deno_ast::swc::utils::ident::IdentLike;
+use deno_ast::swc::utils::Id;
+use deno_ast::swc::visit::{
+  noop_visit_type, Node, Visit, VisitAll, VisitAllWith, VisitWith,
+};
+use derive_more::Display;
+use std::collections::HashSet;
+
+#[derive(Debug)]
+pub struct NoImpliedEval;
+
+const CODE: &str = "no-implied-eval";
+const HINT: &str = "Pass a function instead of a string";
+
+/// Functions evaluating a string passed as their first argument as code
+const EVAL_LIKE_FUNCTIONS: &[&str] =
+  &["setTimeout", "setInterval", "setImmediate", "execScript"];
+
+/// Names of the global object, like in `window.setTimeout`
+const GLOBAL_OBJECTS: &[&str] = &["globalThis", "window", "self"];
+
+#[derive(Display)]
+enum NoImpliedEvalMessage {
+  #[display(
+    fmt = "Implied `eval`: the string passed to `{}` is evaluated as code",
+    _0
+  )]
+  ImpliedEval(String),
+}
+
+impl LintRule for NoImpliedEval {
+  fn new() -> Box<Self> {
+    Box::new(NoImpliedEval)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    let mut collector = StringConstCollector::default();
+    match program {
+      ProgramRef::Module(m) => m.visit_all_with(&DUMMY_NODE, &mut collector),
+      ProgramRef::Script(s) => s.visit_all_with(&DUMMY_NODE, &mut collector),
+    }
+
+    let mut visitor = NoImpliedEvalVisitor::new(context, collector.consts);
+    match program {
+      ProgramRef::Module(m) => visitor.visit_module(m, &DUMMY_NODE),
+      ProgramRef::Script(s) => visitor.visit_script(s, &DUMMY_NODE),
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_implied_eval.md")
+  }
+}
+
+/// Checks whether `expr` evaluates to a string, e.g. `"foo"`, `` `foo${bar}` ``
+/// or `"foo" + bar`.
+fn is_string(expr: &Expr, consts: &HashSet<Id>) -> bool {
+  match expr {
+    Expr::Lit(Lit::Str(_)) | Expr::Tpl(_) => true,
+    Expr::Ident(ident) => consts.contains(&ident.to_id()),
+    Expr::Bin(bin) if bin.op == BinaryOp::Add => {
+      is_string(&bin.left, consts) || is_string(&bin.right, consts)
+    }
+    Expr::Paren(paren) => is_string(&paren.expr, consts),
+    _ => false,
+  }
+}
+
+/// Collects `const` variables initialized with a string, which are known to
+/// hold a string wherever they are used.
+#[derive(Default)]
+struct StringConstCollector {
+  consts: HashSet<Id>,
+}
+
+impl VisitAll for StringConstCollector {
+  noop_visit_type!();
+
+  fn visit_var_decl(&mut self, var_decl: &VarDecl, _: &dyn Node) {
+    if var_decl.kind != VarDeclKind::Const {
+      return;
+    }
+    for decl in &var_decl.decls {
+      if let (Pat::Ident(ident), Some(init)) = (&decl.name, &decl.init) {
+        if is_string(init, &self.consts) {
+          self.consts.insert(ident.id.to_id());
+        }
+      }
+    }
+  }
+}
+
+struct NoImpliedEvalVisitor<'c, 'view> {
+  context: &'c mut Context<'view>,
+  consts: HashSet<Id>,
+}
+
+impl<'c, 'view> NoImpliedEvalVisitor<'c, 'view> {
+  fn new(context: &'c mut Context<'view>, consts: HashSet<Id>) -> Self {
+    Self { context, consts }
+  }
+
+  fn is_global_ident(&self, ident: &Ident, names: &[&str]) -> bool {
+    names.iter().any(|name| ident.sym == **name)
+      && self.context.scope().is_global(&ident.to_id())
+  }
+
+  /// Returns the name of the eval-like function `callee` refers to, either
+  /// directly like `setTimeout` or through the global object like
+  /// `window.setTimeout`.
+  fn eval_like_function(&self, callee: &Expr) -> Option<String> {
+    match callee {
+      Expr::Ident(ident) => {
+        if self.is_global_ident(ident, EVAL_LIKE_FUNCTIONS) {
+          Some(ident.sym.to_string())
+        } else {
+          None
+        }
+      }
+      Expr::Member(member) => {
+        let name = member.string_repr()?;
+        if !EVAL_LIKE_FUNCTIONS.contains(&name.as_str()) {
+          return None;
+        }
+        match &member.obj {
+          ExprOrSuper::Expr(obj) => match obj.as_ref() {
+            Expr::Ident(ident)
+              if self.is_global_ident(ident, GLOBAL_OBJECTS) =>
+            {
+              Some(name)
+            }
+            _ => None,
+          },
+          ExprOrSuper::Super(_) => None,
+        }
+      }
+      Expr::Paren(paren) => self.eval_like_function(&paren.expr),
+      _ => None,
+    }
+  }
+}
+
+impl<'c, 'view> Visit for NoImpliedEvalVisitor<'c, 'view> {
+  noop_visit_type!();
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _: &dyn Node) {
+    call_expr.visit_children_with(self);
+
+    let callee = match &call_expr.callee {
+      ExprOrSuper::Expr(callee) => callee,
+      ExprOrSuper::Super(_) => return,
+    };
+    let name = match self.eval_like_function(callee) {
+      Some(name) => name,
+      None => return,
+    };
+    let first_arg = match call_expr.args.first() {
+      Some(arg) if arg.spread.is_none() => arg,
+      _ => return,
+    };
+
+    if is_string(&first_arg.expr, &self.consts) {
+      self.context.add_diagnostic_with_hint(
+        call_expr.span,
+        CODE,
+        NoImpliedEvalMessage::ImpliedEval(name),
+        HINT,
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_implied_eval_valid() {
+    assert_lint_ok! {
+      NoImpliedEval,
+      "setTimeout(() => doStuff(), 100);",
+      "setTimeout(function () { doStuff(); }, 100);",
+      "setInterval(doStuff, 100);",
+      "setImmediate(doStuff);",
+      "setTimeout(foo, 100, 'bar');",
+      "setTimeout(foo(), 100);",
+      "setTimeout();",
+      "foo.setTimeout('doStuff()', 100);",
+      "window.foo('doStuff()', 100);",
+      "function f(setTimeout) { setTimeout('doStuff()', 100); }",
+      "const window = { setTimeout() {} }; window.setTimeout('foo');",
+      "let code = 'doStuff()'; code = doStuff; setTimeout(code, 100);",
+      "const n = 100; setTimeout(n);",
+    };
+  }
+
+  #[test]
+  fn no_implied_eval_invalid() {
+    assert_lint_err! {
+      NoImpliedEval,
+      "setTimeout('doStuff()', 100);": [
+        {
+          col: 0,
+          message: variant!(NoImpliedEvalMessage, ImpliedEval, "setTimeout"),
+          hint: HINT,
+        },
+      ],
+      "setInterval(`doStuff()`, 100);": [
+        {
+          col: 0,
+          message: variant!(NoImpliedEvalMessage, ImpliedEval, "setInterval"),
+          hint: HINT,
+        },
+      ],
+      "setImmediate('do' + stuff + '()');": [
+        {
+          col: 0,
+          message: variant!(NoImpliedEvalMessage, ImpliedEval, "setImmediate"),
+          hint: HINT,
+        },
+      ],
+      "execScript('doStuff()');": [
+        {
+          col: 0,
+          message: variant!(NoImpliedEvalMessage, ImpliedEval, "execScript"),
+          hint: HINT,
+        },
+      ],
+      "window.setTimeout('doStuff()', 100);": [
+        {
+          col: 0,
+          message: variant!(NoImpliedEvalMessage, ImpliedEval, "setTimeout"),
+          hint: HINT,
+        },
+      ],
+      "globalThis['setInterval']('doStuff()', 100);": [
+        {
+          col: 0,
+          message: variant!(NoImpliedEvalMessage, ImpliedEval, "setInterval"),
+          hint: HINT,
+        },
+      ],
+      "const code = 'doStuff()'; setTimeout(code, 100);": [
+        {
+          col: 26,
+          message: variant!(NoImpliedEvalMessage, ImpliedEval, "setTimeout"),
+          hint: HINT,
+        },
+      ],
+      "foo(setTimeout('doStuff()'));": [
+        {
+          col: 4,
+          message: variant!(NoImpliedEvalMessage, ImpliedEval, "setTimeout"),
+          hint: HINT,
+        },
+      ],
+    };
+  }
+}
@@ -11,10 +11,35 @@ use deno_ast::swc::visit::{
   noop_visit_type, Node, Visit, VisitAll, VisitAllWith, VisitWith,
 };
 use derive_more::Display;
+use serde::Deserialize;
 use std::collections::HashSet;
 
-#[derive(Debug)]
-pub struct NoInnerDeclarations;
+#[derive(Debug, Default)]
+pub struct NoInnerDeclarations {
+  options: NoInnerDeclarationsOptions,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoInnerDeclarationsOptions {
+  /// Which kinds of declarations are checked.
+  pub mode: NoInnerDeclarationsMode,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NoInnerDeclarationsMode {
+  /// Only function declarations are checked.
+  Functions,
+  /// Both function and `var` declarations are checked.
+  Both,
+}
+
+impl Default for NoInnerDeclarationsMode {
+  fn default() -> Self {
+    NoInnerDeclarationsMode::Both
+  }
+}
 
 const CODE: &str = "no-inner-declarations";
 
@@ -30,9 +55,15 @@ enum NoInnerDeclarationsHint {
   Move,
 }
 
+impl NoInnerDeclarations {
+  pub fn with_options(options: NoInnerDeclarationsOptions) -> Box<Self> {
+    Box::new(NoInnerDeclarations { options })
+  }
+}
+
 impl LintRule for NoInnerDeclarations {
   fn new() -> Box<Self> {
-    Box::new(NoInnerDeclarations)
+    Box::new(NoInnerDeclarations::default())
   }
 
   fn tags(&self) -> &'static [&'static str] {
@@ -58,8 +89,11 @@ impl LintRule for NoInnerDeclarations {
       }
     }
 
-    let mut visitor =
-      NoInnerDeclarationsVisitor::new(context, valid_visitor.valid_decls);
+    let mut visitor = NoInnerDeclarationsVisitor::new(
+      context,
+      valid_visitor.valid_decls,
+      self.options.mode,
+    );
     match program {
       ProgramRef::Module(m) => m.visit_with(&DUMMY_NODE, &mut visitor),
       ProgramRef::Script(s) => s.visit_with(&DUMMY_NODE, &mut visitor),
@@ -176,14 +210,20 @@ impl VisitAll for ValidDeclsVisitor {
 struct NoInnerDeclarationsVisitor<'c, 'view> {
   context: &'c mut Context<'view>,
   valid_decls: HashSet<Span>,
+  mode: NoInnerDeclarationsMode,
   in_function: bool,
 }
 
 impl<'c, 'view> NoInnerDeclarationsVisitor<'c, 'view> {
-  fn new(context: &'c mut Context<'view>, valid_decls: HashSet<Span>) -> Self {
+  fn new(
+    context: &'c mut Context<'view>,
+    valid_decls: HashSet<Span>,
+    mode: NoInnerDeclarationsMode,
+  ) -> Self {
     Self {
       context,
       valid_decls,
+      mode,
       in_function: false,
     }
   }
@@ -236,7 +276,10 @@ impl<'c, 'view> Visit for NoInnerDeclarationsVisitor<'c, 'view> {
   fn visit_var_decl(&mut self, decl: &VarDecl, _: &dyn Node) {
     let span = decl.span();
 
-    if decl.kind == VarDeclKind::Var && !self.valid_decls.contains(&span) {
+    if self.mode == NoInnerDeclarationsMode::Both
+      && decl.kind == VarDeclKind::Var
+      && !self.valid_decls.contains(&span)
+    {
       self.add_diagnostic(span, "variable");
     }
 
@@ -247,6 +290,7 @@ impl<'c, 'view> Visit for NoInnerDeclarationsVisitor<'c, 'view> {
 #[cfg(test)]
 mod tests {
   use super::*;
+  use serde_json::json;
 
   #[test]
   fn no_inner_declarations_valid() {
@@ -276,6 +320,13 @@ mod tests {
       "exports.foo = function(){}",
       "module.exports = function foo(){}",
     };
+
+    assert_lint_ok! {
+      NoInnerDeclarations,
+      options: json!({ "mode": "functions" }),
+      "if (foo) { var a; }",
+      "function bar() { if (foo) { var a; } }",
+    };
   }
 
   #[test]
@@ -404,5 +455,17 @@ mod tests {
         }
       ]
     };
+
+    assert_lint_err! {
+      NoInnerDeclarations,
+      options: json!({ "mode": "functions" }),
+      "if (foo) { var a; function f() {} }": [
+        {
+          col: 18,
+          message: variant!(NoInnerDeclarationsMessage, Move, "function", "module"),
+          hint: NoInnerDeclarationsHint::Move,
+        }
+      ],
+    };
   }
 }
@@ -0,0 +1,313 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::scopes::bindings::{
+  add_constructor_params, add_lexical_module_items, add_lexical_stmts,
+  add_lexical_var_decl, add_pat, function_body_bindings, Bindings,
+  VarCollector,
+};
+use crate::ProgramRef;
+use deno_ast::swc::ast::{
+  ArrowExpr, BlockStmt, BlockStmtOrExpr, CatchClause, Constructor, ForInStmt,
+  ForOfStmt, ForStmt, Function, GetterProp, LabeledStmt, Module, Script,
+  SetterProp, SwitchStmt, VarDeclOrExpr, VarDeclOrPat,
+};
+use deno_ast::swc::atoms::JsWord;
+use deno_ast::swc::visit::{noop_visit_type, Node, Visit, VisitWith};
+use derive_more::Display;
+use std::collections::HashSet;
+
+#[derive(Debug)]
+pub struct NoLabelVar;
+
+const CODE: &str = "no-label-var";
+const HINT: &str = "Rename the label or the variable";
+
+#[derive(Display)]
+enum NoLabelVarMessage {
+  #[display(fmt = "Label `{}` has the same name as a variable in scope", _0)]
+  LabelVar(String),
+}
+
+impl LintRule for NoLabelVar {
+  fn new() -> Box<Self> {
+    Box::new(NoLabelVar)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    let mut visitor = NoLabelVarVisitor::new(context);
+    match program {
+      ProgramRef::Module(m) => visitor.visit_module(m, &DUMMY_NODE),
+      ProgramRef::Script(s) => visitor.visit_script(s, &DUMMY_NODE),
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_label_var.md")
+  }
+}
+
+struct NoLabelVarVisitor<'c, 'view> {
+  context: &'c mut Context<'view>,
+  scopes: Vec<HashSet<JsWord>>,
+}
+
+impl<'c, 'view> NoLabelVarVisitor<'c, 'view> {
+  fn new(context: &'c mut Context<'view>) -> Self {
+    Self {
+      context,
+      scopes: Vec::new(),
+    }
+  }
+
+  fn with_scope<F>(&mut self, bindings: Bindings, op: F)
+  where
+    F: FnOnce(&mut Self),
+  {
+    self
+      .scopes
+      .push(bindings.into_iter().map(|(name, _)| name).collect());
+    op(self);
+    self.scopes.pop();
+  }
+
+  fn is_visible(&self, name: &JsWord) -> bool {
+    self.scopes.iter().any(|scope| scope.contains(name))
+  }
+}
+
+impl<'c, 'view> Visit for NoLabelVarVisitor<'c, 'view> {
+  noop_visit_type!();
+
+  fn visit_labeled_stmt(&mut self, labeled_stmt: &LabeledStmt, _: &dyn Node) {
+    let label = &labeled_stmt.label;
+    if self.is_visible(&label.sym) {
+      self.context.add_diagnostic_with_hint(
+        label.span,
+        CODE,
+        NoLabelVarMessage::LabelVar(label.sym.to_string()),
+        HINT,
+      );
+    }
+    labeled_stmt.body.visit_with(labeled_stmt, self);
+  }
+
+  fn visit_module(&mut self, module: &Module, _: &dyn Node) {
+    let mut bindings = Vec::new();
+    VarCollector::collect(&module.body, &mut bindings);
+    add_lexical_module_items(&module.body, &mut bindings);
+
+    self.with_scope(bindings, |v| module.visit_children_with(v));
+  }
+
+  fn visit_script(&mut self, script: &Script, _: &dyn Node) {
+    let mut bindings = Vec::new();
+    VarCollector::collect(&script.body, &mut bindings);
+    add_lexical_stmts(&script.body, &mut bindings);
+
+    self.with_scope(bindings, |v| script.visit_children_with(v));
+  }
+
+  fn visit_function(&mut self, function: &Function, _: &dyn Node) {
+    let body = match &function.body {
+      Some(body) => body,
+      None => return,
+    };
+
+    let mut bindings = Vec::new();
+    for param in &function.params {
+      add_pat(&param.pat, &mut bindings);
+    }
+    function_body_bindings(body, &mut bindings);
+
+    self.with_scope(bindings, |v| {
+      function.params.visit_with(function, v);
+      body.stmts.visit_with(body, v);
+    });
+  }
+
+  fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr, _: &dyn Node) {
+    let mut bindings = Vec::new();
+    for param in &arrow_expr.params {
+      add_pat(param, &mut bindings);
+    }
+    if let BlockStmtOrExpr::BlockStmt(body) = &arrow_expr.body {
+      function_body_bindings(body, &mut bindings);
+    }
+
+    self.with_scope(bindings, |v| match &arrow_expr.body {
+      BlockStmtOrExpr::BlockStmt(body) => body.stmts.visit_with(body, v),
+      BlockStmtOrExpr::Expr(expr) => expr.visit_with(arrow_expr, v),
+    });
+  }
+
+  fn visit_constructor(&mut self, constructor: &Constructor, _: &dyn Node) {
+    let body = match &constructor.body {
+      Some(body) => body,
+      None => return,
+    };
+
+    let mut bindings = Vec::new();
+    add_constructor_params(constructor, &mut bindings);
+    function_body_bindings(body, &mut bindings);
+
+    self.with_scope(bindings, |v| body.stmts.visit_with(body, v));
+  }
+
+  fn visit_getter_prop(&mut self, getter_prop: &GetterProp, _: &dyn Node) {
+    if let Some(body) = &getter_prop.body {
+      let mut bindings = Vec::new();
+      function_body_bindings(body, &mut bindings);
+
+      self.with_scope(bindings, |v| body.stmts.visit_with(body, v));
+    }
+  }
+
+  fn visit_setter_prop(&mut self, setter_prop: &SetterProp, _: &dyn Node) {
+    if let Some(body) = &setter_prop.body {
+      let mut bindings = Vec::new();
+      add_pat(&setter_prop.param, &mut bindings);
+      function_body_bindings(body, &mut bindings);
+
+      self.with_scope(bindings, |v| body.stmts.visit_with(body, v));
+    }
+  }
+
+  fn visit_block_stmt(&mut self, block_stmt: &BlockStmt, _: &dyn Node) {
+    let mut bindings = Vec::new();
+    add_lexical_stmts(&block_stmt.stmts, &mut bindings);
+
+    self.with_scope(bindings, |v| block_stmt.visit_children_with(v));
+  }
+
+  fn visit_for_stmt(&mut self, for_stmt: &ForStmt, _: &dyn Node) {
+    let mut bindings = Vec::new();
+    if let Some(VarDeclOrExpr::VarDecl(var_decl)) = &for_stmt.init {
+      add_lexical_var_decl(var_decl, &mut bindings);
+    }
+
+    self.with_scope(bindings, |v| for_stmt.visit_children_with(v));
+  }
+
+  fn visit_for_in_stmt(&mut self, for_in: &ForInStmt, _: &dyn Node) {
+    let mut bindings = Vec::new();
+    if let VarDeclOrPat::VarDecl(var_decl) = &for_in.left {
+      add_lexical_var_decl(var_decl, &mut bindings);
+    }
+
+    self.with_scope(bindings, |v| for_in.visit_children_with(v));
+  }
+
+  fn visit_for_of_stmt(&mut self, for_of: &ForOfStmt, _: &dyn Node) {
+    let mut bindings = Vec::new();
+    if let VarDeclOrPat::VarDecl(var_decl) = &for_of.left {
+      add_lexical_var_decl(var_decl, &mut bindings);
+    }
+
+    self.with_scope(bindings, |v| for_of.visit_children_with(v));
+  }
+
+  fn visit_catch_clause(&mut self, catch_clause: &CatchClause, _: &dyn Node) {
+    let mut bindings = Vec::new();
+    if let Some(param) = &catch_clause.param {
+      add_pat(param, &mut bindings);
+    }
+
+    self.with_scope(bindings, |v| catch_clause.visit_children_with(v));
+  }
+
+  fn visit_switch_stmt(&mut self, switch_stmt: &SwitchStmt, _: &dyn Node) {
+    switch_stmt.discriminant.visit_with(switch_stmt, self);
+
+    let mut bindings = Vec::new();
+    add_lexical_stmts(
+      switch_stmt.cases.iter().flat_map(|case| &case.cons),
+      &mut bindings,
+    );
+
+    self.with_scope(bindings, |v| switch_stmt.cases.visit_with(switch_stmt, v));
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_label_var_valid() {
+    assert_lint_ok! {
+      NoLabelVar,
+      "function bar() { q: for(;;) { break q; } } function foo () { var q = t; }",
+      "function bar() { var x = foo; q: for(;;) { break q; } }",
+      "x: for (;;) { break x; }",
+      "function f() { let x; } x: while (true) { break x; }",
+      "{ let x; } x: for (;;) { break x; }",
+      "try {} catch (x) {} x: for (;;) { break x; }",
+      "const f = (x) => x; x: for (;;) { break x; }",
+    };
+  }
+
+  #[test]
+  fn no_label_var_invalid() {
+    assert_lint_err! {
+      NoLabelVar,
+      "var x = foo; function bar() { x: for(;;) { break x; } }": [
+        {
+          col: 30,
+          message: variant!(NoLabelVarMessage, LabelVar, "x"),
+          hint: HINT,
+        },
+      ],
+      "function bar() { var x = foo; x: for(;;) { break x; } }": [
+        {
+          col: 30,
+          message: variant!(NoLabelVarMessage, LabelVar, "x"),
+          hint: HINT,
+        },
+      ],
+      "function bar(x) { x: for(;;) { break x; } }": [
+        {
+          col: 18,
+          message: variant!(NoLabelVarMessage, LabelVar, "x"),
+          hint: HINT,
+        },
+      ],
+      "let x; x: for (;;) { break x; }": [
+        {
+          col: 7,
+          message: variant!(NoLabelVarMessage, LabelVar, "x"),
+          hint: HINT,
+        },
+      ],
+      "x: for (;;) { break x; } var x;": [
+        {
+          col: 0,
+          message: variant!(NoLabelVarMessage, LabelVar, "x"),
+          hint: HINT,
+        },
+      ],
+      "try {} catch (x) { x: for (;;) { break x; } }": [
+        {
+          col: 19,
+          message: variant!(NoLabelVarMessage, LabelVar, "x"),
+          hint: HINT,
+        },
+      ],
+      "for (const x of xs) { x: while (true) { break x; } }": [
+        {
+          col: 22,
+          message: variant!(NoLabelVarMessage, LabelVar, "x"),
+          hint: HINT,
+        },
+      ],
+    };
+  }
+}
@@ -0,0 +1,126 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{Decl, Stmt, VarDeclKind};
+use deno_ast::swc::common::Spanned;
+use deno_ast::view as ast_view;
+
+#[derive(Debug)]
+pub struct NoLoneBlocks;
+
+const CODE: &str = "no-lone-blocks";
+const MESSAGE: &str = "Block is redundant";
+const HINT: &str = "Remove the braces and keep their contents";
+
+impl LintRule for NoLoneBlocks {
+  fn new() -> Box<Self> {
+    Box::new(NoLoneBlocks)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoLoneBlocksHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_lone_blocks.md")
+  }
+}
+
+/// Checks if the statement declares a binding scoped to the enclosing block.
+fn is_block_scoped_decl(stmt: &Stmt) -> bool {
+  match stmt {
+    Stmt::Decl(Decl::Var(var_decl)) => var_decl.kind != VarDeclKind::Var,
+    Stmt::Decl(Decl::Class(_)) | Stmt::Decl(Decl::Fn(_)) => true,
+    _ => false,
+  }
+}
+
+/// Checks if the block is a standalone statement, rather than the body of a
+/// function, a loop, an `if` and so on.
+fn is_lone_block(block_stmt: &ast_view::BlockStmt) -> bool {
+  match block_stmt.parent() {
+    ast_view::Node::BlockStmt(_)
+    | ast_view::Node::Module(_)
+    | ast_view::Node::Script(_) => true,
+    // `case 0: { ... }` is a common style to give each case its own scope
+    ast_view::Node::SwitchCase(switch_case) => {
+      switch_case.inner.cons.len() != 1
+        || switch_case.inner.cons[0].span() != block_stmt.inner.span
+    }
+    _ => false,
+  }
+}
+
+struct NoLoneBlocksHandler;
+
+impl Handler for NoLoneBlocksHandler {
+  fn block_stmt(
+    &mut self,
+    block_stmt: &ast_view::BlockStmt,
+    ctx: &mut Context,
+  ) {
+    if is_lone_block(block_stmt)
+      && !block_stmt.inner.stmts.iter().any(is_block_scoped_decl)
+    {
+      ctx.add_diagnostic_with_hint(block_stmt.inner.span, CODE, MESSAGE, HINT);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_lone_blocks_valid() {
+    assert_lint_ok! {
+      NoLoneBlocks,
+      "if (foo) { bar(); }",
+      "while (foo) { bar(); }",
+      "function foo() { bar(); }",
+      "{ let x = 1; }",
+      "{ const x = 1; }",
+      "{ class Foo {} }",
+      "{ function foo() {} }",
+      "if (foo) { { let x = 1; } bar(); }",
+      "foo: { break foo; }",
+      "switch (foo) { case 0: { bar(); } }",
+      "try { foo(); } catch { bar(); } finally { baz(); }",
+      "class A { static { foo(); } }",
+    };
+  }
+
+  #[test]
+  fn no_lone_blocks_invalid() {
+    assert_lint_err! {
+      NoLoneBlocks,
+      "{}": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "{ foo(); }": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "{ var x = 1; }": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "function foo() { bar(); { baz(); } }": [
+        { col: 24, message: MESSAGE, hint: HINT },
+      ],
+      "{ { foo(); } }": [
+        { col: 0, message: MESSAGE, hint: HINT },
+        { col: 2, message: MESSAGE, hint: HINT },
+      ],
+      "switch (foo) { case 0: bar(); { baz(); } }": [
+        { col: 30, message: MESSAGE, hint: HINT },
+      ],
+    };
+  }
+}
@@ -0,0 +1,307 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::ProgramRef;
+use deno_ast::swc::ast::{BinExpr, CondExpr, Expr};
+use deno_ast::swc::visit::{noop_visit_type, Node, VisitAll, VisitAllWith};
+use derive_more::Display;
+use serde::Deserialize;
+
+#[derive(Debug, Default)]
+pub struct NoMixedOperators {
+  options: NoMixedOperatorsOptions,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoMixedOperatorsOptions {
+  /// Groups of operators which must not be mixed with each other. Operators
+  /// which are not in the same group may be mixed freely. The ternary
+  /// operator is written as `"?:"`.
+  pub groups: Vec<Vec<String>>,
+  /// Whether operators with the same precedence, e.g. `+` and `-`, may be
+  /// mixed.
+  pub allow_same_precedence: bool,
+}
+
+const ARITHMETIC_OPERATORS: &[&str] = &["+", "-", "*", "/", "%", "**"];
+const BITWISE_OPERATORS: &[&str] = &["&", "|", "^", "~", "<<", ">>", ">>>"];
+const COMPARISON_OPERATORS: &[&str] =
+  &["==", "!=", "===", "!==", ">", ">=", "<", "<="];
+const LOGICAL_OPERATORS: &[&str] = &["&&", "||"];
+const RELATIONAL_OPERATORS: &[&str] = &["in", "instanceof"];
+
+const TERNARY_OPERATOR: &str = "?:";
+
+impl Default for NoMixedOperatorsOptions {
+  fn default() -> Self {
+    let groups = [
+      ARITHMETIC_OPERATORS,
+      BITWISE_OPERATORS,
+      COMPARISON_OPERATORS,
+      LOGICAL_OPERATORS,
+      RELATIONAL_OPERATORS,
+    ]
+    .iter()
+    .map(|group| group.iter().map(|op| op.to_string()).collect())
+    .collect();
+
+    Self {
+      groups,
+      allow_same_precedence: true,
+    }
+  }
+}
+
+const CODE: &str = "no-mixed-operators";
+const HINT: &str =
+  "Use parentheses to clarify the intended order of operations";
+
+#[derive(Display)]
+enum NoMixedOperatorsMessage {
+  #[display(fmt = "Unexpected mix of `{}` and `{}`", _0, _1)]
+  Mixed(String, String),
+}
+
+impl NoMixedOperators {
+  pub fn with_options(options: NoMixedOperatorsOptions) -> Box<Self> {
+    Box::new(NoMixedOperators { options })
+  }
+}
+
+impl LintRule for NoMixedOperators {
+  fn new() -> Box<Self> {
+    Box::new(NoMixedOperators::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    let mut visitor = NoMixedOperatorsVisitor::new(context, &self.options);
+    match program {
+      ProgramRef::Module(m) => m.visit_all_with(&DUMMY_NODE, &mut visitor),
+      ProgramRef::Script(s) => s.visit_all_with(&DUMMY_NODE, &mut visitor),
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_mixed_operators.md")
+  }
+}
+
+struct NoMixedOperatorsVisitor<'c, 'view, 'o> {
+  context: &'c mut Context<'view>,
+  options: &'o NoMixedOperatorsOptions,
+}
+
+impl<'c, 'view, 'o> NoMixedOperatorsVisitor<'c, 'view, 'o> {
+  fn new(
+    context: &'c mut Context<'view>,
+    options: &'o NoMixedOperatorsOptions,
+  ) -> Self {
+    Self { context, options }
+  }
+
+  fn in_same_group(&self, a: &str, b: &str) -> bool {
+    self.options.groups.iter().any(|group| {
+      group.iter().any(|op| op == a) && group.iter().any(|op| op == b)
+    })
+  }
+
+  /// Checks an operand of an expression with the operator `parent_op`.
+  /// Parenthesized operands are represented by `Expr::Paren`, so only
+  /// operands without parentheses are inspected.
+  fn check_operand(
+    &mut self,
+    parent_op: &str,
+    parent_precedence: u8,
+    operand: &Expr,
+    operand_comes_first: bool,
+  ) {
+    let bin_expr = match operand {
+      Expr::Bin(bin_expr) => bin_expr,
+      _ => return,
+    };
+
+    let op = bin_expr.op.to_string();
+    if op == parent_op || !self.in_same_group(&op, parent_op) {
+      return;
+    }
+    if self.options.allow_same_precedence
+      && bin_expr.op.precedence() == parent_precedence
+    {
+      return;
+    }
+
+    let message = if operand_comes_first {
+      NoMixedOperatorsMessage::Mixed(op, parent_op.to_string())
+    } else {
+      NoMixedOperatorsMessage::Mixed(parent_op.to_string(), op)
+    };
+    self
+      .context
+      .add_diagnostic_with_hint(bin_expr.span, CODE, message, HINT);
+  }
+}
+
+impl<'c, 'view, 'o> VisitAll for NoMixedOperatorsVisitor<'c, 'view, 'o> {
+  noop_visit_type!();
+
+  fn visit_bin_expr(&mut self, bin_expr: &BinExpr, _: &dyn Node) {
+    let op = bin_expr.op.to_string();
+    let precedence = bin_expr.op.precedence();
+    self.check_operand(&op, precedence, &bin_expr.left, true);
+    self.check_operand(&op, precedence, &bin_expr.right, false);
+  }
+
+  fn visit_cond_expr(&mut self, cond_expr: &CondExpr, _: &dyn Node) {
+    // The ternary operator binds looser than any binary operator
+    self.check_operand(TERNARY_OPERATOR, 0, &cond_expr.test, true);
+    self.check_operand(TERNARY_OPERATOR, 0, &cond_expr.cons, false);
+    self.check_operand(TERNARY_OPERATOR, 0, &cond_expr.alt, false);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  #[test]
+  fn no_mixed_operators_valid() {
+    assert_lint_ok! {
+      NoMixedOperators,
+      "a && b && c && d",
+      "a || b || c || d",
+      "(a || b) && c && d",
+      "a || (b && c && d)",
+      "(a || b || c) && d",
+      "a || b || (c && d)",
+      "a + b + c",
+      "a + b - c",
+      "a * b / c",
+      "a + b << c",
+      "a == b && c < d",
+      "(a + b) * c",
+      "a ? b && c : d",
+      "a && b ? c : d",
+      "a ?? b ?? c",
+      "a == b != c",
+      "a in b instanceof c",
+    };
+
+    assert_lint_ok! {
+      NoMixedOperators,
+      options: json!({
+        "groups": [["&", "|", "^", "~", "<<", ">>", ">>>"], ["&&", "||"]]
+      }),
+      "a + b * c",
+      "a == b != c",
+    };
+  }
+
+  #[test]
+  fn no_mixed_operators_invalid() {
+    assert_lint_err! {
+      NoMixedOperators,
+      "a && b || c": [
+        {
+          col: 0,
+          message: variant!(NoMixedOperatorsMessage, Mixed, "&&", "||"),
+          hint: HINT,
+        },
+      ],
+      "a || b && c": [
+        {
+          col: 5,
+          message: variant!(NoMixedOperatorsMessage, Mixed, "||", "&&"),
+          hint: HINT,
+        },
+      ],
+      "a + b * c": [
+        {
+          col: 4,
+          message: variant!(NoMixedOperatorsMessage, Mixed, "+", "*"),
+          hint: HINT,
+        },
+      ],
+      "a & b | c": [
+        {
+          col: 0,
+          message: variant!(NoMixedOperatorsMessage, Mixed, "&", "|"),
+          hint: HINT,
+        },
+      ],
+    };
+
+    assert_lint_err! {
+      NoMixedOperators,
+      options: json!({ "allowSamePrecedence": false }),
+      "a + b - c": [
+        {
+          col: 0,
+          message: variant!(NoMixedOperatorsMessage, Mixed, "+", "-"),
+          hint: HINT,
+        },
+      ],
+      "a * b / c": [
+        {
+          col: 0,
+          message: variant!(NoMixedOperatorsMessage, Mixed, "*", "/"),
+          hint: HINT,
+        },
+      ],
+      "a == b != c": [
+        {
+          col: 0,
+          message: variant!(NoMixedOperatorsMessage, Mixed, "==", "!="),
+          hint: HINT,
+        },
+      ],
+      "a in b instanceof c": [
+        {
+          col: 0,
+          message: variant!(NoMixedOperatorsMessage, Mixed, "in", "instanceof"),
+          hint: HINT,
+        },
+      ],
+    };
+
+    assert_lint_err! {
+      NoMixedOperators,
+      options: json!({ "groups": [["+", "<<"]] }),
+      "a + b << c": [
+        {
+          col: 0,
+          message: variant!(NoMixedOperatorsMessage, Mixed, "+", "<<"),
+          hint: HINT,
+        },
+      ],
+    };
+
+    assert_lint_err! {
+      NoMixedOperators,
+      options: json!({ "groups": [["&&", "||", "?:"]] }),
+      "a && b ? c : d": [
+        {
+          col: 0,
+          message: variant!(NoMixedOperatorsMessage, Mixed, "&&", "?:"),
+          hint: HINT,
+        },
+      ],
+      "a ? b : c || d": [
+        {
+          col: 8,
+          message: variant!(NoMixedOperatorsMessage, Mixed, "?:", "||"),
+          hint: HINT,
+        },
+      ],
+    };
+  }
+}
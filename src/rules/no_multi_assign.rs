@@ -0,0 +1,147 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::Expr;
+use deno_ast::view as ast_view;
+use serde::Deserialize;
+
+#[derive(Debug, Default)]
+pub struct NoMultiAssign {
+  options: NoMultiAssignOptions,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoMultiAssignOptions {
+  /// Whether chained assignments outside of variable declarations, e.g.
+  /// `a = b = 0`, are allowed.
+  pub ignore_non_declaration: bool,
+}
+
+const CODE: &str = "no-multi-assign";
+const MESSAGE: &str = "Unexpected chained assignment";
+const HINT: &str = "Split it into separate assignment statements";
+
+impl NoMultiAssign {
+  pub fn with_options(options: NoMultiAssignOptions) -> Box<Self> {
+    Box::new(NoMultiAssign { options })
+  }
+}
+
+impl LintRule for NoMultiAssign {
+  fn new() -> Box<Self> {
+    Box::new(NoMultiAssign::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoMultiAssignHandler::new(&self.options).traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_multi_assign.md")
+  }
+}
+
+struct NoMultiAssignHandler<'o> {
+  options: &'o NoMultiAssignOptions,
+}
+
+impl<'o> NoMultiAssignHandler<'o> {
+  fn new(options: &'o NoMultiAssignOptions) -> Self {
+    Self { options }
+  }
+}
+
+impl<'o> Handler for NoMultiAssignHandler<'o> {
+  fn assign_expr(
+    &mut self,
+    assign_expr: &ast_view::AssignExpr,
+    ctx: &mut Context,
+  ) {
+    if self.options.ignore_non_declaration {
+      return;
+    }
+
+    if matches!(&*assign_expr.inner.right, Expr::Assign(_)) {
+      ctx.add_diagnostic_with_hint(assign_expr.inner.span, CODE, MESSAGE, HINT);
+    }
+  }
+
+  fn var_declarator(
+    &mut self,
+    var_declarator: &ast_view::VarDeclarator,
+    ctx: &mut Context,
+  ) {
+    if let Some(init) = &var_declarator.inner.init {
+      if matches!(&**init, Expr::Assign(_)) {
+        ctx.add_diagnostic_with_hint(
+          var_declarator.inner.span,
+          CODE,
+          MESSAGE,
+          HINT,
+        );
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  #[test]
+  fn no_multi_assign_valid() {
+    assert_lint_ok! {
+      NoMultiAssign,
+      "a = 0; b = 0;",
+      "let a = 0; let b = a;",
+      "a += 1;",
+      "const a = (b) => b;",
+      "foo(a = 1);",
+      "for (a = 0, b = 0; ; ) {}",
+    };
+
+    assert_lint_ok! {
+      NoMultiAssign,
+      options: json!({ "ignoreNonDeclaration": true }),
+      "a = b = 0;",
+      "a.x = b.y = c;",
+    };
+  }
+
+  #[test]
+  fn no_multi_assign_invalid() {
+    assert_lint_err! {
+      NoMultiAssign,
+      "a = b = 0;": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "a = b = c = 0;": [
+        { col: 0, message: MESSAGE, hint: HINT },
+        { col: 4, message: MESSAGE, hint: HINT },
+      ],
+      "a.x = b += 1;": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "let a = b = 0;": [{ col: 4, message: MESSAGE, hint: HINT }],
+      "const a = 1, b = c = 2;": [{ col: 13, message: MESSAGE, hint: HINT }],
+    };
+
+    assert_lint_err! {
+      NoMultiAssign,
+      options: json!({ "ignoreNonDeclaration": true }),
+      "let a = b = 0;": [{ col: 4, message: MESSAGE, hint: HINT }],
+    };
+  }
+}
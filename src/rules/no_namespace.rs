@@ -2,12 +2,35 @@
 use super::{Context, LintRule};
 use crate::handler::{Handler, Traverse};
 use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::TsModuleName;
 use deno_ast::swc::common::Spanned;
 use deno_ast::view as ast_view;
 use deno_ast::view::NodeTrait;
+use serde::Deserialize;
 
-#[derive(Debug)]
-pub struct NoNamespace;
+#[derive(Debug, Default)]
+pub struct NoNamespace {
+  options: NoNamespaceOptions,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoNamespaceOptions {
+  /// Whether namespaces declared with `declare`, and the ones nested in them,
+  /// are allowed.
+  pub allow_declarations: bool,
+  /// Whether namespaces are allowed in definition files (`.d.ts`).
+  pub allow_definition_files: bool,
+}
+
+impl Default for NoNamespaceOptions {
+  fn default() -> Self {
+    Self {
+      allow_declarations: true,
+      allow_definition_files: true,
+    }
+  }
+}
 
 const CODE: &str = "no-namespace";
 const MESSAGE: &str = "TypeScript's `module` and `namespace` are discouraged to
@@ -15,9 +38,15 @@ use";
 const HINT: &str = "Use ES2015 module syntax (`import`/`export`) to organize
 the code instead";
 
+impl NoNamespace {
+  pub fn with_options(options: NoNamespaceOptions) -> Box<Self> {
+    Box::new(NoNamespace { options })
+  }
+}
+
 impl LintRule for NoNamespace {
   fn new() -> Box<Self> {
-    Box::new(NoNamespace)
+    Box::new(NoNamespace::default())
   }
 
   fn tags(&self) -> &'static [&'static str] {
@@ -37,11 +66,13 @@ impl LintRule for NoNamespace {
     context: &mut Context,
     program: Program<'_>,
   ) {
-    if context.file_name().ends_with(".d.ts") {
+    if self.options.allow_definition_files
+      && context.file_name().ends_with(".d.ts")
+    {
       return;
     }
 
-    NoNamespaceHandler.traverse(program, context);
+    NoNamespaceHandler::new(&self.options).traverse(program, context);
   }
 
   #[cfg(feature = "docs")]
@@ -50,9 +81,17 @@ impl LintRule for NoNamespace {
   }
 }
 
-struct NoNamespaceHandler;
+struct NoNamespaceHandler<'o> {
+  options: &'o NoNamespaceOptions,
+}
+
+impl<'o> NoNamespaceHandler<'o> {
+  fn new(options: &'o NoNamespaceOptions) -> Self {
+    Self { options }
+  }
+}
 
-impl Handler for NoNamespaceHandler {
+impl<'o> Handler for NoNamespaceHandler<'o> {
   fn ts_module_decl(
     &mut self,
     module_decl: &ast_view::TsModuleDecl,
@@ -69,15 +108,27 @@ impl Handler for NoNamespaceHandler {
       }
     }
 
-    if !inside_ambient_context(module_decl.as_node()) {
-      ctx.add_diagnostic_with_hint(module_decl.span(), CODE, MESSAGE, HINT);
+    // `declare global {}` and `declare module "foo" {}` augment existing
+    // scopes and modules, so they have no ES module alternative
+    if module_decl.inner.global
+      || matches!(module_decl.inner.id, TsModuleName::Str(_))
+    {
+      return;
+    }
+    if self.options.allow_declarations
+      && inside_ambient_context(module_decl.as_node())
+    {
+      return;
     }
+
+    ctx.add_diagnostic_with_hint(module_decl.span(), CODE, MESSAGE, HINT);
   }
 }
 
 #[cfg(test)]
 mod tests {
   use super::*;
+  use serde_json::json;
 
   #[test]
   fn no_namespace_valid() {
@@ -141,6 +192,20 @@ export declare namespace Utility {
 }
       "#,
     };
+
+    assert_lint_ok! {
+      NoNamespace,
+      options: json!({ "allowDeclarations": false }),
+      r#"declare global {}"#,
+      r#"declare module 'foo' {}"#,
+    };
+
+    assert_lint_ok! {
+      NoNamespace,
+      filename: "test.d.ts",
+      options: json!({ "allowDefinitionFiles": false }),
+      r#"declare namespace foo {}"#,
+    };
   }
 
   #[test]
@@ -181,5 +246,49 @@ export declare namespace Utility {
         },
       ],
     };
+
+    assert_lint_err! {
+      NoNamespace,
+      options: json!({ "allowDeclarations": false }),
+      "declare namespace foo {}": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "declare module foo { namespace bar {} }": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        },
+        {
+          col: 21,
+          message: MESSAGE,
+          hint: HINT,
+        },
+      ],
+      "declare global { namespace foo {} }": [
+        {
+          col: 17,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoNamespace,
+      filename: "test.d.ts",
+      options: json!({ "allowDefinitionFiles": false }),
+      "namespace foo {}": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+    };
   }
 }
@@ -0,0 +1,161 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{BinaryOp, Expr, Stmt, UnaryOp};
+use deno_ast::view as ast_view;
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct NoNegatedCondition;
+
+const CODE: &str = "no-negated-condition";
+
+#[derive(Display)]
+enum NoNegatedConditionMessage {
+  #[display(fmt = "Unexpected negated condition in `if` with `else`")]
+  IfStmt,
+  #[display(fmt = "Unexpected negated condition in ternary expression")]
+  CondExpr,
+}
+
+#[derive(Display)]
+enum NoNegatedConditionHint {
+  #[display(fmt = "Invert the condition and swap the branches")]
+  Invert,
+}
+
+impl LintRule for NoNegatedCondition {
+  fn new() -> Box<Self> {
+    Box::new(NoNegatedCondition)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoNegatedConditionHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_negated_condition.md")
+  }
+}
+
+/// Checks if the expression is `!x`, `a != b` or `a !== b`.
+fn is_negated(expr: &Expr) -> bool {
+  match expr {
+    Expr::Unary(unary) => unary.op == UnaryOp::Bang,
+    Expr::Bin(bin) => {
+      matches!(bin.op, BinaryOp::NotEq | BinaryOp::NotEqEq)
+    }
+    _ => false,
+  }
+}
+
+struct NoNegatedConditionHandler;
+
+impl Handler for NoNegatedConditionHandler {
+  fn if_stmt(&mut self, if_stmt: &ast_view::IfStmt, ctx: &mut Context) {
+    let alt = match &if_stmt.inner.alt {
+      Some(alt) => alt,
+      None => return,
+    };
+    // Swapping is not applicable to `else if` chains
+    if matches!(&**alt, Stmt::If(_)) {
+      return;
+    }
+
+    if is_negated(&if_stmt.inner.test) {
+      ctx.add_diagnostic_with_hint(
+        if_stmt.inner.span,
+        CODE,
+        NoNegatedConditionMessage::IfStmt,
+        NoNegatedConditionHint::Invert,
+      );
+    }
+  }
+
+  fn cond_expr(&mut self, cond_expr: &ast_view::CondExpr, ctx: &mut Context) {
+    if is_negated(&cond_expr.inner.test) {
+      ctx.add_diagnostic_with_hint(
+        cond_expr.inner.span,
+        CODE,
+        NoNegatedConditionMessage::CondExpr,
+        NoNegatedConditionHint::Invert,
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_negated_condition_valid() {
+    assert_lint_ok! {
+      NoNegatedCondition,
+      "if (!a) { b(); }",
+      "if (a !== b) { c(); }",
+      "if (a) { b(); } else { c(); }",
+      "if (!a) { b(); } else if (c) { d(); }",
+      "if (!a) { b(); } else if (c) { d(); } else { e(); }",
+      "const x = a ? b : c;",
+      "const x = a === b ? c : d;",
+      "const x = !a || b ? c : d;",
+    };
+  }
+
+  #[test]
+  fn no_negated_condition_invalid() {
+    assert_lint_err! {
+      NoNegatedCondition,
+      "if (!a) { b(); } else { c(); }": [
+        {
+          col: 0,
+          message: NoNegatedConditionMessage::IfStmt,
+          hint: NoNegatedConditionHint::Invert,
+        },
+      ],
+      "if (a != b) { c(); } else { d(); }": [
+        {
+          col: 0,
+          message: NoNegatedConditionMessage::IfStmt,
+          hint: NoNegatedConditionHint::Invert,
+        },
+      ],
+      "if (a !== b) c(); else d();": [
+        {
+          col: 0,
+          message: NoNegatedConditionMessage::IfStmt,
+          hint: NoNegatedConditionHint::Invert,
+        },
+      ],
+      "const x = !a ? b : c;": [
+        {
+          col: 10,
+          message: NoNegatedConditionMessage::CondExpr,
+          hint: NoNegatedConditionHint::Invert,
+        },
+      ],
+      "const x = a !== b ? c : d;": [
+        {
+          col: 10,
+          message: NoNegatedConditionMessage::CondExpr,
+          hint: NoNegatedConditionHint::Invert,
+        },
+      ],
+    };
+  }
+}
@@ -0,0 +1,118 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::ProgramRef;
+use deno_ast::swc::ast::{CallExpr, Expr, ExprOrSuper, NewExpr};
+use deno_ast::swc::common::Span;
+use deno_ast::swc::utils::ident::IdentLike;
+use deno_ast::swc::visit::noop_visit_type;
+use deno_ast::swc::visit::Node;
+use deno_ast::swc::visit::{VisitAll, VisitAllWith};
+
+#[derive(Debug)]
+pub struct NoNewFunc;
+
+const CODE: &str = "no-new-func";
+const MESSAGE: &str = "The `Function` constructor is a form of `eval`";
+const HINT: &str =
+  "Avoid generating code at runtime, define the function statically instead";
+
+impl LintRule for NoNewFunc {
+  fn new() -> Box<Self> {
+    Box::new(NoNewFunc)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    let mut visitor = NoNewFuncVisitor::new(context);
+    match program {
+      ProgramRef::Module(m) => m.visit_all_with(&DUMMY_NODE, &mut visitor),
+      ProgramRef::Script(s) => s.visit_all_with(&DUMMY_NODE, &mut visitor),
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_new_func.md")
+  }
+}
+
+struct NoNewFuncVisitor<'c, 'view> {
+  context: &'c mut Context<'view>,
+}
+
+impl<'c, 'view> NoNewFuncVisitor<'c, 'view> {
+  fn new(context: &'c mut Context<'view>) -> Self {
+    Self { context }
+  }
+
+  fn check_callee(&mut self, callee: &Expr, span: Span) {
+    if let Expr::Ident(ident) = callee {
+      if ident.sym == *"Function"
+        && self.context.scope().var(&ident.to_id()).is_none()
+      {
+        self
+          .context
+          .add_diagnostic_with_hint(span, CODE, MESSAGE, HINT);
+      }
+    }
+  }
+}
+
+impl<'c, 'view> VisitAll for NoNewFuncVisitor<'c, 'view> {
+  noop_visit_type!();
+
+  fn visit_new_expr(&mut self, new_expr: &NewExpr, _parent: &dyn Node) {
+    self.check_callee(&new_expr.callee, new_expr.span);
+  }
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _parent: &dyn Node) {
+    if let ExprOrSuper::Expr(callee) = &call_expr.callee {
+      self.check_callee(callee, call_expr.span);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_new_func_valid() {
+    assert_lint_ok! {
+      NoNewFunc,
+      "const add = (a, b) => a + b;",
+      "const f = function (a) { return a; };",
+      "new Foo('a', 'return a');",
+      "Function.prototype.call.call(foo);",
+      "function foo(Function) { return new Function('a', 'return a'); }",
+      "class Function {}; new Function();",
+      "const Function = () => {}; Function('return 1');",
+    };
+  }
+
+  #[test]
+  fn no_new_func_invalid() {
+    assert_lint_err! {
+      NoNewFunc,
+      "const add = new Function('a', 'b', 'return a + b');": [
+        { col: 12, message: MESSAGE, hint: HINT },
+      ],
+      "const add = Function('a', 'b', 'return a + b');": [
+        { col: 12, message: MESSAGE, hint: HINT },
+      ],
+      "new Function;": [
+        { col: 0, message: MESSAGE, hint: HINT },
+      ],
+      "function foo() { return Function('return this')(); }": [
+        { col: 24, message: MESSAGE, hint: HINT },
+      ],
+    };
+  }
+}
@@ -0,0 +1,146 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::Expr;
+use deno_ast::swc::common::Spanned;
+use deno_ast::swc::utils::ident::IdentLike;
+use deno_ast::view as ast_view;
+use deno_ast::view::NodeTrait;
+
+#[derive(Debug)]
+pub struct NoNewObject;
+
+const CODE: &str = "no-new-object";
+const MESSAGE: &str = "Use an object literal instead of `new Object()`";
+const HINT: &str = "Replace it with `{}`";
+
+impl LintRule for NoNewObject {
+  fn new() -> Box<Self> {
+    Box::new(NoNewObject)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoNewObjectHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_new_object.md")
+  }
+}
+
+/// Checks if an object literal placed at the position of the node would be
+/// parsed as a block, e.g. at the start of a statement or as the body of an
+/// arrow function, so that it needs to be wrapped in parentheses.
+fn needs_parens(node: ast_view::Node) -> bool {
+  let lo = node.span().lo;
+  let mut current = node;
+  while let Some(parent) = current.parent() {
+    match parent {
+      ast_view::Node::ExprStmt(_) => return true,
+      ast_view::Node::ArrowExpr(arrow) => {
+        return arrow.body.span() == current.span()
+      }
+      _ if parent.span().lo == lo => current = parent,
+      _ => return false,
+    }
+  }
+  false
+}
+
+struct NoNewObjectHandler;
+
+impl Handler for NoNewObjectHandler {
+  fn new_expr(&mut self, new_expr: &ast_view::NewExpr, ctx: &mut Context) {
+    let ident = match &*new_expr.inner.callee {
+      Expr::Ident(ident) => ident,
+      _ => return,
+    };
+    if ident.sym != *"Object" || ctx.scope().var(&ident.to_id()).is_some() {
+      return;
+    }
+
+    let arg_count = new_expr.inner.args.as_ref().map_or(0, |args| args.len());
+    match arg_count {
+      0 => {
+        let new_text = if needs_parens(new_expr.as_node()) {
+          "({})"
+        } else {
+          "{}"
+        };
+        let fix = ctx.create_fix(
+          new_expr.inner.span,
+          new_text,
+          "Replace with an object literal",
+        );
+        ctx.add_diagnostic_with_hint_and_fix(
+          new_expr.inner.span,
+          CODE,
+          MESSAGE,
+          HINT,
+          fix,
+        );
+      }
+      1 => {
+        ctx.add_diagnostic_with_hint(new_expr.inner.span, CODE, MESSAGE, HINT);
+      }
+      _ => {}
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_new_object_valid() {
+    assert_lint_ok! {
+      NoNewObject,
+      "const foo = {};",
+      "const foo = Object(bar);",
+      "const foo = new Foo();",
+      "const foo = new Object(a, b);",
+      "function f(Object) { return new Object(); }",
+      "class Object {}; const foo = new Object();",
+    };
+  }
+
+  #[test]
+  fn no_new_object_invalid() {
+    assert_lint_err! {
+      NoNewObject,
+      "const foo = new Object();": [
+        { col: 12, message: MESSAGE, hint: HINT, fix: "const foo = {};" },
+      ],
+      "const foo = new Object;": [
+        { col: 12, message: MESSAGE, hint: HINT, fix: "const foo = {};" },
+      ],
+      "const foo = new Object(bar);": [
+        { col: 12, message: MESSAGE, hint: HINT },
+      ],
+      "new Object();": [
+        { col: 0, message: MESSAGE, hint: HINT, fix: "({});" },
+      ],
+      "new Object().foo = 1;": [
+        { col: 0, message: MESSAGE, hint: HINT, fix: "({}).foo = 1;" },
+      ],
+      "const f = () => new Object();": [
+        { col: 16, message: MESSAGE, hint: HINT, fix: "const f = () => ({});" },
+      ],
+    };
+  }
+}
@@ -0,0 +1,207 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::ProgramRef;
+use deno_ast::swc::ast::{JSXAttrValue, Lit, Str};
+use deno_ast::swc::common::{BytePos, Span};
+use deno_ast::swc::visit::{noop_visit_type, Node, Visit, VisitWith};
+
+#[derive(Debug)]
+pub struct NoNonoctalDecimalEscape;
+
+const CODE: &str = "no-nonoctal-decimal-escape";
+
+fn message(escape: &str) -> String {
+  format!("Don't use `{}` escape sequence", escape)
+}
+
+fn hint(digit: char) -> String {
+  format!(
+    "Replace it with `{}`, or use `\\\\{}` if a backslash is intended",
+    digit, digit
+  )
+}
+
+impl LintRule for NoNonoctalDecimalEscape {
+  fn new() -> Box<Self> {
+    Box::new(NoNonoctalDecimalEscape)
+  }
+
+  fn tags(&self) -> &'static [&'static str] {
+    &["recommended"]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    let mut visitor = NoNonoctalDecimalEscapeVisitor::new(context);
+    match program {
+      ProgramRef::Module(m) => m.visit_with(&DUMMY_NODE, &mut visitor),
+      ProgramRef::Script(s) => s.visit_with(&DUMMY_NODE, &mut visitor),
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_nonoctal_decimal_escape.md")
+  }
+}
+
+struct NonoctalDecimalEscape {
+  /// Byte offset of the backslash in the raw literal
+  offset: usize,
+  digit: char,
+  /// Whether the escape directly follows `\0`, in which case replacing it
+  /// with the plain digit would turn `\0` into an octal escape
+  follows_null_escape: bool,
+}
+
+/// Finds all `\8` and `\9` escape sequences in the raw text of a string
+/// literal.
+fn find_nonoctal_decimal_escapes(raw: &str) -> Vec<NonoctalDecimalEscape> {
+  let bytes = raw.as_bytes();
+  let mut escapes = vec![];
+  let mut prev_escape = None;
+  let mut i = 0;
+  while i < bytes.len() {
+    if bytes[i] != b'\\' {
+      prev_escape = None;
+      i += 1;
+      continue;
+    }
+
+    match bytes.get(i + 1) {
+      Some(digit @ b'8') | Some(digit @ b'9') => {
+        escapes.push(NonoctalDecimalEscape {
+          offset: i,
+          digit: *digit as char,
+          follows_null_escape: prev_escape == Some(b'0'),
+        });
+      }
+      _ => {}
+    }
+    prev_escape = bytes.get(i + 1).copied();
+    i += 2;
+  }
+  escapes
+}
+
+struct NoNonoctalDecimalEscapeVisitor<'c, 'view> {
+  context: &'c mut Context<'view>,
+}
+
+impl<'c, 'view> NoNonoctalDecimalEscapeVisitor<'c, 'view> {
+  fn new(context: &'c mut Context<'view>) -> Self {
+    Self { context }
+  }
+}
+
+impl<'c, 'view> Visit for NoNonoctalDecimalEscapeVisitor<'c, 'view> {
+  noop_visit_type!();
+
+  fn visit_str(&mut self, str_lit: &Str, _: &dyn Node) {
+    let raw = self.context.file_text_substring(&str_lit.span);
+    for escape in find_nonoctal_decimal_escapes(raw) {
+      let lo = str_lit.span.lo + BytePos(escape.offset as u32);
+      let span = Span::new(lo, lo + BytePos(2), Default::default());
+      let new_text = if escape.follows_null_escape {
+        format!("\\x3{}", escape.digit)
+      } else {
+        escape.digit.to_string()
+      };
+      let fix = self.context.create_fix(
+        span,
+        new_text,
+        "Replace the escape sequence with the digit",
+      );
+      self.context.add_diagnostic_with_hint_and_fix(
+        span,
+        CODE,
+        message(&format!("\\{}", escape.digit)),
+        hint(escape.digit),
+        fix,
+      );
+    }
+  }
+
+  fn visit_jsx_attr_value(&mut self, value: &JSXAttrValue, _: &dyn Node) {
+    // Escape sequences are not interpreted in JSX attribute strings
+    if !matches!(value, JSXAttrValue::Lit(Lit::Str(_))) {
+      value.visit_children_with(self);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_nonoctal_decimal_escape_valid() {
+    assert_lint_ok! {
+      NoNonoctalDecimalEscape,
+      r#"const foo = "8";"#,
+      r#"const foo = "\\8";"#,
+      r#"const foo = "\x38";"#,
+      r#"const foo = "9";"#,
+      r#"const foo = "\0";"#,
+      r#"const foo = `8`;"#,
+    };
+
+    assert_lint_ok! {
+      NoNonoctalDecimalEscape,
+      filename: "foo.tsx",
+      r#"<div title="\8" />"#,
+    };
+  }
+
+  #[test]
+  fn no_nonoctal_decimal_escape_invalid() {
+    assert_lint_err! {
+      NoNonoctalDecimalEscape,
+      r#"const foo = "\8";"#: [
+        {
+          col: 13,
+          message: message(r"\8"),
+          hint: hint('8'),
+          fix: r#"const foo = "8";"#,
+        },
+      ],
+      r#"const foo = "a\9b\8";"#: [
+        {
+          col: 14,
+          message: message(r"\9"),
+          hint: hint('9'),
+          fix: r#"const foo = "a9b\8";"#,
+        },
+        {
+          col: 17,
+          message: message(r"\8"),
+          hint: hint('8'),
+          fix: r#"const foo = "a\9b8";"#,
+        },
+      ],
+      r#"const foo = "\\\8";"#: [
+        {
+          col: 15,
+          message: message(r"\8"),
+          hint: hint('8'),
+          fix: r#"const foo = "\\8";"#,
+        },
+      ],
+      r#"const foo = "\0\8";"#: [
+        {
+          col: 15,
+          message: message(r"\8"),
+          hint: hint('8'),
+          fix: r#"const foo = "\0\x38";"#,
+        },
+      ],
+    };
+  }
+}
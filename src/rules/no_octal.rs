@@ -84,6 +84,13 @@ mod tests {
       "\"07\"",
       "0x08",
       "-0.01",
+      "0",
+      "0o71",
+      "0O71",
+      "0b101",
+      "0.5",
+      "0e10",
+      "0n",
     };
   }
 
@@ -93,6 +100,8 @@ mod tests {
       NoOctal,
       "07": [{col: 0, message: MESSAGE, hint: HINT}],
       "let x = 7 + 07": [{col: 12, message: MESSAGE, hint: HINT}],
+      "08": [{col: 0, message: MESSAGE, hint: HINT}],
+      "0.5 + 071": [{col: 6, message: MESSAGE, hint: HINT}],
 
       // https://github.com/denoland/deno/issues/10954
       // Make sure it doesn't panic
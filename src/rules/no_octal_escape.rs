@@ -0,0 +1,177 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::ProgramRef;
+use deno_ast::swc::ast::{JSXAttrValue, Lit, Str};
+use deno_ast::swc::visit::{noop_visit_type, Node, Visit, VisitWith};
+
+#[derive(Debug)]
+pub struct NoOctalEscape;
+
+const CODE: &str = "no-octal-escape";
+
+fn message(escape: &str) -> String {
+  format!("Octal escape sequence `{}` is deprecated", escape)
+}
+
+fn hint(code: u32) -> String {
+  format!(
+    "Use a hex escape `\\x{:02X}` or a Unicode escape `\\u{:04X}` instead",
+    code, code
+  )
+}
+
+impl LintRule for NoOctalEscape {
+  fn new() -> Box<Self> {
+    Box::new(NoOctalEscape)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    let mut visitor = NoOctalEscapeVisitor::new(context);
+    match program {
+      ProgramRef::Module(m) => m.visit_with(&DUMMY_NODE, &mut visitor),
+      ProgramRef::Script(s) => s.visit_with(&DUMMY_NODE, &mut visitor),
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_octal_escape.md")
+  }
+}
+
+/// Finds the first octal escape sequence in the raw text of a string literal,
+/// e.g. `\251`, and returns it along with its value. `\0` is a valid escape
+/// sequence for the null character unless it is followed by a decimal digit.
+fn find_octal_escape(raw: &str) -> Option<(&str, u32)> {
+  let bytes = raw.as_bytes();
+  let mut i = 0;
+  while i < bytes.len() {
+    if bytes[i] != b'\\' {
+      i += 1;
+      continue;
+    }
+
+    let start = i;
+    let is_octal_digit = |i: usize| matches!(bytes.get(i), Some(b'0'..=b'7'));
+    let max_len = match bytes.get(i + 1) {
+      Some(b'0') => match bytes.get(i + 2) {
+        Some(b'0'..=b'7') => 3,
+        Some(b'8') | Some(b'9') => 1,
+        _ => 0,
+      },
+      Some(b'1'..=b'3') => 3,
+      Some(b'4'..=b'7') => 2,
+      _ => 0,
+    };
+    if max_len == 0 {
+      // Skip the escaped character
+      i += 2;
+      continue;
+    }
+
+    let mut end = start + 1;
+    while end - start - 1 < max_len && is_octal_digit(end) {
+      end += 1;
+    }
+    let digits = &raw[start + 1..end];
+    let value = u32::from_str_radix(digits, 8).unwrap_or(0);
+    return Some((&raw[start..end], value));
+  }
+  None
+}
+
+struct NoOctalEscapeVisitor<'c, 'view> {
+  context: &'c mut Context<'view>,
+}
+
+impl<'c, 'view> NoOctalEscapeVisitor<'c, 'view> {
+  fn new(context: &'c mut Context<'view>) -> Self {
+    Self { context }
+  }
+}
+
+impl<'c, 'view> Visit for NoOctalEscapeVisitor<'c, 'view> {
+  noop_visit_type!();
+
+  fn visit_str(&mut self, str_lit: &Str, _: &dyn Node) {
+    let raw = self.context.file_text_substring(&str_lit.span);
+    if let Some((escape, value)) = find_octal_escape(raw) {
+      let message = message(escape);
+      self.context.add_diagnostic_with_hint(
+        str_lit.span,
+        CODE,
+        message,
+        hint(value),
+      );
+    }
+  }
+
+  fn visit_jsx_attr_value(&mut self, value: &JSXAttrValue, _: &dyn Node) {
+    // Escape sequences are not interpreted in JSX attribute strings
+    if !matches!(value, JSXAttrValue::Lit(Lit::Str(_))) {
+      value.visit_children_with(self);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_octal_escape_valid() {
+    assert_lint_ok! {
+      NoOctalEscape,
+      r#"const foo = "\xA9";"#,
+      r#"const foo = "©";"#,
+      r#"const foo = "\0";"#,
+      r#"const foo = "foo\0";"#,
+      r#"const foo = "\\251";"#,
+      r#"const foo = "\8";"#,
+      r#"const foo = `\x00`;"#,
+      "const foo = '251';",
+    };
+
+    assert_lint_ok! {
+      NoOctalEscape,
+      filename: "foo.tsx",
+      r#"<div title="\251" />"#,
+    };
+  }
+
+  #[test]
+  fn no_octal_escape_invalid() {
+    assert_lint_err! {
+      NoOctalEscape,
+      r#"const foo = "\251";"#: [
+        { col: 12, message: message(r"\251"), hint: hint(0o251) },
+      ],
+      r#"const foo = "Copyright \251 \252";"#: [
+        { col: 12, message: message(r"\251"), hint: hint(0o251) },
+      ],
+      r#"const foo = '\1';"#: [
+        { col: 12, message: message(r"\1"), hint: hint(1) },
+      ],
+      r#"const foo = '\01';"#: [
+        { col: 12, message: message(r"\01"), hint: hint(1) },
+      ],
+      r#"const foo = '\08';"#: [
+        { col: 12, message: message(r"\0"), hint: hint(0) },
+      ],
+      r#"const foo = '\477';"#: [
+        { col: 12, message: message(r"\47"), hint: hint(0o47) },
+      ],
+      r#"const foo = '\\\7';"#: [
+        { col: 12, message: message(r"\7"), hint: hint(7) },
+      ],
+    };
+  }
+}
@@ -0,0 +1,364 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::scopes::BindingKind;
+use crate::ProgramRef;
+use deno_ast::swc::ast::{
+  AssignExpr, Expr, ExprOrSuper, ForInStmt, ForOfStmt, Ident, ObjectPatProp,
+  Pat, PatOrExpr, UnaryExpr, UnaryOp, UpdateExpr, VarDeclOrPat,
+};
+use deno_ast::swc::utils::ident::IdentLike;
+use deno_ast::swc::visit::{noop_visit_type, Node, VisitAll, VisitAllWith};
+use derive_more::Display;
+use serde::Deserialize;
+
+#[derive(Debug, Default)]
+pub struct NoParamReassign {
+  options: NoParamReassignOptions,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoParamReassignOptions {
+  /// Whether modifications of properties of parameters, e.g. `x.foo = 1`, are
+  /// reported as well.
+  pub props: bool,
+  /// Names of parameters whose properties may be modified even if `props` is
+  /// enabled, e.g. `"acc"` for accumulators of `Array.prototype.reduce`.
+  pub ignore_property_modifications_for: Vec<String>,
+}
+
+const CODE: &str = "no-param-reassign";
+const HINT: &str = "Assign the new value to a local variable instead";
+
+#[derive(Display)]
+enum NoParamReassignMessage {
+  #[display(fmt = "Assignment to function parameter `{}`", _0)]
+  Param(String),
+  #[display(fmt = "Assignment to property of function parameter `{}`", _0)]
+  Property(String),
+}
+
+impl NoParamReassign {
+  pub fn with_options(options: NoParamReassignOptions) -> Box<Self> {
+    Box::new(NoParamReassign { options })
+  }
+}
+
+impl LintRule for NoParamReassign {
+  fn new() -> Box<Self> {
+    Box::new(NoParamReassign::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    let mut visitor = NoParamReassignVisitor::new(context, &self.options);
+    match program {
+      ProgramRef::Module(m) => m.visit_all_with(&DUMMY_NODE, &mut visitor),
+      ProgramRef::Script(s) => s.visit_all_with(&DUMMY_NODE, &mut visitor),
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_param_reassign.md")
+  }
+}
+
+/// Returns the identifier at the root of a member expression, e.g. `foo` of
+/// `foo.bar[baz]`.
+fn root_ident(expr: &Expr) -> Option<&Ident> {
+  match expr {
+    Expr::Ident(ident) => Some(ident),
+    Expr::Member(member_expr) => match &member_expr.obj {
+      ExprOrSuper::Expr(obj) => root_ident(obj),
+      ExprOrSuper::Super(_) => None,
+    },
+    Expr::Paren(paren) => root_ident(&paren.expr),
+    _ => None,
+  }
+}
+
+struct NoParamReassignVisitor<'c, 'view, 'o> {
+  context: &'c mut Context<'view>,
+  options: &'o NoParamReassignOptions,
+}
+
+impl<'c, 'view, 'o> NoParamReassignVisitor<'c, 'view, 'o> {
+  fn new(
+    context: &'c mut Context<'view>,
+    options: &'o NoParamReassignOptions,
+  ) -> Self {
+    Self { context, options }
+  }
+
+  /// Bindings are resolved through the scope analysis, so an inner
+  /// declaration shadowing a parameter is not treated as the parameter.
+  fn is_param(&self, ident: &Ident) -> bool {
+    self
+      .context
+      .scope()
+      .var(&ident.to_id())
+      .map_or(false, |var| var.kind() == BindingKind::Param)
+  }
+
+  fn check_ident(&mut self, ident: &Ident) {
+    if self.is_param(ident) {
+      self.context.add_diagnostic_with_hint(
+        ident.span,
+        CODE,
+        NoParamReassignMessage::Param(ident.sym.to_string()),
+        HINT,
+      );
+    }
+  }
+
+  fn check_property_of(&mut self, expr: &Expr) {
+    if !self.options.props {
+      return;
+    }
+
+    if let Some(ident) = root_ident(expr) {
+      let ignored = self
+        .options
+        .ignore_property_modifications_for
+        .iter()
+        .any(|name| ident.sym == **name);
+      if !ignored && self.is_param(ident) {
+        self.context.add_diagnostic_with_hint(
+          ident.span,
+          CODE,
+          NoParamReassignMessage::Property(ident.sym.to_string()),
+          HINT,
+        );
+      }
+    }
+  }
+
+  fn check_expr(&mut self, expr: &Expr) {
+    match expr {
+      Expr::Ident(ident) => self.check_ident(ident),
+      Expr::Member(_) => self.check_property_of(expr),
+      Expr::Paren(paren) => self.check_expr(&paren.expr),
+      _ => {}
+    }
+  }
+
+  fn check_pat(&mut self, pat: &Pat) {
+    match pat {
+      Pat::Ident(binding_ident) => self.check_ident(&binding_ident.id),
+      Pat::Array(array_pat) => {
+        for elem in array_pat.elems.iter().flatten() {
+          self.check_pat(elem);
+        }
+      }
+      Pat::Object(object_pat) => {
+        for prop in &object_pat.props {
+          match prop {
+            ObjectPatProp::KeyValue(key_value) => {
+              self.check_pat(&key_value.value)
+            }
+            ObjectPatProp::Assign(assign) => self.check_ident(&assign.key),
+            ObjectPatProp::Rest(rest) => self.check_pat(&rest.arg),
+          }
+        }
+      }
+      Pat::Rest(rest_pat) => self.check_pat(&rest_pat.arg),
+      Pat::Assign(assign_pat) => self.check_pat(&assign_pat.left),
+      Pat::Expr(expr) => self.check_expr(expr),
+      Pat::Invalid(_) => {}
+    }
+  }
+}
+
+impl<'c, 'view, 'o> VisitAll for NoParamReassignVisitor<'c, 'view, 'o> {
+  noop_visit_type!();
+
+  fn visit_assign_expr(&mut self, assign_expr: &AssignExpr, _: &dyn Node) {
+    match &assign_expr.left {
+      PatOrExpr::Pat(pat) => self.check_pat(pat),
+      PatOrExpr::Expr(expr) => self.check_expr(expr),
+    }
+  }
+
+  fn visit_update_expr(&mut self, update_expr: &UpdateExpr, _: &dyn Node) {
+    self.check_expr(&update_expr.arg);
+  }
+
+  fn visit_unary_expr(&mut self, unary_expr: &UnaryExpr, _: &dyn Node) {
+    if unary_expr.op == UnaryOp::Delete {
+      self.check_property_of(&unary_expr.arg);
+    }
+  }
+
+  fn visit_for_in_stmt(&mut self, for_in: &ForInStmt, _: &dyn Node) {
+    if let VarDeclOrPat::Pat(pat) = &for_in.left {
+      self.check_pat(pat);
+    }
+  }
+
+  fn visit_for_of_stmt(&mut self, for_of: &ForOfStmt, _: &dyn Node) {
+    if let VarDeclOrPat::Pat(pat) = &for_of.left {
+      self.check_pat(pat);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  #[test]
+  fn no_param_reassign_valid() {
+    assert_lint_ok! {
+      NoParamReassign,
+      "function foo(a) { var b = a; }",
+      "function foo(a) { a.prop = 'value'; }",
+      "function foo(a) { for (a.prop in obj); }",
+      "function foo(a) { a.b++; delete a.c; }",
+      "function foo(a) { var a; }",
+      "function foo(a) { { let a = 1; a = 2; } }",
+      "function foo(a) { const f = (a) => a; a.x = f(1); }",
+      "function foo() { let a = 1; a = 2; }",
+      "try {} catch (e) { e = 1; }",
+    };
+
+    assert_lint_ok! {
+      NoParamReassign,
+      options: json!({
+        "props": true,
+        "ignorePropertyModificationsFor": ["acc"]
+      }),
+      "function foo(a) { bar(a.b); }",
+      "function foo(a) { a.get(0).b = 1; }",
+      "list.reduce((acc, x) => { acc[x.id] = x; return acc; }, {});",
+      "function foo(acc) { acc.total++; delete acc.stale; }",
+      "function foo(a) { { let a = {}; a.b = 1; } }",
+    };
+  }
+
+  #[test]
+  fn no_param_reassign_invalid() {
+    assert_lint_err! {
+      NoParamReassign,
+      "function foo(bar) { bar = 13; }": [
+        {
+          col: 20,
+          message: variant!(NoParamReassignMessage, Param, "bar"),
+          hint: HINT,
+        },
+      ],
+      "function foo(bar) { bar += 13; }": [
+        {
+          col: 20,
+          message: variant!(NoParamReassignMessage, Param, "bar"),
+          hint: HINT,
+        },
+      ],
+      "function foo(bar) { (function() { bar = 13; })(); }": [
+        {
+          col: 34,
+          message: variant!(NoParamReassignMessage, Param, "bar"),
+          hint: HINT,
+        },
+      ],
+      "function foo(bar) { ++bar; }": [
+        {
+          col: 22,
+          message: variant!(NoParamReassignMessage, Param, "bar"),
+          hint: HINT,
+        },
+      ],
+      "function foo(bar) { for (bar in baz) {} }": [
+        {
+          col: 25,
+          message: variant!(NoParamReassignMessage, Param, "bar"),
+          hint: HINT,
+        },
+      ],
+      "function foo(bar) { [bar] = qux; }": [
+        {
+          col: 21,
+          message: variant!(NoParamReassignMessage, Param, "bar"),
+          hint: HINT,
+        },
+      ],
+      "function foo({ bar }) { ({ bar } = qux); }": [
+        {
+          col: 27,
+          message: variant!(NoParamReassignMessage, Param, "bar"),
+          hint: HINT,
+        },
+      ],
+      "const foo = (bar) => { bar = 1; };": [
+        {
+          col: 23,
+          message: variant!(NoParamReassignMessage, Param, "bar"),
+          hint: HINT,
+        },
+      ],
+      "class A { m(bar) { bar = 1; } }": [
+        {
+          col: 19,
+          message: variant!(NoParamReassignMessage, Param, "bar"),
+          hint: HINT,
+        },
+      ],
+    };
+
+    assert_lint_err! {
+      NoParamReassign,
+      options: json!({
+        "props": true,
+        "ignorePropertyModificationsFor": ["acc"]
+      }),
+      "function foo(bar) { bar.a = 0; }": [
+        {
+          col: 20,
+          message: variant!(NoParamReassignMessage, Property, "bar"),
+          hint: HINT,
+        },
+      ],
+      "function foo(bar) { bar.a.b = 0; bar[0].b++; }": [
+        {
+          col: 20,
+          message: variant!(NoParamReassignMessage, Property, "bar"),
+          hint: HINT,
+        },
+        {
+          col: 33,
+          message: variant!(NoParamReassignMessage, Property, "bar"),
+          hint: HINT,
+        },
+      ],
+      "function foo(bar) { delete bar.a; }": [
+        {
+          col: 27,
+          message: variant!(NoParamReassignMessage, Property, "bar"),
+          hint: HINT,
+        },
+      ],
+      "function foo(bar) { for (bar.a of baz) {} }": [
+        {
+          col: 25,
+          message: variant!(NoParamReassignMessage, Property, "bar"),
+          hint: HINT,
+        },
+      ],
+      "function foo(acc) { acc = {}; }": [
+        {
+          col: 20,
+          message: variant!(NoParamReassignMessage, Param, "acc"),
+          hint: HINT,
+        },
+      ],
+    };
+  }
+}
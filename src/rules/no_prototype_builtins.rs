@@ -5,9 +5,11 @@ use crate::ProgramRef;
 use deno_ast::swc::ast::CallExpr;
 use deno_ast::swc::ast::Expr;
 use deno_ast::swc::ast::ExprOrSuper;
+use deno_ast::swc::common::Spanned;
 use deno_ast::swc::visit::noop_visit_type;
 use deno_ast::swc::visit::Node;
 use deno_ast::swc::visit::Visit;
+use deno_ast::swc::visit::VisitWith;
 
 const BANNED_PROPERTIES: &[&str] =
   &["hasOwnProperty", "isPrototypeOf", "propertyIsEnumerable"];
@@ -24,6 +26,16 @@ fn get_message(prop: &str) -> String {
   )
 }
 
+fn get_hint(prop: &str) -> String {
+  if prop == "hasOwnProperty" {
+    "Use `Object.hasOwn(obj, key)` instead".to_string()
+  } else {
+    format!("Use `Object.prototype.{}.call(obj, ...)` instead", prop)
+  }
+}
+
+const FIX_DESC: &str = "Use `Object.hasOwn(obj, key)`";
+
 impl LintRule for NoPrototypeBuiltins {
   fn new() -> Box<Self> {
     Box::new(NoPrototypeBuiltins)
@@ -69,6 +81,8 @@ impl<'c, 'view> Visit for NoPrototypeBuiltinsVisitor<'c, 'view> {
   noop_visit_type!();
 
   fn visit_call_expr(&mut self, call_expr: &CallExpr, _parent: &dyn Node) {
+    call_expr.visit_children_with(self);
+
     let member_expr = match &call_expr.callee {
       ExprOrSuper::Expr(boxed_expr) => match &**boxed_expr {
         Expr::Member(member_expr) => {
@@ -82,13 +96,46 @@ impl<'c, 'view> Visit for NoPrototypeBuiltinsVisitor<'c, 'view> {
       ExprOrSuper::Super(_) => return,
     };
 
-    if let Expr::Ident(ident) = &*member_expr.prop {
-      let prop_name = ident.sym.as_ref();
-      if BANNED_PROPERTIES.contains(&prop_name) {
-        self.context.add_diagnostic(
+    let prop_name = match &*member_expr.prop {
+      Expr::Ident(ident) => ident.sym.as_ref(),
+      _ => return,
+    };
+    if !BANNED_PROPERTIES.contains(&prop_name) {
+      return;
+    }
+
+    // `obj.hasOwnProperty(key)` can be rewritten as `Object.hasOwn(obj, key)`
+    // when the key is a single non-spread argument and `Object` isn't shadowed
+    let fix_parts = match (&member_expr.obj, &*call_expr.args) {
+      (ExprOrSuper::Expr(obj), [key])
+        if prop_name == "hasOwnProperty"
+          && key.spread.is_none()
+          && self.context.scope().is_global_symbol(&"Object".into()) =>
+      {
+        Some((obj, key))
+      }
+      _ => None,
+    };
+    match fix_parts {
+      Some((obj, key)) => {
+        let obj_text = self.context.file_text_substring(&obj.span());
+        let key_text = self.context.file_text_substring(&key.expr.span());
+        let fixed = format!("Object.hasOwn({}, {})", obj_text, key_text);
+        let fix = self.context.create_fix(call_expr.span, fixed, FIX_DESC);
+        self.context.add_diagnostic_with_hint_and_fix(
+          call_expr.span,
+          CODE,
+          get_message(prop_name),
+          get_hint(prop_name),
+          fix,
+        );
+      }
+      None => {
+        self.context.add_diagnostic_with_hint(
           call_expr.span,
           CODE,
           get_message(prop_name),
+          get_hint(prop_name),
         );
       }
     }
@@ -127,10 +174,73 @@ mod tests {
   fn no_prototype_builtins_invalid() {
     assert_lint_err! {
       NoPrototypeBuiltins,
-      "foo.hasOwnProperty('bar');": [{col: 0, message: get_message("hasOwnProperty")}],
-      "foo.isPrototypeOf('bar');": [{col: 0, message: get_message("isPrototypeOf")}],
-      "foo.propertyIsEnumerable('bar');": [{col: 0, message: get_message("propertyIsEnumerable")}],
-      "foo.bar.baz.hasOwnProperty('bar');": [{col: 0, message: get_message("hasOwnProperty")}],
+      "foo.hasOwnProperty('bar');": [
+        {
+          col: 0,
+          message: get_message("hasOwnProperty"),
+          hint: get_hint("hasOwnProperty"),
+          fix: "Object.hasOwn(foo, 'bar');",
+        }
+      ],
+      "foo.isPrototypeOf('bar');": [
+        {
+          col: 0,
+          message: get_message("isPrototypeOf"),
+          hint: get_hint("isPrototypeOf"),
+        }
+      ],
+      "foo.propertyIsEnumerable('bar');": [
+        {
+          col: 0,
+          message: get_message("propertyIsEnumerable"),
+          hint: get_hint("propertyIsEnumerable"),
+        }
+      ],
+      "foo.bar.baz.hasOwnProperty('bar');": [
+        {
+          col: 0,
+          message: get_message("hasOwnProperty"),
+          hint: get_hint("hasOwnProperty"),
+          fix: "Object.hasOwn(foo.bar.baz, 'bar');",
+        }
+      ],
+      "(a, b).hasOwnProperty(key);": [
+        {
+          col: 0,
+          message: get_message("hasOwnProperty"),
+          hint: get_hint("hasOwnProperty"),
+          fix: "Object.hasOwn((a, b), key);",
+        }
+      ],
+      "foo.hasOwnProperty(...keys);": [
+        {
+          col: 0,
+          message: get_message("hasOwnProperty"),
+          hint: get_hint("hasOwnProperty"),
+        }
+      ],
+      "const Object = {}; foo.hasOwnProperty(key);": [
+        {
+          col: 19,
+          message: get_message("hasOwnProperty"),
+          hint: get_hint("hasOwnProperty"),
+        }
+      ],
+      "function f(Object) { return foo.hasOwnProperty(key); }": [
+        {
+          col: 28,
+          message: get_message("hasOwnProperty"),
+          hint: get_hint("hasOwnProperty"),
+        }
+      ],
+      "if (check(foo.hasOwnProperty(key))) {}": [
+        {
+          col: 10,
+          message: get_message("hasOwnProperty"),
+          hint: get_hint("hasOwnProperty"),
+          fix: "if (check(Object.hasOwn(foo, key))) {}",
+        }
+      ],
     }
   }
 }
@@ -1,23 +1,43 @@
 // Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
 use super::{Context, LintRule, DUMMY_NODE};
+use crate::globals::GLOBALS;
+use crate::scopes::{BindingKind, ScopeKind};
 use crate::ProgramRef;
 use deno_ast::swc::visit::noop_visit_type;
 use deno_ast::swc::{
   ast::*, utils::find_ids, utils::ident::IdentLike, utils::Id, visit::Node,
   visit::Visit, visit::VisitWith,
 };
-
+use serde::Deserialize;
 use std::collections::HashSet;
 
-#[derive(Debug)]
-pub struct NoRedeclare;
+#[derive(Debug, Default)]
+pub struct NoRedeclare {
+  options: NoRedeclareOptions,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoRedeclareOptions {
+  /// Whether top-level declarations of built-in globals like `Object` are
+  /// reported as well.
+  pub builtin_globals: bool,
+}
 
 const CODE: &str = "no-redeclare";
 const MESSAGE: &str = "Redeclaration is not allowed";
+const BUILTIN_GLOBAL_MESSAGE: &str =
+  "Redeclaration of a built-in global is not allowed";
+
+impl NoRedeclare {
+  pub fn with_options(options: NoRedeclareOptions) -> Box<Self> {
+    Box::new(NoRedeclare { options })
+  }
+}
 
 impl LintRule for NoRedeclare {
   fn new() -> Box<Self> {
-    Box::new(NoRedeclare)
+    Box::new(NoRedeclare::default())
   }
 
   fn tags(&self) -> &'static [&'static str] {
@@ -35,6 +55,7 @@ impl LintRule for NoRedeclare {
   ) {
     let mut visitor = NoRedeclareVisitor {
       context,
+      options: &self.options,
       bindings: Default::default(),
     };
     match program {
@@ -49,23 +70,54 @@ impl LintRule for NoRedeclare {
   }
 }
 
-struct NoRedeclareVisitor<'c, 'view> {
+struct NoRedeclareVisitor<'c, 'view, 'o> {
   context: &'c mut Context<'view>,
+  options: &'o NoRedeclareOptions,
   /// TODO(kdy1): Change this to HashMap<Id, Vec<Span>> and use those spans to point previous bindings/
   bindings: HashSet<Id>,
 }
 
-impl<'c, 'view> NoRedeclareVisitor<'c, 'view> {
+impl<'c, 'view, 'o> NoRedeclareVisitor<'c, 'view, 'o> {
   fn declare(&mut self, i: &Ident) {
     let id = i.to_id();
 
-    if !self.bindings.insert(id) {
+    if self.bindings.contains(&id) {
       self.context.add_diagnostic(i.span, CODE, MESSAGE);
+      return;
+    }
+
+    if self.options.builtin_globals
+      && self.is_top_level(&id)
+      && GLOBALS.iter().any(|(global, _)| i.sym == **global)
+    {
+      self
+        .context
+        .add_diagnostic(i.span, CODE, BUILTIN_GLOBAL_MESSAGE);
+    }
+    self.bindings.insert(id);
+  }
+
+  /// Whether the binding belongs to the top-level scope. `var` declarations
+  /// are hoisted out of blocks, so only function-like scopes count for them.
+  fn is_top_level(&self, id: &Id) -> bool {
+    let var = match self.context.scope().var(id) {
+      Some(var) => var,
+      None => return false,
+    };
+    if var.kind() == BindingKind::Var {
+      var.path().iter().all(|kind| {
+        !matches!(
+          kind,
+          ScopeKind::Function | ScopeKind::Arrow | ScopeKind::Class
+        )
+      })
+    } else {
+      var.path().is_empty()
     }
   }
 }
 
-impl<'c, 'view> Visit for NoRedeclareVisitor<'c, 'view> {
+impl<'c, 'view, 'o> Visit for NoRedeclareVisitor<'c, 'view, 'o> {
   noop_visit_type!();
 
   fn visit_fn_decl(&mut self, f: &FnDecl, _: &dyn Node) {
@@ -106,6 +158,7 @@ impl<'c, 'view> Visit for NoRedeclareVisitor<'c, 'view> {
 #[cfg(test)]
 mod tests {
   use super::*;
+  use serde_json::json;
 
   #[test]
   fn no_redeclare_valid() {
@@ -123,6 +176,21 @@ mod tests {
 
       // https://github.com/denoland/deno_lint/issues/615
       "class T { #foo(x) {} #bar(x) {} }",
+
+      // overload signatures and declaration merging
+      "function f(a: string): void; function f(a: number): void; function f(a) {}",
+      "interface A { a: string } interface A { b: number }",
+      "var Object = 0; let Array = 1; function Map() {}",
+    };
+
+    assert_lint_ok! {
+      NoRedeclare,
+      options: json!({ "builtinGlobals": true }),
+      "var a = 0;",
+      "function f() { var Object = 0; }",
+      "function f(Array) {}",
+      "{ let Object = 0; }",
+      "const f = () => { var Map; };",
     };
   }
 
@@ -158,6 +226,19 @@ mod tests {
       "var a; var {a = 0, b: Object = 0} = {};": [{line: 1, col: 12, message: MESSAGE}],
       "var a; var {a = 0, b: globalThis = 0} = {};": [{line: 1, col: 12, message: MESSAGE}],
       "function f(foo: number, foo: string) {}": [{line: 1, col: 24, message: MESSAGE}],
-    }
+    };
+
+    assert_lint_err! {
+      NoRedeclare,
+      options: json!({ "builtinGlobals": true }),
+      "var Object = 0;": [{col: 4, message: BUILTIN_GLOBAL_MESSAGE}],
+      "let Array = [];": [{col: 4, message: BUILTIN_GLOBAL_MESSAGE}],
+      "function Map() {}": [{col: 9, message: BUILTIN_GLOBAL_MESSAGE}],
+      "if (a) { var undefined; }": [{col: 13, message: BUILTIN_GLOBAL_MESSAGE}],
+      "var Object; var Object;": [
+        {col: 4, message: BUILTIN_GLOBAL_MESSAGE},
+        {col: 16, message: MESSAGE},
+      ],
+    };
   }
 }
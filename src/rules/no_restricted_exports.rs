@@ -0,0 +1,462 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::ProgramRef;
+use deno_ast::swc::ast::{
+  Decl, ExportDecl, ExportDefaultDecl, ExportDefaultExpr, ExportSpecifier,
+  Ident, NamedExport, TsModuleName,
+};
+use deno_ast::swc::common::Span;
+use deno_ast::swc::utils::find_ids;
+use deno_ast::swc::visit::{noop_visit_type, Node, Visit, VisitWith};
+use derive_more::Display;
+use serde::Deserialize;
+
+#[derive(Debug, Default)]
+pub struct NoRestrictedExports {
+  options: NoRestrictedExportsOptions,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoRestrictedExportsOptions {
+  /// The kinds of default exports which are disallowed.
+  pub restrict_default_exports: RestrictDefaultExports,
+  /// The names which are not allowed to be exported. Including `default`
+  /// disallows all kinds of default exports.
+  pub restricted_named_exports: Vec<String>,
+}
+
+impl Default for NoRestrictedExportsOptions {
+  fn default() -> Self {
+    Self {
+      restrict_default_exports: RestrictDefaultExports::default(),
+      // Exporting `then` turns the module namespace object into a thenable,
+      // so awaiting a dynamic import of the module calls the function
+      restricted_named_exports: vec!["then".to_string()],
+    }
+  }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RestrictDefaultExports {
+  /// `export default foo;`
+  pub direct: bool,
+  /// `export { foo as default };`
+  pub named: bool,
+  /// `export { default } from "./mod.ts";`
+  pub default_from: bool,
+  /// `export { foo as default } from "./mod.ts";`
+  pub named_from: bool,
+  /// `export * as default from "./mod.ts";`
+  pub namespace_from: bool,
+}
+
+#[derive(Clone, Copy)]
+enum DefaultExportKind {
+  Direct,
+  Named,
+  DefaultFrom,
+  NamedFrom,
+  NamespaceFrom,
+}
+
+const CODE: &str = "no-restricted-exports";
+const NAMED_HINT: &str = "Use a different name for the export";
+const DEFAULT_HINT: &str = "Use a named export instead";
+
+#[derive(Display)]
+enum NoRestrictedExportsMessage {
+  #[display(
+    fmt = "'{}' is restricted from being used as an exported name",
+    _0
+  )]
+  Named(String),
+  #[display(fmt = "Exporting 'default' is restricted")]
+  Default,
+}
+
+impl NoRestrictedExports {
+  pub fn with_options(options: NoRestrictedExportsOptions) -> Box<Self> {
+    Box::new(NoRestrictedExports { options })
+  }
+}
+
+impl LintRule for NoRestrictedExports {
+  fn new() -> Box<Self> {
+    Box::new(NoRestrictedExports::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    let mut visitor = NoRestrictedExportsVisitor::new(context, &self.options);
+    match program {
+      ProgramRef::Module(m) => m.visit_with(&DUMMY_NODE, &mut visitor),
+      ProgramRef::Script(s) => s.visit_with(&DUMMY_NODE, &mut visitor),
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_restricted_exports.md")
+  }
+}
+
+struct NoRestrictedExportsVisitor<'c, 'view, 'o> {
+  context: &'c mut Context<'view>,
+  options: &'o NoRestrictedExportsOptions,
+}
+
+impl<'c, 'view, 'o> NoRestrictedExportsVisitor<'c, 'view, 'o> {
+  fn new(
+    context: &'c mut Context<'view>,
+    options: &'o NoRestrictedExportsOptions,
+  ) -> Self {
+    Self { context, options }
+  }
+
+  fn is_restricted_name(&self, name: &str) -> bool {
+    self
+      .options
+      .restricted_named_exports
+      .iter()
+      .any(|restricted| restricted == name)
+  }
+
+  fn check_name(&mut self, ident: &Ident) {
+    if self.is_restricted_name(&ident.sym) {
+      self.context.add_diagnostic_with_hint(
+        ident.span,
+        CODE,
+        NoRestrictedExportsMessage::Named(ident.sym.to_string()),
+        NAMED_HINT,
+      );
+    }
+  }
+
+  fn check_default(&mut self, span: Span, kind: DefaultExportKind) {
+    let restricted = &self.options.restrict_default_exports;
+    let is_restricted = match kind {
+      DefaultExportKind::Direct => restricted.direct,
+      DefaultExportKind::Named => restricted.named,
+      DefaultExportKind::DefaultFrom => restricted.default_from,
+      DefaultExportKind::NamedFrom => restricted.named_from,
+      DefaultExportKind::NamespaceFrom => restricted.namespace_from,
+    };
+    if is_restricted || self.is_restricted_name("default") {
+      self.context.add_diagnostic_with_hint(
+        span,
+        CODE,
+        NoRestrictedExportsMessage::Default,
+        DEFAULT_HINT,
+      );
+    }
+  }
+}
+
+impl<'c, 'view, 'o> Visit for NoRestrictedExportsVisitor<'c, 'view, 'o> {
+  noop_visit_type!();
+
+  fn visit_export_decl(&mut self, export_decl: &ExportDecl, _: &dyn Node) {
+    // Interfaces and type aliases only exist at compile time, so they are
+    // not checked
+    match &export_decl.decl {
+      Decl::Class(class_decl) => self.check_name(&class_decl.ident),
+      Decl::Fn(fn_decl) => self.check_name(&fn_decl.ident),
+      Decl::Var(var_decl) => {
+        for declarator in &var_decl.decls {
+          let idents: Vec<Ident> = find_ids(&declarator.name);
+          for ident in &idents {
+            self.check_name(ident);
+          }
+        }
+      }
+      Decl::TsEnum(ts_enum) => self.check_name(&ts_enum.id),
+      Decl::TsModule(ts_module) => {
+        if let TsModuleName::Ident(ident) = &ts_module.id {
+          self.check_name(ident);
+        }
+      }
+      Decl::TsInterface(_) | Decl::TsTypeAlias(_) => {}
+    }
+  }
+
+  fn visit_export_default_decl(
+    &mut self,
+    export_default_decl: &ExportDefaultDecl,
+    _: &dyn Node,
+  ) {
+    self.check_default(export_default_decl.span, DefaultExportKind::Direct);
+  }
+
+  fn visit_export_default_expr(
+    &mut self,
+    export_default_expr: &ExportDefaultExpr,
+    _: &dyn Node,
+  ) {
+    self.check_default(export_default_expr.span, DefaultExportKind::Direct);
+  }
+
+  fn visit_named_export(&mut self, named_export: &NamedExport, _: &dyn Node) {
+    if named_export.type_only {
+      return;
+    }
+
+    let has_source = named_export.src.is_some();
+    for specifier in &named_export.specifiers {
+      match specifier {
+        ExportSpecifier::Namespace(namespace) => {
+          if namespace.name.sym == *"default" {
+            self.check_default(
+              namespace.name.span,
+              DefaultExportKind::NamespaceFrom,
+            );
+          } else {
+            self.check_name(&namespace.name);
+          }
+        }
+        // `export foo from "./mod.ts";` exports the default export of the
+        // module under the name `foo`
+        ExportSpecifier::Default(default) => self.check_name(&default.exported),
+        ExportSpecifier::Named(named) => {
+          let exported = named.exported.as_ref().unwrap_or(&named.orig);
+          if exported.sym != *"default" {
+            self.check_name(exported);
+            continue;
+          }
+          let kind = if !has_source {
+            DefaultExportKind::Named
+          } else if named.orig.sym == *"default" {
+            DefaultExportKind::DefaultFrom
+          } else {
+            DefaultExportKind::NamedFrom
+          };
+          self.check_default(exported.span, kind);
+        }
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  #[test]
+  fn no_restricted_exports_valid() {
+    assert_lint_ok! {
+      NoRestrictedExports,
+      "export const foo = 1;",
+      "export const thenable = 1;",
+      "export function then2() {}",
+      "export { then as foo };",
+      "export { then as foo } from './mod.ts';",
+      "import { then } from './mod.ts';",
+      "const then = 1;",
+      "export const obj = { then() {} };",
+      "export type then = string;",
+      "export interface then {}",
+      "export type { then } from './mod.ts';",
+      "export * from './mod.ts';",
+      "export default 1;",
+      "export default function then() {}",
+      "export { foo as default };",
+      "export { default } from './mod.ts';",
+    };
+
+    assert_lint_ok! {
+      NoRestrictedExports,
+      options: json!({ "restrictDefaultExports": { "direct": true } }),
+      "export { foo as default };",
+      "export { default } from './mod.ts';",
+      "export { foo as default } from './mod.ts';",
+      "export * as default from './mod.ts';",
+      "export const foo = 1;",
+    };
+
+    assert_lint_ok! {
+      NoRestrictedExports,
+      options: json!({ "restrictedNamedExports": [] }),
+      "export const then = 1;",
+    };
+
+    assert_lint_ok! {
+      NoRestrictedExports,
+      options: json!({ "restrictedNamedExports": ["foo"] }),
+      "export const then = 1;",
+      "export default foo;",
+    };
+  }
+
+  #[test]
+  fn no_restricted_exports_invalid() {
+    assert_lint_err! {
+      NoRestrictedExports,
+      "export const then = 1;": [
+        {
+          col: 13,
+          message: NoRestrictedExportsMessage::Named("then".to_string()),
+          hint: NAMED_HINT,
+        },
+      ],
+      "export function then() {}": [
+        {
+          col: 16,
+          message: NoRestrictedExportsMessage::Named("then".to_string()),
+          hint: NAMED_HINT,
+        },
+      ],
+      "export class then {}": [
+        {
+          col: 13,
+          message: NoRestrictedExportsMessage::Named("then".to_string()),
+          hint: NAMED_HINT,
+        },
+      ],
+      "export enum then {}": [
+        {
+          col: 12,
+          message: NoRestrictedExportsMessage::Named("then".to_string()),
+          hint: NAMED_HINT,
+        },
+      ],
+      "export const { a: { then } } = foo;": [
+        {
+          col: 20,
+          message: NoRestrictedExportsMessage::Named("then".to_string()),
+          hint: NAMED_HINT,
+        },
+      ],
+      "export { then };": [
+        {
+          col: 9,
+          message: NoRestrictedExportsMessage::Named("then".to_string()),
+          hint: NAMED_HINT,
+        },
+      ],
+      "export { foo as then };": [
+        {
+          col: 16,
+          message: NoRestrictedExportsMessage::Named("then".to_string()),
+          hint: NAMED_HINT,
+        },
+      ],
+      "export { then } from './mod.ts';": [
+        {
+          col: 9,
+          message: NoRestrictedExportsMessage::Named("then".to_string()),
+          hint: NAMED_HINT,
+        },
+      ],
+      "export * as then from './mod.ts';": [
+        {
+          col: 12,
+          message: NoRestrictedExportsMessage::Named("then".to_string()),
+          hint: NAMED_HINT,
+        },
+      ],
+    };
+
+    assert_lint_err! {
+      NoRestrictedExports,
+      options: json!({
+        "restrictDefaultExports": {
+          "direct": true,
+          "named": true,
+          "defaultFrom": true,
+          "namedFrom": true,
+          "namespaceFrom": true
+        },
+        "restrictedNamedExports": ["foo", "bar"]
+      }),
+      "export default 1;": [
+        {
+          col: 0,
+          message: NoRestrictedExportsMessage::Default,
+          hint: DEFAULT_HINT,
+        },
+      ],
+      "export default function () {}": [
+        {
+          col: 0,
+          message: NoRestrictedExportsMessage::Default,
+          hint: DEFAULT_HINT,
+        },
+      ],
+      "export default class A {}": [
+        {
+          col: 0,
+          message: NoRestrictedExportsMessage::Default,
+          hint: DEFAULT_HINT,
+        },
+      ],
+      "export { baz as default };": [
+        {
+          col: 16,
+          message: NoRestrictedExportsMessage::Default,
+          hint: DEFAULT_HINT,
+        },
+      ],
+      "export { default } from './mod.ts';": [
+        {
+          col: 9,
+          message: NoRestrictedExportsMessage::Default,
+          hint: DEFAULT_HINT,
+        },
+      ],
+      "export { baz as default } from './mod.ts';": [
+        {
+          col: 16,
+          message: NoRestrictedExportsMessage::Default,
+          hint: DEFAULT_HINT,
+        },
+      ],
+      "export * as default from './mod.ts';": [
+        {
+          col: 12,
+          message: NoRestrictedExportsMessage::Default,
+          hint: DEFAULT_HINT,
+        },
+      ],
+      "export const foo = 1, baz = 2, bar = 3;": [
+        {
+          col: 13,
+          message: NoRestrictedExportsMessage::Named("foo".to_string()),
+          hint: NAMED_HINT,
+        },
+        {
+          col: 31,
+          message: NoRestrictedExportsMessage::Named("bar".to_string()),
+          hint: NAMED_HINT,
+        },
+      ],
+    };
+
+    assert_lint_err! {
+      NoRestrictedExports,
+      options: json!({ "restrictedNamedExports": ["default"] }),
+      "export default 1;": [
+        {
+          col: 0,
+          message: NoRestrictedExportsMessage::Default,
+          hint: DEFAULT_HINT,
+        },
+      ],
+      "export { foo as default };": [
+        {
+          col: 16,
+          message: NoRestrictedExportsMessage::Default,
+          hint: DEFAULT_HINT,
+        },
+      ],
+    };
+  }
+}
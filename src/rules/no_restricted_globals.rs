@@ -0,0 +1,213 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::ProgramRef;
+use deno_ast::swc::ast::{
+  AssignPatProp, ClassProp, Expr, Ident, MemberExpr, Pat, Prop,
+};
+use deno_ast::swc::utils::ident::IdentLike;
+use deno_ast::swc::visit::{noop_visit_type, Node, Visit, VisitWith};
+use serde::Deserialize;
+
+#[derive(Debug, Default)]
+pub struct NoRestrictedGlobals {
+  options: NoRestrictedGlobalsOptions,
+}
+
+/// A list of global variables that are not allowed to be referenced.
+#[derive(Debug, Default, Deserialize)]
+pub struct NoRestrictedGlobalsOptions(pub Vec<RestrictedGlobal>);
+
+#[derive(Debug, Deserialize)]
+pub struct RestrictedGlobal {
+  /// Name of the global variable, e.g. `event`
+  pub name: String,
+  /// Custom message to report instead of the default one
+  pub message: Option<String>,
+}
+
+const CODE: &str = "no-restricted-globals";
+
+fn default_message(name: &str) -> String {
+  format!("Unexpected use of the global variable `{}`", name)
+}
+
+impl NoRestrictedGlobals {
+  pub fn with_options(options: NoRestrictedGlobalsOptions) -> Box<Self> {
+    Box::new(NoRestrictedGlobals { options })
+  }
+}
+
+impl LintRule for NoRestrictedGlobals {
+  fn new() -> Box<Self> {
+    Box::new(NoRestrictedGlobals::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    if self.options.0.is_empty() {
+      return;
+    }
+
+    let mut visitor = NoRestrictedGlobalsVisitor::new(context, &self.options);
+    match program {
+      ProgramRef::Module(m) => m.visit_with(&DUMMY_NODE, &mut visitor),
+      ProgramRef::Script(s) => s.visit_with(&DUMMY_NODE, &mut visitor),
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_restricted_globals.md")
+  }
+}
+
+struct NoRestrictedGlobalsVisitor<'c, 'view, 'o> {
+  context: &'c mut Context<'view>,
+  options: &'o NoRestrictedGlobalsOptions,
+}
+
+impl<'c, 'view, 'o> NoRestrictedGlobalsVisitor<'c, 'view, 'o> {
+  fn new(
+    context: &'c mut Context<'view>,
+    options: &'o NoRestrictedGlobalsOptions,
+  ) -> Self {
+    Self { context, options }
+  }
+
+  fn check(&mut self, ident: &Ident) {
+    let restricted =
+      match self.options.0.iter().find(|r| r.name == ident.sym.as_ref()) {
+        Some(r) => r,
+        None => return,
+      };
+
+    // References to local bindings with the same name are fine
+    if !self.context.scope().is_global(&ident.to_id()) {
+      return;
+    }
+
+    let message = restricted
+      .message
+      .clone()
+      .unwrap_or_else(|| default_message(&restricted.name));
+    self.context.add_diagnostic(ident.span, CODE, message);
+  }
+}
+
+impl<'c, 'view, 'o> Visit for NoRestrictedGlobalsVisitor<'c, 'view, 'o> {
+  noop_visit_type!();
+
+  fn visit_member_expr(&mut self, e: &MemberExpr, _: &dyn Node) {
+    e.obj.visit_with(e, self);
+    if e.computed {
+      e.prop.visit_with(e, self);
+    }
+  }
+
+  fn visit_expr(&mut self, e: &Expr, _: &dyn Node) {
+    e.visit_children_with(self);
+
+    if let Expr::Ident(ident) = e {
+      self.check(ident);
+    }
+  }
+
+  fn visit_class_prop(&mut self, p: &ClassProp, _: &dyn Node) {
+    p.value.visit_with(p, self);
+  }
+
+  fn visit_prop(&mut self, p: &Prop, _: &dyn Node) {
+    p.visit_children_with(self);
+
+    if let Prop::Shorthand(i) = &p {
+      self.check(i);
+    }
+  }
+
+  fn visit_pat(&mut self, p: &Pat, _: &dyn Node) {
+    if let Pat::Ident(i) = p {
+      self.check(&i.id);
+    } else {
+      p.visit_children_with(self);
+    }
+  }
+
+  fn visit_assign_pat_prop(&mut self, p: &AssignPatProp, _: &dyn Node) {
+    self.check(&p.key);
+    p.value.visit_with(p, self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  #[test]
+  fn no_restricted_globals_valid() {
+    assert_lint_ok! {
+      NoRestrictedGlobals,
+      "event;",
+      "name;",
+    };
+
+    assert_lint_ok! {
+      NoRestrictedGlobals,
+      options: json!([{ "name": "event" }, { "name": "name" }]),
+      "foo;",
+      "function f(event) { event.preventDefault(); }",
+      "const name = 'deno'; console.log(name);",
+      "let event; event = 1;",
+      "foo.event;",
+      "foo.name();",
+      "const obj = { event: 1, name() {} };",
+      "class A { event = 1; name() {} }",
+      "function f() { const { event } = foo; return event; }",
+      "try {} catch (event) { console.log(event); }",
+    };
+  }
+
+  #[test]
+  fn no_restricted_globals_invalid() {
+    assert_lint_err! {
+      NoRestrictedGlobals,
+      options: json!([{ "name": "event" }]),
+      "event;": [{ col: 0, message: default_message("event") }],
+      "console.log(event.target);": [
+        { col: 12, message: default_message("event") },
+      ],
+      "foo[event];": [{ col: 4, message: default_message("event") }],
+      "const obj = { event };": [
+        { col: 14, message: default_message("event") },
+      ],
+      "typeof event;": [{ col: 7, message: default_message("event") }],
+      r#"
+function f(event) { return event; }
+function g() { return event; }
+      "#: [{ line: 3, col: 22, message: default_message("event") }],
+    };
+
+    assert_lint_err! {
+      NoRestrictedGlobals,
+      options: json!([
+        { "name": "fdescribe", "message": "Do not commit fdescribe. Use describe instead." },
+        { "name": "name" }
+      ]),
+      "fdescribe('foo', () => {});": [
+        { col: 0, message: "Do not commit fdescribe. Use describe instead." },
+      ],
+      "name = 'foo';": [{ col: 0, message: default_message("name") }],
+      "fdescribe(name, () => {});": [
+        { col: 0, message: "Do not commit fdescribe. Use describe instead." },
+        { col: 10, message: default_message("name") },
+      ],
+    };
+  }
+}
@@ -0,0 +1,292 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::ProgramRef;
+use deno_ast::swc::ast::{
+  Expr, ExprOrSuper, Lit, MemberExpr, ObjectPatProp, Pat, PropName,
+  VarDeclarator,
+};
+use deno_ast::swc::common::{Span, Spanned};
+use deno_ast::swc::visit::{noop_visit_type, Node, Visit, VisitWith};
+use serde::Deserialize;
+
+#[derive(Debug, Default)]
+pub struct NoRestrictedProperties {
+  options: NoRestrictedPropertiesOptions,
+}
+
+/// A list of properties that are not allowed to be accessed.
+#[derive(Debug, Default, Deserialize)]
+pub struct NoRestrictedPropertiesOptions(pub Vec<RestrictedProperty>);
+
+#[derive(Debug, Deserialize)]
+pub struct RestrictedProperty {
+  /// Name of the object, e.g. `window`. If omitted, the property is
+  /// restricted on all objects.
+  pub object: Option<String>,
+  /// Name of the property, e.g. `location`. If omitted, all properties of the
+  /// object are restricted.
+  pub property: Option<String>,
+  /// Custom message to report instead of the default one
+  pub message: Option<String>,
+}
+
+impl RestrictedProperty {
+  fn matches(&self, object: Option<&str>, property: &str) -> bool {
+    let matches_object = match &self.object {
+      Some(name) => object == Some(name.as_str()),
+      None => true,
+    };
+    let matches_property = match &self.property {
+      Some(name) => name == property,
+      None => true,
+    };
+    (self.object.is_some() || self.property.is_some())
+      && matches_object
+      && matches_property
+  }
+
+  fn message(&self) -> String {
+    if let Some(message) = &self.message {
+      return message.clone();
+    }
+    match (&self.object, &self.property) {
+      (Some(object), Some(property)) => default_message(object, property),
+      (Some(object), None) => default_object_message(object),
+      (None, Some(property)) => default_property_message(property),
+      (None, None) => unreachable!(),
+    }
+  }
+}
+
+const CODE: &str = "no-restricted-properties";
+
+fn default_message(object: &str, property: &str) -> String {
+  format!("Unexpected use of the property `{}.{}`", object, property)
+}
+
+fn default_object_message(object: &str) -> String {
+  format!("Unexpected use of a property of `{}`", object)
+}
+
+fn default_property_message(property: &str) -> String {
+  format!("Unexpected use of the property `{}`", property)
+}
+
+impl NoRestrictedProperties {
+  pub fn with_options(options: NoRestrictedPropertiesOptions) -> Box<Self> {
+    Box::new(NoRestrictedProperties { options })
+  }
+}
+
+impl LintRule for NoRestrictedProperties {
+  fn new() -> Box<Self> {
+    Box::new(NoRestrictedProperties::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    if self.options.0.is_empty() {
+      return;
+    }
+
+    let mut visitor =
+      NoRestrictedPropertiesVisitor::new(context, &self.options);
+    match program {
+      ProgramRef::Module(m) => m.visit_with(&DUMMY_NODE, &mut visitor),
+      ProgramRef::Script(s) => s.visit_with(&DUMMY_NODE, &mut visitor),
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_restricted_properties.md")
+  }
+}
+
+struct NoRestrictedPropertiesVisitor<'c, 'view, 'o> {
+  context: &'c mut Context<'view>,
+  options: &'o NoRestrictedPropertiesOptions,
+}
+
+impl<'c, 'view, 'o> NoRestrictedPropertiesVisitor<'c, 'view, 'o> {
+  fn new(
+    context: &'c mut Context<'view>,
+    options: &'o NoRestrictedPropertiesOptions,
+  ) -> Self {
+    Self { context, options }
+  }
+
+  fn check(&mut self, span: Span, object: Option<&str>, property: &str) {
+    if let Some(restricted) =
+      self.options.0.iter().find(|r| r.matches(object, property))
+    {
+      self
+        .context
+        .add_diagnostic(span, CODE, restricted.message());
+    }
+  }
+}
+
+/// Returns the name of the accessed property if it is known statically, e.g.
+/// `b` for both `a.b` and `a["b"]`.
+fn property_name(member_expr: &MemberExpr) -> Option<&str> {
+  match (&*member_expr.prop, member_expr.computed) {
+    (Expr::Ident(ident), false) => Some(&*ident.sym),
+    (Expr::Lit(Lit::Str(str)), true) => Some(&*str.value),
+    _ => None,
+  }
+}
+
+fn prop_name(prop_name: &PropName) -> Option<&str> {
+  match prop_name {
+    PropName::Ident(ident) => Some(&*ident.sym),
+    PropName::Str(str) => Some(&*str.value),
+    _ => None,
+  }
+}
+
+impl<'c, 'view, 'o> Visit for NoRestrictedPropertiesVisitor<'c, 'view, 'o> {
+  noop_visit_type!();
+
+  fn visit_member_expr(&mut self, member_expr: &MemberExpr, _: &dyn Node) {
+    member_expr.visit_children_with(self);
+
+    if let Some(property) = property_name(member_expr) {
+      let object = match &member_expr.obj {
+        ExprOrSuper::Expr(expr) => match &**expr {
+          Expr::Ident(ident) => Some(&*ident.sym),
+          _ => None,
+        },
+        ExprOrSuper::Super(_) => None,
+      };
+      self.check(member_expr.span, object, property);
+    }
+  }
+
+  // Destructuring accesses the properties as well, e.g.
+  // `const { location } = window;`
+  fn visit_var_declarator(&mut self, declarator: &VarDeclarator, _: &dyn Node) {
+    declarator.visit_children_with(self);
+
+    let object_pat = match &declarator.name {
+      Pat::Object(object_pat) => object_pat,
+      _ => return,
+    };
+    let object = match declarator.init.as_deref() {
+      Some(Expr::Ident(ident)) => Some(&*ident.sym),
+      _ => None,
+    };
+    for prop in &object_pat.props {
+      match prop {
+        ObjectPatProp::KeyValue(key_value) => {
+          if let Some(property) = prop_name(&key_value.key) {
+            self.check(key_value.key.span(), object, property);
+          }
+        }
+        ObjectPatProp::Assign(assign) => {
+          self.check(assign.key.span, object, &assign.key.sym);
+        }
+        ObjectPatProp::Rest(_) => {}
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  #[test]
+  fn no_restricted_properties_valid() {
+    assert_lint_ok! {
+      NoRestrictedProperties,
+      "window.location;",
+      "foo.__proto__;",
+    };
+
+    assert_lint_ok! {
+      NoRestrictedProperties,
+      options: json!([
+        { "object": "window", "property": "location" },
+        { "property": "__proto__" },
+        { "object": "secrets" }
+      ]),
+      "window.history;",
+      "document.location;",
+      "location;",
+      "window[location];",
+      "foo.bar.__proto__x;",
+      "const obj = { __proto__: null };",
+      "const { history } = window;",
+      "const { location } = document;",
+      "secrets;",
+      "foo.secrets.value;",
+      "class A { #location = 1; f() { return this.#location; } }",
+    };
+  }
+
+  #[test]
+  fn no_restricted_properties_invalid() {
+    assert_lint_err! {
+      NoRestrictedProperties,
+      options: json!([
+        { "object": "window", "property": "location" },
+        { "property": "__proto__" },
+        { "object": "secrets" }
+      ]),
+      "window.location.href = 'foo';": [
+        { col: 0, message: default_message("window", "location") },
+      ],
+      "window['location'];": [
+        { col: 0, message: default_message("window", "location") },
+      ],
+      "const { location } = window;": [
+        { col: 8, message: default_message("window", "location") },
+      ],
+      "const { location: loc } = window;": [
+        { col: 8, message: default_message("window", "location") },
+      ],
+      "foo.__proto__ = bar;": [
+        { col: 0, message: default_property_message("__proto__") },
+      ],
+      "foo().bar.__proto__;": [
+        { col: 0, message: default_property_message("__proto__") },
+      ],
+      "const { __proto__: proto } = foo();": [
+        { col: 8, message: default_property_message("__proto__") },
+      ],
+      "secrets.token;": [
+        { col: 0, message: default_object_message("secrets") },
+      ],
+      "f(secrets['token']);": [
+        { col: 2, message: default_object_message("secrets") },
+      ],
+    };
+
+    assert_lint_err! {
+      NoRestrictedProperties,
+      options: json!([
+        {
+          "object": "require",
+          "property": "cache",
+          "message": "Do not modify the module cache."
+        },
+        { "object": "Deno", "property": "exit" }
+      ]),
+      "delete require.cache[key];": [
+        { col: 7, message: "Do not modify the module cache." },
+      ],
+      "if (failed) Deno.exit(1);": [
+        { col: 12, message: default_message("Deno", "exit") },
+      ],
+    };
+  }
+}
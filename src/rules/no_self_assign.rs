@@ -1,5 +1,6 @@
 // Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
 use super::{Context, LintRule, DUMMY_NODE};
+use crate::ast_eq::member_eq;
 use crate::swc_util::StringRepr;
 use crate::ProgramRef;
 
@@ -9,7 +10,7 @@ use deno_ast::swc::ast::Expr;
 use deno_ast::swc::ast::ExprOrSpread;
 use deno_ast::swc::ast::ExprOrSuper;
 use deno_ast::swc::ast::Ident;
-use deno_ast::swc::ast::MemberExpr;
+use deno_ast::swc::ast::Lit;
 use deno_ast::swc::ast::ObjectPatProp;
 use deno_ast::swc::ast::Pat;
 use deno_ast::swc::ast::PatOrExpr;
@@ -89,87 +90,8 @@ impl<'c, 'view> NoSelfAssignVisitor<'c, 'view> {
     );
   }
 
-  fn is_same_property(
-    &mut self,
-    left: &MemberExpr,
-    right: &MemberExpr,
-  ) -> bool {
-    if left.computed == right.computed {
-      match (&*left.prop, &*right.prop) {
-        (Expr::Ident(l_ident), Expr::Ident(r_ident)) => {
-          if self.is_same_ident(l_ident, r_ident) {
-            return true;
-          }
-        }
-        (Expr::Lit(l_lit), Expr::Lit(r_lit)) => {
-          if l_lit.string_repr() == r_lit.string_repr() {
-            return true;
-          }
-        }
-        _ => {}
-      };
-    }
-
-    let left_name = if left.computed {
-      None
-    } else {
-      left.string_repr()
-    };
-    let right_name = if right.computed {
-      None
-    } else {
-      right.string_repr()
-    };
-
-    if let Some(lname) = left_name {
-      if let Some(rname) = right_name {
-        return lname == rname;
-      }
-    }
-
-    false
-  }
-
-  fn is_same_member(&mut self, left: &MemberExpr, right: &MemberExpr) -> bool {
-    let same_prop = self.is_same_property(left, right);
-    if !same_prop {
-      return false;
-    }
-
-    if left.obj.is_super_() || right.obj.is_super_() {
-      return false;
-    }
-
-    match (&left.obj, &right.obj) {
-      (ExprOrSuper::Expr(l_boxed_expr), ExprOrSuper::Expr(r_boxed_expr)) => {
-        match (&**l_boxed_expr, &**r_boxed_expr) {
-          (Expr::Member(l_member_expr), Expr::Member(r_member_expr)) => {
-            self.is_same_member(l_member_expr, r_member_expr)
-          }
-          (Expr::This(_), Expr::This(_)) => true,
-          (Expr::Ident(l_ident), Expr::Ident(r_ident)) => {
-            self.is_same_ident(l_ident, r_ident)
-          }
-          _ => false,
-        }
-      }
-      _ => false,
-    }
-  }
-
-  fn check_same_member(&mut self, left: &MemberExpr, right: &MemberExpr) {
-    if self.is_same_member(left, right) {
-      let name = (&*right.prop).string_repr().expect("Should be identifier");
-      self.add_diagnostic(right.span, name);
-    }
-  }
-
-  fn is_same_ident(&mut self, left: &Ident, right: &Ident) -> bool {
-    left.sym == right.sym
-  }
-
   fn check_same_ident(&mut self, left: &Ident, right: &Ident) {
-    if self.is_same_ident(left, right) {
+    if left.sym == right.sym {
       self.add_diagnostic(right.span, &right.sym);
     }
   }
@@ -180,7 +102,12 @@ impl<'c, 'view> NoSelfAssignVisitor<'c, 'view> {
         self.check_same_ident(l_ident, r_ident);
       }
       (Expr::Member(l_member), Expr::Member(r_member)) => {
-        self.check_same_member(l_member, r_member);
+        if is_reference(left) && member_eq(l_member, r_member) {
+          let name = (&*r_member.prop)
+            .string_repr()
+            .expect("Should be identifier");
+          self.add_diagnostic(r_member.span, name);
+        }
       }
       _ => {}
     }
@@ -297,6 +224,25 @@ impl<'c, 'view> NoSelfAssignVisitor<'c, 'view> {
   }
 }
 
+/// Checks if the expression refers to a variable or to a property with a
+/// static key, like `a`, `this.a` or `a[b].c`. Accesses on the result of a
+/// call like `f().a` are excluded, since each call may return another object.
+fn is_reference(expr: &Expr) -> bool {
+  match expr {
+    Expr::Ident(_) | Expr::This(_) => true,
+    Expr::Member(member) => {
+      let is_static_prop = match (&*member.prop, member.computed) {
+        (Expr::Ident(_), _) => true,
+        (Expr::Lit(Lit::Str(_)), true) | (Expr::Lit(Lit::Num(_)), true) => true,
+        _ => false,
+      };
+      is_static_prop
+        && matches!(&member.obj, ExprOrSuper::Expr(obj) if is_reference(obj))
+    }
+    _ => false,
+  }
+}
+
 impl<'c, 'view> VisitAll for NoSelfAssignVisitor<'c, 'view> {
   noop_visit_type!();
 
@@ -579,6 +525,13 @@ mod tests {
           hint: NoSelfAssignHint::Mistake,
         }
       ],
+      "a.b = a['b']": [
+        {
+          col: 6,
+          message: variant!(NoSelfAssignMessage, Invalid, "b"),
+          hint: NoSelfAssignHint::Mistake,
+        }
+      ],
       "a['b'] = a['b']": [
         {
           col: 9,
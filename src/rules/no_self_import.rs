@@ -0,0 +1,175 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::ProgramRef;
+use deno_ast::swc::ast::{
+  CallExpr, ExportAll, Expr, ExprOrSuper, ImportDecl, Lit, NamedExport, Str,
+};
+use deno_ast::swc::common::Span;
+use deno_ast::swc::visit::{noop_visit_type, Node, VisitAll, VisitAllWith};
+
+#[derive(Debug)]
+pub struct NoSelfImport;
+
+const CODE: &str = "no-self-import";
+const MESSAGE: &str = "Module imports itself";
+const HINT: &str = "Remove the import, or import a different module";
+
+impl LintRule for NoSelfImport {
+  fn new() -> Box<Self> {
+    Box::new(NoSelfImport)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    let mut visitor = NoSelfImportVisitor::new(context);
+    match program {
+      ProgramRef::Module(m) => m.visit_all_with(&DUMMY_NODE, &mut visitor),
+      ProgramRef::Script(s) => s.visit_all_with(&DUMMY_NODE, &mut visitor),
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_self_import.md")
+  }
+}
+
+/// Resolves `specifier` against the file `referrer` by joining the path
+/// segments lexically. Bare specifiers like `"react"` are returned as is.
+fn resolve(referrer: &str, specifier: &str) -> String {
+  if !specifier.starts_with("./") && !specifier.starts_with("../") {
+    return specifier.to_string();
+  }
+
+  let mut segments: Vec<&str> = referrer.split('/').collect();
+  // Drop the file name of the referrer
+  segments.pop();
+  for segment in specifier.split('/') {
+    match segment {
+      "." => {}
+      ".." => {
+        segments.pop();
+      }
+      _ => segments.push(segment),
+    }
+  }
+  segments.join("/")
+}
+
+struct NoSelfImportVisitor<'c, 'view> {
+  context: &'c mut Context<'view>,
+}
+
+impl<'c, 'view> NoSelfImportVisitor<'c, 'view> {
+  fn new(context: &'c mut Context<'view>) -> Self {
+    Self { context }
+  }
+
+  fn check_specifier(&mut self, specifier: &Str, span: Span) {
+    let file_name = self.context.file_name();
+    if resolve(file_name, &specifier.value) == file_name {
+      self
+        .context
+        .add_diagnostic_with_hint(span, CODE, MESSAGE, HINT);
+    }
+  }
+}
+
+impl<'c, 'view> VisitAll for NoSelfImportVisitor<'c, 'view> {
+  noop_visit_type!();
+
+  fn visit_import_decl(&mut self, import_decl: &ImportDecl, _: &dyn Node) {
+    self.check_specifier(&import_decl.src, import_decl.span);
+  }
+
+  fn visit_named_export(&mut self, named_export: &NamedExport, _: &dyn Node) {
+    if let Some(src) = &named_export.src {
+      self.check_specifier(src, named_export.span);
+    }
+  }
+
+  fn visit_export_all(&mut self, export_all: &ExportAll, _: &dyn Node) {
+    self.check_specifier(&export_all.src, export_all.span);
+  }
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _: &dyn Node) {
+    if let ExprOrSuper::Expr(callee) = &call_expr.callee {
+      if let Expr::Ident(ident) = &**callee {
+        if ident.sym == *"import" {
+          if let Some(arg) = call_expr.args.first() {
+            if let Expr::Lit(Lit::Str(specifier)) = &*arg.expr {
+              self.check_specifier(specifier, call_expr.span);
+            }
+          }
+        }
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_self_import_valid() {
+    assert_lint_ok! {
+      NoSelfImport,
+      r#"import { a } from "./other.ts";"#,
+      r#"import "./deno_lint_ok_test.js";"#,
+      r#"import "https://example.com/deno_lint_ok_test.ts";"#,
+      r#"export * from "./other.ts";"#,
+      r#"export { a };"#,
+      r#"import(specifier).then(run);"#,
+    };
+
+    assert_lint_ok! {
+      NoSelfImport,
+      filename: "file:///src/foo.ts",
+      r#"import "./sub/foo.ts";"#,
+      r#"import "../foo.ts";"#,
+      r#"import "./src/foo.ts";"#,
+      r#"import "foo.ts";"#,
+    };
+  }
+
+  #[test]
+  fn no_self_import_invalid() {
+    assert_lint_err! {
+      NoSelfImport,
+      r#"import "./deno_lint_err_test.ts";"#: [
+        { col: 0, message: MESSAGE, hint: HINT },
+      ],
+    };
+
+    assert_lint_err! {
+      NoSelfImport,
+      filename: "file:///src/foo.ts",
+      r#"import { a } from "./foo.ts";"#: [
+        { col: 0, message: MESSAGE, hint: HINT },
+      ],
+      r#"import * as foo from "../src/foo.ts";"#: [
+        { col: 0, message: MESSAGE, hint: HINT },
+      ],
+      r#"import foo from "file:///src/foo.ts";"#: [
+        { col: 0, message: MESSAGE, hint: HINT },
+      ],
+      r#"export * from "./././foo.ts";"#: [
+        { col: 0, message: MESSAGE, hint: HINT },
+      ],
+      r#"export { a } from "./foo.ts";"#: [
+        { col: 0, message: MESSAGE, hint: HINT },
+      ],
+      r#"import("./foo.ts").then(run);"#: [
+        { col: 0, message: MESSAGE, hint: HINT },
+      ],
+    };
+  }
+}
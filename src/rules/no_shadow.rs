@@ -0,0 +1,511 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::globals::GLOBALS;
+use crate::scopes::bindings::{
+  add_constructor_params, add_lexical_module_items, add_lexical_stmts,
+  add_lexical_var_decl, add_pat, function_body_bindings, Binding, Bindings,
+  VarCollector,
+};
+use crate::ProgramRef;
+use deno_ast::swc::ast::{
+  ArrowExpr, BlockStmt, BlockStmtOrExpr, CatchClause, Constructor, ForInStmt,
+  ForOfStmt, ForStmt, Function, GetterProp, Module, Script, SetterProp,
+  SwitchStmt, VarDeclOrExpr, VarDeclOrPat,
+};
+use deno_ast::swc::atoms::JsWord;
+use deno_ast::swc::visit::{noop_visit_type, Node, Visit, VisitWith};
+use derive_more::Display;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+#[derive(Debug, Default)]
+pub struct NoShadow {
+  options: NoShadowOptions,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoShadowOptions {
+  /// Whether shadowing of global variables like `Object` is reported.
+  pub builtin_globals: bool,
+  /// How shadowing of variables declared later in the outer scope is
+  /// handled.
+  pub hoist: NoShadowHoist,
+  /// Names which are allowed to shadow, e.g. `"resolve"` and `"reject"`.
+  pub allow: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NoShadowHoist {
+  /// Shadowing is reported even before the outer variable is declared.
+  All,
+  /// Shadowing is reported before the outer variable is declared only if it's
+  /// a function declaration.
+  Functions,
+  /// Shadowing is never reported before the outer variable is declared.
+  Never,
+}
+
+impl Default for NoShadowHoist {
+  fn default() -> Self {
+    NoShadowHoist::Functions
+  }
+}
+
+const CODE: &str = "no-shadow";
+const HINT: &str = "Rename the variable";
+
+#[derive(Display)]
+enum NoShadowMessage {
+  #[display(fmt = "`{}` is already declared in the upper scope", _0)]
+  Shadow(String),
+  #[display(fmt = "`{}` is already a global variable", _0)]
+  Global(String),
+}
+
+impl NoShadow {
+  pub fn with_options(options: NoShadowOptions) -> Box<Self> {
+    Box::new(NoShadow { options })
+  }
+}
+
+impl LintRule for NoShadow {
+  fn new() -> Box<Self> {
+    Box::new(NoShadow::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    let mut visitor = NoShadowVisitor::new(context, &self.options);
+    match program {
+      ProgramRef::Module(m) => visitor.visit_module(m, &DUMMY_NODE),
+      ProgramRef::Script(s) => visitor.visit_script(s, &DUMMY_NODE),
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_shadow.md")
+  }
+}
+
+struct NoShadowVisitor<'c, 'view, 'o> {
+  context: &'c mut Context<'view>,
+  options: &'o NoShadowOptions,
+  scopes: Vec<HashMap<JsWord, Binding>>,
+}
+
+impl<'c, 'view, 'o> NoShadowVisitor<'c, 'view, 'o> {
+  fn new(
+    context: &'c mut Context<'view>,
+    options: &'o NoShadowOptions,
+  ) -> Self {
+    Self {
+      context,
+      options,
+      scopes: Vec::new(),
+    }
+  }
+
+  fn enter_scope(&mut self, bindings: Bindings) {
+    let mut scope = HashMap::new();
+    for (name, binding) in bindings {
+      if scope.contains_key(&name) {
+        continue;
+      }
+      self.check_binding(&name, binding);
+      scope.insert(name, binding);
+    }
+    self.scopes.push(scope);
+  }
+
+  fn exit_scope(&mut self) {
+    self.scopes.pop();
+  }
+
+  fn check_binding(&mut self, name: &JsWord, binding: Binding) {
+    if *name == *"this" || self.options.allow.iter().any(|a| *name == **a) {
+      return;
+    }
+
+    let shadowed = self
+      .scopes
+      .iter()
+      .rev()
+      .find_map(|scope| scope.get(name))
+      .copied();
+
+    match shadowed {
+      Some(shadowed) => {
+        // The outer variable is declared after the inner one
+        let hoisted = binding.span.hi < shadowed.span.lo;
+        let report = match self.options.hoist {
+          NoShadowHoist::All => true,
+          NoShadowHoist::Functions => !hoisted || shadowed.is_function,
+          NoShadowHoist::Never => !hoisted,
+        };
+        if report {
+          self.context.add_diagnostic_with_hint(
+            binding.span,
+            CODE,
+            NoShadowMessage::Shadow(name.to_string()),
+            HINT,
+          );
+        }
+      }
+      None => {
+        if self.options.builtin_globals
+          && GLOBALS.iter().any(|(global, _)| *name == **global)
+        {
+          self.context.add_diagnostic_with_hint(
+            binding.span,
+            CODE,
+            NoShadowMessage::Global(name.to_string()),
+            HINT,
+          );
+        }
+      }
+    }
+  }
+}
+
+impl<'c, 'view, 'o> Visit for NoShadowVisitor<'c, 'view, 'o> {
+  noop_visit_type!();
+
+  fn visit_module(&mut self, module: &Module, _: &dyn Node) {
+    let mut bindings = Vec::new();
+    VarCollector::collect(&module.body, &mut bindings);
+    add_lexical_module_items(&module.body, &mut bindings);
+
+    self.enter_scope(bindings);
+    module.visit_children_with(self);
+    self.exit_scope();
+  }
+
+  fn visit_script(&mut self, script: &Script, _: &dyn Node) {
+    let mut bindings = Vec::new();
+    VarCollector::collect(&script.body, &mut bindings);
+    add_lexical_stmts(&script.body, &mut bindings);
+
+    self.enter_scope(bindings);
+    script.visit_children_with(self);
+    self.exit_scope();
+  }
+
+  fn visit_function(&mut self, function: &Function, _: &dyn Node) {
+    // Overload signatures and declarations without a body can't shadow
+    let body = match &function.body {
+      Some(body) => body,
+      None => return,
+    };
+
+    let mut bindings = Vec::new();
+    for param in &function.params {
+      add_pat(&param.pat, &mut bindings);
+    }
+    function_body_bindings(body, &mut bindings);
+
+    self.enter_scope(bindings);
+    function.decorators.visit_with(function, self);
+    function.params.visit_with(function, self);
+    // The body shares the scope with the parameters
+    body.stmts.visit_with(body, self);
+    self.exit_scope();
+  }
+
+  fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr, _: &dyn Node) {
+    let mut bindings = Vec::new();
+    for param in &arrow_expr.params {
+      add_pat(param, &mut bindings);
+    }
+    if let BlockStmtOrExpr::BlockStmt(body) = &arrow_expr.body {
+      function_body_bindings(body, &mut bindings);
+    }
+
+    self.enter_scope(bindings);
+    arrow_expr.params.visit_with(arrow_expr, self);
+    match &arrow_expr.body {
+      BlockStmtOrExpr::BlockStmt(body) => body.stmts.visit_with(body, self),
+      BlockStmtOrExpr::Expr(expr) => expr.visit_with(arrow_expr, self),
+    }
+    self.exit_scope();
+  }
+
+  fn visit_constructor(&mut self, constructor: &Constructor, _: &dyn Node) {
+    let body = match &constructor.body {
+      Some(body) => body,
+      None => return,
+    };
+
+    let mut bindings = Vec::new();
+    add_constructor_params(constructor, &mut bindings);
+    function_body_bindings(body, &mut bindings);
+
+    self.enter_scope(bindings);
+    constructor.params.visit_with(constructor, self);
+    body.stmts.visit_with(body, self);
+    self.exit_scope();
+  }
+
+  fn visit_getter_prop(&mut self, getter_prop: &GetterProp, _: &dyn Node) {
+    getter_prop.key.visit_with(getter_prop, self);
+    if let Some(body) = &getter_prop.body {
+      let mut bindings = Vec::new();
+      function_body_bindings(body, &mut bindings);
+
+      self.enter_scope(bindings);
+      body.stmts.visit_with(body, self);
+      self.exit_scope();
+    }
+  }
+
+  fn visit_setter_prop(&mut self, setter_prop: &SetterProp, _: &dyn Node) {
+    setter_prop.key.visit_with(setter_prop, self);
+    if let Some(body) = &setter_prop.body {
+      let mut bindings = Vec::new();
+      add_pat(&setter_prop.param, &mut bindings);
+      function_body_bindings(body, &mut bindings);
+
+      self.enter_scope(bindings);
+      setter_prop.param.visit_with(setter_prop, self);
+      body.stmts.visit_with(body, self);
+      self.exit_scope();
+    }
+  }
+
+  fn visit_block_stmt(&mut self, block_stmt: &BlockStmt, _: &dyn Node) {
+    let mut bindings = Vec::new();
+    add_lexical_stmts(&block_stmt.stmts, &mut bindings);
+
+    self.enter_scope(bindings);
+    block_stmt.visit_children_with(self);
+    self.exit_scope();
+  }
+
+  fn visit_for_stmt(&mut self, for_stmt: &ForStmt, _: &dyn Node) {
+    let mut bindings = Vec::new();
+    if let Some(VarDeclOrExpr::VarDecl(var_decl)) = &for_stmt.init {
+      add_lexical_var_decl(var_decl, &mut bindings);
+    }
+
+    self.enter_scope(bindings);
+    for_stmt.visit_children_with(self);
+    self.exit_scope();
+  }
+
+  fn visit_for_in_stmt(&mut self, for_in: &ForInStmt, _: &dyn Node) {
+    let mut bindings = Vec::new();
+    if let VarDeclOrPat::VarDecl(var_decl) = &for_in.left {
+      add_lexical_var_decl(var_decl, &mut bindings);
+    }
+
+    self.enter_scope(bindings);
+    for_in.visit_children_with(self);
+    self.exit_scope();
+  }
+
+  fn visit_for_of_stmt(&mut self, for_of: &ForOfStmt, _: &dyn Node) {
+    let mut bindings = Vec::new();
+    if let VarDeclOrPat::VarDecl(var_decl) = &for_of.left {
+      add_lexical_var_decl(var_decl, &mut bindings);
+    }
+
+    self.enter_scope(bindings);
+    for_of.visit_children_with(self);
+    self.exit_scope();
+  }
+
+  fn visit_catch_clause(&mut self, catch_clause: &CatchClause, _: &dyn Node) {
+    let mut bindings = Vec::new();
+    if let Some(param) = &catch_clause.param {
+      add_pat(param, &mut bindings);
+    }
+
+    self.enter_scope(bindings);
+    catch_clause.visit_children_with(self);
+    self.exit_scope();
+  }
+
+  fn visit_switch_stmt(&mut self, switch_stmt: &SwitchStmt, _: &dyn Node) {
+    switch_stmt.discriminant.visit_with(switch_stmt, self);
+
+    let mut bindings = Vec::new();
+    add_lexical_stmts(
+      switch_stmt.cases.iter().flat_map(|case| &case.cons),
+      &mut bindings,
+    );
+
+    self.enter_scope(bindings);
+    switch_stmt.cases.visit_with(switch_stmt, self);
+    self.exit_scope();
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v7.10.0/tests/lib/rules/no-shadow.js
+  // MIT Licensed.
+
+  #[test]
+  fn no_shadow_valid() {
+    assert_lint_ok! {
+      NoShadow,
+      "var a = 3; function b(x) { a++; return x + a; }",
+      "var a = 3; function b() { var c = a; }",
+      "function a() {} function b() { var c; }",
+      "var a; function b(c) { var d = c; }",
+      "function foo() { var Object = 0; }",
+      "function f() { var a; } var a;",
+      "if (true) { let a; } let a;",
+      "class A { m(b) {} } const b = 1;",
+      "const a = function a() {};",
+      "function foo(x) { return function bar(y) {}; }",
+      "{ let a; } { let a; }",
+      "for (let i = 0; i < 1; i++) {} for (let i = 0; i < 1; i++) {}",
+      "try {} catch (e) {} function f(e) {}",
+      "function f(a: string): void; function f(a: any) {}",
+      "function f(this: Foo) { const g = function(this: Bar) {}; }",
+      "class A { constructor(private x) {} m() { const x = 1; } }",
+    };
+
+    assert_lint_ok! {
+      NoShadow,
+      options: json!({ "allow": ["done", "resolve", "reject"] }),
+      "function foo(done) { bar(function(done) {}); }",
+      "new Promise((resolve, reject) => { run((resolve) => resolve()); });",
+    };
+
+    assert_lint_ok! {
+      NoShadow,
+      options: json!({ "hoist": "never" }),
+      "function f() { var a; } function a() {}",
+    };
+  }
+
+  #[test]
+  fn no_shadow_invalid() {
+    assert_lint_err! {
+      NoShadow,
+      "var a = 3; function b() { var a = 10; }": [
+        {
+          col: 30,
+          message: variant!(NoShadowMessage, Shadow, "a"),
+          hint: HINT,
+        },
+      ],
+      "function a(x) { if (x) { let x = 1; } }": [
+        {
+          col: 29,
+          message: variant!(NoShadowMessage, Shadow, "x"),
+          hint: HINT,
+        },
+      ],
+      "let x = 1; { let x = 2; }": [
+        {
+          col: 17,
+          message: variant!(NoShadowMessage, Shadow, "x"),
+          hint: HINT,
+        },
+      ],
+      "const x = 1; const f = (x) => x;": [
+        {
+          col: 24,
+          message: variant!(NoShadowMessage, Shadow, "x"),
+          hint: HINT,
+        },
+      ],
+      "function f() { var a; } function a() {}": [
+        {
+          col: 19,
+          message: variant!(NoShadowMessage, Shadow, "a"),
+          hint: HINT,
+        },
+      ],
+      "const e = 1; try {} catch (e) {}": [
+        {
+          col: 27,
+          message: variant!(NoShadowMessage, Shadow, "e"),
+          hint: HINT,
+        },
+      ],
+      "import { a } from './a.ts'; function f(a) {}": [
+        {
+          col: 39,
+          message: variant!(NoShadowMessage, Shadow, "a"),
+          hint: HINT,
+        },
+      ],
+      "var x = 1; for (let x of xs) {}": [
+        {
+          col: 20,
+          message: variant!(NoShadowMessage, Shadow, "x"),
+          hint: HINT,
+        },
+      ],
+      "let x; switch (a) { case 1: let x; }": [
+        {
+          col: 32,
+          message: variant!(NoShadowMessage, Shadow, "x"),
+          hint: HINT,
+        },
+      ],
+      "function f(a) { return () => { var a; }; }": [
+        {
+          col: 35,
+          message: variant!(NoShadowMessage, Shadow, "a"),
+          hint: HINT,
+        },
+      ],
+    };
+
+    assert_lint_err! {
+      NoShadow,
+      options: json!({ "hoist": "all" }),
+      "function f() { var a; } var a;": [
+        {
+          col: 19,
+          message: variant!(NoShadowMessage, Shadow, "a"),
+          hint: HINT,
+        },
+      ],
+      "if (true) { let a; } let a;": [
+        {
+          col: 16,
+          message: variant!(NoShadowMessage, Shadow, "a"),
+          hint: HINT,
+        },
+      ],
+    };
+
+    assert_lint_err! {
+      NoShadow,
+      options: json!({ "builtinGlobals": true }),
+      "function foo() { var Object = 0; }": [
+        {
+          col: 21,
+          message: variant!(NoShadowMessage, Global, "Object"),
+          hint: HINT,
+        },
+      ],
+      "const Object = 1; export {};": [
+        {
+          col: 6,
+          message: variant!(NoShadowMessage, Global, "Object"),
+          hint: HINT,
+        },
+      ],
+    };
+  }
+}
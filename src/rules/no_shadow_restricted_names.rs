@@ -2,8 +2,10 @@
 use super::{Context, LintRule, DUMMY_NODE};
 use crate::ProgramRef;
 use deno_ast::swc::ast::{
-  ArrowExpr, AssignExpr, CatchClause, Expr, FnDecl, FnExpr, Ident,
-  ObjectPatProp, Pat, PatOrExpr, VarDecl,
+  ArrowExpr, AssignExpr, CatchClause, ClassDecl, ClassExpr, Constructor, Expr,
+  FnDecl, FnExpr, Function, Ident, ImportDefaultSpecifier,
+  ImportNamedSpecifier, ImportStarAsSpecifier, ObjectPatProp,
+  ParamOrTsParamProp, Pat, PatOrExpr, SetterProp, TsParamPropParam, VarDecl,
 };
 use deno_ast::swc::visit::{noop_visit_type, Node, VisitAll, VisitAllWith};
 use derive_more::Display;
@@ -99,7 +101,10 @@ impl<'c, 'view> NoShadowRestrictedNamesVisitor<'c, 'view> {
       Pat::Rest(rest_pat) => {
         self.check_pat(&rest_pat.arg);
       }
-      _ => {}
+      Pat::Assign(assign_pat) => {
+        self.check_pat(&assign_pat.left);
+      }
+      Pat::Invalid(_) => {}
     }
   }
 
@@ -136,22 +141,72 @@ impl<'c, 'view> VisitAll for NoShadowRestrictedNamesVisitor<'c, 'view> {
 
   fn visit_fn_decl(&mut self, node: &FnDecl, _: &dyn Node) {
     self.check_shadowing(&node.ident);
-
-    for param in &node.function.params {
-      self.check_pat(&param.pat);
-    }
   }
 
   fn visit_fn_expr(&mut self, node: &FnExpr, _: &dyn Node) {
     if let Some(ident) = node.ident.as_ref() {
       self.check_shadowing(ident)
     }
+  }
 
-    for param in &node.function.params {
+  // Covers parameters of function declarations and expressions, as well as
+  // of class and object methods
+  fn visit_function(&mut self, node: &Function, _: &dyn Node) {
+    for param in &node.params {
       self.check_pat(&param.pat);
     }
   }
 
+  fn visit_constructor(&mut self, node: &Constructor, _: &dyn Node) {
+    for param in &node.params {
+      match param {
+        ParamOrTsParamProp::Param(param) => self.check_pat(&param.pat),
+        ParamOrTsParamProp::TsParamProp(prop) => match &prop.param {
+          TsParamPropParam::Ident(ident) => self.check_shadowing(&ident.id),
+          TsParamPropParam::Assign(assign) => self.check_pat(&assign.left),
+        },
+      }
+    }
+  }
+
+  fn visit_setter_prop(&mut self, node: &SetterProp, _: &dyn Node) {
+    self.check_pat(&node.param);
+  }
+
+  fn visit_class_decl(&mut self, node: &ClassDecl, _: &dyn Node) {
+    self.check_shadowing(&node.ident);
+  }
+
+  fn visit_class_expr(&mut self, node: &ClassExpr, _: &dyn Node) {
+    if let Some(ident) = node.ident.as_ref() {
+      self.check_shadowing(ident);
+    }
+  }
+
+  fn visit_import_named_specifier(
+    &mut self,
+    node: &ImportNamedSpecifier,
+    _: &dyn Node,
+  ) {
+    self.check_shadowing(&node.local);
+  }
+
+  fn visit_import_default_specifier(
+    &mut self,
+    node: &ImportDefaultSpecifier,
+    _: &dyn Node,
+  ) {
+    self.check_shadowing(&node.local);
+  }
+
+  fn visit_import_star_as_specifier(
+    &mut self,
+    node: &ImportStarAsSpecifier,
+    _: &dyn Node,
+  ) {
+    self.check_shadowing(&node.local);
+  }
+
   fn visit_arrow_expr(&mut self, node: &ArrowExpr, _: &dyn Node) {
     for param in &node.params {
       self.check_pat(param);
@@ -439,6 +494,84 @@ mod tests {
           message: variant!(NoShadowRestrictedNamesMessage, Shadowing, "NaN"),
         }
       ],
+
+      // classes and methods
+      "class NaN {}": [
+        {
+          col: 6,
+          message: variant!(NoShadowRestrictedNamesMessage, Shadowing, "NaN"),
+        }
+      ],
+      "const A = class Infinity {};": [
+        {
+          col: 16,
+          message: variant!(NoShadowRestrictedNamesMessage, Shadowing, "Infinity"),
+        }
+      ],
+      "class A { m(eval) {} }": [
+        {
+          col: 12,
+          message: variant!(NoShadowRestrictedNamesMessage, Shadowing, "eval"),
+        }
+      ],
+      "class A { constructor(arguments) {} }": [
+        {
+          col: 22,
+          message: variant!(NoShadowRestrictedNamesMessage, Shadowing, "arguments"),
+        }
+      ],
+      "({ m(NaN) {} });": [
+        {
+          col: 5,
+          message: variant!(NoShadowRestrictedNamesMessage, Shadowing, "NaN"),
+        }
+      ],
+      "({ set a(undefined) {} });": [
+        {
+          col: 9,
+          message: variant!(NoShadowRestrictedNamesMessage, Shadowing, "undefined"),
+        }
+      ],
+
+      // default values
+      "function f(undefined = 1) {}": [
+        {
+          col: 11,
+          message: variant!(NoShadowRestrictedNamesMessage, Shadowing, "undefined"),
+        }
+      ],
+      "const f = ({ a: NaN } = {}) => {};": [
+        {
+          col: 16,
+          message: variant!(NoShadowRestrictedNamesMessage, Shadowing, "NaN"),
+        }
+      ],
+
+      // imports
+      r#"import { NaN } from "./a.ts";"#: [
+        {
+          col: 9,
+          message: variant!(NoShadowRestrictedNamesMessage, Shadowing, "NaN"),
+        }
+      ],
+      r#"import eval from "./a.ts";"#: [
+        {
+          col: 7,
+          message: variant!(NoShadowRestrictedNamesMessage, Shadowing, "eval"),
+        }
+      ],
+      r#"import * as Infinity from "./a.ts";"#: [
+        {
+          col: 12,
+          message: variant!(NoShadowRestrictedNamesMessage, Shadowing, "Infinity"),
+        }
+      ],
+      r#"import { a as arguments } from "./a.ts";"#: [
+        {
+          col: 14,
+          message: variant!(NoShadowRestrictedNamesMessage, Shadowing, "arguments"),
+        }
+      ],
     };
   }
 }
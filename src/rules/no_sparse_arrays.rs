@@ -1,13 +1,27 @@
 // Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
 use super::{Context, LintRule, DUMMY_NODE};
 use crate::ProgramRef;
+use deno_ast::swc::ast::ArrayLit;
+use deno_ast::swc::common::{BytePos, Span, Spanned};
 use deno_ast::swc::visit::noop_visit_type;
 use deno_ast::swc::visit::Node;
 use deno_ast::swc::visit::Visit;
+use deno_ast::swc::visit::VisitWith;
 use derive_more::Display;
+use serde::Deserialize;
 
-#[derive(Debug)]
-pub struct NoSparseArrays;
+#[derive(Debug, Default)]
+pub struct NoSparseArrays {
+  options: NoSparseArraysOptions,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoSparseArraysOptions {
+  /// Whether an array literal may contain a single hole, e.g. as a
+  /// placeholder.
+  pub allow_single_hole: bool,
+}
 
 const CODE: &str = "no-sparse-arrays";
 
@@ -17,9 +31,15 @@ enum NoSparseArraysMessage {
   Disallowed,
 }
 
+impl NoSparseArrays {
+  pub fn with_options(options: NoSparseArraysOptions) -> Box<Self> {
+    Box::new(NoSparseArrays { options })
+  }
+}
+
 impl LintRule for NoSparseArrays {
   fn new() -> Box<Self> {
-    Box::new(NoSparseArrays)
+    Box::new(NoSparseArrays::default())
   }
 
   fn code(&self) -> &'static str {
@@ -31,7 +51,7 @@ impl LintRule for NoSparseArrays {
     context: &mut Context<'view>,
     program: ProgramRef<'view>,
   ) {
-    let mut visitor = NoSparseArraysVisitor::new(context);
+    let mut visitor = NoSparseArraysVisitor::new(context, &self.options);
     match program {
       ProgramRef::Module(m) => visitor.visit_module(m, &DUMMY_NODE),
       ProgramRef::Script(s) => visitor.visit_script(s, &DUMMY_NODE),
@@ -44,30 +64,98 @@ impl LintRule for NoSparseArrays {
   }
 }
 
-struct NoSparseArraysVisitor<'c, 'view> {
+/// Returns the offsets of the commas in the text between two elements of an
+/// array literal, which only contains commas, whitespaces and comments.
+fn comma_offsets(text: &str) -> Vec<usize> {
+  let mut offsets = Vec::new();
+  let mut i = 0;
+  while i < text.len() {
+    let rest = &text[i..];
+    if rest.starts_with("//") {
+      i += rest.find('\n').unwrap_or_else(|| rest.len());
+    } else if rest.starts_with("/*") {
+      i += rest.find("*/").map_or(rest.len(), |end| end + 2);
+    } else {
+      if rest.starts_with(',') {
+        offsets.push(i);
+      }
+      i += rest.chars().next().map_or(1, char::len_utf8);
+    }
+  }
+  offsets
+}
+
+struct NoSparseArraysVisitor<'c, 'view, 'o> {
   context: &'c mut Context<'view>,
+  options: &'o NoSparseArraysOptions,
 }
 
-impl<'c, 'view> NoSparseArraysVisitor<'c, 'view> {
-  fn new(context: &'c mut Context<'view>) -> Self {
-    Self { context }
+impl<'c, 'view, 'o> NoSparseArraysVisitor<'c, 'view, 'o> {
+  fn new(
+    context: &'c mut Context<'view>,
+    options: &'o NoSparseArraysOptions,
+  ) -> Self {
+    Self { context, options }
+  }
+
+  /// Returns the spans of the commas ending each hole of the array literal.
+  fn hole_spans(&self, array_lit: &ArrayLit) -> Vec<Span> {
+    let mut spans = Vec::new();
+    // Start of the text following the last element, or the opening bracket
+    let mut region_lo = array_lit.span.lo + BytePos(1);
+    let mut after_element = false;
+    let mut holes = 0;
+    let element_spans = array_lit
+      .elems
+      .iter()
+      .map(|elem| elem.as_ref().map(|elem| elem.span()))
+      .chain(std::iter::once(None));
+    for (i, elem_span) in element_spans.enumerate() {
+      let is_end = i == array_lit.elems.len();
+      if elem_span.is_none() && !is_end {
+        holes += 1;
+        continue;
+      }
+      if holes > 0 {
+        let region_hi = match elem_span {
+          Some(elem_span) => elem_span.lo,
+          None => array_lit.span.hi - BytePos(1),
+        };
+        let region = Span::new(region_lo, region_hi, Default::default());
+        let commas = comma_offsets(self.context.file_text_substring(&region));
+        // The first comma ends the preceding element
+        let skipped = if after_element { 1 } else { 0 };
+        for &offset in commas.iter().skip(skipped).take(holes) {
+          let lo = region_lo + BytePos(offset as u32);
+          spans.push(Span::new(lo, lo + BytePos(1), Default::default()));
+        }
+        holes = 0;
+      }
+      if let Some(elem_span) = elem_span {
+        region_lo = elem_span.hi;
+        after_element = true;
+      }
+    }
+    spans
   }
 }
 
-impl<'c, 'view> Visit for NoSparseArraysVisitor<'c, 'view> {
+impl<'c, 'view, 'o> Visit for NoSparseArraysVisitor<'c, 'view, 'o> {
   noop_visit_type!();
 
-  fn visit_array_lit(
-    &mut self,
-    array_lit: &deno_ast::swc::ast::ArrayLit,
-    _parent: &dyn Node,
-  ) {
-    if array_lit.elems.iter().any(|e| e.is_none()) {
-      self.context.add_diagnostic(
-        array_lit.span,
-        CODE,
-        NoSparseArraysMessage::Disallowed,
-      );
+  fn visit_array_lit(&mut self, array_lit: &ArrayLit, _parent: &dyn Node) {
+    array_lit.visit_children_with(self);
+
+    let holes = array_lit.elems.iter().filter(|e| e.is_none()).count();
+    let allowed_holes = if self.options.allow_single_hole { 1 } else { 0 };
+    if holes > allowed_holes {
+      for span in self.hole_spans(array_lit) {
+        self.context.add_diagnostic(
+          span,
+          CODE,
+          NoSparseArraysMessage::Disallowed,
+        );
+      }
     }
   }
 }
@@ -75,12 +163,23 @@ impl<'c, 'view> Visit for NoSparseArraysVisitor<'c, 'view> {
 #[cfg(test)]
 mod tests {
   use super::*;
+  use serde_json::json;
 
   #[test]
   fn no_sparse_arrays_valid() {
     assert_lint_ok! {
       NoSparseArrays,
       "const sparseArray1 = [1,null,3];",
+      "const a = [1, 2,];",
+      "const a = [];",
+    };
+
+    assert_lint_ok! {
+      NoSparseArrays,
+      options: json!({ "allowSingleHole": true }),
+      "const a = [1,,2];",
+      "const [, b] = [, 1];",
+      "const a = [[,1], [2,]];",
     };
   }
 
@@ -89,10 +188,82 @@ mod tests {
     assert_lint_err! {
       NoSparseArrays,
       r#"const sparseArray = [1,,3];"#: [
+      {
+        col: 23,
+        message: NoSparseArraysMessage::Disallowed,
+      }],
+      "const a = [,];": [
+      {
+        col: 11,
+        message: NoSparseArraysMessage::Disallowed,
+      }],
+      "const a = [1,,,2];": [
+      {
+        col: 13,
+        message: NoSparseArraysMessage::Disallowed,
+      },
+      {
+        col: 14,
+        message: NoSparseArraysMessage::Disallowed,
+      }],
+      "const a = [, 1, , 2,,];": [
+      {
+        col: 11,
+        message: NoSparseArraysMessage::Disallowed,
+      },
+      {
+        col: 16,
+        message: NoSparseArraysMessage::Disallowed,
+      },
       {
         col: 20,
         message: NoSparseArraysMessage::Disallowed,
       }],
+      "const a = [1, /* , */ , 2];": [
+      {
+        col: 22,
+        message: NoSparseArraysMessage::Disallowed,
+      }],
+      "const a = [[1,,2]];": [
+      {
+        col: 14,
+        message: NoSparseArraysMessage::Disallowed,
+      }],
+      r#"
+const a = [
+  1,
+  ,
+  2,
+];
+"#: [
+      {
+        line: 4,
+        col: 2,
+        message: NoSparseArraysMessage::Disallowed,
+      }],
+    };
+
+    assert_lint_err! {
+      NoSparseArrays,
+      options: json!({ "allowSingleHole": true }),
+      "const a = [1,,,2];": [
+      {
+        col: 13,
+        message: NoSparseArraysMessage::Disallowed,
+      },
+      {
+        col: 14,
+        message: NoSparseArraysMessage::Disallowed,
+      }],
+      "const a = [,1,,2];": [
+      {
+        col: 11,
+        message: NoSparseArraysMessage::Disallowed,
+      },
+      {
+        col: 14,
+        message: NoSparseArraysMessage::Disallowed,
+      }],
     }
   }
 }
@@ -0,0 +1,101 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::view as ast_view;
+
+#[derive(Debug)]
+pub struct NoTernary;
+
+const CODE: &str = "no-ternary";
+const MESSAGE: &str = "Unexpected use of ternary expression";
+const HINT: &str = "Use an `if`/`else` statement instead";
+
+impl LintRule for NoTernary {
+  fn new() -> Box<Self> {
+    Box::new(NoTernary)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoTernaryHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_ternary.md")
+  }
+}
+
+/// Checks if the expression is a direct operand of another ternary, possibly
+/// wrapped in parentheses.
+fn is_nested(cond_expr: &ast_view::CondExpr) -> bool {
+  let mut current = cond_expr.parent();
+  while let ast_view::Node::ParenExpr(paren) = current {
+    current = paren.parent();
+  }
+  current.is::<ast_view::CondExpr>()
+}
+
+struct NoTernaryHandler;
+
+impl Handler for NoTernaryHandler {
+  fn cond_expr(&mut self, cond_expr: &ast_view::CondExpr, ctx: &mut Context) {
+    if !is_nested(cond_expr) {
+      ctx.add_diagnostic_with_hint(cond_expr.inner.span, CODE, MESSAGE, HINT);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_ternary_valid() {
+    assert_lint_ok! {
+      NoTernary,
+      "if (a) { b(); } else { c(); }",
+      r#"
+let x;
+if (a) {
+  x = b;
+} else {
+  x = c;
+}
+      "#,
+      "const x = a || b;",
+      "const x = a ?? b;",
+    };
+  }
+
+  #[test]
+  fn no_ternary_invalid() {
+    assert_lint_err! {
+      NoTernary,
+      "const x = a ? b : c;": [{ col: 10, message: MESSAGE, hint: HINT }],
+      "foo(a ? b : c);": [{ col: 4, message: MESSAGE, hint: HINT }],
+      "const x = a ? b : c ? d : e;": [
+        { col: 10, message: MESSAGE, hint: HINT },
+      ],
+      "const x = a ? (b ? c : d) : e;": [
+        { col: 10, message: MESSAGE, hint: HINT },
+      ],
+      "const x = a ? f(b ? c : d) : e;": [
+        { col: 10, message: MESSAGE, hint: HINT },
+        { col: 16, message: MESSAGE, hint: HINT },
+      ],
+    };
+  }
+}
@@ -1,9 +1,9 @@
 // Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
 use super::{Context, LintRule};
+use crate::control_flow::super_calls::SuperCalls;
 use crate::handler::{Handler, Traverse};
 use crate::{Program, ProgramRef};
 use deno_ast::view as ast_view;
-use deno_ast::view::{NodeTrait, Span, Spanned};
 
 #[derive(Debug)]
 pub struct NoThisBeforeSuper;
@@ -97,109 +97,14 @@ impl Handler for NoThisBeforeSuperHandler {
     }
 
     if let Some(body) = cons.body {
-      for stmt in &body.stmts {
-        let mut checker = SuperCallChecker::new(stmt.span());
-        checker.traverse(*stmt, ctx);
-        match checker.result() {
-          None => (),
-          Some(FirstAppeared::SuperCalled) => break,
-          Some(FirstAppeared::ThisAccessed(span))
-          | Some(FirstAppeared::SuperAccessed(span)) => {
-            ctx.add_diagnostic_with_hint(span, CODE, MESSAGE, HINT);
-          }
-        }
+      let super_calls = SuperCalls::analyze(body.inner, ctx.control_flow());
+      for span in super_calls.accesses_before_call {
+        ctx.add_diagnostic_with_hint(span, CODE, MESSAGE, HINT);
       }
     }
   }
 }
 
-enum FirstAppeared {
-  SuperCalled,
-  SuperAccessed(Span),
-  ThisAccessed(Span),
-}
-
-struct SuperCallChecker {
-  first_appeared: Option<FirstAppeared>,
-  root_span: Span,
-}
-
-impl SuperCallChecker {
-  fn new(root_span: Span) -> Self {
-    Self {
-      first_appeared: None,
-      root_span,
-    }
-  }
-
-  fn yet_appeared(&self) -> bool {
-    self.first_appeared.is_none()
-  }
-
-  fn result(self) -> Option<FirstAppeared> {
-    self.first_appeared
-  }
-
-  fn node_is_inside_function(&self, node: ast_view::Node) -> bool {
-    fn inside_function(root_span: Span, cur_node: ast_view::Node) -> bool {
-      // Stop recursion if the current node gets out of root_node.
-      if !root_span.contains(cur_node.span()) {
-        return false;
-      }
-
-      if matches!(
-        cur_node,
-        ast_view::Node::Function(_) | ast_view::Node::ArrowExpr(_)
-      ) {
-        return true;
-      }
-
-      inside_function(root_span, cur_node.parent().unwrap())
-    }
-
-    inside_function(self.root_span, node)
-  }
-}
-
-impl Handler for SuperCallChecker {
-  fn this_expr(&mut self, this_expr: &ast_view::ThisExpr, _ctx: &mut Context) {
-    if self.node_is_inside_function(this_expr.as_node()) {
-      return;
-    }
-
-    if self.yet_appeared() {
-      self.first_appeared = Some(FirstAppeared::ThisAccessed(this_expr.span()));
-    }
-  }
-
-  fn super_(&mut self, super_: &ast_view::Super, _ctx: &mut Context) {
-    if self.node_is_inside_function(super_.as_node()) {
-      return;
-    }
-
-    if self.yet_appeared() {
-      self.first_appeared = Some(FirstAppeared::SuperAccessed(super_.span()));
-    }
-  }
-
-  fn call_expr(&mut self, call_expr: &ast_view::CallExpr, ctx: &mut Context) {
-    if self.node_is_inside_function(call_expr.as_node()) {
-      return;
-    }
-
-    // arguments are evaluated before the callee
-    for arg in &call_expr.args {
-      self.traverse(arg.as_node(), ctx);
-    }
-
-    if self.yet_appeared()
-      && matches!(call_expr.callee, ast_view::ExprOrSuper::Super(_))
-    {
-      self.first_appeared = Some(FirstAppeared::SuperCalled);
-    }
-  }
-}
-
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -275,6 +180,92 @@ class A extends class extends B {
 }
       "#,
 
+      r#"
+class A extends B {
+  constructor() {
+    if (foo) {
+      super(1);
+    } else {
+      super(2);
+    }
+    this.a = 0;
+  }
+}
+      "#,
+      r#"
+class A extends B {
+  constructor() {
+    if (!foo) {
+      throw new Error();
+    } else {
+      super();
+    }
+    const a = foo ? super() : super(1);
+    this.b = 1;
+  }
+}
+      "#,
+      r#"
+class A extends B {
+  constructor() {
+    foo && super();
+    super();
+    this.a = 0;
+  }
+}
+      "#,
+      r#"
+class A extends B {
+  constructor() {
+    super();
+    for (const x of xs) {
+      this.add(x);
+    }
+  }
+}
+      "#,
+      r#"
+class A extends B {
+  constructor() {
+    class C {
+      foo = this.bar;
+    }
+    super();
+  }
+}
+      "#,
+      r#"
+class A extends B {
+  constructor() {
+    switch (x) {
+      case 1:
+        super();
+        break;
+      default:
+        super();
+    }
+    this.a = 1;
+  }
+}
+      "#,
+      r#"
+class A extends B {
+  constructor() {
+    switch (x) {
+      case 1:
+      case 2:
+        super(1);
+        break;
+      case 3:
+        throw new Error();
+      default:
+        super();
+    }
+    this.a = 1;
+  }
+}
+      "#,
+
       // nested class
       r#"
 class A extends B {
@@ -524,6 +515,180 @@ class A extends B {
           message: MESSAGE,
           hint: HINT,
         }
+      ],
+      r#"
+class A extends B {
+  constructor() {
+    this.a = 0;
+    this.b = super.c;
+    super();
+  }
+}
+      "#: [
+        {
+          line: 4,
+          col: 4,
+          message: MESSAGE,
+          hint: HINT,
+        },
+        {
+          line: 5,
+          col: 4,
+          message: MESSAGE,
+          hint: HINT,
+        },
+        {
+          line: 5,
+          col: 13,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      r#"
+class A extends B {
+  constructor() {
+    if (foo) {
+      super();
+    }
+    this.a = 0;
+  }
+}
+      "#: [
+        {
+          line: 7,
+          col: 4,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      r#"
+class A extends B {
+  constructor() {
+    if (foo) {
+      super();
+      this.a = 0;
+    } else {
+      this.a = 1;
+    }
+  }
+}
+      "#: [
+        {
+          line: 8,
+          col: 6,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      r#"
+class A extends B {
+  constructor() {
+    foo || super();
+    this.a = 0;
+  }
+}
+      "#: [
+        {
+          line: 5,
+          col: 4,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      r#"
+class A extends B {
+  constructor() {
+    switch (x) {
+      case 1:
+        super();
+        break;
+      case 2:
+        super();
+    }
+    this.a = 1;
+  }
+}
+      "#: [
+        {
+          line: 11,
+          col: 4,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      r#"
+class A extends B {
+  constructor() {
+    switch (x) {
+      case 1:
+        break;
+      default:
+        super();
+    }
+    this.a = 1;
+  }
+}
+      "#: [
+        {
+          line: 10,
+          col: 4,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      r#"
+class A extends B {
+  constructor() {
+    try {
+      this.a = 1;
+    } catch (e) {
+      super();
+    }
+  }
+}
+      "#: [
+        {
+          line: 5,
+          col: 6,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      r#"
+class A extends B {
+  constructor() {
+    try {
+      foo();
+    } catch (e) {
+      super();
+    }
+    this.a = 1;
+  }
+}
+      "#: [
+        {
+          line: 9,
+          col: 4,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      r#"
+class A extends B {
+  constructor() {
+    while (foo) {
+      super();
+    }
+    this.a = 0;
+  }
+}
+      "#: [
+        {
+          line: 7,
+          col: 4,
+          message: MESSAGE,
+          hint: HINT,
+        }
       ]
     };
   }
@@ -0,0 +1,160 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{Expr, Pat, VarDeclKind};
+use deno_ast::swc::common::{BytePos, Span};
+use deno_ast::swc::utils::ident::IdentLike;
+use deno_ast::view as ast_view;
+
+#[derive(Debug)]
+pub struct NoUndefInit;
+
+const CODE: &str = "no-undef-init";
+const MESSAGE: &str = "Initializing to `undefined` is redundant";
+const HINT: &str = "Remove the initializer";
+
+impl LintRule for NoUndefInit {
+  fn new() -> Box<Self> {
+    Box::new(NoUndefInit)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoUndefInitHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_undef_init.md")
+  }
+}
+
+/// Returns the span of ` = undefined`, i.e. from the end of the binding
+/// (including its type annotation, if any) to the end of the initializer.
+fn initializer_span(text: &str, init_span: Span) -> Option<Span> {
+  let before_init = &text[..init_span.lo.0 as usize];
+  let eq = before_init.rfind('=')?;
+  let lo = before_init[..eq].trim_end().len();
+  Some(Span::new(
+    BytePos(lo as u32),
+    init_span.hi,
+    Default::default(),
+  ))
+}
+
+struct NoUndefInitHandler;
+
+impl Handler for NoUndefInitHandler {
+  fn var_declarator(
+    &mut self,
+    var_declarator: &ast_view::VarDeclarator,
+    ctx: &mut Context,
+  ) {
+    let kind = var_declarator.parent().inner.kind;
+    // `const` requires an initializer
+    if kind == VarDeclKind::Const {
+      return;
+    }
+    // `let { a } = undefined` throws, so it's not equivalent to `let { a }`
+    if !matches!(var_declarator.inner.name, Pat::Ident(_)) {
+      return;
+    }
+
+    let init = match &var_declarator.inner.init {
+      Some(init) => init,
+      None => return,
+    };
+    let ident = match &**init {
+      Expr::Ident(ident) => ident,
+      _ => return,
+    };
+    if ident.sym != *"undefined" || !ctx.scope().is_global(&ident.to_id()) {
+      return;
+    }
+
+    // Removing the initializer of `var` changes the behavior when the
+    // declaration is executed repeatedly, e.g. in a loop, since the variable
+    // keeps its previous value
+    let fix_span = match kind {
+      VarDeclKind::Let => {
+        initializer_span(ctx.source_file().text(), ident.span)
+      }
+      _ => None,
+    };
+    match fix_span {
+      Some(fix_span) => {
+        let fix = ctx.create_fix(fix_span, "", "Remove the initializer");
+        ctx.add_diagnostic_with_hint_and_fix(
+          var_declarator.inner.span,
+          CODE,
+          MESSAGE,
+          HINT,
+          fix,
+        );
+      }
+      None => {
+        ctx.add_diagnostic_with_hint(
+          var_declarator.inner.span,
+          CODE,
+          MESSAGE,
+          HINT,
+        );
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_undef_init_valid() {
+    assert_lint_ok! {
+      NoUndefInit,
+      "let a;",
+      "let a = null;",
+      "const a = undefined;",
+      "let { a } = undefined;",
+      "let [a] = undefined;",
+      "function f(undefined) { let a = undefined; }",
+      "let a = void 0;",
+    };
+  }
+
+  #[test]
+  fn no_undef_init_invalid() {
+    assert_lint_err! {
+      NoUndefInit,
+      "let a = undefined;": [
+        { col: 4, message: MESSAGE, hint: HINT, fix: "let a;" },
+      ],
+      "let a: string | undefined = undefined;": [
+        {
+          col: 4,
+          message: MESSAGE,
+          hint: HINT,
+          fix: "let a: string | undefined;",
+        },
+      ],
+      "let a = 1, b = undefined, c;": [
+        { col: 11, message: MESSAGE, hint: HINT, fix: "let a = 1, b, c;" },
+      ],
+      "var a = undefined;": [
+        { col: 4, message: MESSAGE, hint: HINT },
+      ],
+    };
+  }
+}
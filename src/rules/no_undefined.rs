@@ -0,0 +1,157 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::ProgramRef;
+use deno_ast::swc::ast::{
+  AssignPatProp, ClassProp, Expr, Ident, MemberExpr, Pat, Prop,
+};
+use deno_ast::swc::utils::ident::IdentLike;
+use deno_ast::swc::visit::{noop_visit_type, Node, Visit, VisitWith};
+
+#[derive(Debug)]
+pub struct NoUndefined;
+
+const CODE: &str = "no-undefined";
+const MESSAGE: &str = "`undefined` should not be used";
+const HINT: &str =
+  "Use `void 0` instead, or omit the value where it's implicitly `undefined`";
+
+impl LintRule for NoUndefined {
+  fn new() -> Box<Self> {
+    Box::new(NoUndefined)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    let mut visitor = NoUndefinedVisitor::new(context);
+    match program {
+      ProgramRef::Module(m) => m.visit_with(&DUMMY_NODE, &mut visitor),
+      ProgramRef::Script(s) => s.visit_with(&DUMMY_NODE, &mut visitor),
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_undefined.md")
+  }
+}
+
+struct NoUndefinedVisitor<'c, 'view> {
+  context: &'c mut Context<'view>,
+}
+
+impl<'c, 'view> NoUndefinedVisitor<'c, 'view> {
+  fn new(context: &'c mut Context<'view>) -> Self {
+    Self { context }
+  }
+
+  fn check(&mut self, ident: &Ident) {
+    if ident.sym != *"undefined" {
+      return;
+    }
+
+    // Local bindings named `undefined` are reported by
+    // `no-shadow-restricted-names`
+    if !self.context.scope().is_global(&ident.to_id()) {
+      return;
+    }
+
+    self
+      .context
+      .add_diagnostic_with_hint(ident.span, CODE, MESSAGE, HINT);
+  }
+}
+
+impl<'c, 'view> Visit for NoUndefinedVisitor<'c, 'view> {
+  noop_visit_type!();
+
+  fn visit_member_expr(&mut self, e: &MemberExpr, _: &dyn Node) {
+    e.obj.visit_with(e, self);
+    if e.computed {
+      e.prop.visit_with(e, self);
+    }
+  }
+
+  fn visit_expr(&mut self, e: &Expr, _: &dyn Node) {
+    e.visit_children_with(self);
+
+    if let Expr::Ident(ident) = e {
+      self.check(ident);
+    }
+  }
+
+  fn visit_class_prop(&mut self, p: &ClassProp, _: &dyn Node) {
+    p.value.visit_with(p, self);
+  }
+
+  fn visit_prop(&mut self, p: &Prop, _: &dyn Node) {
+    p.visit_children_with(self);
+
+    if let Prop::Shorthand(i) = &p {
+      self.check(i);
+    }
+  }
+
+  fn visit_pat(&mut self, p: &Pat, _: &dyn Node) {
+    if let Pat::Ident(i) = p {
+      self.check(&i.id);
+    } else {
+      p.visit_children_with(self);
+    }
+  }
+
+  fn visit_assign_pat_prop(&mut self, p: &AssignPatProp, _: &dyn Node) {
+    self.check(&p.key);
+    p.value.visit_with(p, self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_undefined_valid() {
+    assert_lint_ok! {
+      NoUndefined,
+      "void 0;",
+      "foo();",
+      "let a;",
+      "foo.undefined;",
+      "foo.undefined();",
+      "const obj = { undefined: 1 };",
+      "class A { undefined = 1; undefined() {} }",
+      "function f(undefined) { return undefined; }",
+      "let x: undefined = void 0;",
+      "type T = string | undefined;",
+    };
+  }
+
+  #[test]
+  fn no_undefined_invalid() {
+    assert_lint_err! {
+      NoUndefined,
+      "undefined;": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "let a = undefined;": [{ col: 8, message: MESSAGE, hint: HINT }],
+      "foo(undefined);": [{ col: 4, message: MESSAGE, hint: HINT }],
+      "if (a === undefined) {}": [{ col: 10, message: MESSAGE, hint: HINT }],
+      "foo[undefined];": [{ col: 4, message: MESSAGE, hint: HINT }],
+      "const obj = { undefined };": [
+        { col: 14, message: MESSAGE, hint: HINT },
+      ],
+      "function f() { return undefined; }": [
+        { col: 22, message: MESSAGE, hint: HINT },
+      ],
+      "undefined = 1;": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "const { a = undefined } = obj;": [
+        { col: 12, message: MESSAGE, hint: HINT },
+      ],
+    };
+  }
+}
@@ -0,0 +1,256 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{Expr, ExprOrSuper, Ident, Pat, PropName};
+use deno_ast::view as ast_view;
+use serde::Deserialize;
+
+#[derive(Debug, Default)]
+pub struct NoUnderscoreDangle {
+  options: NoUnderscoreDangleOptions,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoUnderscoreDangleOptions {
+  /// Identifier names that are always allowed, e.g. `__dirname`.
+  pub allow: Vec<String>,
+  /// Allows dangling underscores in members of `this`, e.g. `this._foo`.
+  pub allow_after_this: bool,
+  /// Allows dangling underscores in members of `super`, e.g. `super._foo`.
+  pub allow_after_super: bool,
+  /// Whether method names are checked as well.
+  pub enforce_in_method_names: bool,
+}
+
+const CODE: &str = "no-underscore-dangle";
+
+fn message(name: &str) -> String {
+  format!("Unexpected dangling '_' in '{}'", name)
+}
+
+impl NoUnderscoreDangle {
+  pub fn with_options(options: NoUnderscoreDangleOptions) -> Box<Self> {
+    Box::new(NoUnderscoreDangle { options })
+  }
+}
+
+impl LintRule for NoUnderscoreDangle {
+  fn new() -> Box<Self> {
+    Box::new(NoUnderscoreDangle::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoUnderscoreDangleHandler::new(&self.options).traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_underscore_dangle.md")
+  }
+}
+
+/// Checks if the name starts or ends with `_`. A lone `_` is not considered
+/// dangling since it's commonly used as a placeholder.
+fn has_dangling_underscore(name: &str) -> bool {
+  name != "_" && (name.starts_with('_') || name.ends_with('_'))
+}
+
+struct NoUnderscoreDangleHandler<'o> {
+  options: &'o NoUnderscoreDangleOptions,
+}
+
+impl<'o> NoUnderscoreDangleHandler<'o> {
+  fn new(options: &'o NoUnderscoreDangleOptions) -> Self {
+    Self { options }
+  }
+
+  fn check_ident(&self, ident: &Ident, ctx: &mut Context) {
+    let name = ident.sym.as_ref();
+    if has_dangling_underscore(name)
+      && !self.options.allow.iter().any(|a| a == name)
+    {
+      ctx.add_diagnostic(ident.span, CODE, message(name));
+    }
+  }
+
+  fn check_method_name(&self, prop_name: &PropName, ctx: &mut Context) {
+    if !self.options.enforce_in_method_names {
+      return;
+    }
+
+    if let PropName::Ident(ident) = prop_name {
+      self.check_ident(ident, ctx);
+    }
+  }
+}
+
+impl<'o> Handler for NoUnderscoreDangleHandler<'o> {
+  fn var_declarator(
+    &mut self,
+    var_declarator: &ast_view::VarDeclarator,
+    ctx: &mut Context,
+  ) {
+    // Names bound by destructuring are not checked, e.g. `const { _foo } = obj;`
+    if let Pat::Ident(binding_ident) = &var_declarator.inner.name {
+      self.check_ident(&binding_ident.id, ctx);
+    }
+  }
+
+  fn fn_decl(&mut self, fn_decl: &ast_view::FnDecl, ctx: &mut Context) {
+    self.check_ident(&fn_decl.inner.ident, ctx);
+  }
+
+  fn fn_expr(&mut self, fn_expr: &ast_view::FnExpr, ctx: &mut Context) {
+    if let Some(ident) = &fn_expr.inner.ident {
+      self.check_ident(ident, ctx);
+    }
+  }
+
+  fn member_expr(
+    &mut self,
+    member_expr: &ast_view::MemberExpr,
+    ctx: &mut Context,
+  ) {
+    if member_expr.inner.computed {
+      return;
+    }
+
+    let ident = match &*member_expr.inner.prop {
+      Expr::Ident(ident) => ident,
+      _ => return,
+    };
+
+    // `__proto__` is a well-known property and always allowed
+    if ident.sym.as_ref() == "__proto__" {
+      return;
+    }
+
+    match &member_expr.inner.obj {
+      ExprOrSuper::Super(_) if self.options.allow_after_super => return,
+      ExprOrSuper::Expr(expr)
+        if self.options.allow_after_this
+          && matches!(&**expr, Expr::This(_)) =>
+      {
+        return
+      }
+      _ => {}
+    }
+
+    self.check_ident(ident, ctx);
+  }
+
+  fn class_method(
+    &mut self,
+    class_method: &ast_view::ClassMethod,
+    ctx: &mut Context,
+  ) {
+    self.check_method_name(&class_method.inner.key, ctx);
+  }
+
+  fn private_method(
+    &mut self,
+    private_method: &ast_view::PrivateMethod,
+    ctx: &mut Context,
+  ) {
+    if self.options.enforce_in_method_names {
+      self.check_ident(&private_method.inner.key.id, ctx);
+    }
+  }
+
+  fn method_prop(
+    &mut self,
+    method_prop: &ast_view::MethodProp,
+    ctx: &mut Context,
+  ) {
+    self.check_method_name(&method_prop.inner.key, ctx);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  #[test]
+  fn no_underscore_dangle_valid() {
+    assert_lint_ok! {
+      NoUnderscoreDangle,
+      "const foo = 1;",
+      "const foo_bar = 1;",
+      "const _ = require('underscore');",
+      "const { _foo } = obj;",
+      "function foo(_bar) {}",
+      "foo._",
+      "obj.__proto__ = 1;",
+      "obj[_foo] = 1;",
+      "obj['_foo'] = 1;",
+      "class Foo { _bar() {} }",
+      "const obj = { _foo() {} };",
+      "_foo();",
+    };
+
+    assert_lint_ok! {
+      NoUnderscoreDangle,
+      options: json!({ "allow": ["__dirname", "_foo"] }),
+      "const path = __dirname;",
+      "const _foo = 1;",
+      "obj._foo();",
+    };
+
+    assert_lint_ok! {
+      NoUnderscoreDangle,
+      options: json!({ "allowAfterThis": true, "allowAfterSuper": true }),
+      "class Foo extends Bar { baz() { return this._qux + super._qux(); } }",
+    };
+  }
+
+  #[test]
+  fn no_underscore_dangle_invalid() {
+    assert_lint_err! {
+      NoUnderscoreDangle,
+      "const _foo = 1;": [{ col: 6, message: message("_foo") }],
+      "let foo_ = 1;": [{ col: 4, message: message("foo_") }],
+      "var __proto__ = 1;": [{ col: 4, message: message("__proto__") }],
+      "function _foo() {}": [{ col: 9, message: message("_foo") }],
+      "const foo = function _bar() {};": [
+        { col: 21, message: message("_bar") },
+      ],
+      "foo._bar();": [{ col: 4, message: message("_bar") }],
+      "foo.bar_.baz;": [{ col: 4, message: message("bar_") }],
+      "this._foo;": [{ col: 5, message: message("_foo") }],
+      "class Foo extends Bar { baz() { super._qux(); } }": [
+        { col: 38, message: message("_qux") },
+      ],
+    };
+
+    assert_lint_err! {
+      NoUnderscoreDangle,
+      options: json!({ "allowAfterThis": true }),
+      "class Foo extends Bar { baz() { this._a; super._b(); } }": [
+        { col: 47, message: message("_b") },
+      ],
+    };
+
+    assert_lint_err! {
+      NoUnderscoreDangle,
+      options: json!({ "enforceInMethodNames": true }),
+      "class Foo { _bar() {} }": [{ col: 12, message: message("_bar") }],
+      "class Foo { #_bar() {} }": [{ col: 13, message: message("_bar") }],
+      "const obj = { bar_() {} };": [{ col: 14, message: message("bar_") }],
+    };
+  }
+}
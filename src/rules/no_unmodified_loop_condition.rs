@@ -0,0 +1,338 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::ProgramRef;
+use deno_ast::swc::ast::{
+  ArrowExpr, AssignExpr, CallExpr, DoWhileStmt, Expr, ForInStmt, ForOfStmt,
+  ForStmt, Function, Ident, NewExpr, Pat, PatOrExpr, Stmt, TaggedTpl, UnaryOp,
+  UpdateExpr, VarDeclOrPat, VarDeclarator, WhileStmt,
+};
+use deno_ast::swc::common::{Span, Spanned};
+use deno_ast::swc::utils::ident::IdentLike;
+use deno_ast::swc::utils::{find_ids, Id};
+use deno_ast::swc::visit::{noop_visit_type, Node, Visit, VisitWith};
+use std::collections::HashMap;
+
+#[derive(Debug)]
+pub struct NoUnmodifiedLoopCondition;
+
+const CODE: &str = "no-unmodified-loop-condition";
+const MESSAGE: &str =
+  "The variables of the loop condition are not modified in the loop";
+const HINT: &str =
+  "Update a variable of the condition in the loop, otherwise it never ends";
+
+impl LintRule for NoUnmodifiedLoopCondition {
+  fn new() -> Box<Self> {
+    Box::new(NoUnmodifiedLoopCondition)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    let mut collector = WriteCollector::default();
+    match program {
+      ProgramRef::Module(m) => m.visit_with(&DUMMY_NODE, &mut collector),
+      ProgramRef::Script(s) => s.visit_with(&DUMMY_NODE, &mut collector),
+    }
+
+    let mut visitor =
+      NoUnmodifiedLoopConditionVisitor::new(context, collector.writes);
+    match program {
+      ProgramRef::Module(m) => visitor.visit_module(m, &DUMMY_NODE),
+      ProgramRef::Script(s) => visitor.visit_script(s, &DUMMY_NODE),
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_unmodified_loop_condition.md")
+  }
+}
+
+struct Write {
+  span: Span,
+  /// The innermost function containing the write
+  function: Option<Span>,
+}
+
+/// Collects the writes to each variable, by assignments, updates and
+/// declarations.
+#[derive(Default)]
+struct WriteCollector {
+  functions: Vec<Span>,
+  writes: HashMap<Id, Vec<Write>>,
+}
+
+impl WriteCollector {
+  fn add_write(&mut self, ident: &Ident) {
+    let write = Write {
+      span: ident.span,
+      function: self.functions.last().copied(),
+    };
+    self.writes.entry(ident.to_id()).or_default().push(write);
+  }
+
+  fn add_pat_writes(&mut self, pat: &Pat) {
+    let idents: Vec<Ident> = find_ids(pat);
+    for ident in &idents {
+      self.add_write(ident);
+    }
+  }
+
+  /// The variables in the head of `for-in` and `for-of` loops are assigned on
+  /// each iteration.
+  fn add_loop_head_writes(&mut self, left: &VarDeclOrPat) {
+    match left {
+      VarDeclOrPat::VarDecl(var_decl) => {
+        for decl in &var_decl.decls {
+          self.add_pat_writes(&decl.name);
+        }
+      }
+      VarDeclOrPat::Pat(pat) => self.add_pat_writes(pat),
+    }
+  }
+}
+
+impl Visit for WriteCollector {
+  noop_visit_type!();
+
+  fn visit_function(&mut self, function: &Function, _: &dyn Node) {
+    self.functions.push(function.span);
+    function.visit_children_with(self);
+    self.functions.pop();
+  }
+
+  fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr, _: &dyn Node) {
+    self.functions.push(arrow_expr.span);
+    arrow_expr.visit_children_with(self);
+    self.functions.pop();
+  }
+
+  fn visit_assign_expr(&mut self, assign_expr: &AssignExpr, _: &dyn Node) {
+    match &assign_expr.left {
+      PatOrExpr::Pat(pat) => self.add_pat_writes(pat),
+      PatOrExpr::Expr(expr) => {
+        if let Expr::Ident(ident) = &**expr {
+          self.add_write(ident);
+        }
+      }
+    }
+    assign_expr.visit_children_with(self);
+  }
+
+  fn visit_update_expr(&mut self, update_expr: &UpdateExpr, _: &dyn Node) {
+    let mut arg = &*update_expr.arg;
+    while let Expr::Paren(paren) = arg {
+      arg = &paren.expr;
+    }
+    if let Expr::Ident(ident) = arg {
+      self.add_write(ident);
+    }
+    update_expr.visit_children_with(self);
+  }
+
+  fn visit_var_declarator(&mut self, declarator: &VarDeclarator, _: &dyn Node) {
+    if declarator.init.is_some() {
+      self.add_pat_writes(&declarator.name);
+    }
+    declarator.visit_children_with(self);
+  }
+
+  fn visit_for_in_stmt(&mut self, for_in: &ForInStmt, _: &dyn Node) {
+    self.add_loop_head_writes(&for_in.left);
+    for_in.visit_children_with(self);
+  }
+
+  fn visit_for_of_stmt(&mut self, for_of: &ForOfStmt, _: &dyn Node) {
+    self.add_loop_head_writes(&for_of.left);
+    for_of.visit_children_with(self);
+  }
+}
+
+/// Collects the identifiers of a loop condition. Returns `false` if the
+/// condition contains something which may change without the identifiers being
+/// modified, like a call or a property access.
+fn collect_idents<'a>(expr: &'a Expr, idents: &mut Vec<&'a Ident>) -> bool {
+  match expr {
+    Expr::Ident(ident) => {
+      idents.push(ident);
+      true
+    }
+    Expr::Lit(_) => true,
+    Expr::Paren(paren) => collect_idents(&paren.expr, idents),
+    Expr::Unary(unary) => {
+      unary.op != UnaryOp::Delete && collect_idents(&unary.arg, idents)
+    }
+    Expr::Bin(bin) => {
+      collect_idents(&bin.left, idents) && collect_idents(&bin.right, idents)
+    }
+    Expr::Cond(cond) => {
+      collect_idents(&cond.test, idents)
+        && collect_idents(&cond.cons, idents)
+        && collect_idents(&cond.alt, idents)
+    }
+    Expr::Tpl(tpl) => tpl.exprs.iter().all(|expr| collect_idents(expr, idents)),
+    _ => false,
+  }
+}
+
+/// Checks if a part of a loop contains something which may modify variables
+/// declared elsewhere, like a function call.
+#[derive(Default)]
+struct CallFinder {
+  found: bool,
+}
+
+impl Visit for CallFinder {
+  noop_visit_type!();
+
+  fn visit_call_expr(&mut self, _: &CallExpr, _: &dyn Node) {
+    self.found = true;
+  }
+
+  fn visit_new_expr(&mut self, _: &NewExpr, _: &dyn Node) {
+    self.found = true;
+  }
+
+  fn visit_tagged_tpl(&mut self, _: &TaggedTpl, _: &dyn Node) {
+    self.found = true;
+  }
+}
+
+struct NoUnmodifiedLoopConditionVisitor<'c, 'view> {
+  context: &'c mut Context<'view>,
+  writes: HashMap<Id, Vec<Write>>,
+}
+
+impl<'c, 'view> NoUnmodifiedLoopConditionVisitor<'c, 'view> {
+  fn new(
+    context: &'c mut Context<'view>,
+    writes: HashMap<Id, Vec<Write>>,
+  ) -> Self {
+    Self { context, writes }
+  }
+
+  /// Checks the condition of a loop, which is modified if one of its
+  /// variables is assigned in the loop. `update` is the update expression of
+  /// `for` loops.
+  fn check_loop(&mut self, test: &Expr, body: &Stmt, update: Option<&Expr>) {
+    let mut idents = Vec::new();
+    if !collect_idents(test, &mut idents) || idents.is_empty() {
+      return;
+    }
+
+    let mut part_spans = vec![test.span(), body.span()];
+    let mut call_finder = CallFinder::default();
+    test.visit_with(&DUMMY_NODE, &mut call_finder);
+    body.visit_with(&DUMMY_NODE, &mut call_finder);
+    if let Some(update) = update {
+      part_spans.push(update.span());
+      update.visit_with(&DUMMY_NODE, &mut call_finder);
+    }
+
+    let is_modified = |ident: &Ident| {
+      // Globals may be modified anywhere
+      let var = match self.context.scope().resolve(ident) {
+        Some(var) => var,
+        None => return true,
+      };
+      let writes = match self.writes.get(&ident.to_id()) {
+        Some(writes) => writes,
+        None => return false,
+      };
+      writes.iter().any(|write| {
+        let in_loop = part_spans.iter().any(|span| span.contains(write.span));
+        // A called function may modify the variables it closes over
+        let in_closure = write
+          .function
+          .map_or(false, |function| !function.contains(var.declared_at()));
+        in_loop || (call_finder.found && in_closure)
+      })
+    };
+    if !idents.iter().any(|ident| is_modified(ident)) {
+      self
+        .context
+        .add_diagnostic_with_hint(test.span(), CODE, MESSAGE, HINT);
+    }
+  }
+}
+
+impl<'c, 'view> Visit for NoUnmodifiedLoopConditionVisitor<'c, 'view> {
+  noop_visit_type!();
+
+  fn visit_while_stmt(&mut self, while_stmt: &WhileStmt, _: &dyn Node) {
+    while_stmt.visit_children_with(self);
+    self.check_loop(&while_stmt.test, &while_stmt.body, None);
+  }
+
+  fn visit_do_while_stmt(&mut self, do_while: &DoWhileStmt, _: &dyn Node) {
+    do_while.visit_children_with(self);
+    self.check_loop(&do_while.test, &do_while.body, None);
+  }
+
+  fn visit_for_stmt(&mut self, for_stmt: &ForStmt, _: &dyn Node) {
+    for_stmt.visit_children_with(self);
+    if let Some(test) = &for_stmt.test {
+      self.check_loop(test, &for_stmt.body, for_stmt.update.as_deref());
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_unmodified_loop_condition_valid() {
+    assert_lint_ok! {
+      NoUnmodifiedLoopCondition,
+      "let a = 0; while (a < 10) { a++; }",
+      "let a = true; while (a) { a = false; }",
+      "let a = 0; do { a += 1; } while (a < 10);",
+      "for (let i = 0; i < 10; i++) {}",
+      "for (let i = 0; i < 10;) { i = next(i); }",
+      "let node = head; while (node) { node = node.next; }",
+      "let a = true; function stop() { a = false; } while (a) { stop(); }",
+      "let a = true; const stop = () => { a = false; }; while (a) { stop(); }",
+      "let a = true, b = true; while (a && b) { b = false; }",
+      "let x; while (x = next()) {}",
+      "while (foo.bar) {}",
+      "while (check()) {}",
+      "while (true) { if (done()) break; }",
+      "while (globalFlag) {}",
+      "for (;;) {}",
+      "let a; while (a) { for (a in obj) {} }",
+    };
+  }
+
+  #[test]
+  fn no_unmodified_loop_condition_invalid() {
+    assert_lint_err! {
+      NoUnmodifiedLoopCondition,
+      "let a = 0; while (a < 10) { b++; }": [
+        { col: 18, message: MESSAGE, hint: HINT },
+      ],
+      "let a = true; while (a) { doSomething(); }": [
+        { col: 21, message: MESSAGE, hint: HINT },
+      ],
+      "let a = 0; do { b += 1; } while (a < 10);": [
+        { col: 33, message: MESSAGE, hint: HINT },
+      ],
+      "let i = 0, j = 0; for (; i < 10; j++) {}": [
+        { col: 25, message: MESSAGE, hint: HINT },
+      ],
+      "let a = true; function stop() { a = false; } while (a) {}": [
+        { col: 52, message: MESSAGE, hint: HINT },
+      ],
+      "function f(a, b) { while (a < b) { console.log(a); } }": [
+        { col: 26, message: MESSAGE, hint: HINT },
+      ],
+    };
+  }
+}
@@ -0,0 +1,209 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::ProgramRef;
+use deno_ast::swc::ast::{BinExpr, BinaryOp, Expr, Lit};
+use deno_ast::swc::common::Spanned;
+use deno_ast::swc::visit::{noop_visit_type, Node, Visit, VisitWith};
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct NoUnnecessaryBooleanLiteralCompare;
+
+const CODE: &str = "no-unnecessary-boolean-literal-compare";
+const MESSAGE: &str =
+  "Comparing a boolean value to a boolean literal is unnecessary";
+
+#[derive(Display)]
+enum NoUnnecessaryBooleanLiteralCompareHint {
+  #[display(fmt = "Use `{}` directly", _0)]
+  UseValue(String),
+  #[display(fmt = "Use `{}` instead", _0)]
+  UseNegation(String),
+}
+
+impl LintRule for NoUnnecessaryBooleanLiteralCompare {
+  fn new() -> Box<Self> {
+    Box::new(NoUnnecessaryBooleanLiteralCompare)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    let mut visitor = NoUnnecessaryBooleanLiteralCompareVisitor::new(context);
+    match program {
+      ProgramRef::Module(m) => visitor.visit_module(m, &DUMMY_NODE),
+      ProgramRef::Script(s) => visitor.visit_script(s, &DUMMY_NODE),
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_unnecessary_boolean_literal_compare.md")
+  }
+}
+
+fn boolean_literal(expr: &Expr) -> Option<bool> {
+  match expr {
+    Expr::Lit(Lit::Bool(bool)) => Some(bool.value),
+    _ => None,
+  }
+}
+
+/// Checks if the expression needs to be wrapped in parentheses when negated
+/// with `!`.
+fn needs_parens_for_negation(expr: &Expr) -> bool {
+  matches!(
+    expr,
+    Expr::Bin(_)
+      | Expr::Assign(_)
+      | Expr::Cond(_)
+      | Expr::Seq(_)
+      | Expr::Arrow(_)
+      | Expr::Yield(_)
+      | Expr::TsAs(_)
+  )
+}
+
+struct NoUnnecessaryBooleanLiteralCompareVisitor<'c, 'view> {
+  context: &'c mut Context<'view>,
+}
+
+impl<'c, 'view> NoUnnecessaryBooleanLiteralCompareVisitor<'c, 'view> {
+  fn new(context: &'c mut Context<'view>) -> Self {
+    Self { context }
+  }
+}
+
+impl<'c, 'view> Visit for NoUnnecessaryBooleanLiteralCompareVisitor<'c, 'view> {
+  noop_visit_type!();
+
+  fn visit_bin_expr(&mut self, bin_expr: &BinExpr, _: &dyn Node) {
+    bin_expr.visit_children_with(self);
+
+    let is_negated_compare = match bin_expr.op {
+      BinaryOp::EqEqEq => false,
+      BinaryOp::NotEqEq => true,
+      _ => return,
+    };
+    let (value, literal) = match (
+      boolean_literal(&bin_expr.left),
+      boolean_literal(&bin_expr.right),
+    ) {
+      (None, Some(literal)) => (&*bin_expr.left, literal),
+      (Some(literal), None) => (&*bin_expr.right, literal),
+      _ => return,
+    };
+
+    let value_text = self.context.file_text_substring(&value.span());
+    // `x === false` and `x !== true` are the negation of `x`
+    let hint = if literal == is_negated_compare {
+      NoUnnecessaryBooleanLiteralCompareHint::UseValue(value_text.to_string())
+    } else if needs_parens_for_negation(value) {
+      NoUnnecessaryBooleanLiteralCompareHint::UseNegation(format!(
+        "!({})",
+        value_text
+      ))
+    } else {
+      NoUnnecessaryBooleanLiteralCompareHint::UseNegation(format!(
+        "!{}",
+        value_text
+      ))
+    };
+    self
+      .context
+      .add_diagnostic_with_hint(bin_expr.span, CODE, MESSAGE, hint);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_unnecessary_boolean_literal_compare_valid() {
+    assert_lint_ok! {
+      NoUnnecessaryBooleanLiteralCompare,
+      "if (a) {}",
+      "if (!a) {}",
+      "if (a === b) {}",
+      "if (a == true) {}",
+      "if (a != false) {}",
+      "if (true === false) {}",
+      "if (a === 'true') {}",
+    };
+  }
+
+  #[test]
+  fn no_unnecessary_boolean_literal_compare_invalid() {
+    assert_lint_err! {
+      NoUnnecessaryBooleanLiteralCompare,
+      "if (a === true) {}": [
+        {
+          col: 4,
+          message: MESSAGE,
+          hint: NoUnnecessaryBooleanLiteralCompareHint::UseValue(
+            "a".to_string()
+          ),
+        },
+      ],
+      "if (a !== false) {}": [
+        {
+          col: 4,
+          message: MESSAGE,
+          hint: NoUnnecessaryBooleanLiteralCompareHint::UseValue(
+            "a".to_string()
+          ),
+        },
+      ],
+      "if (a.b() === false) {}": [
+        {
+          col: 4,
+          message: MESSAGE,
+          hint: NoUnnecessaryBooleanLiteralCompareHint::UseNegation(
+            "!a.b()".to_string()
+          ),
+        },
+      ],
+      "const b = true !== a;": [
+        {
+          col: 10,
+          message: MESSAGE,
+          hint: NoUnnecessaryBooleanLiteralCompareHint::UseNegation(
+            "!a".to_string()
+          ),
+        },
+      ],
+      "const b = a < c === false;": [
+        {
+          col: 10,
+          message: MESSAGE,
+          hint: NoUnnecessaryBooleanLiteralCompareHint::UseNegation(
+            "!(a < c)".to_string()
+          ),
+        },
+      ],
+      "const b = (a === true) === false;": [
+        {
+          col: 11,
+          message: MESSAGE,
+          hint: NoUnnecessaryBooleanLiteralCompareHint::UseValue(
+            "a".to_string()
+          ),
+        },
+        {
+          col: 10,
+          message: MESSAGE,
+          hint: NoUnnecessaryBooleanLiteralCompareHint::UseNegation(
+            "!(a === true)".to_string()
+          ),
+        },
+      ],
+    };
+  }
+}
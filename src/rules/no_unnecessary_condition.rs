@@ -0,0 +1,267 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::ProgramRef;
+use deno_ast::swc::ast::{
+  BinaryOp, CondExpr, DoWhileStmt, Expr, ForStmt, IfStmt, Lit, UnaryOp,
+  WhileStmt,
+};
+use deno_ast::swc::common::Spanned;
+use deno_ast::swc::visit::{noop_visit_type, Node, Visit, VisitWith};
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct NoUnnecessaryCondition;
+
+const CODE: &str = "no-unnecessary-condition";
+
+#[derive(Display)]
+enum NoUnnecessaryConditionMessage {
+  #[display(fmt = "This condition is always truthy")]
+  AlwaysTruthy,
+  #[display(fmt = "This condition is always falsy")]
+  AlwaysFalsy,
+  #[display(fmt = "This condition compares a value with itself")]
+  SelfComparison,
+}
+
+#[derive(Display)]
+enum NoUnnecessaryConditionHint {
+  #[display(fmt = "Remove the condition, or check the intended value")]
+  RemoveCondition,
+  #[display(fmt = "Use `Number.isNaN()` to check for `NaN`")]
+  UseIsNaN,
+}
+
+impl LintRule for NoUnnecessaryCondition {
+  fn new() -> Box<Self> {
+    Box::new(NoUnnecessaryCondition)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    let mut visitor = NoUnnecessaryConditionVisitor::new(context);
+    match program {
+      ProgramRef::Module(m) => visitor.visit_module(m, &DUMMY_NODE),
+      ProgramRef::Script(s) => visitor.visit_script(s, &DUMMY_NODE),
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_unnecessary_condition.md")
+  }
+}
+
+/// Returns the truthiness of an expression if it is known from its syntax
+/// alone. Boolean literals are not handled, since `while (true)` is a common
+/// idiom.
+fn literal_truthiness(expr: &Expr) -> Option<bool> {
+  match expr {
+    Expr::Paren(paren) => literal_truthiness(&paren.expr),
+    Expr::Unary(unary) if unary.op == UnaryOp::Bang => {
+      literal_truthiness(&unary.arg).map(|truthy| !truthy)
+    }
+    Expr::Object(_)
+    | Expr::Array(_)
+    | Expr::Fn(_)
+    | Expr::Arrow(_)
+    | Expr::Class(_)
+    | Expr::Lit(Lit::Regex(_)) => Some(true),
+    Expr::Lit(Lit::Num(num)) => Some(num.value != 0.0 && !num.value.is_nan()),
+    Expr::Lit(Lit::Str(str)) => Some(!str.value.is_empty()),
+    Expr::Tpl(tpl) if tpl.exprs.is_empty() => {
+      Some(tpl.quasis.iter().any(|quasi| !quasi.raw.value.is_empty()))
+    }
+    _ => None,
+  }
+}
+
+/// Checks if the expression is like `x === x`.
+fn is_self_comparison(expr: &Expr) -> bool {
+  match expr {
+    Expr::Paren(paren) => is_self_comparison(&paren.expr),
+    Expr::Bin(bin) if matches!(bin.op, BinaryOp::EqEqEq | BinaryOp::EqEq) => {
+      matches!(
+        (&*bin.left, &*bin.right),
+        (Expr::Ident(left), Expr::Ident(right)) if left.sym == right.sym
+      )
+    }
+    _ => false,
+  }
+}
+
+struct NoUnnecessaryConditionVisitor<'c, 'view> {
+  context: &'c mut Context<'view>,
+}
+
+impl<'c, 'view> NoUnnecessaryConditionVisitor<'c, 'view> {
+  fn new(context: &'c mut Context<'view>) -> Self {
+    Self { context }
+  }
+
+  fn check_condition(&mut self, test: &Expr) {
+    let (message, hint) = match literal_truthiness(test) {
+      Some(true) => (
+        NoUnnecessaryConditionMessage::AlwaysTruthy,
+        NoUnnecessaryConditionHint::RemoveCondition,
+      ),
+      Some(false) => (
+        NoUnnecessaryConditionMessage::AlwaysFalsy,
+        NoUnnecessaryConditionHint::RemoveCondition,
+      ),
+      None if is_self_comparison(test) => (
+        NoUnnecessaryConditionMessage::SelfComparison,
+        NoUnnecessaryConditionHint::UseIsNaN,
+      ),
+      None => return,
+    };
+    self
+      .context
+      .add_diagnostic_with_hint(test.span(), CODE, message, hint);
+  }
+}
+
+impl<'c, 'view> Visit for NoUnnecessaryConditionVisitor<'c, 'view> {
+  noop_visit_type!();
+
+  fn visit_if_stmt(&mut self, if_stmt: &IfStmt, _: &dyn Node) {
+    if_stmt.visit_children_with(self);
+    self.check_condition(&if_stmt.test);
+  }
+
+  fn visit_while_stmt(&mut self, while_stmt: &WhileStmt, _: &dyn Node) {
+    while_stmt.visit_children_with(self);
+    self.check_condition(&while_stmt.test);
+  }
+
+  fn visit_do_while_stmt(&mut self, do_while: &DoWhileStmt, _: &dyn Node) {
+    do_while.visit_children_with(self);
+    self.check_condition(&do_while.test);
+  }
+
+  fn visit_for_stmt(&mut self, for_stmt: &ForStmt, _: &dyn Node) {
+    for_stmt.visit_children_with(self);
+    if let Some(test) = &for_stmt.test {
+      self.check_condition(test);
+    }
+  }
+
+  fn visit_cond_expr(&mut self, cond_expr: &CondExpr, _: &dyn Node) {
+    cond_expr.visit_children_with(self);
+    self.check_condition(&cond_expr.test);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_unnecessary_condition_valid() {
+    assert_lint_ok! {
+      NoUnnecessaryCondition,
+      "if (x) {}",
+      "if (x.length) {}",
+      "while (true) {}",
+      "do {} while (false);",
+      "for (;;) {}",
+      "const a = b ? 1 : 2;",
+      "if (`${a}`) {}",
+      "if (a === b) {}",
+      "if (a.b === a.b) {}",
+      "if (a !== a) {}",
+      "if ([].length) {}",
+    };
+  }
+
+  #[test]
+  fn no_unnecessary_condition_invalid() {
+    assert_lint_err! {
+      NoUnnecessaryCondition,
+      "if ({}) {}": [
+        {
+          col: 4,
+          message: NoUnnecessaryConditionMessage::AlwaysTruthy,
+          hint: NoUnnecessaryConditionHint::RemoveCondition,
+        },
+      ],
+      "if ([]) {}": [
+        {
+          col: 4,
+          message: NoUnnecessaryConditionMessage::AlwaysTruthy,
+          hint: NoUnnecessaryConditionHint::RemoveCondition,
+        },
+      ],
+      "while (function () {}) {}": [
+        {
+          col: 7,
+          message: NoUnnecessaryConditionMessage::AlwaysTruthy,
+          hint: NoUnnecessaryConditionHint::RemoveCondition,
+        },
+      ],
+      "do {} while (/a/);": [
+        {
+          col: 13,
+          message: NoUnnecessaryConditionMessage::AlwaysTruthy,
+          hint: NoUnnecessaryConditionHint::RemoveCondition,
+        },
+      ],
+      "for (; 1;) {}": [
+        {
+          col: 7,
+          message: NoUnnecessaryConditionMessage::AlwaysTruthy,
+          hint: NoUnnecessaryConditionHint::RemoveCondition,
+        },
+      ],
+      "const a = 'str' ? 1 : 2;": [
+        {
+          col: 10,
+          message: NoUnnecessaryConditionMessage::AlwaysTruthy,
+          hint: NoUnnecessaryConditionHint::RemoveCondition,
+        },
+      ],
+      "if (0) {}": [
+        {
+          col: 4,
+          message: NoUnnecessaryConditionMessage::AlwaysFalsy,
+          hint: NoUnnecessaryConditionHint::RemoveCondition,
+        },
+      ],
+      "if ('') {}": [
+        {
+          col: 4,
+          message: NoUnnecessaryConditionMessage::AlwaysFalsy,
+          hint: NoUnnecessaryConditionHint::RemoveCondition,
+        },
+      ],
+      "if (!(() => {})) {}": [
+        {
+          col: 4,
+          message: NoUnnecessaryConditionMessage::AlwaysFalsy,
+          hint: NoUnnecessaryConditionHint::RemoveCondition,
+        },
+      ],
+      "if (x === x) {}": [
+        {
+          col: 4,
+          message: NoUnnecessaryConditionMessage::SelfComparison,
+          hint: NoUnnecessaryConditionHint::UseIsNaN,
+        },
+      ],
+      "while (a == a) {}": [
+        {
+          col: 7,
+          message: NoUnnecessaryConditionMessage::SelfComparison,
+          hint: NoUnnecessaryConditionHint::UseIsNaN,
+        },
+      ],
+    };
+  }
+}
@@ -0,0 +1,299 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::ProgramRef;
+use deno_ast::swc::ast::{
+  ArrowExpr, Class, ContinueStmt, Decl, DoWhileStmt, ForInStmt, ForOfStmt,
+  ForStmt, Function, LabeledStmt, Stmt, WhileStmt,
+};
+use deno_ast::swc::atoms::JsWord;
+use deno_ast::swc::common::{Span, Spanned};
+use deno_ast::swc::visit::{noop_visit_type, Node, Visit, VisitWith};
+use serde::Deserialize;
+use std::mem::take;
+
+#[derive(Debug, Default)]
+pub struct NoUnreachableLoop {
+  options: NoUnreachableLoopOptions,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoUnreachableLoopOptions {
+  /// Kinds of loops which are not checked. Supported values are
+  /// `"WhileStatement"`, `"DoWhileStatement"`, `"ForStatement"`,
+  /// `"ForInStatement"` and `"ForOfStatement"`.
+  pub ignore: Vec<String>,
+}
+
+const CODE: &str = "no-unreachable-loop";
+const MESSAGE: &str = "Invalid loop. Its body allows only one iteration";
+const HINT: &str =
+  "Make the exit conditional, or replace the loop with a plain statement";
+
+impl NoUnreachableLoop {
+  pub fn with_options(options: NoUnreachableLoopOptions) -> Box<Self> {
+    Box::new(NoUnreachableLoop { options })
+  }
+}
+
+impl LintRule for NoUnreachableLoop {
+  fn new() -> Box<Self> {
+    Box::new(NoUnreachableLoop::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    let mut visitor = NoUnreachableLoopVisitor::new(context, &self.options);
+    match program {
+      ProgramRef::Module(m) => visitor.visit_module(m, &DUMMY_NODE),
+      ProgramRef::Script(s) => visitor.visit_script(s, &DUMMY_NODE),
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_unreachable_loop.md")
+  }
+}
+
+struct NoUnreachableLoopVisitor<'c, 'view, 'o> {
+  context: &'c mut Context<'view>,
+  options: &'o NoUnreachableLoopOptions,
+  /// Labels attached to the statement being visited.
+  labels: Vec<JsWord>,
+}
+
+impl<'c, 'view, 'o> NoUnreachableLoopVisitor<'c, 'view, 'o> {
+  fn new(
+    context: &'c mut Context<'view>,
+    options: &'o NoUnreachableLoopOptions,
+  ) -> Self {
+    Self {
+      context,
+      options,
+      labels: Vec::new(),
+    }
+  }
+
+  fn check_loop(
+    &mut self,
+    kind: &str,
+    span: Span,
+    body: &Stmt,
+    labels: &[JsWord],
+  ) {
+    if self.options.ignore.iter().any(|ignored| ignored == kind) {
+      return;
+    }
+
+    let stops_execution = match body {
+      // The metadata of the body itself describes the whole loop, so look at
+      // the statements inside of it instead. Function declarations share
+      // their position with the function body, so they are skipped.
+      Stmt::Block(block) => block
+        .stmts
+        .iter()
+        .filter(|stmt| !matches!(stmt, Stmt::Decl(Decl::Fn(_))))
+        .any(|stmt| {
+          self
+            .context
+            .control_flow()
+            .meta(stmt.span().lo)
+            .map_or(false, |meta| meta.stops_execution())
+        }),
+      Stmt::Return(_) | Stmt::Throw(_) | Stmt::Break(_) => true,
+      _ => false,
+    };
+    if !stops_execution {
+      return;
+    }
+
+    let mut finder = ContinueFinder {
+      labels,
+      loop_depth: 0,
+      found: false,
+    };
+    body.visit_with(&DUMMY_NODE, &mut finder);
+    if finder.found {
+      return;
+    }
+
+    self
+      .context
+      .add_diagnostic_with_hint(span, CODE, MESSAGE, HINT);
+  }
+}
+
+impl<'c, 'view, 'o> Visit for NoUnreachableLoopVisitor<'c, 'view, 'o> {
+  noop_visit_type!();
+
+  fn visit_stmt(&mut self, stmt: &Stmt, _: &dyn Node) {
+    let labels = match stmt {
+      Stmt::Labeled(_) => Vec::new(),
+      _ => take(&mut self.labels),
+    };
+
+    match stmt {
+      Stmt::While(WhileStmt { span, body, .. }) => {
+        self.check_loop("WhileStatement", *span, body, &labels);
+      }
+      Stmt::DoWhile(DoWhileStmt { span, body, .. }) => {
+        self.check_loop("DoWhileStatement", *span, body, &labels);
+      }
+      Stmt::For(ForStmt { span, body, .. }) => {
+        self.check_loop("ForStatement", *span, body, &labels);
+      }
+      Stmt::ForIn(ForInStmt { span, body, .. }) => {
+        self.check_loop("ForInStatement", *span, body, &labels);
+      }
+      Stmt::ForOf(ForOfStmt { span, body, .. }) => {
+        self.check_loop("ForOfStatement", *span, body, &labels);
+      }
+      _ => {}
+    }
+
+    stmt.visit_children_with(self);
+  }
+
+  fn visit_labeled_stmt(&mut self, labeled_stmt: &LabeledStmt, _: &dyn Node) {
+    self.labels.push(labeled_stmt.label.sym.clone());
+    labeled_stmt.body.visit_with(labeled_stmt, self);
+  }
+}
+
+/// Looks for a `continue` statement that jumps back to the start of the loop
+/// being checked.
+struct ContinueFinder<'a> {
+  labels: &'a [JsWord],
+  loop_depth: usize,
+  found: bool,
+}
+
+impl Visit for ContinueFinder<'_> {
+  noop_visit_type!();
+
+  fn visit_continue_stmt(
+    &mut self,
+    continue_stmt: &ContinueStmt,
+    _: &dyn Node,
+  ) {
+    self.found |= match &continue_stmt.label {
+      Some(label) => self.labels.contains(&label.sym),
+      None => self.loop_depth == 0,
+    };
+  }
+
+  fn visit_while_stmt(&mut self, while_stmt: &WhileStmt, _: &dyn Node) {
+    self.loop_depth += 1;
+    while_stmt.visit_children_with(self);
+    self.loop_depth -= 1;
+  }
+
+  fn visit_do_while_stmt(&mut self, do_while: &DoWhileStmt, _: &dyn Node) {
+    self.loop_depth += 1;
+    do_while.visit_children_with(self);
+    self.loop_depth -= 1;
+  }
+
+  fn visit_for_stmt(&mut self, for_stmt: &ForStmt, _: &dyn Node) {
+    self.loop_depth += 1;
+    for_stmt.visit_children_with(self);
+    self.loop_depth -= 1;
+  }
+
+  fn visit_for_in_stmt(&mut self, for_in: &ForInStmt, _: &dyn Node) {
+    self.loop_depth += 1;
+    for_in.visit_children_with(self);
+    self.loop_depth -= 1;
+  }
+
+  fn visit_for_of_stmt(&mut self, for_of: &ForOfStmt, _: &dyn Node) {
+    self.loop_depth += 1;
+    for_of.visit_children_with(self);
+    self.loop_depth -= 1;
+  }
+
+  // `continue` can't jump out of functions and classes
+  fn visit_function(&mut self, _: &Function, _: &dyn Node) {}
+
+  fn visit_arrow_expr(&mut self, _: &ArrowExpr, _: &dyn Node) {}
+
+  fn visit_class(&mut self, _: &Class, _: &dyn Node) {}
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  #[test]
+  fn no_unreachable_loop_valid() {
+    assert_lint_ok! {
+      NoUnreachableLoop,
+      "while (a) { foo(); }",
+      "for (const x of xs) { if (x) return x; }",
+      "for (const x of xs) { if (x) continue; return x; }",
+      "while (a) { if (b) { break; } foo(); }",
+      "do { if (a) break; } while (b);",
+      "for (;;) { switch (a) { case 1: break; default: break; } }",
+      "for (const x of xs) { function f() { return x; } }",
+      "for (const x of xs) { const f = () => { return x; }; f(); }",
+      "for (const x of xs) { try { foo(); } catch { break; } }",
+      "A: for (const x of xs) { for (const y of ys) { if (y) continue A; } return x; }",
+      "while (a) { if (b) { continue; } else { return; } }",
+    };
+
+    assert_lint_ok! {
+      NoUnreachableLoop,
+      options: json!({ "ignore": ["ForInStatement", "WhileStatement"] }),
+      "for (const key in obj) { return key; }",
+      "while (a) { break; }",
+    };
+  }
+
+  #[test]
+  fn no_unreachable_loop_invalid() {
+    assert_lint_err! {
+      NoUnreachableLoop,
+      "for (const x of xs) { return x; }": [
+        { col: 0, message: MESSAGE, hint: HINT },
+      ],
+      "for (const key in obj) { return key; }": [
+        { col: 0, message: MESSAGE, hint: HINT },
+      ],
+      "while (a) { foo(); break; }": [
+        { col: 0, message: MESSAGE, hint: HINT },
+      ],
+      "do { throw new Error(); } while (a);": [
+        { col: 0, message: MESSAGE, hint: HINT },
+      ],
+      "for (let i = 0; i < 10; i++) break;": [
+        { col: 0, message: MESSAGE, hint: HINT },
+      ],
+      "for (const x of xs) { if (x) { return 1; } else { return 2; } }": [
+        { col: 0, message: MESSAGE, hint: HINT },
+      ],
+      "for (const x of xs) { for (const y of ys) { if (y) continue; } return x; }": [
+        { col: 0, message: MESSAGE, hint: HINT },
+      ],
+      "A: while (a) { for (const x of xs) { continue A; } }": [
+        { col: 15, message: MESSAGE, hint: HINT },
+      ],
+    };
+
+    assert_lint_err! {
+      NoUnreachableLoop,
+      options: json!({ "ignore": ["ForInStatement"] }),
+      "for (const x of xs) { return x; }": [
+        { col: 0, message: MESSAGE, hint: HINT },
+      ],
+    };
+  }
+}
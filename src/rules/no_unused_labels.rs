@@ -2,7 +2,7 @@
 use super::{Context, LintRule};
 use crate::handler::{Handler, Traverse};
 use crate::{Program, ProgramRef};
-use deno_ast::swc::common::Spanned;
+use deno_ast::swc::common::{Span, Spanned};
 use deno_ast::view as ast_view;
 use derive_more::Display;
 use if_chain::if_chain;
@@ -108,11 +108,28 @@ impl Handler for NoUnusedLabelsHandler {
       if let Some(label) = self.labels.pop();
       if !label.used;
       then {
-        ctx.add_diagnostic(
-          labeled_stmt.span(),
-          CODE,
-          NoUnusedLabelsMessage::Unused(label.name),
+        let label_span = Span::new(
+          labeled_stmt.span().lo,
+          labeled_stmt.inner.body.span().lo,
+          Default::default(),
         );
+        let message = NoUnusedLabelsMessage::Unused(label.name);
+        // Removing the label would also remove comments placed between it
+        // and the statement
+        if ctx
+          .all_comments()
+          .any(|comment| label_span.contains(comment.span))
+        {
+          ctx.add_diagnostic(labeled_stmt.span(), CODE, message);
+        } else {
+          let fix = ctx.create_fix(label_span, "", "Remove the label");
+          ctx.add_diagnostic_with_fix(
+            labeled_stmt.span(),
+            CODE,
+            message,
+            fix,
+          );
+        }
       }
     }
   }
@@ -143,6 +160,7 @@ mod tests {
         {
           col: 0,
           message: variant!(NoUnusedLabelsMessage, Unused, "LABEL"),
+          fix: "var a = 0;",
         }
       ],
       "LABEL: if (something) { a(); }": [
@@ -155,12 +173,41 @@ mod tests {
         {
           col: 0,
           message: variant!(NoUnusedLabelsMessage, Unused, "LABEL"),
+          fix: "for (let i = 0; i < 5; i++) { a(); b(); }",
         }
       ],
       "A: for (var i = 0; i < 10; ++i) { B: break A; }": [
         {
           col: 34,
           message: variant!(NoUnusedLabelsMessage, Unused, "B"),
+          fix: "A: for (var i = 0; i < 10; ++i) { break A; }",
+        }
+      ],
+      "A: /* comment */ while (true) {}": [
+        {
+          col: 0,
+          message: variant!(NoUnusedLabelsMessage, Unused, "A"),
+        }
+      ],
+      "A: { B: { break B; } }": [
+        {
+          col: 0,
+          message: variant!(NoUnusedLabelsMessage, Unused, "A"),
+          fix: "{ B: { break B; } }",
+        }
+      ],
+      "A: { A: { break A; } }": [
+        {
+          col: 0,
+          message: variant!(NoUnusedLabelsMessage, Unused, "A"),
+          fix: "{ A: { break A; } }",
+        }
+      ],
+      "A: { A: { foo(); } break A; }": [
+        {
+          col: 5,
+          message: variant!(NoUnusedLabelsMessage, Unused, "A"),
+          fix: "A: { { foo(); } break A; }",
         }
       ],
       "A: { let A = 0; console.log(A); }": [
@@ -4,24 +4,78 @@ use crate::ProgramRef;
 use deno_ast::swc::ast::{
   ArrowExpr, AssignPatProp, CallExpr, CatchClause, ClassDecl, ClassMethod,
   ClassProp, Constructor, Decl, DefaultDecl, ExportDecl, ExportDefaultDecl,
-  ExportNamedSpecifier, Expr, FnDecl, FnExpr, Function, Ident,
-  ImportDefaultSpecifier, ImportNamedSpecifier, ImportStarAsSpecifier,
-  MemberExpr, MethodKind, NamedExport, Param, Pat, PrivateMethod, Prop,
-  PropName, SetterProp, TsEntityName, TsEnumDecl, TsExprWithTypeArgs,
-  TsInterfaceDecl, TsModuleDecl, TsNamespaceDecl, TsPropertySignature,
-  TsTypeAliasDecl, TsTypeQueryExpr, TsTypeRef, VarDecl, VarDeclarator,
+  Expr, FnDecl, FnExpr, Ident, ImportDefaultSpecifier, ImportNamedSpecifier,
+  ImportStarAsSpecifier, MemberExpr, MethodKind, NamedExport, ObjectPatProp,
+  Param, ParamOrTsParamProp, Pat, PrivateMethod, Prop, PropName, SetterProp,
+  TsEntityName, TsEnumDecl, TsInterfaceDecl, TsModuleDecl, TsNamespaceDecl,
+  TsPropertySignature, TsTypeAliasDecl, TsTypeRef, VarDecl, VarDeclarator,
 };
-use deno_ast::swc::atoms::js_word;
+use deno_ast::swc::common::Span;
 use deno_ast::swc::utils::ident::IdentLike;
 use deno_ast::swc::utils::{find_ids, Id};
 use deno_ast::swc::visit::{Node, Visit, VisitWith};
 use derive_more::Display;
-use if_chain::if_chain;
+use regex::Regex;
+use serde::Deserialize;
 use std::collections::HashSet;
 use std::iter;
 
 #[derive(Debug)]
-pub struct NoUnusedVars;
+pub struct NoUnusedVars {
+  args: NoUnusedVarsArgs,
+  /// Compiled `vars_ignore_pattern`
+  vars_ignore_pattern: Option<Regex>,
+  /// Compiled `args_ignore_pattern`
+  args_ignore_pattern: Option<Regex>,
+  ignore_rest_siblings: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoUnusedVarsOptions {
+  /// Which function parameters are checked.
+  pub args: NoUnusedVarsArgs,
+  /// Regular expression for names of variables, imports and catch parameters
+  /// which are allowed to be unused. An invalid pattern is ignored.
+  pub vars_ignore_pattern: Option<String>,
+  /// Regular expression for names of function parameters which are allowed
+  /// to be unused. An invalid pattern is ignored.
+  pub args_ignore_pattern: Option<String>,
+  /// Whether siblings of a rest property in object destructuring, like `a`
+  /// of `const { a, ...rest } = obj;`, are allowed to be unused.
+  pub ignore_rest_siblings: bool,
+}
+
+/// The ignore pattern which the hints of this rule suggest to match.
+const DEFAULT_IGNORE_PATTERN: &str = "^_";
+
+impl Default for NoUnusedVarsOptions {
+  fn default() -> Self {
+    Self {
+      args: NoUnusedVarsArgs::default(),
+      vars_ignore_pattern: Some(DEFAULT_IGNORE_PATTERN.to_string()),
+      args_ignore_pattern: Some(DEFAULT_IGNORE_PATTERN.to_string()),
+      ignore_rest_siblings: false,
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum NoUnusedVarsArgs {
+  /// Parameters before the last used parameter are not checked.
+  AfterUsed,
+  /// All parameters are checked.
+  All,
+  /// Parameters are not checked.
+  None,
+}
+
+impl Default for NoUnusedVarsArgs {
+  fn default() -> Self {
+    NoUnusedVarsArgs::All
+  }
+}
 
 const CODE: &str = "no-unused-vars";
 
@@ -46,9 +100,23 @@ enum NoUnusedVarsHint {
   Alias(String),
 }
 
+impl NoUnusedVars {
+  pub fn with_options(options: NoUnusedVarsOptions) -> Box<Self> {
+    let compile = |pattern: Option<String>| {
+      pattern.and_then(|pattern| Regex::new(&pattern).ok())
+    };
+    Box::new(NoUnusedVars {
+      args: options.args,
+      vars_ignore_pattern: compile(options.vars_ignore_pattern),
+      args_ignore_pattern: compile(options.args_ignore_pattern),
+      ignore_rest_siblings: options.ignore_rest_siblings,
+    })
+  }
+}
+
 impl LintRule for NoUnusedVars {
   fn new() -> Box<Self> {
-    Box::new(NoUnusedVars)
+    NoUnusedVars::with_options(NoUnusedVarsOptions::default())
   }
 
   fn tags(&self) -> &'static [&'static str] {
@@ -79,7 +147,8 @@ impl LintRule for NoUnusedVars {
 
     let mut visitor = NoUnusedVarVisitor::new(
       context,
-      collector.used_vars,
+      self,
+      collector.self_references,
       collector.used_types,
     );
     match program {
@@ -100,10 +169,13 @@ fn is_jsx_file(filename: &str) -> bool {
   filename.ends_with(".jsx") || filename.ends_with(".tsx")
 }
 
-/// Collects information about variable usages.
+/// Collects information about variable usages which the references recorded by
+/// the scope analysis don't tell.
 #[derive(Default)]
 struct Collector {
-  used_vars: HashSet<Id>,
+  /// Spans of the references made while their binding is being declared,
+  /// which are not usages.
+  self_references: HashSet<Span>,
   used_types: HashSet<Id>,
   /// Currently defining functions or variables.
   ///
@@ -182,15 +254,10 @@ impl Collector {
   }
 
   fn mark_as_usage(&mut self, i: &Ident) {
-    let id = i.to_id();
-
     // Recursive calls are not usage
-    if self.cur_defining.contains(&id) {
-      return;
+    if self.cur_defining.contains(&i.to_id()) {
+      self.self_references.insert(i.span);
     }
-
-    // Mark the variable as used.
-    self.used_vars.insert(id);
   }
 }
 
@@ -228,24 +295,6 @@ impl Visit for Collector {
     self.used_types.insert(id);
   }
 
-  fn visit_ts_expr_with_type_args(
-    &mut self,
-    n: &TsExprWithTypeArgs,
-    _: &dyn Node,
-  ) {
-    let id = get_id(&n.expr);
-    self.used_vars.insert(id);
-    n.type_args.visit_with(n, self);
-  }
-
-  fn visit_ts_type_query_expr(&mut self, n: &TsTypeQueryExpr, _: &dyn Node) {
-    if let TsTypeQueryExpr::TsEntityName(e) = n {
-      let id = get_id(e);
-      self.used_vars.insert(id);
-    }
-    n.visit_children_with(self);
-  }
-
   fn visit_prop(&mut self, n: &Prop, _: &dyn Node) {
     match n {
       Prop::Shorthand(i) => self.mark_as_usage(i),
@@ -297,15 +346,6 @@ impl Visit for Collector {
     }
   }
 
-  /// export is kind of usage
-  fn visit_export_named_specifier(
-    &mut self,
-    export: &ExportNamedSpecifier,
-    _: &dyn Node,
-  ) {
-    self.used_vars.insert(export.orig.to_id());
-  }
-
   fn visit_fn_decl(&mut self, decl: &FnDecl, _: &dyn Node) {
     let id = decl.ident.to_id();
     self.with_cur_defining(iter::once(id), |a| {
@@ -319,25 +359,6 @@ impl Visit for Collector {
     expr.function.visit_with(expr, self);
   }
 
-  fn visit_function(&mut self, function: &Function, _: &dyn Node) {
-    if_chain! {
-      if let Some(first_param) = function.params.get(0);
-      if let Pat::Ident(ident) = &first_param.pat;
-      if ident.type_ann.is_some();
-      if ident.id.sym == js_word!("this");
-      then {
-        // If the first parameter of a function is `this` keyword with type annotated, it is a
-        // fake parameter specifying what type `this` becomes inside the function body.
-        // (See https://www.typescriptlang.org/docs/handbook/functions.html#this-parameters
-        // for more info)
-        // Since it's just a fake parameter, we can mark it as used.
-        self.mark_as_usage(&ident.id);
-      }
-    }
-
-    function.visit_children_with(self);
-  }
-
   fn visit_call_expr(&mut self, call_expr: &CallExpr, _: &dyn Node) {
     call_expr.callee.visit_children_with(self);
 
@@ -401,24 +422,82 @@ fn get_id(r: &TsEntityName) -> Id {
   }
 }
 
-struct NoUnusedVarVisitor<'c, 'view> {
+/// Collects the bindings of `pat` which are siblings of a rest property in
+/// object destructuring, like `a` of `{ a, ...rest }`.
+fn collect_rest_siblings(pat: &Pat, siblings: &mut HashSet<Id>) {
+  match pat {
+    Pat::Object(object_pat) => {
+      let has_rest = object_pat
+        .props
+        .iter()
+        .any(|prop| matches!(prop, ObjectPatProp::Rest(_)));
+      for prop in &object_pat.props {
+        match prop {
+          ObjectPatProp::KeyValue(key_value) => {
+            if has_rest {
+              let ids: Vec<Id> = find_ids(&key_value.value);
+              siblings.extend(ids);
+            } else {
+              collect_rest_siblings(&key_value.value, siblings);
+            }
+          }
+          ObjectPatProp::Assign(assign) => {
+            if has_rest {
+              siblings.insert(assign.key.to_id());
+            }
+          }
+          ObjectPatProp::Rest(rest) => {
+            collect_rest_siblings(&rest.arg, siblings)
+          }
+        }
+      }
+    }
+    Pat::Array(array_pat) => {
+      for elem in array_pat.elems.iter().flatten() {
+        collect_rest_siblings(elem, siblings);
+      }
+    }
+    Pat::Assign(assign_pat) => {
+      collect_rest_siblings(&assign_pat.left, siblings)
+    }
+    Pat::Rest(rest_pat) => collect_rest_siblings(&rest_pat.arg, siblings),
+    Pat::Ident(_) | Pat::Expr(_) | Pat::Invalid(_) => {}
+  }
+}
+
+struct NoUnusedVarVisitor<'c, 'view, 'r> {
   context: &'c mut Context<'view>,
-  used_vars: HashSet<Id>,
+  rule: &'r NoUnusedVars,
+  self_references: HashSet<Span>,
   used_types: HashSet<Id>,
 }
 
-impl<'c, 'view> NoUnusedVarVisitor<'c, 'view> {
+impl<'c, 'view, 'r> NoUnusedVarVisitor<'c, 'view, 'r> {
   fn new(
     context: &'c mut Context<'view>,
-    used_vars: HashSet<Id>,
+    rule: &'r NoUnusedVars,
+    self_references: HashSet<Span>,
     used_types: HashSet<Id>,
   ) -> Self {
     Self {
       context,
-      used_vars,
+      rule,
+      self_references,
       used_types,
     }
   }
+
+  /// Whether the binding declared by `ident` is read outside of its own
+  /// declaration.
+  fn is_used(&self, ident: &Ident) -> bool {
+    match self.context.scope().var(&ident.to_id()) {
+      Some(var) => var.references().iter().any(|reference| {
+        reference.is_read() && !self.self_references.contains(&reference.span())
+      }),
+      // Not a binding known to the scope analysis, so it can't be checked
+      None => true,
+    }
+  }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -455,32 +534,73 @@ impl<'a> IdentKind<'a> {
   }
 }
 
-impl<'c, 'view> NoUnusedVarVisitor<'c, 'view> {
+impl<'c, 'view, 'r> NoUnusedVarVisitor<'c, 'view, 'r> {
   fn handle_id(&mut self, ident: IdentKind) {
+    self.report_if_unused(ident, self.rule.vars_ignore_pattern.as_ref());
+  }
+
+  /// Checks the parameters of a function, where `params` contains the
+  /// patterns of the parameters in order.
+  fn handle_params<'a>(&mut self, params: impl Iterator<Item = &'a Pat>) {
+    let params: Vec<Vec<Ident>> = params.map(find_ids).collect();
+    let last_used = params
+      .iter()
+      .rposition(|idents| idents.iter().any(|ident| self.is_used(ident)));
+
+    for (i, idents) in params.iter().enumerate() {
+      let checked = match self.rule.args {
+        NoUnusedVarsArgs::All => true,
+        NoUnusedVarsArgs::AfterUsed => last_used.map_or(true, |last| i > last),
+        NoUnusedVarsArgs::None => false,
+      };
+      if !checked {
+        continue;
+      }
+      for ident in idents {
+        self.report_if_unused(
+          IdentKind::Other(ident),
+          self.rule.args_ignore_pattern.as_ref(),
+        );
+      }
+    }
+  }
+
+  fn report_if_unused(
+    &mut self,
+    ident: IdentKind,
+    ignore_pattern: Option<&Regex>,
+  ) {
     let inner = ident.inner();
-    if inner.sym.starts_with('_') {
+    if ignore_pattern.map_or(false, |pattern| pattern.is_match(&inner.sym)) {
       return;
     }
 
-    if !self.used_vars.contains(&inner.to_id()) {
-      // The variable is not used.
+    if self.is_used(inner) {
+      return;
+    }
+
+    // Prefixing the name with an underscore only helps with the default
+    // ignore pattern
+    if ignore_pattern
+      .map_or(false, |pattern| pattern.as_str() == DEFAULT_IGNORE_PATTERN)
+    {
       self.context.add_diagnostic_with_hint(
         inner.span,
         CODE,
         ident.to_message(),
         ident.to_hint(),
       );
+    } else {
+      self
+        .context
+        .add_diagnostic(inner.span, CODE, ident.to_message());
     }
   }
 }
 
-impl<'c, 'view> Visit for NoUnusedVarVisitor<'c, 'view> {
+impl<'c, 'view, 'r> Visit for NoUnusedVarVisitor<'c, 'view, 'r> {
   fn visit_arrow_expr(&mut self, expr: &ArrowExpr, _: &dyn Node) {
-    let declared_idents: Vec<Ident> = find_ids(&expr.params);
-
-    for ident in declared_idents {
-      self.handle_id(IdentKind::Other(&ident));
-    }
+    self.handle_params(expr.params.iter());
     expr.body.visit_with(expr, self)
   }
 
@@ -507,8 +627,15 @@ impl<'c, 'view> Visit for NoUnusedVarVisitor<'c, 'view> {
 
   fn visit_var_declarator(&mut self, declarator: &VarDeclarator, _: &dyn Node) {
     let declared_idents: Vec<Ident> = find_ids(&declarator.name);
+    let mut rest_siblings = HashSet::new();
+    if self.rule.ignore_rest_siblings {
+      collect_rest_siblings(&declarator.name, &mut rest_siblings);
+    }
 
     for ident in declared_idents {
+      if rest_siblings.contains(&ident.to_id()) {
+        continue;
+      }
       self.handle_id(IdentKind::Other(&ident));
     }
     declarator.name.visit_with(declarator, self);
@@ -545,6 +672,13 @@ impl<'c, 'view> Visit for NoUnusedVarVisitor<'c, 'view> {
       return;
     }
 
+    // Parameter properties are class members, so they are not checked
+    self.handle_params(constructor.params.iter().filter_map(
+      |param| match param {
+        ParamOrTsParamProp::Param(param) => Some(&param.pat),
+        ParamOrTsParamProp::TsParamProp(_) => None,
+      },
+    ));
     constructor.visit_children_with(self);
   }
 
@@ -555,7 +689,7 @@ impl<'c, 'view> Visit for NoUnusedVarVisitor<'c, 'view> {
     // If method body is not present, it's an overload definition
     if matches!(method.kind, MethodKind::Method if method.function.body.is_some())
     {
-      method.function.params.visit_children_with(self);
+      method.function.params.visit_with(method, self);
     }
 
     method.function.body.visit_with(method, self);
@@ -567,21 +701,12 @@ impl<'c, 'view> Visit for NoUnusedVarVisitor<'c, 'view> {
 
     // If method body is not present, it's an overload definition
     if method.function.body.is_some() {
-      method.function.params.visit_children_with(self);
+      method.function.params.visit_with(method, self);
     }
 
     method.function.body.visit_with(method, self);
   }
 
-  fn visit_param(&mut self, param: &Param, _: &dyn Node) {
-    let declared_idents: Vec<Ident> = find_ids(&param.pat);
-
-    for ident in declared_idents {
-      self.handle_id(IdentKind::Other(&ident));
-    }
-    param.visit_children_with(self);
-  }
-
   fn visit_import_named_specifier(
     &mut self,
     import: &ImportNamedSpecifier,
@@ -660,17 +785,16 @@ impl<'c, 'view> Visit for NoUnusedVarVisitor<'c, 'view> {
   }
 
   fn visit_params(&mut self, params: &[Param], parent: &dyn Node) {
-    match params.first() {
+    let params = match params.first() {
       Some(Param {
         pat: Pat::Ident(i), ..
-      }) if i.id.sym == *"this" => params
-        .iter()
-        .skip(1)
-        .for_each(|param| param.visit_with(parent, self)),
-      _ => params
-        .iter()
-        .for_each(|param| param.visit_with(parent, self)),
-    }
+      }) if i.id.sym == *"this" => &params[1..],
+      _ => params,
+    };
+    self.handle_params(params.iter().map(|param| &param.pat));
+    params
+      .iter()
+      .for_each(|param| param.visit_with(parent, self));
   }
 
   fn visit_ts_enum_decl(&mut self, n: &TsEnumDecl, _: &dyn Node) {
@@ -707,6 +831,7 @@ impl<'c, 'view> Visit for NoUnusedVarVisitor<'c, 'view> {
 #[cfg(test)]
 mod tests {
   use super::*;
+  use serde_json::json;
 
   #[test]
   fn no_unused_vars_valid() {
@@ -1443,6 +1568,39 @@ class Component extends React.Component { render() { return null; } }
 export default <Component />;
       "#,
     };
+
+    assert_lint_ok! {
+      NoUnusedVars,
+      options: json!({ "args": "after-used" }),
+      "function foo(a, b) { return b; } foo();",
+      "const foo = (a, { b }, c) => c; foo();",
+      "class A { m(a, b) { return b; } } new A();",
+    };
+
+    assert_lint_ok! {
+      NoUnusedVars,
+      options: json!({ "args": "none" }),
+      "function foo(a, b) {} foo();",
+      "const foo = (a) => {}; foo();",
+    };
+
+    assert_lint_ok! {
+      NoUnusedVars,
+      options: json!({
+        "varsIgnorePattern": "^ignored",
+        "argsIgnorePattern": "^unused"
+      }),
+      "const ignoredValue = 1;",
+      "function foo(unusedArg) {} foo();",
+    };
+
+    assert_lint_ok! {
+      NoUnusedVars,
+      options: json!({ "ignoreRestSiblings": true }),
+      "const { a, ...rest } = obj; console.log(rest);",
+      "const { a: { b }, c = 1, ...rest } = obj; console.log(rest);",
+      "const [{ a, ...rest }] = obj; console.log(rest);",
+    };
   }
 
   #[test]
@@ -2226,6 +2384,85 @@ export class Foo {
     };
   }
 
+  #[test]
+  fn no_unused_vars_err_options() {
+    assert_lint_err! {
+      NoUnusedVars,
+      options: json!({ "args": "after-used" }),
+      "function foo(a, b, c) { return b; } foo();": [
+        {
+          col: 19,
+          message: variant!(NoUnusedVarsMessage, NeverUsed, "c"),
+          hint: variant!(NoUnusedVarsHint, AddPrefix, "c"),
+        }
+      ],
+      "function foo(a) {} foo();": [
+        {
+          col: 13,
+          message: variant!(NoUnusedVarsMessage, NeverUsed, "a"),
+          hint: variant!(NoUnusedVarsHint, AddPrefix, "a"),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoUnusedVars,
+      options: json!({ "args": "none" }),
+      "function foo(a) { const b = 1; } foo();": [
+        {
+          col: 24,
+          message: variant!(NoUnusedVarsMessage, NeverUsed, "b"),
+          hint: variant!(NoUnusedVarsHint, AddPrefix, "b"),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoUnusedVars,
+      options: json!({
+        "varsIgnorePattern": "^ignored",
+        "argsIgnorePattern": "^unused"
+      }),
+      "const _a = 1;": [
+        {
+          col: 6,
+          message: variant!(NoUnusedVarsMessage, NeverUsed, "_a"),
+        }
+      ],
+      "function foo(ignoredArg) {} foo();": [
+        {
+          col: 13,
+          message: variant!(NoUnusedVarsMessage, NeverUsed, "ignoredArg"),
+        }
+      ],
+      "import { a } from 'a';": [
+        {
+          col: 9,
+          message: variant!(NoUnusedVarsMessage, NeverUsed, "a"),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoUnusedVars,
+      options: json!({ "ignoreRestSiblings": true }),
+      "const { a, ...rest } = obj;": [
+        {
+          col: 14,
+          message: variant!(NoUnusedVarsMessage, NeverUsed, "rest"),
+          hint: variant!(NoUnusedVarsHint, AddPrefix, "rest"),
+        }
+      ],
+      "const { a, b } = obj; console.log(b);": [
+        {
+          col: 8,
+          message: variant!(NoUnusedVarsMessage, NeverUsed, "a"),
+          hint: variant!(NoUnusedVarsHint, AddPrefix, "a"),
+        }
+      ],
+    };
+  }
+
   // TODO(magurotuna): deals with this using ControlFlow
   #[test]
   #[ignore = "control flow analysis is not implemented yet"]
@@ -0,0 +1,459 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::scopes::BindingKind;
+use crate::ProgramRef;
+use deno_ast::swc::ast::{
+  ArrowExpr, AssignPatProp, ClassProp, Constructor, ExportSpecifier, Expr,
+  Function, GetterProp, Ident, MemberExpr, NamedExport, Pat, PrivateProp, Prop,
+  SetterProp,
+};
+use deno_ast::swc::common::{BytePos, Span};
+use deno_ast::swc::utils::ident::IdentLike;
+use deno_ast::swc::visit::{noop_visit_type, Node, Visit, VisitWith};
+use derive_more::Display;
+use serde::Deserialize;
+
+#[derive(Debug, Default)]
+pub struct NoUseBeforeDefine {
+  options: NoUseBeforeDefineOptions,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NoUseBeforeDefineOptions {
+  /// Whether references to function declarations before their definition
+  /// are reported. Function declarations are hoisted, so such references are
+  /// safe.
+  pub functions: bool,
+  /// Whether references to classes from nested functions are reported, even
+  /// if they are declared after the reference.
+  pub classes: bool,
+  /// Whether references to variables from nested functions are reported,
+  /// even if they are declared after the reference.
+  pub variables: bool,
+  /// Whether references in `export { foo };` declarations are allowed before
+  /// the definition of `foo`.
+  pub allow_named_exports: bool,
+}
+
+impl Default for NoUseBeforeDefineOptions {
+  fn default() -> Self {
+    Self {
+      functions: true,
+      classes: true,
+      variables: true,
+      allow_named_exports: false,
+    }
+  }
+}
+
+const CODE: &str = "no-use-before-define";
+const HINT: &str = "Move the declaration before its first use";
+
+#[derive(Display)]
+enum NoUseBeforeDefineMessage {
+  #[display(fmt = "`{}` was used before it was defined", _0)]
+  UsedBeforeDefined(String),
+}
+
+impl NoUseBeforeDefine {
+  pub fn with_options(options: NoUseBeforeDefineOptions) -> Box<Self> {
+    Box::new(NoUseBeforeDefine { options })
+  }
+}
+
+impl LintRule for NoUseBeforeDefine {
+  fn new() -> Box<Self> {
+    Box::new(NoUseBeforeDefine::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    let mut visitor = NoUseBeforeDefineVisitor::new(context, &self.options);
+    match program {
+      ProgramRef::Module(m) => visitor.visit_module(m, &DUMMY_NODE),
+      ProgramRef::Script(s) => visitor.visit_script(s, &DUMMY_NODE),
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_use_before_define.md")
+  }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum DeclKind {
+  Variable,
+  Function,
+  Class,
+}
+
+impl DeclKind {
+  /// Returns the kind of declarations checked by this rule which `kind`
+  /// belongs to, if any.
+  fn of(kind: BindingKind) -> Option<Self> {
+    match kind {
+      BindingKind::Var | BindingKind::Let | BindingKind::Const => {
+        Some(DeclKind::Variable)
+      }
+      BindingKind::Function => Some(DeclKind::Function),
+      BindingKind::Class => Some(DeclKind::Class),
+      _ => None,
+    }
+  }
+}
+
+#[derive(Clone, Copy)]
+struct Declaration {
+  lo: BytePos,
+  kind: DeclKind,
+}
+
+struct NoUseBeforeDefineVisitor<'c, 'view, 'o> {
+  context: &'c mut Context<'view>,
+  options: &'o NoUseBeforeDefineOptions,
+  /// Spans of the functions enclosing the node being visited, innermost last.
+  functions: Vec<Span>,
+}
+
+impl<'c, 'view, 'o> NoUseBeforeDefineVisitor<'c, 'view, 'o> {
+  fn new(
+    context: &'c mut Context<'view>,
+    options: &'o NoUseBeforeDefineOptions,
+  ) -> Self {
+    Self {
+      context,
+      options,
+      functions: Vec::new(),
+    }
+  }
+
+  /// Returns the first declaration of the binding `ident` refers to.
+  fn declaration_of(&self, ident: &Ident) -> Option<Declaration> {
+    let var = self.context.scope().var(&ident.to_id())?;
+    Some(Declaration {
+      lo: var.declared_at().lo,
+      kind: DeclKind::of(var.kind())?,
+    })
+  }
+
+  fn with_function<F>(&mut self, span: Span, op: F)
+  where
+    F: FnOnce(&mut Self),
+  {
+    self.functions.push(span);
+    op(self);
+    self.functions.pop();
+  }
+
+  /// Whether the reference being visited is evaluated in a function nested
+  /// inside the scope of the declaration, so that it may run only after the
+  /// declaration has been evaluated.
+  fn in_separate_context(&self, declaration: &Declaration) -> bool {
+    match self.functions.last() {
+      Some(span) => declaration.lo < span.lo || span.hi <= declaration.lo,
+      None => false,
+    }
+  }
+
+  fn check_ident(&mut self, ident: &Ident) {
+    let declaration = match self.declaration_of(ident) {
+      Some(declaration) => declaration,
+      None => return,
+    };
+    if declaration.lo <= ident.span.lo {
+      return;
+    }
+
+    let allowed = match declaration.kind {
+      DeclKind::Function => !self.options.functions,
+      DeclKind::Class => {
+        !self.options.classes && self.in_separate_context(&declaration)
+      }
+      DeclKind::Variable => {
+        !self.options.variables && self.in_separate_context(&declaration)
+      }
+    };
+    if !allowed {
+      self.context.add_diagnostic_with_hint(
+        ident.span,
+        CODE,
+        NoUseBeforeDefineMessage::UsedBeforeDefined(ident.sym.to_string()),
+        HINT,
+      );
+    }
+  }
+}
+
+impl<'c, 'view, 'o> Visit for NoUseBeforeDefineVisitor<'c, 'view, 'o> {
+  noop_visit_type!();
+
+  fn visit_expr(&mut self, expr: &Expr, _: &dyn Node) {
+    match expr {
+      Expr::Ident(ident) => self.check_ident(ident),
+      _ => expr.visit_children_with(self),
+    }
+  }
+
+  // Binding identifiers of declarations are checked too, which is harmless
+  // since they never precede the first declaration of their binding.
+  fn visit_pat(&mut self, pat: &Pat, _: &dyn Node) {
+    match pat {
+      Pat::Ident(binding_ident) => self.check_ident(&binding_ident.id),
+      _ => pat.visit_children_with(self),
+    }
+  }
+
+  fn visit_assign_pat_prop(&mut self, prop: &AssignPatProp, _: &dyn Node) {
+    self.check_ident(&prop.key);
+    prop.value.visit_with(prop, self);
+  }
+
+  fn visit_prop(&mut self, prop: &Prop, _: &dyn Node) {
+    match prop {
+      Prop::Shorthand(ident) => self.check_ident(ident),
+      _ => prop.visit_children_with(self),
+    }
+  }
+
+  fn visit_member_expr(&mut self, member_expr: &MemberExpr, _: &dyn Node) {
+    member_expr.obj.visit_with(member_expr, self);
+    if member_expr.computed {
+      member_expr.prop.visit_with(member_expr, self);
+    }
+  }
+
+  fn visit_named_export(&mut self, named_export: &NamedExport, _: &dyn Node) {
+    // Re-exports refer to bindings of other modules
+    if named_export.src.is_some() || self.options.allow_named_exports {
+      return;
+    }
+    for specifier in &named_export.specifiers {
+      if let ExportSpecifier::Named(named) = specifier {
+        self.check_ident(&named.orig);
+      }
+    }
+  }
+
+  fn visit_function(&mut self, function: &Function, _: &dyn Node) {
+    self.with_function(function.span, |v| function.visit_children_with(v));
+  }
+
+  fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr, _: &dyn Node) {
+    self.with_function(arrow_expr.span, |v| arrow_expr.visit_children_with(v));
+  }
+
+  fn visit_constructor(&mut self, constructor: &Constructor, _: &dyn Node) {
+    self
+      .with_function(constructor.span, |v| constructor.visit_children_with(v));
+  }
+
+  fn visit_getter_prop(&mut self, getter_prop: &GetterProp, _: &dyn Node) {
+    self
+      .with_function(getter_prop.span, |v| getter_prop.visit_children_with(v));
+  }
+
+  fn visit_setter_prop(&mut self, setter_prop: &SetterProp, _: &dyn Node) {
+    self
+      .with_function(setter_prop.span, |v| setter_prop.visit_children_with(v));
+  }
+
+  // Initializers of class fields are evaluated when the class is
+  // instantiated, like the body of a function.
+  fn visit_class_prop(&mut self, class_prop: &ClassProp, _: &dyn Node) {
+    class_prop.decorators.visit_with(class_prop, self);
+    if class_prop.computed {
+      class_prop.key.visit_with(class_prop, self);
+    }
+    self.with_function(class_prop.span, |v| {
+      class_prop.value.visit_with(class_prop, v)
+    });
+  }
+
+  fn visit_private_prop(&mut self, private_prop: &PrivateProp, _: &dyn Node) {
+    private_prop.decorators.visit_with(private_prop, self);
+    self.with_function(private_prop.span, |v| {
+      private_prop.value.visit_with(private_prop, v)
+    });
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  #[test]
+  fn no_use_before_define_valid() {
+    assert_lint_ok! {
+      NoUseBeforeDefine,
+      "var a = 10; alert(a);",
+      "function b(a) { alert(a); }",
+      "Object.hasOwnProperty.call(a);",
+      "function a() { alert(arguments); }",
+      "a(); try { throw new Error(); } catch (a) {}",
+      "const a = 1; function f() { return a; }",
+      "function f() { return f(); }",
+      "class A { m() { return new A(); } }",
+      "const a = { b: 1 }; a.b;",
+      "foo.bar; const bar = 1;",
+      "const obj = { bar: 1 }; const bar = 2;",
+      "class A { bar = 1; } const bar = 2;",
+      "function f() { let a; { a = 1; } } const a = 2;",
+      "const { a, b = a } = obj;",
+      r#"export { a } from "./a.ts"; const a = 1;"#,
+      "let a; export { a };",
+    };
+
+    assert_lint_ok! {
+      NoUseBeforeDefine,
+      options: json!({
+        "functions": false,
+        "classes": true,
+        "variables": true,
+        "allowNamedExports": false
+      }),
+      "a(); function a() {}",
+      "function f() { return g(); } function g() {}",
+    };
+
+    assert_lint_ok! {
+      NoUseBeforeDefine,
+      options: json!({ "variables": false, "classes": false }),
+      "function f() { return a; } const a = 1;",
+      "const f = () => a; let a;",
+      "function f() { return new A(); } class A {}",
+      "class B { foo = a; } const a = 1;",
+      "({ get a() { return b; } }); const b = 1;",
+    };
+
+    assert_lint_ok! {
+      NoUseBeforeDefine,
+      options: json!({ "allowNamedExports": true }),
+      "export { a }; const a = 1;",
+      "export { a as b }; function a() {}",
+    };
+  }
+
+  #[test]
+  fn no_use_before_define_invalid() {
+    assert_lint_err! {
+      NoUseBeforeDefine,
+      "a++; var a = 19;": [
+        {
+          col: 0,
+          message: variant!(NoUseBeforeDefineMessage, UsedBeforeDefined, "a"),
+          hint: HINT,
+        },
+      ],
+      "alert(a); var a = 10;": [
+        {
+          col: 6,
+          message: variant!(NoUseBeforeDefineMessage, UsedBeforeDefined, "a"),
+          hint: HINT,
+        },
+      ],
+      "f(); function f() {}": [
+        {
+          col: 0,
+          message: variant!(NoUseBeforeDefineMessage, UsedBeforeDefined, "f"),
+          hint: HINT,
+        },
+      ],
+      "new A(); class A {}": [
+        {
+          col: 4,
+          message: variant!(NoUseBeforeDefineMessage, UsedBeforeDefined, "A"),
+          hint: HINT,
+        },
+      ],
+      "function f() { return a; } const a = 1;": [
+        {
+          col: 22,
+          message: variant!(NoUseBeforeDefineMessage, UsedBeforeDefined, "a"),
+          hint: HINT,
+        },
+      ],
+      "{ a; let a = 1; }": [
+        {
+          col: 2,
+          message: variant!(NoUseBeforeDefineMessage, UsedBeforeDefined, "a"),
+          hint: HINT,
+        },
+      ],
+      "const b = { a }; const a = 1;": [
+        {
+          col: 12,
+          message: variant!(NoUseBeforeDefineMessage, UsedBeforeDefined, "a"),
+          hint: HINT,
+        },
+      ],
+      "[a] = [1]; let a;": [
+        {
+          col: 1,
+          message: variant!(NoUseBeforeDefineMessage, UsedBeforeDefined, "a"),
+          hint: HINT,
+        },
+      ],
+      "obj[a]; const a = 'key';": [
+        {
+          col: 4,
+          message: variant!(NoUseBeforeDefineMessage, UsedBeforeDefined, "a"),
+          hint: HINT,
+        },
+      ],
+      "export { a }; const a = 1;": [
+        {
+          col: 9,
+          message: variant!(NoUseBeforeDefineMessage, UsedBeforeDefined, "a"),
+          hint: HINT,
+        },
+      ],
+    };
+
+    assert_lint_err! {
+      NoUseBeforeDefine,
+      options: json!({
+        "functions": false,
+        "classes": false,
+        "variables": false
+      }),
+      "a; const a = 1;": [
+        {
+          col: 0,
+          message: variant!(NoUseBeforeDefineMessage, UsedBeforeDefined, "a"),
+          hint: HINT,
+        },
+      ],
+      "new A(); class A {}": [
+        {
+          col: 4,
+          message: variant!(NoUseBeforeDefineMessage, UsedBeforeDefined, "A"),
+          hint: HINT,
+        },
+      ],
+      "function f() { { a; } let a; }": [
+        {
+          col: 17,
+          message: variant!(NoUseBeforeDefineMessage, UsedBeforeDefined, "a"),
+          hint: HINT,
+        },
+      ],
+      "class B { [a] = 1; } const a = 'key';": [
+        {
+          col: 11,
+          message: variant!(NoUseBeforeDefineMessage, UsedBeforeDefined, "a"),
+          hint: HINT,
+        },
+      ],
+    };
+  }
+}
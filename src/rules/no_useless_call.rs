@@ -0,0 +1,142 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::is_nullish;
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{CallExpr, Expr, ExprOrSpread, ExprOrSuper};
+use deno_ast::view as ast_view;
+use if_chain::if_chain;
+
+#[derive(Debug)]
+pub struct NoUselessCall;
+
+const CODE: &str = "no-useless-call";
+
+fn message(method: &str) -> String {
+  format!("Unnecessary `.{}()`", method)
+}
+
+fn hint(callee: &str) -> String {
+  format!("Call `{}` directly instead", callee)
+}
+
+impl LintRule for NoUselessCall {
+  fn new() -> Box<Self> {
+    Box::new(NoUselessCall)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoUselessCallHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_useless_call.md")
+  }
+}
+
+/// `.call()` is always replaceable, while `.apply()` is only if the arguments
+/// are given as an array literal, e.g. `foo.apply(null, [a, b])`.
+fn is_replaceable_call(method: &str, args: &[ExprOrSpread]) -> bool {
+  match method {
+    "call" => true,
+    "apply" => {
+      args.len() == 2
+        && args[1].spread.is_none()
+        && matches!(&*args[1].expr, Expr::Array(_))
+    }
+    _ => false,
+  }
+}
+
+struct NoUselessCallHandler;
+
+impl Handler for NoUselessCallHandler {
+  fn call_expr(&mut self, call_expr: &ast_view::CallExpr, ctx: &mut Context) {
+    let CallExpr { callee, args, .. } = call_expr.inner;
+
+    if_chain! {
+      if let ExprOrSuper::Expr(callee_expr) = callee;
+      if let Expr::Member(member_expr) = &**callee_expr;
+      if !member_expr.computed;
+      if let Expr::Ident(method) = &*member_expr.prop;
+      if is_replaceable_call(method.sym.as_ref(), args);
+      // Only plain function references are checked, since calling a member
+      // like `obj.foo()` directly would bind `this` to `obj`
+      if let ExprOrSuper::Expr(obj) = &member_expr.obj;
+      if let Expr::Ident(func) = &**obj;
+      if let Some(this_arg) = args.first();
+      if this_arg.spread.is_none();
+      if is_nullish(&this_arg.expr, ctx.scope());
+      then {
+        ctx.add_diagnostic_with_hint(
+          call_expr.inner.span,
+          CODE,
+          message(method.sym.as_ref()),
+          hint(func.sym.as_ref()),
+        );
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_useless_call_valid() {
+    assert_lint_ok! {
+      NoUselessCall,
+      "foo(a, b);",
+      "foo.call(obj, a, b);",
+      "foo.apply(obj, [a, b]);",
+      "obj.foo.call(null, a, b);",
+      "obj.foo.apply(undefined, [a, b]);",
+      "foo.apply(null, args);",
+      "foo.apply(undefined, [a, b], c);",
+      "foo.call();",
+      "foo.call(...args);",
+      "foo['call'](null, a);",
+      "foo.bind(null, a);",
+      "function f(undefined) { foo.call(undefined, a); }",
+    };
+  }
+
+  #[test]
+  fn no_useless_call_invalid() {
+    assert_lint_err! {
+      NoUselessCall,
+      "foo.call(undefined, a, b);": [
+        { col: 0, message: message("call"), hint: hint("foo") },
+      ],
+      "foo.call(null);": [
+        { col: 0, message: message("call"), hint: hint("foo") },
+      ],
+      "foo.call(void 0, a);": [
+        { col: 0, message: message("call"), hint: hint("foo") },
+      ],
+      "foo.apply(null, [a, b]);": [
+        { col: 0, message: message("apply"), hint: hint("foo") },
+      ],
+      "foo.apply(undefined, []);": [
+        { col: 0, message: message("apply"), hint: hint("foo") },
+      ],
+      "bar(foo.call(null, a));": [
+        { col: 4, message: message("call"), hint: hint("foo") },
+      ],
+    };
+  }
+}
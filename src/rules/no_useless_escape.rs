@@ -0,0 +1,325 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::ProgramRef;
+use deno_ast::swc::ast::{JSXAttrValue, Lit, Regex, Str, TaggedTpl, Tpl};
+use deno_ast::swc::common::{BytePos, Span};
+use deno_ast::swc::visit::{noop_visit_type, Node, Visit, VisitWith};
+
+#[derive(Debug)]
+pub struct NoUselessEscape;
+
+const CODE: &str = "no-useless-escape";
+const HINT: &str = "Remove the backslash";
+
+fn message(c: char) -> String {
+  format!("Unnecessary escape character: \\{}", c)
+}
+
+impl LintRule for NoUselessEscape {
+  fn new() -> Box<Self> {
+    Box::new(NoUselessEscape)
+  }
+
+  fn tags(&self) -> &'static [&'static str] {
+    &["recommended"]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    let mut visitor = NoUselessEscapeVisitor::new(context);
+    match program {
+      ProgramRef::Module(m) => m.visit_with(&DUMMY_NODE, &mut visitor),
+      ProgramRef::Script(s) => s.visit_with(&DUMMY_NODE, &mut visitor),
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_useless_escape.md")
+  }
+}
+
+/// Characters which have a meaning when escaped in string and template
+/// literals, in addition to digits and line terminators.
+const STRING_ESCAPES: &str = "\\nrvtbfux";
+/// Characters which have a meaning when escaped anywhere in regular
+/// expressions.
+const REGEX_GENERAL_ESCAPES: &str = "\\bcdDfnpPrsStvwWxu0123456789]";
+/// Characters which have a meaning when escaped outside of character classes
+/// in regular expressions.
+const REGEX_NON_CHARCLASS_ESCAPES: &str = "^/.$*+?[{}|()Bk";
+
+fn is_line_terminator(c: char) -> bool {
+  matches!(c, '\n' | '\r' | '\u{2028}' | '\u{2029}')
+}
+
+/// Returns byte offsets of useless backslashes in the raw text of a string
+/// literal, including the quotes, along with the escaped characters.
+fn find_useless_string_escapes(raw: &str) -> Vec<(usize, char)> {
+  let quote = raw.chars().next();
+  let mut escapes = vec![];
+  let mut chars = raw.char_indices();
+  while let Some((i, c)) = chars.next() {
+    if c != '\\' {
+      continue;
+    }
+    if let Some((_, escaped)) = chars.next() {
+      let is_useful = STRING_ESCAPES.contains(escaped)
+        || escaped.is_ascii_digit()
+        || is_line_terminator(escaped)
+        || Some(escaped) == quote;
+      if !is_useful {
+        escapes.push((i, escaped));
+      }
+    }
+  }
+  escapes
+}
+
+/// Returns byte offsets of useless backslashes in the raw text of a template
+/// literal element, along with the escaped characters.
+fn find_useless_template_escapes(raw: &str) -> Vec<(usize, char)> {
+  let mut escapes = vec![];
+  let mut chars = raw.char_indices().peekable();
+  let mut prev = None;
+  while let Some((i, c)) = chars.next() {
+    if c != '\\' {
+      prev = Some(c);
+      continue;
+    }
+    if let Some((_, escaped)) = chars.next() {
+      let next = chars.peek().map(|(_, c)| *c);
+      let is_useful = STRING_ESCAPES.contains(escaped)
+        || escaped.is_ascii_digit()
+        || is_line_terminator(escaped)
+        || escaped == '`'
+        || (escaped == '$' && next == Some('{'))
+        || (escaped == '{' && prev == Some('$'));
+      if !is_useful {
+        escapes.push((i, escaped));
+      }
+      prev = Some(escaped);
+    }
+  }
+  escapes
+}
+
+/// Returns byte offsets of useless backslashes in a regular expression
+/// pattern, along with the escaped characters.
+fn find_useless_regex_escapes(pattern: &str) -> Vec<(usize, char)> {
+  let mut escapes = vec![];
+  let mut chars = pattern.char_indices();
+  let mut in_class = false;
+  let mut class_start = 0;
+  while let Some((i, c)) = chars.next() {
+    match c {
+      '\\' => {
+        let escaped = match chars.next() {
+          Some((_, escaped)) => escaped,
+          None => break,
+        };
+        let is_useful = REGEX_GENERAL_ESCAPES.contains(escaped)
+          || if in_class {
+            // `^` negates the class only at its start, while `-` and `/`
+            // are commonly escaped for clarity
+            (escaped == '^' && i == class_start)
+              || escaped == '-'
+              || escaped == '/'
+          } else {
+            REGEX_NON_CHARCLASS_ESCAPES.contains(escaped)
+          };
+        if !is_useful {
+          escapes.push((i, escaped));
+        }
+      }
+      '[' if !in_class => {
+        in_class = true;
+        class_start = i + 1;
+      }
+      ']' if in_class => in_class = false,
+      _ => {}
+    }
+  }
+  escapes
+}
+
+struct NoUselessEscapeVisitor<'c, 'view> {
+  context: &'c mut Context<'view>,
+}
+
+impl<'c, 'view> NoUselessEscapeVisitor<'c, 'view> {
+  fn new(context: &'c mut Context<'view>) -> Self {
+    Self { context }
+  }
+
+  /// Reports the backslashes at the given offsets from `start`.
+  fn report(&mut self, start: BytePos, escapes: Vec<(usize, char)>) {
+    for (offset, escaped) in escapes {
+      let lo = start + BytePos(offset as u32);
+      let span = Span::new(lo, lo + BytePos(1), Default::default());
+      let fix = self.context.create_fix(span, "", HINT);
+      self.context.add_diagnostic_with_hint_and_fix(
+        span,
+        CODE,
+        message(escaped),
+        HINT,
+        fix,
+      );
+    }
+  }
+
+  fn check_tpl_quasis(&mut self, tpl: &Tpl) {
+    for quasi in &tpl.quasis {
+      let raw = self.context.file_text_substring(&quasi.span);
+      let escapes = find_useless_template_escapes(raw);
+      self.report(quasi.span.lo, escapes);
+    }
+  }
+}
+
+impl<'c, 'view> Visit for NoUselessEscapeVisitor<'c, 'view> {
+  noop_visit_type!();
+
+  fn visit_str(&mut self, str_lit: &Str, _: &dyn Node) {
+    let raw = self.context.file_text_substring(&str_lit.span);
+    let escapes = find_useless_string_escapes(raw);
+    self.report(str_lit.span.lo, escapes);
+  }
+
+  fn visit_tpl(&mut self, tpl: &Tpl, _: &dyn Node) {
+    self.check_tpl_quasis(tpl);
+    for expr in &tpl.exprs {
+      expr.visit_with(tpl, self);
+    }
+  }
+
+  fn visit_tagged_tpl(&mut self, tagged_tpl: &TaggedTpl, _: &dyn Node) {
+    // Escapes in tagged templates may be meaningful to the tag function, e.g.
+    // `String.raw`
+    tagged_tpl.tag.visit_with(tagged_tpl, self);
+    for expr in &tagged_tpl.tpl.exprs {
+      expr.visit_with(&tagged_tpl.tpl, self);
+    }
+  }
+
+  fn visit_regex(&mut self, regex: &Regex, _: &dyn Node) {
+    let escapes = find_useless_regex_escapes(&regex.exp);
+    // Skip the leading `/`
+    self.report(regex.span.lo + BytePos(1), escapes);
+  }
+
+  fn visit_jsx_attr_value(&mut self, value: &JSXAttrValue, _: &dyn Node) {
+    // Escape sequences are not interpreted in JSX attribute strings
+    if !matches!(value, JSXAttrValue::Lit(Lit::Str(_))) {
+      value.visit_children_with(self);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_useless_escape_valid() {
+    assert_lint_ok! {
+      NoUselessEscape,
+      r#"const foo = "\n\r\t\v\b\f\\\x41A\u{41}\0";"#,
+      r#"const foo = "\"";"#,
+      r#"const foo = '\'';"#,
+      "const foo = 'a\\\nb';",
+      r#"const foo = `\``;"#,
+      r#"const foo = `\${bar}`;"#,
+      r#"const foo = `$\{bar}`;"#,
+      r#"const foo = `${"\n"}`;"#,
+      r#"const foo = String.raw`\d`;"#,
+      r#"const foo = /\d\.\/\(\)\[\]\{\}\|\*\+\?\^\$/;"#,
+      r#"const foo = /[\]\-\/\\]/;"#,
+      r#"const foo = /[\^a]/;"#,
+      r#"const foo = /\bfoo\B/;"#,
+      r#"const foo = /(?<a>.)\k<a>/;"#,
+    };
+
+    assert_lint_ok! {
+      NoUselessEscape,
+      filename: "foo.tsx",
+      r#"<div title="\d" />"#,
+    };
+  }
+
+  #[test]
+  fn no_useless_escape_invalid() {
+    assert_lint_err! {
+      NoUselessEscape,
+      r#"const foo = "\q";"#: [
+        {
+          col: 13,
+          message: message('q'),
+          hint: HINT,
+          fix: r#"const foo = "q";"#,
+        },
+      ],
+      r#"const foo = "\'";"#: [
+        {
+          col: 13,
+          message: message('\''),
+          hint: HINT,
+          fix: r#"const foo = "'";"#,
+        },
+      ],
+      r#"const foo = `\"${bar}\$`;"#: [
+        {
+          col: 13,
+          message: message('"'),
+          hint: HINT,
+          fix: r#"const foo = `"${bar}\$`;"#,
+        },
+        {
+          col: 21,
+          message: message('$'),
+          hint: HINT,
+          fix: r#"const foo = `\"${bar}$`;"#,
+        },
+      ],
+      r#"const foo = /\q/;"#: [
+        {
+          col: 13,
+          message: message('q'),
+          hint: HINT,
+          fix: r#"const foo = /q/;"#,
+        },
+      ],
+      r#"const foo = /[a\.]/;"#: [
+        {
+          col: 15,
+          message: message('.'),
+          hint: HINT,
+          fix: r#"const foo = /[a.]/;"#,
+        },
+      ],
+      r#"const foo = /[a\^]/;"#: [
+        {
+          col: 15,
+          message: message('^'),
+          hint: HINT,
+          fix: r#"const foo = /[a^]/;"#,
+        },
+      ],
+      r#"const foo = /\-/;"#: [
+        {
+          col: 13,
+          message: message('-'),
+          hint: HINT,
+          fix: r#"const foo = /-/;"#,
+        },
+      ],
+    };
+  }
+}
@@ -0,0 +1,306 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::ProgramRef;
+use deno_ast::swc::ast::{
+  ArrayLit, CallExpr, Expr, ExprOrSpread, NewExpr, ObjectLit, Prop, PropName,
+  PropOrSpread,
+};
+use deno_ast::swc::common::{Span, Spanned};
+use deno_ast::swc::visit::{noop_visit_type, Node, Visit, VisitWith};
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct NoUselessSpread;
+
+const CODE: &str = "no-useless-spread";
+
+#[derive(Clone, Copy, Display)]
+enum NoUselessSpreadMessage {
+  #[display(
+    fmt = "Spreading an array literal into an array literal is unnecessary"
+  )]
+  ArrayInArray,
+  #[display(
+    fmt = "Spreading an object literal into an object literal is unnecessary"
+  )]
+  ObjectInObject,
+  #[display(fmt = "Spreading an array literal into arguments is unnecessary")]
+  ArrayInArgs,
+}
+
+#[derive(Display)]
+enum NoUselessSpreadHint {
+  #[display(fmt = "Use the elements of the array literal directly")]
+  InlineElements,
+  #[display(fmt = "Use the properties of the object literal directly")]
+  InlineProperties,
+}
+
+impl LintRule for NoUselessSpread {
+  fn new() -> Box<Self> {
+    Box::new(NoUselessSpread)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    let mut visitor = NoUselessSpreadVisitor::new(context);
+    match program {
+      ProgramRef::Module(m) => visitor.visit_module(m, &DUMMY_NODE),
+      ProgramRef::Script(s) => visitor.visit_script(s, &DUMMY_NODE),
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_useless_spread.md")
+  }
+}
+
+/// Returns the span covering all elements of the array literal, or `None` if
+/// the elements can't be inlined, like when the array is empty or has holes.
+fn inlinable_elements(array_lit: &ArrayLit) -> Option<Span> {
+  let mut elems = array_lit.elems.iter();
+  let first = elems.next()?.as_ref()?.span();
+  let mut last = first;
+  for elem in elems {
+    last = elem.as_ref()?.span();
+  }
+  Some(Span::new(first.lo, last.hi, Default::default()))
+}
+
+/// Checks if spreading the object literal behaves differently from writing its
+/// properties directly: getters and setters are invoked by the spread, and
+/// `__proto__: value` sets the prototype instead of defining a property.
+fn has_spread_specific_props(object_lit: &ObjectLit) -> bool {
+  object_lit.props.iter().any(|prop| match prop {
+    PropOrSpread::Prop(prop) => match &**prop {
+      Prop::Getter(_) | Prop::Setter(_) => true,
+      Prop::KeyValue(key_value) => match &key_value.key {
+        PropName::Ident(ident) => ident.sym == *"__proto__",
+        PropName::Str(str) => str.value == *"__proto__",
+        _ => false,
+      },
+      _ => false,
+    },
+    PropOrSpread::Spread(_) => false,
+  })
+}
+
+struct NoUselessSpreadVisitor<'c, 'view> {
+  context: &'c mut Context<'view>,
+}
+
+impl<'c, 'view> NoUselessSpreadVisitor<'c, 'view> {
+  fn new(context: &'c mut Context<'view>) -> Self {
+    Self { context }
+  }
+
+  fn report(
+    &mut self,
+    span: Span,
+    message: NoUselessSpreadMessage,
+    hint: NoUselessSpreadHint,
+    inner: Option<Span>,
+  ) {
+    match inner {
+      Some(inner) => {
+        let fixed = self.context.file_text_substring(&inner).to_string();
+        let fix = self.context.create_fix(span, fixed, &hint);
+        self
+          .context
+          .add_diagnostic_with_hint_and_fix(span, CODE, message, hint, fix);
+      }
+      None => {
+        self
+          .context
+          .add_diagnostic_with_hint(span, CODE, message, hint);
+      }
+    }
+  }
+
+  fn check_array_spreads<'a>(
+    &mut self,
+    elems: impl Iterator<Item = &'a ExprOrSpread>,
+    message: NoUselessSpreadMessage,
+  ) {
+    for elem in elems {
+      let dot3_token = match elem.spread {
+        Some(dot3_token) => dot3_token,
+        None => continue,
+      };
+      if let Expr::Array(array_lit) = &*elem.expr {
+        self.report(
+          dot3_token.to(array_lit.span),
+          message,
+          NoUselessSpreadHint::InlineElements,
+          inlinable_elements(array_lit),
+        );
+      }
+    }
+  }
+}
+
+impl<'c, 'view> Visit for NoUselessSpreadVisitor<'c, 'view> {
+  noop_visit_type!();
+
+  fn visit_array_lit(&mut self, array_lit: &ArrayLit, _: &dyn Node) {
+    array_lit.visit_children_with(self);
+
+    self.check_array_spreads(
+      array_lit.elems.iter().flatten(),
+      NoUselessSpreadMessage::ArrayInArray,
+    );
+  }
+
+  fn visit_object_lit(&mut self, object_lit: &ObjectLit, _: &dyn Node) {
+    object_lit.visit_children_with(self);
+
+    for prop in &object_lit.props {
+      let spread = match prop {
+        PropOrSpread::Spread(spread) => spread,
+        PropOrSpread::Prop(_) => continue,
+      };
+      let inner = match &*spread.expr {
+        Expr::Object(inner) if !has_spread_specific_props(inner) => inner,
+        _ => continue,
+      };
+      let props = match (inner.props.first(), inner.props.last()) {
+        (Some(first), Some(last)) => Some(Span::new(
+          first.span().lo,
+          last.span().hi,
+          Default::default(),
+        )),
+        _ => None,
+      };
+      self.report(
+        spread.span(),
+        NoUselessSpreadMessage::ObjectInObject,
+        NoUselessSpreadHint::InlineProperties,
+        props,
+      );
+    }
+  }
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _: &dyn Node) {
+    call_expr.visit_children_with(self);
+    self.check_array_spreads(
+      call_expr.args.iter(),
+      NoUselessSpreadMessage::ArrayInArgs,
+    );
+  }
+
+  fn visit_new_expr(&mut self, new_expr: &NewExpr, _: &dyn Node) {
+    new_expr.visit_children_with(self);
+    if let Some(args) = &new_expr.args {
+      self
+        .check_array_spreads(args.iter(), NoUselessSpreadMessage::ArrayInArgs);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_useless_spread_valid() {
+    assert_lint_ok! {
+      NoUselessSpread,
+      "const a = [...arr];",
+      "const a = [...arr, 1];",
+      "const a = { ...obj };",
+      "const a = { ...obj, b: 1 };",
+      "fn(...args);",
+      "fn(...[a, b].map(f));",
+      "new Foo(...args);",
+      "const a = [...'abc'];",
+      "const a = { ...{ get b() { return 1; } } };",
+      "const a = { ...{ __proto__: null } };",
+      "const a = { ...{ '__proto__': null } };",
+    };
+  }
+
+  #[test]
+  fn no_useless_spread_invalid() {
+    assert_lint_err! {
+      NoUselessSpread,
+      "const a = [...[1, 2, 3]];": [
+        {
+          col: 11,
+          message: NoUselessSpreadMessage::ArrayInArray,
+          hint: NoUselessSpreadHint::InlineElements,
+          fix: "const a = [1, 2, 3];",
+        },
+      ],
+      "const a = [0, ...[1, 2,], 3];": [
+        {
+          col: 14,
+          message: NoUselessSpreadMessage::ArrayInArray,
+          hint: NoUselessSpreadHint::InlineElements,
+          fix: "const a = [0, 1, 2, 3];",
+        },
+      ],
+      "const a = [...[...b, c]];": [
+        {
+          col: 11,
+          message: NoUselessSpreadMessage::ArrayInArray,
+          hint: NoUselessSpreadHint::InlineElements,
+          fix: "const a = [...b, c];",
+        },
+      ],
+      "const a = [...[, 1]];": [
+        {
+          col: 11,
+          message: NoUselessSpreadMessage::ArrayInArray,
+          hint: NoUselessSpreadHint::InlineElements,
+        },
+      ],
+      "const a = { ...{ b: 1, c } };": [
+        {
+          col: 12,
+          message: NoUselessSpreadMessage::ObjectInObject,
+          hint: NoUselessSpreadHint::InlineProperties,
+          fix: "const a = { b: 1, c };",
+        },
+      ],
+      "const a = { b: 0, ...{}, c: 1 };": [
+        {
+          col: 18,
+          message: NoUselessSpreadMessage::ObjectInObject,
+          hint: NoUselessSpreadHint::InlineProperties,
+        },
+      ],
+      "fn(...[a, b]);": [
+        {
+          col: 3,
+          message: NoUselessSpreadMessage::ArrayInArgs,
+          hint: NoUselessSpreadHint::InlineElements,
+          fix: "fn(a, b);",
+        },
+      ],
+      "obj.method(x, ...[y]);": [
+        {
+          col: 14,
+          message: NoUselessSpreadMessage::ArrayInArgs,
+          hint: NoUselessSpreadHint::InlineElements,
+          fix: "obj.method(x, y);",
+        },
+      ],
+      "new Foo(...[a]);": [
+        {
+          col: 8,
+          message: NoUselessSpreadMessage::ArrayInArgs,
+          hint: NoUselessSpreadHint::InlineElements,
+          fix: "new Foo(a);",
+        },
+      ],
+    };
+  }
+}
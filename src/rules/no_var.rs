@@ -1,17 +1,25 @@
 // Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
 use super::{Context, LintRule, DUMMY_NODE};
 use crate::ProgramRef;
-use deno_ast::swc::ast::VarDecl;
-use deno_ast::swc::ast::VarDeclKind;
+use deno_ast::swc::ast::{
+  ArrowExpr, BlockStmt, Decl, DoWhileStmt, ForInStmt, ForOfStmt, ForStmt,
+  Function, Ident, ModuleDecl, ModuleItem, Stmt, SwitchCase, VarDecl,
+  VarDeclKind, VarDeclOrExpr, VarDeclOrPat, WhileStmt,
+};
+use deno_ast::swc::common::{BytePos, Span};
+use deno_ast::swc::utils::find_ids;
+use deno_ast::swc::utils::ident::IdentLike;
 use deno_ast::swc::visit::noop_visit_type;
 use deno_ast::swc::visit::Node;
-use deno_ast::swc::visit::Visit;
+use deno_ast::swc::visit::{Visit, VisitAll, VisitAllWith, VisitWith};
+use std::collections::HashSet;
 
 #[derive(Debug)]
 pub struct NoVar;
 
 const MESSAGE: &str = "`var` keyword is not allowed.";
 const CODE: &str = "no-var";
+const FIX_DESC: &str = "Replace `var` with `let`";
 
 impl LintRule for NoVar {
   fn new() -> Box<Self> {
@@ -31,7 +39,15 @@ impl LintRule for NoVar {
     context: &mut Context<'view>,
     program: ProgramRef<'view>,
   ) {
-    let mut visitor = NoVarVisitor::new(context);
+    let mut collector = FunctionCollector::default();
+    match program {
+      ProgramRef::Module(m) => m.visit_all_with(&DUMMY_NODE, &mut collector),
+      ProgramRef::Script(s) => s.visit_all_with(&DUMMY_NODE, &mut collector),
+    }
+
+    let is_module = matches!(program, ProgramRef::Module(_));
+    let mut visitor =
+      NoVarVisitor::new(context, collector.functions, is_module);
     match program {
       ProgramRef::Module(m) => visitor.visit_module(m, &DUMMY_NODE),
       ProgramRef::Script(s) => visitor.visit_script(s, &DUMMY_NODE),
@@ -44,13 +60,147 @@ impl LintRule for NoVar {
   }
 }
 
+/// Collects the spans of all functions, which are needed to find out whether
+/// a variable declared in a loop is captured by a closure.
+#[derive(Default)]
+struct FunctionCollector {
+  functions: Vec<Span>,
+}
+
+impl VisitAll for FunctionCollector {
+  noop_visit_type!();
+
+  fn visit_function(&mut self, function: &Function, _: &dyn Node) {
+    self.functions.push(function.span);
+  }
+
+  fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr, _: &dyn Node) {
+    self.functions.push(arrow_expr.span);
+  }
+}
+
 struct NoVarVisitor<'c, 'view> {
   context: &'c mut Context<'view>,
+  functions: Vec<Span>,
+  is_module: bool,
+  /// `var` declarations placed where a `let` declaration is allowed too, i.e.
+  /// in a statement list or in the head of a `for` statement
+  replaceable: HashSet<Span>,
+  blocks: Vec<Span>,
+  /// Loops enclosing the current position within the current function
+  loops: Vec<Span>,
 }
 
 impl<'c, 'view> NoVarVisitor<'c, 'view> {
-  fn new(context: &'c mut Context<'view>) -> Self {
-    Self { context }
+  fn new(
+    context: &'c mut Context<'view>,
+    functions: Vec<Span>,
+    is_module: bool,
+  ) -> Self {
+    Self {
+      context,
+      functions,
+      is_module,
+      replaceable: HashSet::new(),
+      blocks: Vec::new(),
+      loops: Vec::new(),
+    }
+  }
+
+  fn with_block<F>(&mut self, span: Span, is_loop: bool, op: F)
+  where
+    F: FnOnce(&mut Self),
+  {
+    self.blocks.push(span);
+    if is_loop {
+      self.loops.push(span);
+    }
+    op(self);
+    if is_loop {
+      self.loops.pop();
+    }
+    self.blocks.pop();
+  }
+
+  fn with_function<F>(&mut self, op: F)
+  where
+    F: FnOnce(&mut Self),
+  {
+    let loops = std::mem::take(&mut self.loops);
+    op(self);
+    self.loops = loops;
+  }
+
+  fn mark_replaceable_stmt(&mut self, stmt: &Stmt) {
+    if let Stmt::Decl(Decl::Var(var_decl)) = stmt {
+      self.replaceable.insert(var_decl.span);
+    }
+  }
+
+  /// Checks whether replacing `var` with `let` keeps the behavior of the code.
+  fn is_fixable(&self, var_decl: &VarDecl, keyword: Span) -> bool {
+    if !self.replaceable.contains(&var_decl.span)
+      || (!self.is_module && self.blocks.is_empty())
+      || self.context.file_text_substring(&keyword) != "var"
+    {
+      return false;
+    }
+
+    // `for` statements are pushed as both a block and a loop
+    let in_loop_head =
+      !self.loops.is_empty() && self.loops.last() == self.blocks.last();
+
+    var_decl.decls.iter().all(|decl| {
+      // Each iteration of a loop starts with a fresh `let` binding, so an
+      // uninitialized one wouldn't keep the value of the previous iteration
+      if decl.init.is_none() && !self.loops.is_empty() && !in_loop_head {
+        return false;
+      }
+      let idents: Vec<Ident> = find_ids(&decl.name);
+      idents
+        .iter()
+        .all(|ident| self.is_fixable_ident(ident, decl.span))
+    })
+  }
+
+  fn is_fixable_ident(&self, ident: &Ident, decl_span: Span) -> bool {
+    if ident.sym == *"let" {
+      return false;
+    }
+
+    let scope = self.context.scope();
+    let id = ident.to_id();
+    let declarations = scope
+      .ids_with_symbol(&ident.sym)
+      .map_or(0, |ids| ids.iter().filter(|i| **i == id).count());
+    if declarations != 1 {
+      return false;
+    }
+    let var = match scope.var(&id) {
+      Some(var) => var,
+      None => return false,
+    };
+
+    var.references().iter().all(|reference| {
+      let reference = reference.span();
+      // used before the declaration would be in the temporal dead zone
+      if reference.lo < decl_span.hi {
+        return false;
+      }
+      // used outside of the block
+      if let Some(block) = self.blocks.last() {
+        if !block.contains(reference) {
+          return false;
+        }
+      }
+      // closures capture the binding of each iteration separately
+      match self.loops.last() {
+        Some(loop_span) => !self.functions.iter().any(|function| {
+          loop_span.contains(*function) && function.contains(reference)
+        }),
+        None => true,
+      }
+    })
   }
 }
 
@@ -59,9 +209,100 @@ impl<'c, 'view> Visit for NoVarVisitor<'c, 'view> {
 
   fn visit_var_decl(&mut self, var_decl: &VarDecl, _parent: &dyn Node) {
     if var_decl.kind == VarDeclKind::Var {
-      self.context.add_diagnostic(var_decl.span, CODE, MESSAGE);
+      let keyword = Span::new(
+        var_decl.span.lo,
+        var_decl.span.lo + BytePos(3),
+        var_decl.span.ctxt,
+      );
+      if self.is_fixable(var_decl, keyword) {
+        let fix = self.context.create_fix(keyword, "let", FIX_DESC);
+        self
+          .context
+          .add_diagnostic_with_fix(var_decl.span, CODE, MESSAGE, fix);
+      } else {
+        self.context.add_diagnostic(var_decl.span, CODE, MESSAGE);
+      }
+    }
+    var_decl.visit_children_with(self);
+  }
+
+  fn visit_stmts(&mut self, stmts: &[Stmt], _: &dyn Node) {
+    for stmt in stmts {
+      self.mark_replaceable_stmt(stmt);
+      stmt.visit_with(&DUMMY_NODE, self);
+    }
+  }
+
+  fn visit_module_items(&mut self, items: &[ModuleItem], _: &dyn Node) {
+    for item in items {
+      match item {
+        ModuleItem::Stmt(stmt) => self.mark_replaceable_stmt(stmt),
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => {
+          if let Decl::Var(var_decl) = &export_decl.decl {
+            self.replaceable.insert(var_decl.span);
+          }
+        }
+        _ => {}
+      }
+      item.visit_with(&DUMMY_NODE, self);
     }
   }
+
+  fn visit_switch_case(&mut self, switch_case: &SwitchCase, _: &dyn Node) {
+    // `let` declarations in a case clause are shared by all the other cases,
+    // so the statements are visited one by one without being marked
+    switch_case.test.visit_with(switch_case, self);
+    for stmt in &switch_case.cons {
+      stmt.visit_with(switch_case, self);
+    }
+  }
+
+  fn visit_block_stmt(&mut self, block_stmt: &BlockStmt, _: &dyn Node) {
+    self.with_block(block_stmt.span, false, |v| {
+      block_stmt.visit_children_with(v)
+    });
+  }
+
+  fn visit_function(&mut self, function: &Function, _: &dyn Node) {
+    self.with_function(|v| function.visit_children_with(v));
+  }
+
+  fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr, _: &dyn Node) {
+    self.with_function(|v| arrow_expr.visit_children_with(v));
+  }
+
+  fn visit_for_stmt(&mut self, for_stmt: &ForStmt, _: &dyn Node) {
+    if let Some(VarDeclOrExpr::VarDecl(var_decl)) = &for_stmt.init {
+      self.replaceable.insert(var_decl.span);
+    }
+    self.with_block(for_stmt.span, true, |v| for_stmt.visit_children_with(v));
+  }
+
+  fn visit_for_in_stmt(&mut self, for_in: &ForInStmt, _: &dyn Node) {
+    if let VarDeclOrPat::VarDecl(var_decl) = &for_in.left {
+      self.replaceable.insert(var_decl.span);
+    }
+    self.with_block(for_in.span, true, |v| for_in.visit_children_with(v));
+  }
+
+  fn visit_for_of_stmt(&mut self, for_of: &ForOfStmt, _: &dyn Node) {
+    if let VarDeclOrPat::VarDecl(var_decl) = &for_of.left {
+      self.replaceable.insert(var_decl.span);
+    }
+    self.with_block(for_of.span, true, |v| for_of.visit_children_with(v));
+  }
+
+  fn visit_while_stmt(&mut self, while_stmt: &WhileStmt, _: &dyn Node) {
+    self.loops.push(while_stmt.span);
+    while_stmt.visit_children_with(self);
+    self.loops.pop();
+  }
+
+  fn visit_do_while_stmt(&mut self, do_while: &DoWhileStmt, _: &dyn Node) {
+    self.loops.push(do_while.span);
+    do_while.visit_children_with(self);
+    self.loops.pop();
+  }
 }
 
 #[cfg(test)]
@@ -97,4 +338,76 @@ mod tests {
       ]
     );
   }
+
+  #[test]
+  fn no_var_fix() {
+    assert_lint_err! {
+      NoVar,
+      "function f() { var a = 1, b = a; return b; }": [
+        {
+          col: 15,
+          message: MESSAGE,
+          fix: "function f() { let a = 1, b = a; return b; }",
+        },
+      ],
+      "var a = 1; console.log(a); export {};": [
+        {
+          col: 0,
+          message: MESSAGE,
+          fix: "let a = 1; console.log(a); export {};",
+        },
+      ],
+      "export var a = 1;": [
+        {
+          col: 7,
+          message: MESSAGE,
+          fix: "export let a = 1;",
+        },
+      ],
+      "function f(xs) { for (var i = 0; i < xs.length; i++) { xs[i]; } }": [
+        {
+          col: 22,
+          message: MESSAGE,
+          fix: "function f(xs) { for (let i = 0; i < xs.length; i++) { xs[i]; } }",
+        },
+      ],
+      "function f(o) { for (var k in o) { console.log(k); } }": [
+        {
+          col: 21,
+          message: MESSAGE,
+          fix: "function f(o) { for (let k in o) { console.log(k); } }",
+        },
+      ],
+      // global object property in a script
+      "var a = 1; console.log(a);": [{ col: 0, message: MESSAGE }],
+      // redeclared
+      "function f() { var a = 1; var a = 2; }": [
+        { col: 15, message: MESSAGE },
+        { col: 26, message: MESSAGE },
+      ],
+      // used before the declaration
+      "function f() { a = 0; var a; }": [{ col: 22, message: MESSAGE }],
+      "function f() { var a = a; }": [{ col: 15, message: MESSAGE }],
+      // used outside of the block
+      "function f(c) { if (c) { var a = 1; } return a; }": [
+        { col: 25, message: MESSAGE },
+      ],
+      "function f(xs) { for (var i = 0; i < xs.length; i++) {} return i; }": [
+        { col: 22, message: MESSAGE },
+      ],
+      // captured by a closure in a loop
+      "function f(fs) { for (var i = 0; i < 3; i++) { fs.push(() => i); } }": [
+        { col: 22, message: MESSAGE },
+      ],
+      // uninitialized in a loop
+      "function f(c) { while (c) { var a; if (a) {} a = 1; } }": [
+        { col: 28, message: MESSAGE },
+      ],
+      // `let` is not allowed as a statement of `if` or in a case clause
+      "function f(c) { if (c) var a = 1; }": [{ col: 23, message: MESSAGE }],
+      "function f(c) { switch (c) { case 0: var a = 1; } }": [
+        { col: 37, message: MESSAGE },
+      ],
+    };
+  }
 }
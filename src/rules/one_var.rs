@@ -0,0 +1,273 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{Decl, ModuleItem, Stmt, VarDecl, VarDeclKind};
+use deno_ast::view as ast_view;
+use derive_more::Display;
+use serde::Deserialize;
+
+#[derive(Debug, Default)]
+pub struct OneVar {
+  options: OneVarOptions,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct OneVarOptions {
+  /// Style of `var` declarations.
+  pub var: OneVarMode,
+  /// Style of `let` declarations.
+  #[serde(rename = "let")]
+  pub let_: OneVarMode,
+  /// Style of `const` declarations.
+  #[serde(rename = "const")]
+  pub const_: OneVarMode,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OneVarMode {
+  /// Consecutive declarations of the same kind must be combined into one.
+  Always,
+  /// Each declaration must declare a single variable.
+  Never,
+}
+
+impl Default for OneVarMode {
+  fn default() -> Self {
+    OneVarMode::Always
+  }
+}
+
+impl OneVarOptions {
+  fn mode(&self, kind: VarDeclKind) -> OneVarMode {
+    match kind {
+      VarDeclKind::Var => self.var,
+      VarDeclKind::Let => self.let_,
+      VarDeclKind::Const => self.const_,
+    }
+  }
+}
+
+const CODE: &str = "one-var";
+
+#[derive(Display)]
+enum OneVarMessage {
+  #[display(fmt = "Split the `{}` declarations into separate statements", _0)]
+  Split(VarDeclKind),
+  #[display(fmt = "Combine this with the previous `{}` statement", _0)]
+  Combine(VarDeclKind),
+}
+
+impl OneVar {
+  pub fn with_options(options: OneVarOptions) -> Box<Self> {
+    Box::new(OneVar { options })
+  }
+}
+
+impl LintRule for OneVar {
+  fn new() -> Box<Self> {
+    Box::new(OneVar::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    OneVarHandler::new(&self.options).traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/one_var.md")
+  }
+}
+
+fn stmt_var_decl(stmt: &Stmt) -> Option<&VarDecl> {
+  match stmt {
+    Stmt::Decl(Decl::Var(var_decl)) if !var_decl.declare => Some(var_decl),
+    _ => None,
+  }
+}
+
+fn module_item_var_decl(item: &ModuleItem) -> Option<&VarDecl> {
+  match item {
+    ModuleItem::Stmt(stmt) => stmt_var_decl(stmt),
+    ModuleItem::ModuleDecl(_) => None,
+  }
+}
+
+struct OneVarHandler<'o> {
+  options: &'o OneVarOptions,
+}
+
+impl<'o> OneVarHandler<'o> {
+  fn new(options: &'o OneVarOptions) -> Self {
+    Self { options }
+  }
+
+  /// Reports declarations that directly follow another declaration of the
+  /// same kind in a statement list. `None` stands for any other statement.
+  fn check_consecutive<'a>(
+    &self,
+    var_decls: impl Iterator<Item = Option<&'a VarDecl>>,
+    ctx: &mut Context,
+  ) {
+    let mut prev_kind = None;
+    for var_decl in var_decls {
+      let kind = var_decl.map(|var_decl| var_decl.kind);
+      if let (Some(var_decl), Some(prev_kind)) = (var_decl, prev_kind) {
+        if var_decl.kind == prev_kind
+          && self.options.mode(var_decl.kind) == OneVarMode::Always
+        {
+          ctx.add_diagnostic(
+            var_decl.span,
+            CODE,
+            OneVarMessage::Combine(var_decl.kind),
+          );
+        }
+      }
+      prev_kind = kind;
+    }
+  }
+}
+
+impl<'o> Handler for OneVarHandler<'o> {
+  fn var_decl(&mut self, var_decl: &ast_view::VarDecl, ctx: &mut Context) {
+    // Declarations in the head of `for` loops can't be split
+    if matches!(
+      var_decl.parent(),
+      ast_view::Node::ForStmt(_)
+        | ast_view::Node::ForInStmt(_)
+        | ast_view::Node::ForOfStmt(_)
+    ) {
+      return;
+    }
+
+    let kind = var_decl.inner.kind;
+    if var_decl.inner.decls.len() > 1
+      && self.options.mode(kind) == OneVarMode::Never
+    {
+      ctx.add_diagnostic(var_decl.inner.span, CODE, OneVarMessage::Split(kind));
+    }
+  }
+
+  fn module(&mut self, module: &ast_view::Module, ctx: &mut Context) {
+    self.check_consecutive(
+      module.inner.body.iter().map(module_item_var_decl),
+      ctx,
+    );
+  }
+
+  fn script(&mut self, script: &ast_view::Script, ctx: &mut Context) {
+    self.check_consecutive(script.inner.body.iter().map(stmt_var_decl), ctx);
+  }
+
+  fn ts_module_block(
+    &mut self,
+    ts_module_block: &ast_view::TsModuleBlock,
+    ctx: &mut Context,
+  ) {
+    self.check_consecutive(
+      ts_module_block.inner.body.iter().map(module_item_var_decl),
+      ctx,
+    );
+  }
+
+  fn block_stmt(
+    &mut self,
+    block_stmt: &ast_view::BlockStmt,
+    ctx: &mut Context,
+  ) {
+    self
+      .check_consecutive(block_stmt.inner.stmts.iter().map(stmt_var_decl), ctx);
+  }
+
+  fn switch_case(
+    &mut self,
+    switch_case: &ast_view::SwitchCase,
+    ctx: &mut Context,
+  ) {
+    self
+      .check_consecutive(switch_case.inner.cons.iter().map(stmt_var_decl), ctx);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  #[test]
+  fn one_var_valid() {
+    assert_lint_ok! {
+      OneVar,
+      "let a = 1, b = 2;",
+      "var a; let b; const c = 1;",
+      "let a; foo(); let b;",
+      "function f() { let a, b; }",
+      "let a; function f() { let b; }",
+      "for (let i = 0, j = 0; ; ) {}",
+      "declare let a: number; declare let b: number;",
+    };
+
+    assert_lint_ok! {
+      OneVar,
+      options: json!({ "var": "never", "let": "never", "const": "never" }),
+      "let a; let b;",
+      "const a = 1; const b = 2;",
+      "var a; var b;",
+      "for (let i = 0, j = 0; ; ) {}",
+    };
+
+    assert_lint_ok! {
+      OneVar,
+      options: json!({ "let": "never" }),
+      "let a; let b; const c = 1, d = 2;",
+    };
+  }
+
+  #[test]
+  fn one_var_invalid() {
+    assert_lint_err! {
+      OneVar,
+      "let a; let b;": [
+        { col: 7, message: OneVarMessage::Combine(VarDeclKind::Let) },
+      ],
+      "const a = 1; const b = 2; const c = 3;": [
+        { col: 13, message: OneVarMessage::Combine(VarDeclKind::Const) },
+        { col: 26, message: OneVarMessage::Combine(VarDeclKind::Const) },
+      ],
+      "function f() { var a; var b; }": [
+        { col: 22, message: OneVarMessage::Combine(VarDeclKind::Var) },
+      ],
+      "switch (x) { case 0: let a; let b; }": [
+        { col: 28, message: OneVarMessage::Combine(VarDeclKind::Let) },
+      ],
+    };
+
+    assert_lint_err! {
+      OneVar,
+      options: json!({ "var": "never", "let": "never", "const": "never" }),
+      "let a, b;": [
+        { col: 0, message: OneVarMessage::Split(VarDeclKind::Let) },
+      ],
+      "function f() { const a = 1, b = 2; }": [
+        { col: 15, message: OneVarMessage::Split(VarDeclKind::Const) },
+      ],
+      "var a = 1, b, c;": [
+        { col: 0, message: OneVarMessage::Split(VarDeclKind::Var) },
+      ],
+    };
+  }
+}
@@ -2,9 +2,10 @@
 use super::{Context, LintRule, DUMMY_NODE};
 use crate::ProgramRef;
 use deno_ast::swc::ast::{
-  ArrayPat, BindingIdent, Expr, Lit, ObjectPat, Pat, TsAsExpr, TsLit, TsType,
-  TsTypeAnn, TsTypeAssertion, VarDecl,
+  ArrayPat, BindingIdent, ClassProp, Expr, Lit, ObjectPat, Pat, PrivateProp,
+  TplElement, TsAsExpr, TsLit, TsType, TsTypeAnn, TsTypeAssertion, VarDecl,
 };
+use deno_ast::swc::atoms::JsWord;
 use deno_ast::swc::common::{Span, Spanned};
 use deno_ast::swc::visit::Node;
 use deno_ast::swc::visit::{VisitAll, VisitAllWith};
@@ -61,6 +62,15 @@ impl LintRule for PreferAsConst {
   }
 }
 
+/// Returns the value of a part of a template literal, with its escapes
+/// interpreted if they are valid.
+fn tpl_element_value(element: &TplElement) -> &JsWord {
+  match &element.cooked {
+    Some(cooked) => &cooked.value,
+    None => &element.raw.value,
+  }
+}
+
 struct PreferAsConstVisitor<'c, 'view> {
   context: &'c mut Context<'view>,
 }
@@ -81,20 +91,33 @@ impl<'c, 'view> PreferAsConstVisitor<'c, 'view> {
 
   fn compare(&mut self, type_ann: &TsType, expr: &Expr, span: Span) {
     if let TsType::TsLitType(lit_type) = &*type_ann {
-      if let Expr::Lit(expr_lit) = &*expr {
-        match (expr_lit, &lit_type.lit) {
-          (Lit::Str(value_literal), TsLit::Str(type_literal)) => {
-            if value_literal.value == type_literal.value {
-              self.add_diagnostic_helper(span)
-            }
+      match (expr, &lit_type.lit) {
+        (Expr::Lit(Lit::Str(value_literal)), TsLit::Str(type_literal)) => {
+          if value_literal.value == type_literal.value {
+            self.add_diagnostic_helper(span)
+          }
+        }
+        (Expr::Lit(Lit::Num(value_literal)), TsLit::Number(type_literal)) => {
+          if (value_literal.value - type_literal.value).abs() < f64::EPSILON {
+            self.add_diagnostic_helper(span)
+          }
+        }
+        (Expr::Lit(Lit::Bool(value_literal)), TsLit::Bool(type_literal)) => {
+          if value_literal.value == type_literal.value {
+            self.add_diagnostic_helper(span)
           }
-          (Lit::Num(value_literal), TsLit::Number(type_literal)) => {
-            if (value_literal.value - type_literal.value).abs() < f64::EPSILON {
-              self.add_diagnostic_helper(span)
-            }
+        }
+        (Expr::Tpl(value_literal), TsLit::Tpl(type_literal))
+          if value_literal.exprs.is_empty()
+            && type_literal.types.is_empty() =>
+        {
+          let value_quasis = value_literal.quasis.iter().map(tpl_element_value);
+          let type_quasis = type_literal.quasis.iter().map(tpl_element_value);
+          if value_quasis.eq(type_quasis) {
+            self.add_diagnostic_helper(span)
           }
-          _ => return,
         }
+        _ => {}
       }
     }
   }
@@ -131,6 +154,22 @@ impl<'c, 'view> VisitAll for PreferAsConstVisitor<'c, 'view> {
       }
     }
   }
+
+  fn visit_class_prop(&mut self, class_prop: &ClassProp, _: &dyn Node) {
+    if let (Some(TsTypeAnn { type_ann, .. }), Some(value)) =
+      (&class_prop.type_ann, &class_prop.value)
+    {
+      self.compare(type_ann, value, type_ann.span());
+    }
+  }
+
+  fn visit_private_prop(&mut self, private_prop: &PrivateProp, _: &dyn Node) {
+    if let (Some(TsTypeAnn { type_ann, .. }), Some(value)) =
+      (&private_prop.type_ann, &private_prop.value)
+    {
+      self.compare(type_ann, value, type_ann.span());
+    }
+  }
 }
 
 #[cfg(test)]
@@ -150,14 +189,20 @@ mod tests {
       "let foo = <bar>'bar';",
       "let foo = <string>'bar';",
       "let foo = 'bar' as string;",
-      "let foo = `bar` as `bar`;",
       "let foo = `bar` as `foo`;",
+      "let foo = `bar${baz}` as `bar${baz}`;",
+      "let foo: `bar${string}` = `bar${baz}`;",
+      "let foo: true = false;",
+      "let foo: boolean = true;",
+      "let foo = true as const;",
       "let foo = `bar` as 'bar';",
       "let foo: string = 'bar';",
       "let foo: number = 1;",
       "let foo: 'bar' = baz;",
       "let foo = 'bar';",
-      "class foo { bar: 'baz' = 'baz'; }",
+      "class foo { bar: string = 'baz'; }",
+      "class foo { bar: 'baz'; }",
+      "class foo { bar: 'baz' = 'qux' as any; }",
       "class foo { bar = 'baz'; }",
       "let foo: 'bar';",
       "let foo = { bar };",
@@ -271,6 +316,57 @@ mod tests {
         }
       ],
 
+      "class foo { bar: 'baz' = 'baz'; }": [
+        {
+          col: 17,
+          message: PreferAsConstMessage::ExpectedConstAssertion,
+          hint: PreferAsConstHint::AddAsConst,
+        }
+      ],
+      "class foo { static readonly x: 5 = 5; }": [
+        {
+          col: 31,
+          message: PreferAsConstMessage::ExpectedConstAssertion,
+          hint: PreferAsConstHint::AddAsConst,
+        }
+      ],
+      "class foo { #bar: 'baz' = 'baz'; }": [
+        {
+          col: 18,
+          message: PreferAsConstMessage::ExpectedConstAssertion,
+          hint: PreferAsConstHint::AddAsConst,
+        }
+      ],
+
+      "let foo = `bar` as `bar`;": [
+        {
+          col: 19,
+          message: PreferAsConstMessage::ExpectedConstAssertion,
+          hint: PreferAsConstHint::AddAsConst,
+        }
+      ],
+      "let foo: `bar` = `bar`;": [
+        {
+          col: 9,
+          message: PreferAsConstMessage::ExpectedConstAssertion,
+          hint: PreferAsConstHint::AddAsConst,
+        }
+      ],
+      "let foo: true = true;": [
+        {
+          col: 9,
+          message: PreferAsConstMessage::ExpectedConstAssertion,
+          hint: PreferAsConstHint::AddAsConst,
+        }
+      ],
+      "let foo = false as false;": [
+        {
+          col: 19,
+          message: PreferAsConstMessage::ExpectedConstAssertion,
+          hint: PreferAsConstHint::AddAsConst,
+        }
+      ],
+
       // nested
       "let foo = () => { let x: 'x' = 'x'; };": [
         {
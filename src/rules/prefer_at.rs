@@ -0,0 +1,247 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::ast_eq::expr_eq;
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{BinaryOp, Expr, ExprOrSuper, Lit, UnaryOp};
+use deno_ast::swc::common::Spanned;
+use deno_ast::view as ast_view;
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct PreferAt;
+
+const CODE: &str = "prefer-at";
+const MESSAGE: &str =
+  "Prefer `.at()` over indexing with `length` to access elements from the end";
+
+#[derive(Display)]
+enum PreferAtHint {
+  #[display(fmt = "Use `.at(-{})` instead", _0)]
+  UseAt(u64),
+}
+
+impl LintRule for PreferAt {
+  fn new() -> Box<Self> {
+    Box::new(PreferAt)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    PreferAtHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/prefer_at.md")
+  }
+}
+
+/// Checks if the expression can be evaluated twice without side effects, like
+/// `foo`, `this.foo` or `foo.bar[0]`.
+pub(super) fn is_simple(expr: &Expr) -> bool {
+  match expr {
+    Expr::Ident(_) | Expr::This(_) => true,
+    Expr::Paren(paren) => is_simple(&paren.expr),
+    Expr::Member(member) => {
+      let is_simple_prop =
+        !member.computed || matches!(&*member.prop, Expr::Lit(_));
+      match &member.obj {
+        ExprOrSuper::Expr(obj) => is_simple_prop && is_simple(obj),
+        ExprOrSuper::Super(_) => false,
+      }
+    }
+    _ => false,
+  }
+}
+
+/// If `index` is like `obj.length - n` for a positive integer `n` and the
+/// given `obj`, returns `n`.
+fn offset_from_end(index: &Expr, obj: &Expr) -> Option<u64> {
+  let bin = match index {
+    Expr::Bin(bin) if bin.op == BinaryOp::Sub => bin,
+    _ => return None,
+  };
+  let length_obj = match &*bin.left {
+    Expr::Member(member) if !member.computed => {
+      match (&member.obj, &*member.prop) {
+        (ExprOrSuper::Expr(length_obj), Expr::Ident(prop))
+          if prop.sym == *"length" =>
+        {
+          length_obj
+        }
+        _ => return None,
+      }
+    }
+    _ => return None,
+  };
+  let n = match &*bin.right {
+    Expr::Lit(Lit::Num(num))
+      if num.value >= 1.0 && num.value.fract() == 0.0 =>
+    {
+      num.value as u64
+    }
+    _ => return None,
+  };
+
+  let is_same_obj = is_simple(obj) && expr_eq(obj, length_obj);
+  if is_same_obj {
+    Some(n)
+  } else {
+    None
+  }
+}
+
+/// Checks if the member expression is written to, where it can't be replaced
+/// with a call.
+fn is_write_target(member_expr: &ast_view::MemberExpr) -> bool {
+  let span = member_expr.inner.span;
+  match member_expr.parent() {
+    ast_view::Node::AssignExpr(assign) => assign.inner.left.span() == span,
+    ast_view::Node::AssignPat(assign) => assign.inner.left.span() == span,
+    ast_view::Node::ForInStmt(for_in) => for_in.inner.left.span() == span,
+    ast_view::Node::ForOfStmt(for_of) => for_of.inner.left.span() == span,
+    ast_view::Node::UnaryExpr(unary) => unary.inner.op == UnaryOp::Delete,
+    ast_view::Node::UpdateExpr(_)
+    | ast_view::Node::ArrayPat(_)
+    | ast_view::Node::ObjectPat(_)
+    | ast_view::Node::KeyValuePatProp(_)
+    | ast_view::Node::RestPat(_) => true,
+    // `obj?.[obj.length - 1]` is left as is for simplicity
+    ast_view::Node::OptChainExpr(_) => true,
+    _ => false,
+  }
+}
+
+struct PreferAtHandler;
+
+impl Handler for PreferAtHandler {
+  fn member_expr(
+    &mut self,
+    member_expr: &ast_view::MemberExpr,
+    ctx: &mut Context,
+  ) {
+    if !member_expr.inner.computed {
+      return;
+    }
+    let obj = match &member_expr.inner.obj {
+      ExprOrSuper::Expr(obj) => obj,
+      ExprOrSuper::Super(_) => return,
+    };
+    let n = match offset_from_end(&member_expr.inner.prop, obj) {
+      Some(n) => n,
+      None => return,
+    };
+    if is_write_target(member_expr) {
+      return;
+    }
+
+    let fixed = format!("{}.at(-{})", ctx.file_text_substring(&obj.span()), n);
+    let fix =
+      ctx.create_fix(member_expr.inner.span, fixed, "Replace with `.at()`");
+    ctx.add_diagnostic_with_hint_and_fix(
+      member_expr.inner.span,
+      CODE,
+      MESSAGE,
+      PreferAtHint::UseAt(n),
+      fix,
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_at_valid() {
+    assert_lint_ok! {
+      PreferAt,
+      "arr.at(-1);",
+      "arr[0];",
+      "arr[arr.length];",
+      "arr[arr.length + 1];",
+      "arr[other.length - 1];",
+      "arr[arr.length - i];",
+      "arr[arr.length - 0];",
+      "arr[arr.length - 1.5];",
+      "arr[arr['length'] - 1];",
+      "arr.length - 1;",
+      "foo()[foo().length - 1];",
+      "arr[arr.length - 1] = 1;",
+      "arr[arr.length - 1] += 1;",
+      "arr[arr.length - 1]++;",
+      "delete arr[arr.length - 1];",
+      "[arr[arr.length - 1]] = [1];",
+      "({ a: arr[arr.length - 1] } = obj);",
+      "for (arr[arr.length - 1] of xs) {}",
+      "arr?.[arr.length - 1];",
+    };
+  }
+
+  #[test]
+  fn prefer_at_invalid() {
+    assert_lint_err! {
+      PreferAt,
+      "arr[arr.length - 1];": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: PreferAtHint::UseAt(1),
+          fix: "arr.at(-1);",
+        }
+      ],
+      "const last = this.items[this.items.length - 2];": [
+        {
+          col: 13,
+          message: MESSAGE,
+          hint: PreferAtHint::UseAt(2),
+          fix: "const last = this.items.at(-2);",
+        }
+      ],
+      "foo(a.b[0][a.b[0].length - 1]);": [
+        {
+          col: 4,
+          message: MESSAGE,
+          hint: PreferAtHint::UseAt(1),
+          fix: "foo(a.b[0].at(-1));",
+        }
+      ],
+      "arr[arr.length - 1].foo = 1;": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: PreferAtHint::UseAt(1),
+          fix: "arr.at(-1).foo = 1;",
+        }
+      ],
+      "x = arr[arr.length - 1];": [
+        {
+          col: 4,
+          message: MESSAGE,
+          hint: PreferAtHint::UseAt(1),
+          fix: "x = arr.at(-1);",
+        }
+      ],
+      "for (const x of arr[arr.length - 1]) {}": [
+        {
+          col: 16,
+          message: MESSAGE,
+          hint: PreferAtHint::UseAt(1),
+          fix: "for (const x of arr.at(-1)) {}",
+        }
+      ],
+    };
+  }
+}
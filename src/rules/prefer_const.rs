@@ -9,19 +9,50 @@ use deno_ast::swc::ast::{
   VarDeclKind, VarDeclOrExpr, VarDeclOrPat, WhileStmt, WithStmt,
 };
 use deno_ast::swc::atoms::JsWord;
-use deno_ast::swc::common::{Span, Spanned};
+use deno_ast::swc::common::{BytePos, Span, Spanned};
 use deno_ast::swc::utils::find_ids;
 use deno_ast::swc::visit::noop_visit_type;
 use deno_ast::swc::visit::{Node, Visit, VisitWith};
 use derive_more::Display;
+use serde::Deserialize;
 use std::cell::RefCell;
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::iter;
 use std::mem;
 use std::rc::Rc;
 
-#[derive(Debug)]
-pub struct PreferConst;
+#[derive(Debug, Default)]
+pub struct PreferConst {
+  options: PreferConstOptions,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PreferConstOptions {
+  /// How variables declared or assigned together by destructuring are
+  /// handled.
+  pub destructuring: PreferConstDestructuring,
+  /// Whether variables which are read before their first assignment are
+  /// ignored.
+  pub ignore_read_before_assign: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PreferConstDestructuring {
+  /// Each variable is reported on its own, even if others in the same
+  /// destructuring are reassigned.
+  Any,
+  /// Variables are reported only if all of the variables in the same
+  /// destructuring can be `const`.
+  All,
+}
+
+impl Default for PreferConstDestructuring {
+  fn default() -> Self {
+    PreferConstDestructuring::All
+  }
+}
 
 const CODE: &str = "prefer-const";
 
@@ -37,9 +68,15 @@ enum PreferConstHint {
   UseConst,
 }
 
+impl PreferConst {
+  pub fn with_options(options: PreferConstOptions) -> Box<Self> {
+    Box::new(PreferConst { options })
+  }
+}
+
 impl LintRule for PreferConst {
   fn new() -> Box<Self> {
-    Box::new(PreferConst)
+    Box::new(PreferConst::default())
   }
 
   fn tags(&self) -> &'static [&'static str] {
@@ -55,7 +92,8 @@ impl LintRule for PreferConst {
     context: &mut Context<'view>,
     program: ProgramRef<'view>,
   ) {
-    let mut collector = VariableCollector::new();
+    let mut collector =
+      VariableCollector::with_destructuring(self.options.destructuring);
     match program {
       ProgramRef::Module(m) => collector.visit_module(m, &DUMMY_NODE),
       ProgramRef::Script(s) => collector.visit_script(s, &DUMMY_NODE),
@@ -63,6 +101,7 @@ impl LintRule for PreferConst {
 
     let mut visitor = PreferConstVisitor::new(
       context,
+      &self.options,
       mem::take(&mut collector.scopes),
       mem::take(&mut collector.var_groups),
     );
@@ -258,14 +297,21 @@ struct VariableCollector {
   scopes: BTreeMap<ScopeRange, Scope>,
   cur_scope: ScopeRange,
   var_groups: DisjointSet,
+  destructuring: PreferConstDestructuring,
 }
 
 impl VariableCollector {
+  #[cfg(test)]
   fn new() -> Self {
+    Self::with_destructuring(PreferConstDestructuring::default())
+  }
+
+  fn with_destructuring(destructuring: PreferConstDestructuring) -> Self {
     Self {
       scopes: BTreeMap::new(),
       cur_scope: ScopeRange::Global,
       var_groups: DisjointSet::new(),
+      destructuring,
     }
   }
 
@@ -287,7 +333,9 @@ impl VariableCollector {
         // If there are more than one idents, they need to be grouped
         for i in others {
           self.insert_var(i, status);
-          self.var_groups.unite(first.span, i.span);
+          if self.destructuring == PreferConstDestructuring::All {
+            self.var_groups.unite(first.span, i.span);
+          }
         }
       }
     }
@@ -598,11 +646,26 @@ impl Visit for VariableCollector {
   }
 }
 
-struct PreferConstVisitor<'c, 'view> {
+struct PreferConstVisitor<'c, 'view, 'o> {
   scopes: BTreeMap<ScopeRange, Scope>,
   cur_scope: ScopeRange,
   var_groups: DisjointSet,
   context: &'c mut Context<'view>,
+  options: &'o PreferConstOptions,
+  /// Key: span of the declaration of a variable
+  /// Value: span of the ident of the first assignment to the variable
+  first_writes: HashMap<Span, Span>,
+  let_decls: Vec<LetDecl>,
+}
+
+/// A `let` declaration, which can be fixed to `const` if all of its variables
+/// are reported.
+struct LetDecl {
+  /// Span of the `let` keyword
+  keyword: Span,
+  idents: Vec<Span>,
+  /// Whether all of the variables are initialized by the declaration
+  initialized: bool,
 }
 
 enum ExtractIdentsArgs<'a> {
@@ -657,28 +720,126 @@ where
   }
 }
 
-impl<'c, 'view> PreferConstVisitor<'c, 'view> {
+impl<'c, 'view, 'o> PreferConstVisitor<'c, 'view, 'o> {
   fn new(
     context: &'c mut Context<'view>,
+    options: &'o PreferConstOptions,
     scopes: BTreeMap<ScopeRange, Scope>,
     var_groups: DisjointSet,
   ) -> Self {
     Self {
       context,
+      options,
       scopes,
       var_groups,
       cur_scope: ScopeRange::Global,
+      first_writes: HashMap::new(),
+      let_decls: Vec::new(),
+    }
+  }
+
+  fn report_all(&mut self) {
+    let spans: Vec<Span> = self
+      .var_groups
+      .dump()
+      .into_iter()
+      .filter(|&span| !self.is_read_before_assign(span))
+      .collect();
+    let reported: HashSet<Span> = spans.iter().copied().collect();
+
+    // The fix is attached to the first variable of each fixable declaration
+    let mut fixes: HashMap<Span, Span> = HashMap::new();
+    for let_decl in &self.let_decls {
+      if let_decl.initialized
+        && let_decl.idents.iter().all(|span| reported.contains(span))
+      {
+        if let Some(&first) = let_decl.idents.first() {
+          fixes.insert(first, let_decl.keyword);
+        }
+      }
+    }
+
+    for span in spans {
+      self.report(span, fixes.get(&span).copied());
     }
   }
 
-  fn report(&mut self, span: Span) {
+  fn report(&mut self, span: Span, let_keyword: Option<Span>) {
     let span_text = self.context.file_text_substring(&span).to_string();
-    self.context.add_diagnostic_with_hint(
-      span,
-      CODE,
-      PreferConstMessage::NeverReassigned(span_text),
-      PreferConstHint::UseConst,
+    let message = PreferConstMessage::NeverReassigned(span_text);
+    match let_keyword {
+      Some(keyword) => {
+        let fix = self.context.create_fix(
+          keyword,
+          "const",
+          "Replace `let` with `const`",
+        );
+        self.context.add_diagnostic_with_hint_and_fix(
+          span,
+          CODE,
+          message,
+          PreferConstHint::UseConst,
+          fix,
+        );
+      }
+      None => {
+        self.context.add_diagnostic_with_hint(
+          span,
+          CODE,
+          message,
+          PreferConstHint::UseConst,
+        );
+      }
+    }
+  }
+
+  /// Whether `ignoreReadBeforeAssign` is enabled and the variable declared at
+  /// `decl_span` is referenced before it's assigned for the first time.
+  fn is_read_before_assign(&self, decl_span: Span) -> bool {
+    if !self.options.ignore_read_before_assign {
+      return false;
+    }
+    // Variables initialized by their declaration can't be read before that
+    let first_write = match self.first_writes.get(&decl_span) {
+      Some(first_write) => *first_write,
+      None => return false,
+    };
+
+    let scope = self.context.scope();
+    let sym: JsWord = self.context.file_text_substring(&decl_span).into();
+    scope
+      .ids_with_symbol(&sym)
+      .into_iter()
+      .flatten()
+      .filter_map(|id| scope.var(id))
+      .filter(|var| var.declared_at() == decl_span)
+      .flat_map(|var| var.references())
+      .any(|reference| reference.span().lo < first_write.lo)
+  }
+
+  fn record_let_decl(&mut self, var_decl: &VarDecl, initialized_by_loop: bool) {
+    if var_decl.kind != VarDeclKind::Let || var_decl.declare {
+      return;
+    }
+    let keyword = Span::new(
+      var_decl.span.lo,
+      var_decl.span.lo + BytePos(3),
+      var_decl.span.ctxt,
     );
+    if self.context.file_text_substring(&keyword) != "let" {
+      return;
+    }
+
+    let mut idents = Vec::new();
+    for decl in &var_decl.decls {
+      extract_idents_from_pat(&mut idents, &decl.name);
+    }
+    self.let_decls.push(LetDecl {
+      keyword,
+      idents: idents.into_iter().map(|ident| ident.span).collect(),
+      initialized: initialized_by_loop
+        || var_decl.decls.iter().all(|decl| decl.init.is_some()),
+    });
   }
 
   fn with_child_scope<F, S>(&mut self, node: &S, op: F)
@@ -718,8 +879,13 @@ impl<'c, 'view> PreferConstVisitor<'c, 'view> {
   ) {
     let scope = self.get_scope();
     let decls: Vec<DeclInfo> = idents
-      .filter_map(|i| get_decl_by_ident(Rc::clone(&scope), i))
+      .filter_map(|i| {
+        let decl = get_decl_by_ident(Rc::clone(&scope), i)?;
+        self.first_writes.entry(decl.span).or_insert(i.span);
+        Some(decl)
+      })
       .collect();
+    let unite = self.options.destructuring == PreferConstDestructuring::All;
 
     match decls.as_slice() {
       [] => {}
@@ -736,30 +902,33 @@ impl<'c, 'view> PreferConstVisitor<'c, 'view> {
           self
             .var_groups
             .proceed_status(s.span, force_reassigned || s.in_other_scope);
-          self.var_groups.unite(first.span, s.span);
+          if unite {
+            self.var_groups.unite(first.span, s.span);
+          }
         }
       }
     }
   }
 }
 
-impl<'c, 'view> Visit for PreferConstVisitor<'c, 'view> {
+impl<'c, 'view, 'o> Visit for PreferConstVisitor<'c, 'view, 'o> {
   noop_visit_type!();
 
   fn visit_module(&mut self, module: &Module, _: &dyn Node) {
     module.visit_children_with(self);
     // After visiting all nodes, reports errors.
-    for span in self.var_groups.dump() {
-      self.report(span);
-    }
+    self.report_all();
   }
 
   fn visit_script(&mut self, script: &Script, _: &dyn Node) {
     script.visit_children_with(self);
     // After visiting all nodes, reports errors.
-    for span in self.var_groups.dump() {
-      self.report(span);
-    }
+    self.report_all();
+  }
+
+  fn visit_var_decl(&mut self, var_decl: &VarDecl, _: &dyn Node) {
+    self.record_let_decl(var_decl, false);
+    var_decl.visit_children_with(self);
   }
 
   fn visit_assign_expr(&mut self, assign_expr: &AssignExpr, _: &dyn Node) {
@@ -859,7 +1028,8 @@ impl<'c, 'view> Visit for PreferConstVisitor<'c, 'view> {
     self.with_child_scope(for_of_stmt, |a| {
       match &for_of_stmt.left {
         VarDeclOrPat::VarDecl(var_decl) => {
-          var_decl.visit_with(&for_of_stmt.left, a);
+          a.record_let_decl(var_decl, true);
+          var_decl.visit_children_with(a);
         }
         VarDeclOrPat::Pat(pat) => {
           a.extract_assign_idents(pat);
@@ -880,7 +1050,8 @@ impl<'c, 'view> Visit for PreferConstVisitor<'c, 'view> {
     self.with_child_scope(for_in_stmt, |a| {
       match &for_in_stmt.left {
         VarDeclOrPat::VarDecl(var_decl) => {
-          var_decl.visit_with(&for_in_stmt.left, a);
+          a.record_let_decl(var_decl, true);
+          var_decl.visit_children_with(a);
         }
         VarDeclOrPat::Pat(pat) => {
           a.extract_assign_idents(pat);
@@ -1753,6 +1924,7 @@ for (let {a, b} of obj) {}
 #[cfg(test)]
 mod prefer_const_tests {
   use super::*;
+  use serde_json::json;
 
   // Some tests are derived from
   // https://github.com/eslint/eslint/blob/v7.10.0/tests/lib/rules/prefer-const.js
@@ -1902,6 +2074,21 @@ mod prefer_const_tests {
       r#"let a; switch (foo) { case 0: bar(); break; default: a = "default"; break; }"#,
       r#"let a; switch (foo) { case 0: { a = 0; break; } }"#,
     };
+
+    assert_lint_ok! {
+      PreferConst,
+      options: json!({ "ignoreReadBeforeAssign": true }),
+      r#"
+let timer;
+function initialize() {
+  if (foo()) {
+    clearInterval(timer);
+  }
+}
+timer = setInterval(initialize, 100);
+      "#,
+      r#"let a; console.log(a); a = 0;"#,
+    };
   }
 
   #[test]
@@ -2317,5 +2504,69 @@ e = 2;
         }
       ]
     };
+
+    assert_lint_err! {
+      PreferConst,
+      r#"let a = 0; console.log(a);"#: [
+        {
+          col: 4,
+          message: variant!(PreferConstMessage, NeverReassigned, "a"),
+          hint: PreferConstHint::UseConst,
+          fix: r#"const a = 0; console.log(a);"#,
+        }
+      ],
+      r#"export let a = 0, b = 1;"#: [
+        {
+          col: 11,
+          message: variant!(PreferConstMessage, NeverReassigned, "a"),
+          hint: PreferConstHint::UseConst,
+          fix: r#"export const a = 0, b = 1;"#,
+        },
+        {
+          col: 18,
+          message: variant!(PreferConstMessage, NeverReassigned, "b"),
+          hint: PreferConstHint::UseConst,
+        }
+      ],
+      r#"for (let x of xs) { console.log(x); }"#: [
+        {
+          col: 9,
+          message: variant!(PreferConstMessage, NeverReassigned, "x"),
+          hint: PreferConstHint::UseConst,
+          fix: r#"for (const x of xs) { console.log(x); }"#,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      PreferConst,
+      options: json!({ "destructuring": "any" }),
+      r#"let {a, b} = obj; b = 0;"#: [
+        {
+          col: 5,
+          message: variant!(PreferConstMessage, NeverReassigned, "a"),
+          hint: PreferConstHint::UseConst,
+        }
+      ],
+      r#"let a, b; ({a, b} = obj); b++;"#: [
+        {
+          col: 4,
+          message: variant!(PreferConstMessage, NeverReassigned, "a"),
+          hint: PreferConstHint::UseConst,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      PreferConst,
+      options: json!({ "ignoreReadBeforeAssign": true }),
+      r#"let a; a = 0; console.log(a);"#: [
+        {
+          col: 4,
+          message: variant!(PreferConstMessage, NeverReassigned, "a"),
+          hint: PreferConstHint::UseConst,
+        }
+      ],
+    };
   }
 }
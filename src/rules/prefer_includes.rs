@@ -0,0 +1,245 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::ProgramRef;
+use deno_ast::swc::ast::{
+  BinExpr, BinaryOp, CallExpr, Expr, ExprOrSuper, Lit, UnaryOp,
+};
+use deno_ast::swc::common::Spanned;
+use deno_ast::swc::visit::{noop_visit_type, Node, Visit, VisitWith};
+
+#[derive(Debug)]
+pub struct PreferIncludes;
+
+const CODE: &str = "prefer-includes";
+const MESSAGE: &str =
+  "Use `.includes()` instead of comparing the result of `.indexOf()`";
+const HINT: &str = "Replace the comparison with a call of `.includes()`";
+
+impl LintRule for PreferIncludes {
+  fn new() -> Box<Self> {
+    Box::new(PreferIncludes)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    let mut visitor = PreferIncludesVisitor::new(context);
+    match program {
+      ProgramRef::Module(m) => visitor.visit_module(m, &DUMMY_NODE),
+      ProgramRef::Script(s) => visitor.visit_script(s, &DUMMY_NODE),
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/prefer_includes.md")
+  }
+}
+
+/// Returns the integer value of a literal like `0` or `-1`.
+fn int_literal(expr: &Expr) -> Option<i64> {
+  match expr {
+    Expr::Lit(Lit::Num(num)) if num.value.fract() == 0.0 => {
+      Some(num.value as i64)
+    }
+    Expr::Unary(unary) if unary.op == UnaryOp::Minus => {
+      int_literal(&unary.arg).map(|value| -value)
+    }
+    Expr::Paren(paren) => int_literal(&paren.expr),
+    _ => None,
+  }
+}
+
+/// If the expression is like `receiver.indexOf(arg)`, returns the call.
+fn index_of_call(expr: &Expr) -> Option<&CallExpr> {
+  let call = match expr {
+    Expr::Call(call) => call,
+    _ => return None,
+  };
+  let member = match &call.callee {
+    ExprOrSuper::Expr(callee) => match &**callee {
+      Expr::Member(member) => member,
+      _ => return None,
+    },
+    ExprOrSuper::Super(_) => return None,
+  };
+  let is_index_of = !member.computed
+    && matches!(&*member.prop, Expr::Ident(ident) if ident.sym == *"indexOf");
+  // `fromIndex` can't be passed to `includes` in the same way for strings
+  // and arrays, so only calls with a single argument are reported
+  let has_single_arg = call.args.len() == 1 && call.args[0].spread.is_none();
+  let is_regex = |expr: &Expr| matches!(expr, Expr::Lit(Lit::Regex(_)));
+  let receiver_is_regex = match &member.obj {
+    ExprOrSuper::Expr(obj) => is_regex(obj),
+    ExprOrSuper::Super(_) => true,
+  };
+
+  if is_index_of
+    && has_single_arg
+    && !receiver_is_regex
+    && !is_regex(&call.args[0].expr)
+  {
+    Some(call)
+  } else {
+    None
+  }
+}
+
+/// Checks whether `index op value` tests that the index was found. Returns
+/// `None` if the comparison doesn't test that at all.
+fn tests_found(op: BinaryOp, value: i64) -> Option<bool> {
+  use BinaryOp::*;
+  match (op, value) {
+    (NotEq | NotEqEq | Gt, -1) | (GtEq, 0) => Some(true),
+    (EqEq | EqEqEq | LtEq, -1) | (Lt, 0) => Some(false),
+    _ => None,
+  }
+}
+
+/// Swaps the operands of a comparison, e.g. `0 <= i` is equivalent to `i >= 0`.
+fn flip(op: BinaryOp) -> BinaryOp {
+  use BinaryOp::*;
+  match op {
+    Lt => Gt,
+    LtEq => GtEq,
+    Gt => Lt,
+    GtEq => LtEq,
+    op => op,
+  }
+}
+
+struct PreferIncludesVisitor<'c, 'view> {
+  context: &'c mut Context<'view>,
+}
+
+impl<'c, 'view> PreferIncludesVisitor<'c, 'view> {
+  fn new(context: &'c mut Context<'view>) -> Self {
+    Self { context }
+  }
+}
+
+impl<'c, 'view> Visit for PreferIncludesVisitor<'c, 'view> {
+  noop_visit_type!();
+
+  fn visit_bin_expr(&mut self, bin_expr: &BinExpr, _: &dyn Node) {
+    bin_expr.visit_children_with(self);
+
+    let (call, op, value) = match (
+      index_of_call(&bin_expr.left),
+      index_of_call(&bin_expr.right),
+    ) {
+      (Some(call), None) => (call, bin_expr.op, int_literal(&bin_expr.right)),
+      (None, Some(call)) => {
+        (call, flip(bin_expr.op), int_literal(&bin_expr.left))
+      }
+      _ => return,
+    };
+    let found = match value.and_then(|value| tests_found(op, value)) {
+      Some(found) => found,
+      None => return,
+    };
+
+    let receiver = match &call.callee {
+      ExprOrSuper::Expr(callee) => match &**callee {
+        Expr::Member(member) => member.obj.span(),
+        _ => return,
+      },
+      ExprOrSuper::Super(_) => return,
+    };
+    let fixed = format!(
+      "{}{}.includes({})",
+      if found { "" } else { "!" },
+      self.context.file_text_substring(&receiver),
+      self.context.file_text_substring(&call.args[0].expr.span()),
+    );
+    let fix = self.context.create_fix(bin_expr.span, fixed, HINT);
+    self.context.add_diagnostic_with_hint_and_fix(
+      bin_expr.span,
+      CODE,
+      MESSAGE,
+      HINT,
+      fix,
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_includes_valid() {
+    assert_lint_ok! {
+      PreferIncludes,
+      "str.includes(x);",
+      "str.indexOf(x);",
+      "str.indexOf(x) === 0;",
+      "str.indexOf(x) > 0;",
+      "str.indexOf(x) !== 1;",
+      "str.indexOf(x) >= -1;",
+      "str.indexOf(x, 1) !== -1;",
+      "str.indexOf(...args) !== -1;",
+      "str.indexOf() !== -1;",
+      "str.indexOf(/x/) !== -1;",
+      "/x/.indexOf(x) !== -1;",
+      "str.lastIndexOf(x) !== -1;",
+      "str['indexOf'](x) !== -1;",
+      "indexOf(x) !== -1;",
+      "str.indexOf(x) !== y;",
+    };
+  }
+
+  #[test]
+  fn prefer_includes_invalid() {
+    assert_lint_err! {
+      PreferIncludes,
+      "str.indexOf(x) !== -1;": [
+        { col: 0, message: MESSAGE, hint: HINT, fix: "str.includes(x);" },
+      ],
+      "str.indexOf(x) != -1;": [
+        { col: 0, message: MESSAGE, hint: HINT, fix: "str.includes(x);" },
+      ],
+      "str.indexOf(x) > -1;": [
+        { col: 0, message: MESSAGE, hint: HINT, fix: "str.includes(x);" },
+      ],
+      "arr.indexOf(x) >= 0;": [
+        { col: 0, message: MESSAGE, hint: HINT, fix: "arr.includes(x);" },
+      ],
+      "arr.indexOf(x) === -1;": [
+        { col: 0, message: MESSAGE, hint: HINT, fix: "!arr.includes(x);" },
+      ],
+      "arr.indexOf(x) == -1;": [
+        { col: 0, message: MESSAGE, hint: HINT, fix: "!arr.includes(x);" },
+      ],
+      "arr.indexOf(x) < 0;": [
+        { col: 0, message: MESSAGE, hint: HINT, fix: "!arr.includes(x);" },
+      ],
+      "arr.indexOf(x) <= -1;": [
+        { col: 0, message: MESSAGE, hint: HINT, fix: "!arr.includes(x);" },
+      ],
+      "-1 !== str.indexOf(x);": [
+        { col: 0, message: MESSAGE, hint: HINT, fix: "str.includes(x);" },
+      ],
+      "0 <= str.indexOf(x);": [
+        { col: 0, message: MESSAGE, hint: HINT, fix: "str.includes(x);" },
+      ],
+      "0 > str.indexOf(x);": [
+        { col: 0, message: MESSAGE, hint: HINT, fix: "!str.includes(x);" },
+      ],
+      "if (a.b().indexOf('foo' + bar) !== -1 && c) {}": [
+        {
+          col: 4,
+          message: MESSAGE,
+          hint: HINT,
+          fix: "if (a.b().includes('foo' + bar) && c) {}",
+        },
+      ],
+    };
+  }
+}
@@ -0,0 +1,204 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::swc_util::extract_regex;
+use crate::ProgramRef;
+use deno_ast::swc::ast::{CallExpr, Expr, ExprOrSuper, NewExpr, Regex};
+use deno_ast::swc::common::{BytePos, Span};
+use deno_ast::swc::visit::noop_visit_type;
+use deno_ast::swc::visit::Node;
+use deno_ast::swc::visit::{VisitAll, VisitAllWith};
+
+#[derive(Debug)]
+pub struct PreferNamedCaptureGroup;
+
+const CODE: &str = "prefer-named-capture-group";
+const HINT: &str =
+  "Name the group like `(?<name>...)`, or make it non-capturing like `(?:...)`";
+
+fn message(group: &str) -> String {
+  format!(
+    "Capture group `{}` should be converted to a named or non-capturing group",
+    group
+  )
+}
+
+impl LintRule for PreferNamedCaptureGroup {
+  fn new() -> Box<Self> {
+    Box::new(PreferNamedCaptureGroup)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    let mut visitor = PreferNamedCaptureGroupVisitor::new(context);
+    match program {
+      ProgramRef::Module(m) => m.visit_all_with(&DUMMY_NODE, &mut visitor),
+      ProgramRef::Script(s) => s.visit_all_with(&DUMMY_NODE, &mut visitor),
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/prefer_named_capture_group.md")
+  }
+}
+
+/// Returns byte ranges of numbered capturing groups in the pattern, i.e.
+/// groups starting with `(` which is not followed by `?`. Escaped parentheses
+/// and those inside character classes are skipped.
+fn find_unnamed_groups(pattern: &str) -> Vec<(usize, usize)> {
+  let mut groups = vec![];
+  // Start positions of open groups, and whether each one is unnamed
+  let mut open_groups: Vec<(usize, bool)> = vec![];
+  let mut in_class = false;
+  let mut chars = pattern.char_indices().peekable();
+
+  while let Some((i, c)) = chars.next() {
+    match c {
+      '\\' => {
+        chars.next();
+      }
+      '[' => in_class = true,
+      ']' => in_class = false,
+      '(' if !in_class => {
+        let is_unnamed = !matches!(chars.peek(), Some((_, '?')));
+        open_groups.push((i, is_unnamed));
+      }
+      ')' if !in_class => {
+        if let Some((start, true)) = open_groups.pop() {
+          groups.push((start, i + 1));
+        }
+      }
+      _ => {}
+    }
+  }
+
+  groups.sort_unstable();
+  groups
+}
+
+struct PreferNamedCaptureGroupVisitor<'c, 'view> {
+  context: &'c mut Context<'view>,
+}
+
+impl<'c, 'view> PreferNamedCaptureGroupVisitor<'c, 'view> {
+  fn new(context: &'c mut Context<'view>) -> Self {
+    Self { context }
+  }
+
+  /// Reports groups in a pattern built by `RegExp()`. Positions inside the
+  /// string don't correspond to the source, so the whole call is reported.
+  fn check_regexp_call(&mut self, pattern: &str, span: Span) {
+    for (start, end) in find_unnamed_groups(pattern) {
+      self.context.add_diagnostic_with_hint(
+        span,
+        CODE,
+        message(&pattern[start..end]),
+        HINT,
+      );
+    }
+  }
+}
+
+impl<'c, 'view> VisitAll for PreferNamedCaptureGroupVisitor<'c, 'view> {
+  noop_visit_type!();
+
+  fn visit_regex(&mut self, regex: &Regex, _: &dyn Node) {
+    let pattern = regex.exp.to_string();
+    // Skip the leading `/`
+    let pattern_start = regex.span.lo + BytePos(1);
+    for (start, end) in find_unnamed_groups(&pattern) {
+      let span = Span::new(
+        pattern_start + BytePos(start as u32),
+        pattern_start + BytePos(end as u32),
+        Default::default(),
+      );
+      self.context.add_diagnostic_with_hint(
+        span,
+        CODE,
+        message(&pattern[start..end]),
+        HINT,
+      );
+    }
+  }
+
+  fn visit_new_expr(&mut self, new_expr: &NewExpr, _: &dyn Node) {
+    if let Expr::Ident(ident) = &*new_expr.callee {
+      if let Some(args) = &new_expr.args {
+        if let Some(regex) = extract_regex(self.context.scope(), ident, args) {
+          self.check_regexp_call(&regex, new_expr.span);
+        }
+      }
+    }
+  }
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _: &dyn Node) {
+    if let ExprOrSuper::Expr(expr) = &call_expr.callee {
+      if let Expr::Ident(ident) = expr.as_ref() {
+        if let Some(regex) =
+          extract_regex(self.context.scope(), ident, &call_expr.args)
+        {
+          self.check_regexp_call(&regex, call_expr.span);
+        }
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_named_capture_group_valid() {
+    assert_lint_ok! {
+      PreferNamedCaptureGroup,
+      "/foo/;",
+      "/(?<year>[0-9]{4})/;",
+      "/(?:foo)/;",
+      "/foo(?=bar)/;",
+      "/foo(?!bar)/;",
+      "/(?<=foo)bar/;",
+      "/(?<!foo)bar/;",
+      r"/\(foo\)/;",
+      "/[()]/;",
+      r"/[\]()]/;",
+      "new RegExp('(?<year>[0-9]{4})');",
+      "RegExp('(?:foo)');",
+      "function f(RegExp) { return new RegExp('(foo)'); }",
+    };
+  }
+
+  #[test]
+  fn prefer_named_capture_group_invalid() {
+    assert_lint_err! {
+      PreferNamedCaptureGroup,
+      "/([0-9]{4})/;": [
+        { col: 1, message: message("([0-9]{4})"), hint: HINT },
+      ],
+      "/(?<year>[0-9]{4})-(\\w{5})/;": [
+        { col: 19, message: message("(\\w{5})"), hint: HINT },
+      ],
+      "/(a(b))/;": [
+        { col: 1, message: message("(a(b))"), hint: HINT },
+        { col: 3, message: message("(b)"), hint: HINT },
+      ],
+      "/(?:a(b))/;": [
+        { col: 5, message: message("(b)"), hint: HINT },
+      ],
+      "new RegExp('([0-9]{4})');": [
+        { col: 0, message: message("([0-9]{4})"), hint: HINT },
+      ],
+      "const re = RegExp('(a)(b)');": [
+        { col: 11, message: message("(a)"), hint: HINT },
+        { col: 11, message: message("(b)"), hint: HINT },
+      ],
+    };
+  }
+}
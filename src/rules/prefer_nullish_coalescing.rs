@@ -0,0 +1,226 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{AssignOp, BinaryOp, Expr, Lit, UnaryOp};
+use deno_ast::swc::common::Spanned;
+use deno_ast::view as ast_view;
+use serde::Deserialize;
+
+#[derive(Debug, Default)]
+pub struct PreferNullishCoalescing {
+  options: PreferNullishCoalescingOptions,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PreferNullishCoalescingOptions {
+  /// Kinds of default values for which `||` is allowed.
+  pub ignore_primitives: IgnorePrimitives,
+}
+
+/// Since there is no type information, the kind of the value is guessed from
+/// the literal used as the default value, e.g. `name || ""` is assumed to
+/// default a string.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct IgnorePrimitives {
+  pub string: bool,
+  pub number: bool,
+  pub boolean: bool,
+  pub bigint: bool,
+}
+
+const CODE: &str = "prefer-nullish-coalescing";
+const MESSAGE: &str = "Prefer `??` over `||` to provide a default value";
+const HINT: &str = "`||` also falls back on `0`, `\"\"` and `false`; use `??` \
+  if only `null` and `undefined` should be replaced";
+
+impl PreferNullishCoalescing {
+  pub fn with_options(options: PreferNullishCoalescingOptions) -> Box<Self> {
+    Box::new(PreferNullishCoalescing { options })
+  }
+}
+
+impl LintRule for PreferNullishCoalescing {
+  fn new() -> Box<Self> {
+    Box::new(PreferNullishCoalescing::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    PreferNullishCoalescingHandler::new(&self.options)
+      .traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/prefer_nullish_coalescing.md")
+  }
+}
+
+/// Checks if the expression is used as a default value: a variable
+/// initializer, the right side of an assignment or a default parameter.
+fn is_defaulting_position(bin_expr: &ast_view::BinExpr) -> bool {
+  let mut span = bin_expr.inner.span;
+  let mut current = bin_expr.parent();
+  while let ast_view::Node::ParenExpr(paren) = current {
+    span = paren.inner.span;
+    current = paren.parent();
+  }
+  match current {
+    ast_view::Node::VarDeclarator(declarator) => declarator
+      .inner
+      .init
+      .as_ref()
+      .map_or(false, |init| init.span() == span),
+    ast_view::Node::AssignExpr(assign) => {
+      assign.inner.op == AssignOp::Assign && assign.inner.right.span() == span
+    }
+    ast_view::Node::AssignPat(assign) => assign.inner.right.span() == span,
+    _ => false,
+  }
+}
+
+/// Checks if the expression evaluates to a boolean, in which case `||` is the
+/// right operator.
+fn is_boolean(expr: &Expr) -> bool {
+  match expr {
+    Expr::Paren(paren) => is_boolean(&paren.expr),
+    Expr::Lit(Lit::Bool(_)) => true,
+    Expr::Unary(unary) => unary.op == UnaryOp::Bang,
+    Expr::Bin(bin) => matches!(
+      bin.op,
+      BinaryOp::EqEq
+        | BinaryOp::NotEq
+        | BinaryOp::EqEqEq
+        | BinaryOp::NotEqEq
+        | BinaryOp::Lt
+        | BinaryOp::LtEq
+        | BinaryOp::Gt
+        | BinaryOp::GtEq
+        | BinaryOp::In
+        | BinaryOp::InstanceOf
+    ),
+    _ => false,
+  }
+}
+
+struct PreferNullishCoalescingHandler<'o> {
+  options: &'o PreferNullishCoalescingOptions,
+}
+
+impl<'o> PreferNullishCoalescingHandler<'o> {
+  fn new(options: &'o PreferNullishCoalescingOptions) -> Self {
+    Self { options }
+  }
+
+  fn is_ignored_default(&self, expr: &Expr) -> bool {
+    let ignore = &self.options.ignore_primitives;
+    match expr {
+      Expr::Paren(paren) => self.is_ignored_default(&paren.expr),
+      Expr::Lit(Lit::Str(_)) | Expr::Tpl(_) => ignore.string,
+      Expr::Lit(Lit::Num(_)) => ignore.number,
+      Expr::Lit(Lit::Bool(_)) => ignore.boolean,
+      Expr::Lit(Lit::BigInt(_)) => ignore.bigint,
+      Expr::Unary(unary) if unary.op == UnaryOp::Minus => {
+        self.is_ignored_default(&unary.arg)
+      }
+      _ => false,
+    }
+  }
+}
+
+impl<'o> Handler for PreferNullishCoalescingHandler<'o> {
+  fn bin_expr(&mut self, bin_expr: &ast_view::BinExpr, ctx: &mut Context) {
+    let bin = bin_expr.inner;
+    if bin.op != BinaryOp::LogicalOr
+      || !is_defaulting_position(bin_expr)
+      || is_boolean(&bin.left)
+      || self.is_ignored_default(&bin.right)
+    {
+      return;
+    }
+    ctx.add_diagnostic_with_hint(bin.span, CODE, MESSAGE, HINT);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  #[test]
+  fn prefer_nullish_coalescing_valid() {
+    assert_lint_ok! {
+      PreferNullishCoalescing,
+      "const a = b ?? c;",
+      "a = b ?? c;",
+      "function f(a = b ?? c) {}",
+      "if (a || b) {}",
+      "foo(a || b);",
+      "const a = b || c || d ? e : f;",
+      "const a = b > 0 || c;",
+      "const a = !b || c;",
+      "a += b || c;",
+      "const a = (b === 1) || c;",
+    };
+
+    assert_lint_ok! {
+      PreferNullishCoalescing,
+      options: json!({
+        "ignorePrimitives": { "string": true, "number": true },
+      }),
+      "const a = b || '';",
+      "const a = b || `default`;",
+      "const a = b || 0;",
+      "const a = b || -1;",
+    };
+
+    assert_lint_ok! {
+      PreferNullishCoalescing,
+      options: json!({
+        "ignorePrimitives": { "boolean": true, "bigint": true },
+      }),
+      "const a = b || false;",
+      "const a = b || 0n;",
+    };
+  }
+
+  #[test]
+  fn prefer_nullish_coalescing_invalid() {
+    assert_lint_err! {
+      PreferNullishCoalescing,
+      "const a = b || c;": [{ col: 10, message: MESSAGE, hint: HINT }],
+      "let a = (b || c);": [{ col: 9, message: MESSAGE, hint: HINT }],
+      "a = b || c;": [{ col: 4, message: MESSAGE, hint: HINT }],
+      "this.a = b.c || {};": [{ col: 9, message: MESSAGE, hint: HINT }],
+      "function f(a = b || c) {}": [{ col: 15, message: MESSAGE, hint: HINT }],
+      "const f = (a = b || []) => a;": [
+        { col: 15, message: MESSAGE, hint: HINT },
+      ],
+      "const a = b || c || d;": [{ col: 10, message: MESSAGE, hint: HINT }],
+      "const a = b || '';": [{ col: 10, message: MESSAGE, hint: HINT }],
+    };
+
+    assert_lint_err! {
+      PreferNullishCoalescing,
+      options: json!({
+        "ignorePrimitives": { "string": true, "number": false },
+      }),
+      "const a = b || 0;": [{ col: 10, message: MESSAGE, hint: HINT }],
+      "const a = b || null;": [{ col: 10, message: MESSAGE, hint: HINT }],
+    };
+  }
+}
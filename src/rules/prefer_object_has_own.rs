@@ -0,0 +1,215 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::unwrap_parens;
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{Expr, ExprOrSuper};
+use deno_ast::swc::common::Spanned;
+use deno_ast::swc::utils::ident::IdentLike;
+use deno_ast::view as ast_view;
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct PreferObjectHasOwn;
+
+const CODE: &str = "prefer-object-has-own";
+
+#[derive(Display)]
+enum PreferObjectHasOwnMessage {
+  #[display(
+    fmt = "Use `Object.hasOwn()` instead of `Object.prototype.hasOwnProperty.call()`"
+  )]
+  PrototypeCall,
+  #[display(fmt = "Use `Object.hasOwn()` instead of `hasOwnProperty()`")]
+  MethodCall,
+}
+
+#[derive(Display)]
+enum PreferObjectHasOwnHint {
+  #[display(fmt = "Replace it with `Object.hasOwn(obj, key)`")]
+  UseHasOwn,
+}
+
+impl LintRule for PreferObjectHasOwn {
+  fn new() -> Box<Self> {
+    Box::new(PreferObjectHasOwn)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    PreferObjectHasOwnHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/prefer_object_has_own.md")
+  }
+}
+
+/// If the expression is a member access like `obj.name`, returns `obj`.
+fn member_object<'a>(expr: &'a Expr, name: &str) -> Option<&'a Expr> {
+  match expr {
+    Expr::Member(member_expr) if !member_expr.computed => {
+      match (&member_expr.obj, &*member_expr.prop) {
+        (ExprOrSuper::Expr(obj), Expr::Ident(prop)) if prop.sym == *name => {
+          Some(obj)
+        }
+        _ => None,
+      }
+    }
+    _ => None,
+  }
+}
+
+/// Checks if the expression refers to `Object.prototype`, or an empty object
+/// literal which has the same prototype.
+fn is_object_prototype(expr: &Expr, ctx: &Context) -> bool {
+  match expr {
+    Expr::Paren(paren) => is_object_prototype(&paren.expr, ctx),
+    Expr::Object(obj) => obj.props.is_empty(),
+    _ => match member_object(expr, "prototype") {
+      Some(Expr::Ident(ident)) => {
+        ident.sym == *"Object" && ctx.scope().is_global(&ident.to_id())
+      }
+      _ => false,
+    },
+  }
+}
+
+struct PreferObjectHasOwnHandler;
+
+impl Handler for PreferObjectHasOwnHandler {
+  fn call_expr(&mut self, call_expr: &ast_view::CallExpr, ctx: &mut Context) {
+    let callee = match &call_expr.inner.callee {
+      ExprOrSuper::Expr(expr) => &**expr,
+      ExprOrSuper::Super(_) => return,
+    };
+
+    // `Object.prototype.hasOwnProperty.call(obj, key)`
+    if let Some(has_own_property) = member_object(callee, "call") {
+      if let Some(prototype) = member_object(has_own_property, "hasOwnProperty")
+      {
+        if is_object_prototype(prototype, ctx) {
+          // `({}).hasOwnProperty.call()` doesn't refer to `Object` itself, so
+          // the fix must make sure that it isn't shadowed
+          let can_fix = !matches!(unwrap_parens(prototype), Expr::Object(_))
+            || ctx.scope().is_global_symbol(&"Object".into());
+          if can_fix {
+            let fix = ctx.create_fix(
+              callee.span(),
+              "Object.hasOwn",
+              "Replace with `Object.hasOwn`",
+            );
+            ctx.add_diagnostic_with_hint_and_fix(
+              call_expr.inner.span,
+              CODE,
+              PreferObjectHasOwnMessage::PrototypeCall,
+              PreferObjectHasOwnHint::UseHasOwn,
+              fix,
+            );
+          } else {
+            ctx.add_diagnostic_with_hint(
+              call_expr.inner.span,
+              CODE,
+              PreferObjectHasOwnMessage::PrototypeCall,
+              PreferObjectHasOwnHint::UseHasOwn,
+            );
+          }
+        }
+        return;
+      }
+    }
+
+    // `obj.hasOwnProperty(key)`
+    if member_object(callee, "hasOwnProperty").is_some() {
+      ctx.add_diagnostic_with_hint(
+        call_expr.inner.span,
+        CODE,
+        PreferObjectHasOwnMessage::MethodCall,
+        PreferObjectHasOwnHint::UseHasOwn,
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_object_has_own_valid() {
+    assert_lint_ok! {
+      PreferObjectHasOwn,
+      "Object.hasOwn(obj, 'foo');",
+      "Object.prototype.toString.call(obj);",
+      "foo.hasOwnProperty;",
+      "obj['hasOwnProperty']('foo');",
+      "Foo.prototype.hasOwnProperty.call(obj, 'foo');",
+      "({ a: 1 }).hasOwnProperty.call(obj, 'foo');",
+      "const Object = {}; Object.prototype.hasOwnProperty.call(obj, 'foo');",
+    };
+  }
+
+  #[test]
+  fn prefer_object_has_own_invalid() {
+    assert_lint_err! {
+      PreferObjectHasOwn,
+      "Object.prototype.hasOwnProperty.call(obj, 'foo');": [
+        {
+          col: 0,
+          message: PreferObjectHasOwnMessage::PrototypeCall,
+          hint: PreferObjectHasOwnHint::UseHasOwn,
+          fix: "Object.hasOwn(obj, 'foo');",
+        },
+      ],
+      "if (({}).hasOwnProperty.call(obj, key)) {}": [
+        {
+          col: 4,
+          message: PreferObjectHasOwnMessage::PrototypeCall,
+          hint: PreferObjectHasOwnHint::UseHasOwn,
+          fix: "if (Object.hasOwn(obj, key)) {}",
+        },
+      ],
+      "function f(Object) {} Object.prototype.hasOwnProperty.call(obj, key);": [
+        {
+          col: 22,
+          message: PreferObjectHasOwnMessage::PrototypeCall,
+          hint: PreferObjectHasOwnHint::UseHasOwn,
+          fix: "function f(Object) {} Object.hasOwn(obj, key);",
+        },
+      ],
+      "function f(Object) { return ({}).hasOwnProperty.call(obj, key); }": [
+        {
+          col: 28,
+          message: PreferObjectHasOwnMessage::PrototypeCall,
+          hint: PreferObjectHasOwnHint::UseHasOwn,
+        },
+      ],
+      "obj.hasOwnProperty('foo');": [
+        {
+          col: 0,
+          message: PreferObjectHasOwnMessage::MethodCall,
+          hint: PreferObjectHasOwnHint::UseHasOwn,
+        },
+      ],
+      "foo.bar.hasOwnProperty(key);": [
+        {
+          col: 0,
+          message: PreferObjectHasOwnMessage::MethodCall,
+          hint: PreferObjectHasOwnHint::UseHasOwn,
+        },
+      ],
+    };
+  }
+}
@@ -0,0 +1,170 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{Ident, Pat};
+use deno_ast::swc::common::{BytePos, Span, Spanned};
+use deno_ast::swc::utils::find_ids;
+use deno_ast::swc::utils::ident::IdentLike;
+use deno_ast::view as ast_view;
+
+#[derive(Debug)]
+pub struct PreferOptionalCatchBinding;
+
+const CODE: &str = "prefer-optional-catch-binding";
+const MESSAGE: &str = "The catch parameter is never used";
+const HINT: &str = "Omit the parameter, like `catch {}`";
+const FIX_DESC: &str = "Remove the catch parameter";
+
+impl LintRule for PreferOptionalCatchBinding {
+  fn new() -> Box<Self> {
+    Box::new(PreferOptionalCatchBinding)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    PreferOptionalCatchBindingHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/prefer_optional_catch_binding.md")
+  }
+}
+
+struct PreferOptionalCatchBindingHandler;
+
+impl Handler for PreferOptionalCatchBindingHandler {
+  fn catch_clause(
+    &mut self,
+    catch_clause: &ast_view::CatchClause,
+    ctx: &mut Context,
+  ) {
+    let param = match &catch_clause.inner.param {
+      Some(param) => param,
+      None => return,
+    };
+
+    let idents: Vec<Ident> = find_ids(param);
+    let is_used = idents.iter().any(|ident| {
+      ctx
+        .scope()
+        .var(&ident.to_id())
+        .map_or(true, |var| !var.references().is_empty())
+    });
+    if is_used {
+      return;
+    }
+
+    // The span between `catch` and the body, like ` (e) ` of `catch (e) {}`
+    let catch_span = catch_clause.span();
+    let keyword_end = catch_span.lo + BytePos(5);
+    let param_span = Span::new(
+      keyword_end,
+      catch_clause.inner.body.span.lo,
+      Default::default(),
+    );
+
+    // Destructuring may throw, e.g. if `null` is thrown, and removing it
+    // would also remove comments in the parameter
+    let is_fixable = matches!(param, Pat::Ident(_))
+      && ctx.file_text_substring(&Span::new(
+        catch_span.lo,
+        keyword_end,
+        Default::default(),
+      )) == "catch"
+      && !ctx
+        .all_comments()
+        .any(|comment| param_span.contains(comment.span));
+
+    if is_fixable {
+      let fix = ctx.create_fix(param_span, " ", FIX_DESC);
+      ctx.add_diagnostic_with_hint_and_fix(
+        param.span(),
+        CODE,
+        MESSAGE,
+        HINT,
+        fix,
+      );
+    } else {
+      ctx.add_diagnostic_with_hint(param.span(), CODE, MESSAGE, HINT);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_optional_catch_binding_valid() {
+    assert_lint_ok! {
+      PreferOptionalCatchBinding,
+      "try {} catch {}",
+      "try {} catch (e) { console.error(e); }",
+      "try {} catch (e) { throw e; }",
+      "try {} catch (e) { setTimeout(() => console.log(e)); }",
+      "try {} catch ({ message }) { console.log(message); }",
+      "try {} catch ([a, b]) { b; }",
+      "try {} catch (e) { e = 1; }",
+      "try {} finally {}",
+    };
+  }
+
+  #[test]
+  fn prefer_optional_catch_binding_invalid() {
+    assert_lint_err! {
+      PreferOptionalCatchBinding,
+      "try {} catch (e) {}": [
+        {
+          col: 14,
+          message: MESSAGE,
+          hint: HINT,
+          fix: "try {} catch {}",
+        },
+      ],
+      "try {} catch(e){ console.log(1); }": [
+        {
+          col: 13,
+          message: MESSAGE,
+          hint: HINT,
+          fix: "try {} catch { console.log(1); }",
+        },
+      ],
+      "try {} catch (e: unknown) { const e2 = 1; e2; }": [
+        {
+          col: 14,
+          message: MESSAGE,
+          hint: HINT,
+          fix: "try {} catch { const e2 = 1; e2; }",
+        },
+      ],
+      "try {} catch (e) { function f(e) { return e; } }": [
+        {
+          col: 14,
+          message: MESSAGE,
+          hint: HINT,
+          fix: "try {} catch { function f(e) { return e; } }",
+        },
+      ],
+      "try {} catch ({ message }) {}": [
+        { col: 14, message: MESSAGE, hint: HINT },
+      ],
+      "try {} catch ({}) {}": [{ col: 14, message: MESSAGE, hint: HINT }],
+      "try {} catch (/* unused */ e) {}": [
+        { col: 27, message: MESSAGE, hint: HINT },
+      ],
+    };
+  }
+}
@@ -0,0 +1,266 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::ast_eq::expr_eq;
+use crate::swc_util::unwrap_parens;
+use crate::ProgramRef;
+use deno_ast::swc::ast::{BinExpr, BinaryOp, Expr, ExprOrSuper};
+use deno_ast::swc::common::{Span, Spanned};
+use deno_ast::swc::visit::{noop_visit_type, Node, Visit, VisitWith};
+
+#[derive(Debug)]
+pub struct PreferOptionalChain;
+
+const CODE: &str = "prefer-optional-chain";
+const MESSAGE: &str =
+  "Prefer optional chaining over chained `&&` checks for existence";
+const HINT: &str = "Use `?.` to access the properties, like `foo?.bar?.baz`";
+
+impl LintRule for PreferOptionalChain {
+  fn new() -> Box<Self> {
+    Box::new(PreferOptionalChain)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    let mut visitor = PreferOptionalChainVisitor::new(context);
+    match program {
+      ProgramRef::Module(m) => visitor.visit_module(m, &DUMMY_NODE),
+      ProgramRef::Script(s) => visitor.visit_script(s, &DUMMY_NODE),
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/prefer_optional_chain.md")
+  }
+}
+
+/// Collects the operands of a left-associative chain like `a && b && c`.
+fn collect_operands<'a>(bin_expr: &'a BinExpr, operands: &mut Vec<&'a Expr>) {
+  match &*bin_expr.left {
+    Expr::Bin(left) if left.op == BinaryOp::LogicalAnd => {
+      collect_operands(left, operands)
+    }
+    left => operands.push(left),
+  }
+  operands.push(&bin_expr.right);
+}
+
+/// Returns the objects and callees which `expr` accesses, e.g. `foo.bar()`
+/// accesses `foo.bar` and `foo`.
+fn accessed_prefixes(expr: &Expr) -> Vec<&Expr> {
+  let mut prefixes = Vec::new();
+  let mut current = expr;
+  loop {
+    let next = match current {
+      Expr::Member(member) => match &member.obj {
+        ExprOrSuper::Expr(obj) => &**obj,
+        ExprOrSuper::Super(_) => break,
+      },
+      Expr::Call(call) => match &call.callee {
+        ExprOrSuper::Expr(callee) => &**callee,
+        ExprOrSuper::Super(_) => break,
+      },
+      _ => break,
+    };
+    prefixes.push(next);
+    current = next;
+  }
+  prefixes
+}
+
+struct PreferOptionalChainVisitor<'c, 'view> {
+  context: &'c mut Context<'view>,
+}
+
+impl<'c, 'view> PreferOptionalChainVisitor<'c, 'view> {
+  fn new(context: &'c mut Context<'view>) -> Self {
+    Self { context }
+  }
+
+  /// If `next` accesses a property of `prev`, like `foo.bar` of `foo`,
+  /// returns the accessed expression within `next`.
+  fn find_prefix<'a>(&self, prev: &Expr, next: &'a Expr) -> Option<&'a Expr> {
+    accessed_prefixes(next)
+      .into_iter()
+      .find(|prefix| expr_eq(prefix, prev))
+  }
+
+  /// Checks the operands of a `&&` chain and reports the runs of operands
+  /// which check the existence of the next one.
+  fn check_operands(&mut self, operands: &[&Expr]) {
+    let mut start = 0;
+    while start < operands.len() {
+      let mut prefixes = Vec::new();
+      let mut end = start;
+      while end + 1 < operands.len() {
+        let prev = unwrap_parens(operands[end]);
+        let next = unwrap_parens(operands[end + 1]);
+        match self.find_prefix(prev, next) {
+          Some(_) => {
+            prefixes.push(prev);
+            end += 1;
+          }
+          None => break,
+        }
+      }
+
+      if end > start {
+        let span = Span::new(
+          operands[start].span().lo,
+          operands[end].span().hi,
+          Default::default(),
+        );
+        self.report(span, &prefixes, unwrap_parens(operands[end]));
+      }
+      start = end + 1;
+    }
+  }
+
+  fn report(&mut self, span: Span, prefixes: &[&Expr], last: &Expr) {
+    match self.create_fixed_text(prefixes, last) {
+      Some(fixed) => {
+        let fix = self.context.create_fix(span, fixed, HINT);
+        self
+          .context
+          .add_diagnostic_with_hint_and_fix(span, CODE, MESSAGE, HINT, fix);
+      }
+      None => {
+        self
+          .context
+          .add_diagnostic_with_hint(span, CODE, MESSAGE, HINT);
+      }
+    }
+  }
+
+  /// Inserts `?.` after each of the `prefixes` in the text of `last`, e.g.
+  /// `foo.bar.baz` becomes `foo?.bar?.baz`.
+  fn create_fixed_text(
+    &self,
+    prefixes: &[&Expr],
+    last: &Expr,
+  ) -> Option<String> {
+    // A call in the chain is evaluated multiple times by the `&&` checks, but
+    // only once in an optional chain
+    if prefixes.iter().any(|prefix| contains_call(prefix)) {
+      return None;
+    }
+
+    let last_span = last.span();
+    let mut text = self.context.file_text_substring(&last_span).to_string();
+    let mut insert_positions = Vec::new();
+    for prefix in prefixes {
+      let accessed = self.find_prefix(prefix, last)?;
+      let offset = (accessed.span().hi - last_span.lo).0 as usize;
+      let insertion = match text[offset..].chars().next()? {
+        '.' => "?",
+        '[' | '(' => "?.",
+        _ => return None,
+      };
+      insert_positions.push((offset, insertion));
+    }
+
+    insert_positions.sort_unstable();
+    insert_positions.dedup();
+    for (offset, insertion) in insert_positions.into_iter().rev() {
+      text.insert_str(offset, insertion);
+    }
+    Some(text)
+  }
+}
+
+fn contains_call(expr: &Expr) -> bool {
+  matches!(expr, Expr::Call(_))
+    || accessed_prefixes(expr)
+      .into_iter()
+      .any(|prefix| matches!(prefix, Expr::Call(_)))
+}
+
+impl<'c, 'view> Visit for PreferOptionalChainVisitor<'c, 'view> {
+  noop_visit_type!();
+
+  fn visit_bin_expr(&mut self, bin_expr: &BinExpr, _: &dyn Node) {
+    if bin_expr.op != BinaryOp::LogicalAnd {
+      bin_expr.visit_children_with(self);
+      return;
+    }
+
+    let mut operands = Vec::new();
+    collect_operands(bin_expr, &mut operands);
+    self.check_operands(&operands);
+
+    // The nested `&&` expressions of the chain itself are skipped, since
+    // they have been checked as a whole
+    for operand in operands {
+      operand.visit_with(bin_expr, self);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_optional_chain_valid() {
+    assert_lint_ok! {
+      PreferOptionalChain,
+      "foo?.bar?.baz;",
+      "foo && bar;",
+      "foo && bar.baz;",
+      "foo.bar && foo.baz;",
+      "foo || foo.bar;",
+      "foo && foo;",
+      "foo && foo.bar === 1;",
+      "foo && typeof foo.bar;",
+      "foo.bar && foo.barbaz;",
+      "foo && fooBar.baz;",
+    };
+  }
+
+  #[test]
+  fn prefer_optional_chain_invalid() {
+    assert_lint_err! {
+      PreferOptionalChain,
+      "foo && foo.bar;": [
+        { col: 0, message: MESSAGE, hint: HINT, fix: "foo?.bar;" },
+      ],
+      "foo && foo.bar && foo.bar.baz;": [
+        { col: 0, message: MESSAGE, hint: HINT, fix: "foo?.bar?.baz;" },
+      ],
+      "foo && foo[bar] && foo[bar].baz;": [
+        { col: 0, message: MESSAGE, hint: HINT, fix: "foo?.[bar]?.baz;" },
+      ],
+      "foo && foo.bar && foo.bar();": [
+        { col: 0, message: MESSAGE, hint: HINT, fix: "foo?.bar?.();" },
+      ],
+      "foo.bar && foo.bar.baz.qux;": [
+        { col: 0, message: MESSAGE, hint: HINT, fix: "foo.bar?.baz.qux;" },
+      ],
+      "x && foo && foo.bar && y;": [
+        { col: 5, message: MESSAGE, hint: HINT, fix: "x && foo?.bar && y;" },
+      ],
+      "if (this.a && this.a.b) {}": [
+        { col: 4, message: MESSAGE, hint: HINT, fix: "if (this.a?.b) {}" },
+      ],
+      "foo && (foo.bar);": [
+        { col: 0, message: MESSAGE, hint: HINT, fix: "foo?.bar;" },
+      ],
+      "f(a && (b && b.c));": [
+        { col: 8, message: MESSAGE, hint: HINT, fix: "f(a && (b?.c));" },
+      ],
+      // calls would be evaluated a different number of times
+      "foo() && foo().bar;": [{ col: 0, message: MESSAGE, hint: HINT }],
+      "foo.bar() && foo.bar().baz;": [
+        { col: 0, message: MESSAGE, hint: HINT },
+      ],
+    };
+  }
+}
@@ -0,0 +1,433 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::ProgramRef;
+use deno_ast::swc::ast::{
+  Accessibility, ArrowExpr, AssignExpr, Class, ClassMember, Constructor, Expr,
+  ExprOrSuper, ForInStmt, ForOfStmt, Function, ObjectPatProp,
+  ParamOrTsParamProp, Pat, PatOrExpr, TsParamPropParam, UnaryExpr, UnaryOp,
+  UpdateExpr, VarDeclOrPat,
+};
+use deno_ast::swc::common::{Span, Spanned};
+use deno_ast::swc::visit::{noop_visit_type, Node, Visit, VisitWith};
+use derive_more::Display;
+use std::collections::HashSet;
+
+#[derive(Debug)]
+pub struct PreferReadonly;
+
+const CODE: &str = "prefer-readonly";
+const HINT: &str = "Add the `readonly` modifier";
+
+#[derive(Display)]
+enum PreferReadonlyMessage {
+  #[display(
+    fmt = "Member '{}' is never reassigned after the initialization",
+    _0
+  )]
+  NeverReassigned(String),
+}
+
+impl LintRule for PreferReadonly {
+  fn new() -> Box<Self> {
+    Box::new(PreferReadonly)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    let mut visitor = PreferReadonlyVisitor::new(context);
+    match program {
+      ProgramRef::Module(m) => visitor.visit_module(m, &DUMMY_NODE),
+      ProgramRef::Script(s) => visitor.visit_script(s, &DUMMY_NODE),
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/prefer_readonly.md")
+  }
+}
+
+/// Identifies a member of a class. `#name` and `name` are distinct members.
+#[derive(PartialEq, Eq, Hash)]
+struct MemberKey {
+  name: String,
+  is_private_name: bool,
+}
+
+impl std::fmt::Display for MemberKey {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    if self.is_private_name {
+      write!(f, "#{}", self.name)
+    } else {
+      write!(f, "{}", self.name)
+    }
+  }
+}
+
+/// A private member which may be made `readonly`
+struct Candidate {
+  key: MemberKey,
+  is_static: bool,
+  /// The span of the member name, before which `readonly` is inserted
+  name_span: Span,
+}
+
+/// Collects the members of a class which are written outside of their
+/// initialization. Writes to `this.member` directly in the constructor are
+/// initializations, while any other write, including the ones through other
+/// objects or in nested functions, is a reassignment.
+#[derive(Default)]
+struct MemberWriteFinder {
+  in_constructor: bool,
+  nested_class_depth: usize,
+  /// Members reassigned after the initialization of the instance
+  written: HashSet<MemberKey>,
+  /// Members assigned through `this` in the constructor
+  initialized: HashSet<MemberKey>,
+}
+
+impl MemberWriteFinder {
+  fn add_write(&mut self, target: &Expr) {
+    let member = match target {
+      Expr::Paren(paren) => return self.add_write(&paren.expr),
+      Expr::Member(member) if !member.computed => member,
+      _ => return,
+    };
+    let key = match &*member.prop {
+      Expr::Ident(ident) => MemberKey {
+        name: ident.sym.to_string(),
+        is_private_name: false,
+      },
+      Expr::PrivateName(private_name) => MemberKey {
+        name: private_name.id.sym.to_string(),
+        is_private_name: true,
+      },
+      _ => return,
+    };
+    let is_this = match &member.obj {
+      ExprOrSuper::Expr(obj) => matches!(&**obj, Expr::This(_)),
+      ExprOrSuper::Super(_) => false,
+    };
+    if self.in_constructor && is_this {
+      self.initialized.insert(key);
+    } else {
+      self.written.insert(key);
+    }
+  }
+
+  fn add_pat_writes(&mut self, pat: &Pat) {
+    match pat {
+      Pat::Expr(expr) => self.add_write(expr),
+      Pat::Array(array) => {
+        for elem in array.elems.iter().flatten() {
+          self.add_pat_writes(elem);
+        }
+      }
+      Pat::Object(object) => {
+        for prop in &object.props {
+          match prop {
+            ObjectPatProp::KeyValue(key_value) => {
+              self.add_pat_writes(&key_value.value)
+            }
+            ObjectPatProp::Rest(rest) => self.add_pat_writes(&rest.arg),
+            ObjectPatProp::Assign(_) => {}
+          }
+        }
+      }
+      Pat::Assign(assign) => self.add_pat_writes(&assign.left),
+      Pat::Rest(rest) => self.add_pat_writes(&rest.arg),
+      Pat::Ident(_) | Pat::Invalid(_) => {}
+    }
+  }
+
+  fn with_constructor_state(
+    &mut self,
+    in_constructor: bool,
+    f: impl FnOnce(&mut Self),
+  ) {
+    let saved = self.in_constructor;
+    self.in_constructor = in_constructor;
+    f(self);
+    self.in_constructor = saved;
+  }
+}
+
+impl Visit for MemberWriteFinder {
+  noop_visit_type!();
+
+  fn visit_constructor(&mut self, constructor: &Constructor, _: &dyn Node) {
+    let in_constructor = self.nested_class_depth == 0;
+    self.with_constructor_state(in_constructor, |finder| {
+      constructor.visit_children_with(finder);
+    });
+  }
+
+  fn visit_function(&mut self, function: &Function, _: &dyn Node) {
+    self.with_constructor_state(false, |finder| {
+      function.visit_children_with(finder);
+    });
+  }
+
+  fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr, _: &dyn Node) {
+    self.with_constructor_state(false, |finder| {
+      arrow_expr.visit_children_with(finder);
+    });
+  }
+
+  fn visit_class(&mut self, class: &Class, _: &dyn Node) {
+    self.nested_class_depth += 1;
+    self.with_constructor_state(false, |finder| {
+      class.visit_children_with(finder);
+    });
+    self.nested_class_depth -= 1;
+  }
+
+  fn visit_assign_expr(&mut self, assign_expr: &AssignExpr, _: &dyn Node) {
+    match &assign_expr.left {
+      PatOrExpr::Expr(expr) => self.add_write(expr),
+      PatOrExpr::Pat(pat) => self.add_pat_writes(pat),
+    }
+    assign_expr.visit_children_with(self);
+  }
+
+  fn visit_update_expr(&mut self, update_expr: &UpdateExpr, _: &dyn Node) {
+    self.add_write(&update_expr.arg);
+    update_expr.visit_children_with(self);
+  }
+
+  fn visit_unary_expr(&mut self, unary_expr: &UnaryExpr, _: &dyn Node) {
+    if unary_expr.op == UnaryOp::Delete {
+      self.add_write(&unary_expr.arg);
+    }
+    unary_expr.visit_children_with(self);
+  }
+
+  fn visit_for_in_stmt(&mut self, for_in_stmt: &ForInStmt, _: &dyn Node) {
+    if let VarDeclOrPat::Pat(pat) = &for_in_stmt.left {
+      self.add_pat_writes(pat);
+    }
+    for_in_stmt.visit_children_with(self);
+  }
+
+  fn visit_for_of_stmt(&mut self, for_of_stmt: &ForOfStmt, _: &dyn Node) {
+    if let VarDeclOrPat::Pat(pat) = &for_of_stmt.left {
+      self.add_pat_writes(pat);
+    }
+    for_of_stmt.visit_children_with(self);
+  }
+}
+
+/// Collects the private members of a class which are not `readonly` yet.
+fn collect_candidates(class: &Class) -> Vec<Candidate> {
+  let mut candidates = Vec::new();
+  for member in &class.body {
+    match member {
+      ClassMember::ClassProp(prop)
+        if prop.accessibility == Some(Accessibility::Private)
+          && !prop.readonly
+          && !prop.declare
+          && !prop.computed =>
+      {
+        if let Expr::Ident(ident) = &*prop.key {
+          candidates.push(Candidate {
+            key: MemberKey {
+              name: ident.sym.to_string(),
+              is_private_name: false,
+            },
+            is_static: prop.is_static,
+            name_span: ident.span,
+          });
+        }
+      }
+      ClassMember::PrivateProp(prop) if !prop.readonly => {
+        candidates.push(Candidate {
+          key: MemberKey {
+            name: prop.key.id.sym.to_string(),
+            is_private_name: true,
+          },
+          is_static: prop.is_static,
+          name_span: prop.key.span,
+        });
+      }
+      ClassMember::Constructor(constructor) => {
+        for param in &constructor.params {
+          let param_prop = match param {
+            ParamOrTsParamProp::TsParamProp(param_prop)
+              if param_prop.accessibility == Some(Accessibility::Private)
+                && !param_prop.readonly =>
+            {
+              param_prop
+            }
+            _ => continue,
+          };
+          let ident = match &param_prop.param {
+            TsParamPropParam::Ident(binding) => &binding.id,
+            TsParamPropParam::Assign(assign) => match &*assign.left {
+              Pat::Ident(binding) => &binding.id,
+              _ => continue,
+            },
+          };
+          candidates.push(Candidate {
+            key: MemberKey {
+              name: ident.sym.to_string(),
+              is_private_name: false,
+            },
+            is_static: false,
+            name_span: param_prop.param.span(),
+          });
+        }
+      }
+      _ => {}
+    }
+  }
+  candidates
+}
+
+struct PreferReadonlyVisitor<'c, 'view> {
+  context: &'c mut Context<'view>,
+}
+
+impl<'c, 'view> PreferReadonlyVisitor<'c, 'view> {
+  fn new(context: &'c mut Context<'view>) -> Self {
+    Self { context }
+  }
+}
+
+impl<'c, 'view> Visit for PreferReadonlyVisitor<'c, 'view> {
+  noop_visit_type!();
+
+  fn visit_class(&mut self, class: &Class, _: &dyn Node) {
+    class.visit_children_with(self);
+
+    let candidates = collect_candidates(class);
+    if candidates.is_empty() {
+      return;
+    }
+    let mut finder = MemberWriteFinder::default();
+    for member in &class.body {
+      member.visit_with(&DUMMY_NODE, &mut finder);
+    }
+
+    for candidate in candidates {
+      // Static members are not initialized by the constructor
+      let is_reassigned = finder.written.contains(&candidate.key)
+        || (candidate.is_static && finder.initialized.contains(&candidate.key));
+      if is_reassigned {
+        continue;
+      }
+      let fix = self.context.create_fix(
+        candidate.name_span.shrink_to_lo(),
+        "readonly ",
+        HINT,
+      );
+      self.context.add_diagnostic_with_hint_and_fix(
+        candidate.name_span,
+        CODE,
+        PreferReadonlyMessage::NeverReassigned(candidate.key.to_string()),
+        HINT,
+        fix,
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_readonly_valid() {
+    assert_lint_ok! {
+      PreferReadonly,
+      "class A { private readonly a = 1; }",
+      "class A { public a = 1; }",
+      "class A { protected a = 1; }",
+      "class A { a = 1; }",
+      "class A { private a = 1; f() { this.a = 2; } }",
+      "class A { private a = 1; f() { this.a++; } }",
+      "class A { private a = 1; f() { [this.a] = [2]; } }",
+      "class A { private a = 1; f() { ({ b: this.a } = o); } }",
+      "class A { private a = 1; f() { for (this.a of xs) {} } }",
+      "class A { private a = 1; f(other: A) { other.a = 2; } }",
+      "class A { private a: number; constructor() { g(() => { this.a = 1; }); } }",
+      "class A { private a = 1; constructor() { g(function () { this.a = 1; }); } }",
+      "class A { #a = 1; f() { this.#a = 2; } }",
+      "class A { readonly #a = 1; }",
+      "class A { constructor(private a: number) {} f() { this.a = 2; } }",
+      "class A { constructor(private readonly a: number) {} }",
+      "class A { private static a = 1; constructor() { this.a = 2; } }",
+      "class A { private static a = 1; static f() { A.a = 2; } }",
+      "class A { private [a] = 1; }",
+      // Writes in nested classes are conservatively treated as reassignments
+      "class A { private a = 1; f() { class B { constructor() { this.a = 1; } } } }",
+    };
+  }
+
+  #[test]
+  fn prefer_readonly_invalid() {
+    assert_lint_err! {
+      PreferReadonly,
+      "class A { private a = 1; }": [
+        {
+          col: 18,
+          message: PreferReadonlyMessage::NeverReassigned("a".to_string()),
+          hint: HINT,
+          fix: "class A { private readonly a = 1; }",
+        },
+      ],
+      "class A { private a: number; constructor() { this.a = 1; } }": [
+        {
+          col: 18,
+          message: PreferReadonlyMessage::NeverReassigned("a".to_string()),
+          hint: HINT,
+          fix: "class A { private readonly a: number; constructor() { this.a = 1; } }",
+        },
+      ],
+      "class A { #a = 1; f() { return this.#a; } }": [
+        {
+          col: 10,
+          message: PreferReadonlyMessage::NeverReassigned("#a".to_string()),
+          hint: HINT,
+          fix: "class A { readonly #a = 1; f() { return this.#a; } }",
+        },
+      ],
+      "class A { constructor(private a: number, private b = 1) {} }": [
+        {
+          col: 30,
+          message: PreferReadonlyMessage::NeverReassigned("a".to_string()),
+          hint: HINT,
+          fix: "class A { constructor(private readonly a: number, private b = 1) {} }",
+        },
+        {
+          col: 49,
+          message: PreferReadonlyMessage::NeverReassigned("b".to_string()),
+          hint: HINT,
+          fix: "class A { constructor(private a: number, private readonly b = 1) {} }",
+        },
+      ],
+      "class A { private static a = 1; }": [
+        {
+          col: 25,
+          message: PreferReadonlyMessage::NeverReassigned("a".to_string()),
+          hint: HINT,
+          fix: "class A { private static readonly a = 1; }",
+        },
+      ],
+      "class A { private a = 1; f() { this.b = 2; } }": [
+        {
+          col: 18,
+          message: PreferReadonlyMessage::NeverReassigned("a".to_string()),
+          hint: HINT,
+          fix: "class A { private readonly a = 1; f() { this.b = 2; } }",
+        },
+      ],
+    };
+  }
+}
@@ -0,0 +1,149 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::ProgramRef;
+use deno_ast::swc::ast::{
+  Constructor, Expr, Function, GetterProp, MemberExpr, SetterProp,
+};
+use deno_ast::swc::utils::ident::IdentLike;
+use deno_ast::swc::visit::{noop_visit_type, Node, Visit, VisitWith};
+
+#[derive(Debug)]
+pub struct PreferRestParams;
+
+const CODE: &str = "prefer-rest-params";
+const MESSAGE: &str = "Use the rest parameters instead of `arguments`";
+const HINT: &str =
+  "Declare the parameters as `(...args)` and refer to `args` instead";
+
+impl LintRule for PreferRestParams {
+  fn new() -> Box<Self> {
+    Box::new(PreferRestParams)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    let mut visitor = PreferRestParamsVisitor::new(context);
+    match program {
+      ProgramRef::Module(m) => m.visit_with(&DUMMY_NODE, &mut visitor),
+      ProgramRef::Script(s) => s.visit_with(&DUMMY_NODE, &mut visitor),
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/prefer_rest_params.md")
+  }
+}
+
+struct PreferRestParamsVisitor<'c, 'view> {
+  context: &'c mut Context<'view>,
+  /// Number of enclosing functions which have their own `arguments`. Arrow
+  /// functions are not counted since they inherit `arguments` from outside.
+  function_depth: usize,
+}
+
+impl<'c, 'view> PreferRestParamsVisitor<'c, 'view> {
+  fn new(context: &'c mut Context<'view>) -> Self {
+    Self {
+      context,
+      function_depth: 0,
+    }
+  }
+
+  fn with_function(&mut self, f: impl FnOnce(&mut Self)) {
+    self.function_depth += 1;
+    f(self);
+    self.function_depth -= 1;
+  }
+}
+
+impl<'c, 'view> Visit for PreferRestParamsVisitor<'c, 'view> {
+  noop_visit_type!();
+
+  fn visit_function(&mut self, function: &Function, _: &dyn Node) {
+    self.with_function(|v| function.visit_children_with(v));
+  }
+
+  fn visit_constructor(&mut self, constructor: &Constructor, _: &dyn Node) {
+    self.with_function(|v| constructor.visit_children_with(v));
+  }
+
+  fn visit_getter_prop(&mut self, getter_prop: &GetterProp, _: &dyn Node) {
+    self.with_function(|v| getter_prop.visit_children_with(v));
+  }
+
+  fn visit_setter_prop(&mut self, setter_prop: &SetterProp, _: &dyn Node) {
+    self.with_function(|v| setter_prop.visit_children_with(v));
+  }
+
+  fn visit_member_expr(&mut self, member_expr: &MemberExpr, _: &dyn Node) {
+    member_expr.obj.visit_with(member_expr, self);
+    if member_expr.computed {
+      member_expr.prop.visit_with(member_expr, self);
+    }
+  }
+
+  fn visit_expr(&mut self, expr: &Expr, _: &dyn Node) {
+    expr.visit_children_with(self);
+
+    if let Expr::Ident(ident) = expr {
+      if self.function_depth > 0
+        && ident.sym == *"arguments"
+        && self.context.scope().var(&ident.to_id()).is_none()
+      {
+        self
+          .context
+          .add_diagnostic_with_hint(ident.span, CODE, MESSAGE, HINT);
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_rest_params_valid() {
+    assert_lint_ok! {
+      PreferRestParams,
+      "function foo(...args) { console.log(args); }",
+      "arguments;",
+      "const f = () => arguments;",
+      "function foo() { const arguments = [1]; return arguments; }",
+      "function foo(arguments) { return arguments; }",
+      "function foo() { return bar.arguments; }",
+      "const obj = { arguments: 1 };",
+    };
+  }
+
+  #[test]
+  fn prefer_rest_params_invalid() {
+    assert_lint_err! {
+      PreferRestParams,
+      "function foo() { console.log(arguments); }": [
+        { col: 29, message: MESSAGE, hint: HINT },
+      ],
+      "function foo() { return arguments.length + arguments[0]; }": [
+        { col: 24, message: MESSAGE, hint: HINT },
+        { col: 43, message: MESSAGE, hint: HINT },
+      ],
+      "function foo() { return () => arguments; }": [
+        { col: 30, message: MESSAGE, hint: HINT },
+      ],
+      "class A { constructor() { super(...arguments); } }": [
+        { col: 35, message: MESSAGE, hint: HINT },
+      ],
+      "const obj = { foo() { return arguments; } };": [
+        { col: 29, message: MESSAGE, hint: HINT },
+      ],
+    };
+  }
+}
@@ -0,0 +1,156 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::is_nullish;
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{Expr, ExprOrSuper};
+use deno_ast::swc::common::Spanned;
+use deno_ast::swc::utils::ident::IdentLike;
+use deno_ast::view as ast_view;
+use if_chain::if_chain;
+
+#[derive(Debug)]
+pub struct PreferSpread;
+
+const CODE: &str = "prefer-spread";
+const MESSAGE: &str = "Use the spread operator instead of `.apply()`";
+const HINT: &str = "Call the function directly, e.g. `foo(...args)`";
+
+impl LintRule for PreferSpread {
+  fn new() -> Box<Self> {
+    Box::new(PreferSpread)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    PreferSpreadHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/prefer_spread.md")
+  }
+}
+
+/// Checks if passing `this_arg` to `.apply()` of `func` binds `this` the same
+/// way as calling `func` directly does. For example, `foo.apply(null, args)`
+/// and `obj.foo.apply(obj, args)` are equivalent to `foo(...args)` and
+/// `obj.foo(...args)` respectively.
+fn is_same_this_binding(func: &Expr, this_arg: &Expr, ctx: &Context) -> bool {
+  match func {
+    Expr::Ident(_) => is_nullish(this_arg, ctx.scope()),
+    Expr::Member(member_expr) => match (&member_expr.obj, this_arg) {
+      (ExprOrSuper::Expr(obj), Expr::Ident(this_ident)) => match &**obj {
+        Expr::Ident(obj_ident) => obj_ident.to_id() == this_ident.to_id(),
+        _ => false,
+      },
+      (ExprOrSuper::Expr(obj), Expr::This(_)) => {
+        matches!(&**obj, Expr::This(_))
+      }
+      _ => false,
+    },
+    _ => false,
+  }
+}
+
+struct PreferSpreadHandler;
+
+impl Handler for PreferSpreadHandler {
+  fn call_expr(&mut self, call_expr: &ast_view::CallExpr, ctx: &mut Context) {
+    let args = &call_expr.inner.args;
+
+    if_chain! {
+      if let ExprOrSuper::Expr(callee) = &call_expr.inner.callee;
+      if let Expr::Member(member_expr) = &**callee;
+      if !member_expr.computed;
+      if let Expr::Ident(method) = &*member_expr.prop;
+      if method.sym == *"apply";
+      if let ExprOrSuper::Expr(func) = &member_expr.obj;
+      if args.len() == 2;
+      if args.iter().all(|arg| arg.spread.is_none());
+      // `foo.apply(null, [a, b])` is handled by `no-useless-call`
+      if !matches!(&*args[1].expr, Expr::Array(_));
+      if is_same_this_binding(func, &args[0].expr, ctx);
+      then {
+        let new_text = format!(
+          "{}(...{})",
+          ctx.file_text_substring(&func.span()),
+          ctx.file_text_substring(&args[1].expr.span()),
+        );
+        let fix = ctx.create_fix(
+          call_expr.inner.span,
+          new_text,
+          "Use the spread operator",
+        );
+        ctx.add_diagnostic_with_hint_and_fix(
+          call_expr.inner.span,
+          CODE,
+          MESSAGE,
+          HINT,
+          fix,
+        );
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_spread_valid() {
+    assert_lint_ok! {
+      PreferSpread,
+      "foo(...args);",
+      "foo.apply(obj, args);",
+      "obj.foo.apply(null, args);",
+      "obj.foo.apply(other, args);",
+      "foo.apply(null, [a, b]);",
+      "foo.apply(null);",
+      "foo.apply(null, args, extra);",
+      "foo.call(null, args);",
+      "foo['apply'](null, args);",
+      "foo.apply(...args);",
+      "function f(undefined) { foo.apply(undefined, args); }",
+    };
+  }
+
+  #[test]
+  fn prefer_spread_invalid() {
+    assert_lint_err! {
+      PreferSpread,
+      "foo.apply(undefined, args);": [
+        { col: 0, message: MESSAGE, hint: HINT, fix: "foo(...args);" },
+      ],
+      "foo.apply(null, args);": [
+        { col: 0, message: MESSAGE, hint: HINT, fix: "foo(...args);" },
+      ],
+      "foo.apply(void 0, getArgs());": [
+        { col: 0, message: MESSAGE, hint: HINT, fix: "foo(...getArgs());" },
+      ],
+      "obj.foo.apply(obj, args);": [
+        { col: 0, message: MESSAGE, hint: HINT, fix: "obj.foo(...args);" },
+      ],
+      "class A { f() { this.foo.apply(this, args); } }": [
+        {
+          col: 16,
+          message: MESSAGE,
+          hint: HINT,
+          fix: "class A { f() { this.foo(...args); } }",
+        },
+      ],
+    };
+  }
+}
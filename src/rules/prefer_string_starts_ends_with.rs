@@ -0,0 +1,406 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::prefer_at::is_simple;
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::ast_eq::expr_eq;
+use crate::ProgramRef;
+use deno_ast::swc::ast::{
+  BinExpr, BinaryOp, CallExpr, Expr, ExprOrSpread, ExprOrSuper, Lit, Regex,
+};
+use deno_ast::swc::common::Spanned;
+use deno_ast::swc::visit::{noop_visit_type, Node, Visit, VisitWith};
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct PreferStringStartsEndsWith;
+
+const CODE: &str = "prefer-string-starts-ends-with";
+
+#[derive(Clone, Copy, Display)]
+enum PreferStringStartsEndsWithMessage {
+  #[display(fmt = "Use `.startsWith()` to check the start of a string")]
+  StartsWith,
+  #[display(fmt = "Use `.endsWith()` to check the end of a string")]
+  EndsWith,
+}
+
+#[derive(Display)]
+enum PreferStringStartsEndsWithHint {
+  #[display(fmt = "Replace it with `str.startsWith(...)`")]
+  StartsWith,
+  #[display(fmt = "Replace it with `str.endsWith(...)`")]
+  EndsWith,
+}
+
+impl PreferStringStartsEndsWithMessage {
+  fn method(self) -> &'static str {
+    match self {
+      Self::StartsWith => "startsWith",
+      Self::EndsWith => "endsWith",
+    }
+  }
+
+  fn hint(self) -> PreferStringStartsEndsWithHint {
+    match self {
+      Self::StartsWith => PreferStringStartsEndsWithHint::StartsWith,
+      Self::EndsWith => PreferStringStartsEndsWithHint::EndsWith,
+    }
+  }
+}
+
+impl LintRule for PreferStringStartsEndsWith {
+  fn new() -> Box<Self> {
+    Box::new(PreferStringStartsEndsWith)
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    let mut visitor = PreferStringStartsEndsWithVisitor::new(context);
+    match program {
+      ProgramRef::Module(m) => visitor.visit_module(m, &DUMMY_NODE),
+      ProgramRef::Script(s) => visitor.visit_script(s, &DUMMY_NODE),
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/prefer_string_starts_ends_with.md")
+  }
+}
+
+/// If the expression is a call like `receiver.name(args)` without spread
+/// arguments, returns the receiver and the arguments.
+fn method_call<'a>(
+  expr: &'a Expr,
+  name: &str,
+) -> Option<(&'a Expr, &'a [ExprOrSpread])> {
+  match expr {
+    Expr::Call(call) => method_call_of(call, name),
+    _ => None,
+  }
+}
+
+fn method_call_of<'a>(
+  call: &'a CallExpr,
+  name: &str,
+) -> Option<(&'a Expr, &'a [ExprOrSpread])> {
+  let member = match &call.callee {
+    ExprOrSuper::Expr(callee) => match &**callee {
+      Expr::Member(member) if !member.computed => member,
+      _ => return None,
+    },
+    ExprOrSuper::Super(_) => return None,
+  };
+  let is_name =
+    matches!(&*member.prop, Expr::Ident(ident) if ident.sym == *name);
+  let has_spread = call.args.iter().any(|arg| arg.spread.is_some());
+  match &member.obj {
+    ExprOrSuper::Expr(obj) if is_name && !has_spread => Some((obj, &call.args)),
+    _ => None,
+  }
+}
+
+fn is_number(expr: &Expr, value: f64) -> bool {
+  matches!(expr, Expr::Lit(Lit::Num(num)) if num.value == value)
+}
+
+/// Checks if the expression is a string literal of a single character, which
+/// can be compared to the result of `charAt()` or an index access.
+fn is_single_char(expr: &Expr) -> bool {
+  matches!(
+    expr,
+    Expr::Lit(Lit::Str(s)) if s.value.encode_utf16().count() == 1
+  )
+}
+
+/// If the expression accesses a character like `str.charAt(i)` or `str[i]`,
+/// returns `str` and `i`.
+fn char_access(expr: &Expr) -> Option<(&Expr, &Expr)> {
+  if let Some((receiver, args)) = method_call(expr, "charAt") {
+    return match args {
+      [arg] => Some((receiver, &arg.expr)),
+      _ => None,
+    };
+  }
+  match expr {
+    Expr::Member(member) if member.computed => match &member.obj {
+      ExprOrSuper::Expr(obj) => Some((obj, &member.prop)),
+      ExprOrSuper::Super(_) => None,
+    },
+    _ => None,
+  }
+}
+
+/// Checks if `index` is like `str.length - 1` for the given `str`.
+fn is_last_index(index: &Expr, receiver: &Expr) -> bool {
+  let bin = match index {
+    Expr::Bin(bin) if bin.op == BinaryOp::Sub && is_number(&bin.right, 1.0) => {
+      bin
+    }
+    _ => return false,
+  };
+  match &*bin.left {
+    Expr::Member(member) if !member.computed => match &member.obj {
+      ExprOrSuper::Expr(obj) => {
+        matches!(&*member.prop, Expr::Ident(ident) if ident.sym == *"length")
+          && is_simple(receiver)
+          && expr_eq(obj, receiver)
+      }
+      ExprOrSuper::Super(_) => false,
+    },
+    _ => false,
+  }
+}
+
+/// Matches `target === other` against the comparisons which can be replaced,
+/// and returns the kind of the check, the string and the searched value.
+fn match_comparison<'a>(
+  target: &'a Expr,
+  other: &'a Expr,
+) -> Option<(PreferStringStartsEndsWithMessage, &'a Expr, &'a Expr)> {
+  // `str.indexOf('a') === 0`
+  if let Some((receiver, [arg])) = method_call(target, "indexOf") {
+    if is_number(other, 0.0) && matches!(&*arg.expr, Expr::Lit(Lit::Str(_))) {
+      return Some((
+        PreferStringStartsEndsWithMessage::StartsWith,
+        receiver,
+        &arg.expr,
+      ));
+    }
+    return None;
+  }
+
+  // `str.charAt(0) === 'a'`, `str[str.length - 1] === 'a'`
+  let (receiver, index) = char_access(target)?;
+  if !is_single_char(other) {
+    return None;
+  }
+  if is_number(index, 0.0) {
+    Some((
+      PreferStringStartsEndsWithMessage::StartsWith,
+      receiver,
+      other,
+    ))
+  } else if is_last_index(index, receiver) {
+    Some((PreferStringStartsEndsWithMessage::EndsWith, receiver, other))
+  } else {
+    None
+  }
+}
+
+/// Checks if the regular expression only matches a plain string at the start
+/// or the end, like `/^foo/` or `/foo$/`.
+fn regex_check(regex: &Regex) -> Option<PreferStringStartsEndsWithMessage> {
+  // Flags like `i` or `m` change what the anchors match
+  if !regex.flags.is_empty() {
+    return None;
+  }
+  let is_plain = |s: &str| {
+    !s.is_empty() && !s.chars().any(|c| r"\.*+?()[]{}|^$/".contains(c))
+  };
+  let exp: &str = &regex.exp;
+  if let Some(rest) = exp.strip_prefix('^') {
+    if is_plain(rest) {
+      return Some(PreferStringStartsEndsWithMessage::StartsWith);
+    }
+  }
+  if let Some(rest) = exp.strip_suffix('$') {
+    if is_plain(rest) {
+      return Some(PreferStringStartsEndsWithMessage::EndsWith);
+    }
+  }
+  None
+}
+
+fn regex_literal(expr: &Expr) -> Option<&Regex> {
+  match expr {
+    Expr::Lit(Lit::Regex(regex)) => Some(regex),
+    _ => None,
+  }
+}
+
+struct PreferStringStartsEndsWithVisitor<'c, 'view> {
+  context: &'c mut Context<'view>,
+}
+
+impl<'c, 'view> PreferStringStartsEndsWithVisitor<'c, 'view> {
+  fn new(context: &'c mut Context<'view>) -> Self {
+    Self { context }
+  }
+}
+
+impl<'c, 'view> Visit for PreferStringStartsEndsWithVisitor<'c, 'view> {
+  noop_visit_type!();
+
+  fn visit_bin_expr(&mut self, bin_expr: &BinExpr, _: &dyn Node) {
+    bin_expr.visit_children_with(self);
+
+    let negated = match bin_expr.op {
+      BinaryOp::EqEq | BinaryOp::EqEqEq => false,
+      BinaryOp::NotEq | BinaryOp::NotEqEq => true,
+      _ => return,
+    };
+    let (message, receiver, search) =
+      match match_comparison(&bin_expr.left, &bin_expr.right)
+        .or_else(|| match_comparison(&bin_expr.right, &bin_expr.left))
+      {
+        Some(matched) => matched,
+        None => return,
+      };
+
+    let fixed = format!(
+      "{}{}.{}({})",
+      if negated { "!" } else { "" },
+      self.context.file_text_substring(&receiver.span()),
+      message.method(),
+      self.context.file_text_substring(&search.span()),
+    );
+    let fix = self.context.create_fix(
+      bin_expr.span,
+      fixed,
+      format!("Replace with `.{}()`", message.method()),
+    );
+    self.context.add_diagnostic_with_hint_and_fix(
+      bin_expr.span,
+      CODE,
+      message,
+      message.hint(),
+      fix,
+    );
+  }
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _: &dyn Node) {
+    call_expr.visit_children_with(self);
+
+    // `/^foo/.test(str)`
+    let regex = match method_call_of(call_expr, "test") {
+      Some((receiver, [_])) => regex_literal(receiver),
+      // `str.match(/^foo/)`
+      _ => match method_call_of(call_expr, "match") {
+        Some((_, [arg])) => regex_literal(&arg.expr),
+        _ => None,
+      },
+    };
+    if let Some(message) = regex.and_then(regex_check) {
+      self.context.add_diagnostic_with_hint(
+        call_expr.span,
+        CODE,
+        message,
+        message.hint(),
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_string_starts_ends_with_valid() {
+    assert_lint_ok! {
+      PreferStringStartsEndsWith,
+      "str.startsWith('a');",
+      "str.endsWith('a');",
+      "str.charAt(1) === 'a';",
+      "str.charAt(0) === 'ab';",
+      "str.charAt(0) === x;",
+      "str.charAt(0) < 'a';",
+      "str[1] === 'a';",
+      "str[other.length - 1] === 'a';",
+      "str.charAt(str.length - 2) === 'a';",
+      "str.indexOf('a') === 1;",
+      "str.indexOf(x) === 0;",
+      "str.indexOf('a', 1) === 0;",
+      "/^foo/i.test(str);",
+      "/^fo+/.test(str);",
+      "/^foo$/.test(str);",
+      "/foo/.test(str);",
+      "/^foo/.exec(str);",
+      "str.match(/foo\\$/);",
+      "regex.test(str);",
+    };
+  }
+
+  #[test]
+  fn prefer_string_starts_ends_with_invalid() {
+    assert_lint_err! {
+      PreferStringStartsEndsWith,
+      "str.charAt(0) === 'a';": [
+        {
+          col: 0,
+          message: PreferStringStartsEndsWithMessage::StartsWith,
+          hint: PreferStringStartsEndsWithHint::StartsWith,
+          fix: "str.startsWith('a');",
+        }
+      ],
+      "str[0] !== 'a';": [
+        {
+          col: 0,
+          message: PreferStringStartsEndsWithMessage::StartsWith,
+          hint: PreferStringStartsEndsWithHint::StartsWith,
+          fix: "!str.startsWith('a');",
+        }
+      ],
+      "'a' == str[0];": [
+        {
+          col: 0,
+          message: PreferStringStartsEndsWithMessage::StartsWith,
+          hint: PreferStringStartsEndsWithHint::StartsWith,
+          fix: "str.startsWith('a');",
+        }
+      ],
+      "str.indexOf('abc') === 0;": [
+        {
+          col: 0,
+          message: PreferStringStartsEndsWithMessage::StartsWith,
+          hint: PreferStringStartsEndsWithHint::StartsWith,
+          fix: "str.startsWith('abc');",
+        }
+      ],
+      "this.name.charAt(this.name.length - 1) === '/';": [
+        {
+          col: 0,
+          message: PreferStringStartsEndsWithMessage::EndsWith,
+          hint: PreferStringStartsEndsWithHint::EndsWith,
+          fix: "this.name.endsWith('/');",
+        }
+      ],
+      "if (str[str.length - 1] != 'a') {}": [
+        {
+          col: 4,
+          message: PreferStringStartsEndsWithMessage::EndsWith,
+          hint: PreferStringStartsEndsWithHint::EndsWith,
+          fix: "if (!str.endsWith('a')) {}",
+        }
+      ],
+      "/^foo/.test(str);": [
+        {
+          col: 0,
+          message: PreferStringStartsEndsWithMessage::StartsWith,
+          hint: PreferStringStartsEndsWithHint::StartsWith,
+        }
+      ],
+      "/bar$/.test(str);": [
+        {
+          col: 0,
+          message: PreferStringStartsEndsWithMessage::EndsWith,
+          hint: PreferStringStartsEndsWithHint::EndsWith,
+        }
+      ],
+      "str.match(/^foo/);": [
+        {
+          col: 0,
+          message: PreferStringStartsEndsWithMessage::StartsWith,
+          hint: PreferStringStartsEndsWithHint::StartsWith,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,214 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule, DUMMY_NODE};
+use crate::ProgramRef;
+use deno_ast::swc::ast::{CallExpr, Expr, ExprOrSuper, Lit};
+use deno_ast::swc::visit::{noop_visit_type, Node, Visit, VisitWith};
+use derive_more::Display;
+use serde::Deserialize;
+
+#[derive(Debug, Default)]
+pub struct RequireArraySortCompare {
+  options: RequireArraySortCompareOptions,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RequireArraySortCompareOptions {
+  /// Whether sorting array literals which only contain strings is allowed.
+  pub ignore_string_arrays: bool,
+}
+
+const CODE: &str = "require-array-sort-compare";
+const HINT: &str =
+  "Pass a compare function, like `(a, b) => a - b` to sort numbers";
+
+#[derive(Display)]
+enum RequireArraySortCompareMessage {
+  #[display(
+    fmt = "`{}()` without a compare function sorts elements as strings",
+    _0
+  )]
+  MissingCompare(&'static str),
+}
+
+impl RequireArraySortCompare {
+  pub fn with_options(options: RequireArraySortCompareOptions) -> Box<Self> {
+    Box::new(RequireArraySortCompare { options })
+  }
+}
+
+impl LintRule for RequireArraySortCompare {
+  fn new() -> Box<Self> {
+    Box::new(RequireArraySortCompare::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: ProgramRef<'view>,
+  ) {
+    let mut visitor =
+      RequireArraySortCompareVisitor::new(context, &self.options);
+    match program {
+      ProgramRef::Module(m) => visitor.visit_module(m, &DUMMY_NODE),
+      ProgramRef::Script(s) => visitor.visit_script(s, &DUMMY_NODE),
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/require_array_sort_compare.md")
+  }
+}
+
+/// Checks if the expression is an array literal containing only strings. Since
+/// there is no type information, this is the only case where the elements are
+/// known to be strings.
+fn is_string_array(expr: &Expr) -> bool {
+  match expr {
+    Expr::Paren(paren) => is_string_array(&paren.expr),
+    Expr::Array(array_lit) => array_lit.elems.iter().all(|elem| match elem {
+      Some(elem) => {
+        elem.spread.is_none()
+          && matches!(&*elem.expr, Expr::Lit(Lit::Str(_)) | Expr::Tpl(_))
+      }
+      None => false,
+    }),
+    _ => false,
+  }
+}
+
+struct RequireArraySortCompareVisitor<'c, 'view, 'o> {
+  context: &'c mut Context<'view>,
+  options: &'o RequireArraySortCompareOptions,
+}
+
+impl<'c, 'view, 'o> RequireArraySortCompareVisitor<'c, 'view, 'o> {
+  fn new(
+    context: &'c mut Context<'view>,
+    options: &'o RequireArraySortCompareOptions,
+  ) -> Self {
+    Self { context, options }
+  }
+}
+
+impl<'c, 'view, 'o> Visit for RequireArraySortCompareVisitor<'c, 'view, 'o> {
+  noop_visit_type!();
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr, _: &dyn Node) {
+    call_expr.visit_children_with(self);
+
+    if !call_expr.args.is_empty() {
+      return;
+    }
+    let member = match &call_expr.callee {
+      ExprOrSuper::Expr(callee) => match &**callee {
+        Expr::Member(member) if !member.computed => member,
+        _ => return,
+      },
+      ExprOrSuper::Super(_) => return,
+    };
+    let method = match &*member.prop {
+      Expr::Ident(prop) if prop.sym == *"sort" => "sort",
+      Expr::Ident(prop) if prop.sym == *"toSorted" => "toSorted",
+      _ => return,
+    };
+    if let ExprOrSuper::Expr(obj) = &member.obj {
+      if self.options.ignore_string_arrays && is_string_array(obj) {
+        return;
+      }
+    }
+
+    self.context.add_diagnostic_with_hint(
+      call_expr.span,
+      CODE,
+      RequireArraySortCompareMessage::MissingCompare(method),
+      HINT,
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  #[test]
+  fn require_array_sort_compare_valid() {
+    assert_lint_ok! {
+      RequireArraySortCompare,
+      "arr.sort((a, b) => a - b);",
+      "arr.toSorted((a, b) => a - b);",
+      "arr.sort(compare);",
+      "sort();",
+      "arr[sort]();",
+      "arr.sorted();",
+    };
+
+    assert_lint_ok! {
+      RequireArraySortCompare,
+      options: json!({ "ignoreStringArrays": true }),
+      "['b', 'a'].sort();",
+      "[`b`, 'a'].toSorted();",
+      "([]).sort();",
+    };
+  }
+
+  #[test]
+  fn require_array_sort_compare_invalid() {
+    assert_lint_err! {
+      RequireArraySortCompare,
+      "arr.sort();": [
+        {
+          col: 0,
+          message: RequireArraySortCompareMessage::MissingCompare("sort"),
+          hint: HINT,
+        },
+      ],
+      "const sorted = arr.toSorted();": [
+        {
+          col: 15,
+          message: RequireArraySortCompareMessage::MissingCompare("toSorted"),
+          hint: HINT,
+        },
+      ],
+      "[3, 20, 100].sort();": [
+        {
+          col: 0,
+          message: RequireArraySortCompareMessage::MissingCompare("sort"),
+          hint: HINT,
+        },
+      ],
+      "['b', 'a'].sort();": [
+        {
+          col: 0,
+          message: RequireArraySortCompareMessage::MissingCompare("sort"),
+          hint: HINT,
+        },
+      ],
+    };
+
+    assert_lint_err! {
+      RequireArraySortCompare,
+      options: json!({ "ignoreStringArrays": true }),
+      "['b', 1].sort();": [
+        {
+          col: 0,
+          message: RequireArraySortCompareMessage::MissingCompare("sort"),
+          hint: HINT,
+        },
+      ],
+      "strings.sort();": [
+        {
+          col: 0,
+          message: RequireArraySortCompareMessage::MissingCompare("sort"),
+          hint: HINT,
+        },
+      ],
+    };
+  }
+}
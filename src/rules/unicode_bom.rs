@@ -0,0 +1,168 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::diagnostic::LintFix;
+use crate::ProgramRef;
+use deno_ast::swc::common::{BytePos, Span};
+use derive_more::Display;
+use serde::Deserialize;
+
+#[derive(Debug, Default)]
+pub struct UnicodeBom {
+  options: UnicodeBomOptions,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct UnicodeBomOptions {
+  /// Whether files must or must not start with a byte order mark.
+  pub mode: UnicodeBomMode,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UnicodeBomMode {
+  /// Files must start with a byte order mark.
+  Always,
+  /// Files must not start with a byte order mark.
+  Never,
+}
+
+impl Default for UnicodeBomMode {
+  fn default() -> Self {
+    UnicodeBomMode::Never
+  }
+}
+
+const CODE: &str = "unicode-bom";
+
+#[derive(Display)]
+enum UnicodeBomMessage {
+  #[display(
+    fmt = "Expected a Unicode byte order mark at the start of the file"
+  )]
+  Expected,
+  #[display(
+    fmt = "Unexpected Unicode byte order mark at the start of the file"
+  )]
+  Unexpected,
+}
+
+#[derive(Display)]
+enum UnicodeBomHint {
+  #[display(fmt = "Add a byte order mark (U+FEFF) at the start of the file")]
+  Add,
+  #[display(fmt = "Remove the byte order mark at the start of the file")]
+  Remove,
+}
+
+impl UnicodeBom {
+  pub fn with_options(options: UnicodeBomOptions) -> Box<Self> {
+    Box::new(UnicodeBom { options })
+  }
+}
+
+impl LintRule for UnicodeBom {
+  fn new() -> Box<Self> {
+    Box::new(UnicodeBom::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program<'view>(
+    &self,
+    context: &mut Context<'view>,
+    _program: ProgramRef<'view>,
+  ) {
+    // Nothing can be told about a source whose byte order mark is unknown
+    let has_bom = match context.has_bom() {
+      Some(has_bom) => has_bom,
+      None => return,
+    };
+    let start = Span::new(BytePos(0), BytePos(0), Default::default());
+    match (self.options.mode, has_bom) {
+      (UnicodeBomMode::Always, false) => {
+        let fix =
+          context.create_fix(start, '\u{FEFF}', "Add a byte order mark");
+        context.add_diagnostic_with_hint_and_fix(
+          start,
+          CODE,
+          UnicodeBomMessage::Expected,
+          UnicodeBomHint::Add,
+          fix,
+        );
+      }
+      (UnicodeBomMode::Never, true) => {
+        // Positions don't account for the stripped byte order mark, so the
+        // fix is flagged instead of covering it with its range
+        let fix = LintFix {
+          removes_bom: true,
+          ..context.create_fix(start, "", "Remove the byte order mark")
+        };
+        context.add_diagnostic_with_hint_and_fix(
+          start,
+          CODE,
+          UnicodeBomMessage::Unexpected,
+          UnicodeBomHint::Remove,
+          fix,
+        );
+      }
+      _ => {}
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/unicode_bom.md")
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  #[test]
+  fn unicode_bom_valid() {
+    assert_lint_ok! {
+      UnicodeBom,
+      "const a = 1;",
+      "",
+    };
+
+    assert_lint_ok! {
+      UnicodeBom,
+      options: json!({ "mode": "always" }),
+      "\u{FEFF}const a = 1;",
+    };
+  }
+
+  #[test]
+  fn unicode_bom_invalid() {
+    assert_lint_err! {
+      UnicodeBom,
+      "\u{FEFF}const a = 1;": [
+        {
+          col: 0,
+          message: UnicodeBomMessage::Unexpected,
+          hint: UnicodeBomHint::Remove,
+          fix: "const a = 1;",
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      UnicodeBom,
+      options: json!({ "mode": "always" }),
+      "const a = 1;": [
+        {
+          col: 0,
+          message: UnicodeBomMessage::Expected,
+          hint: UnicodeBomHint::Add,
+          fix: "\u{FEFF}const a = 1;",
+        }
+      ],
+    };
+  }
+}
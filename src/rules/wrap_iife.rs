@@ -0,0 +1,335 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{CallExpr, Expr, ExprOrSuper};
+use deno_ast::swc::common::{BytePos, Span};
+use deno_ast::view as ast_view;
+use derive_more::Display;
+use serde::Deserialize;
+
+#[derive(Debug, Default)]
+pub struct WrapIife {
+  options: WrapIifeOptions,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct WrapIifeOptions {
+  /// Where the wrapping parentheses should be placed.
+  pub style: WrapIifeStyle,
+  /// Whether functions invoked via `.call()` or `.apply()` are checked too.
+  pub function_prototype_methods: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WrapIifeStyle {
+  /// `(function () {}())`
+  Outside,
+  /// `(function () {})()`
+  Inside,
+  /// Either of the above
+  Any,
+}
+
+impl Default for WrapIifeStyle {
+  fn default() -> Self {
+    WrapIifeStyle::Outside
+  }
+}
+
+const CODE: &str = "wrap-iife";
+
+#[derive(Display)]
+enum WrapIifeMessage {
+  #[display(fmt = "Wrap an immediate function invocation in parentheses")]
+  WrapInvocation,
+  #[display(fmt = "Wrap only the function expression in parentheses")]
+  WrapFunctionOnly,
+  #[display(
+    fmt = "Move the invocation into the parentheses that contain the function"
+  )]
+  MoveInvocationIntoParens,
+}
+
+impl WrapIife {
+  pub fn with_options(options: WrapIifeOptions) -> Box<Self> {
+    Box::new(WrapIife { options })
+  }
+}
+
+impl LintRule for WrapIife {
+  fn new() -> Box<Self> {
+    Box::new(WrapIife::default())
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program(&self, _context: &mut Context, _program: ProgramRef<'_>) {
+    unreachable!();
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    WrapIifeHandler::new(&self.options).traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/wrap_iife.md")
+  }
+}
+
+/// Returns the span of the function expression if the call is an IIFE, i.e.
+/// `function () {}()`, or `function () {}.call()` if
+/// `function_prototype_methods` is enabled. Arrow functions are not checked
+/// since they can only be invoked when they are wrapped in parentheses.
+fn iife_function_span(
+  call_expr: &CallExpr,
+  function_prototype_methods: bool,
+) -> Option<Span> {
+  fn unwrap_fn(expr: &Expr) -> Option<Span> {
+    match expr {
+      Expr::Paren(paren) => unwrap_fn(&paren.expr),
+      Expr::Fn(fn_expr) => Some(fn_expr.function.span),
+      _ => None,
+    }
+  }
+
+  let callee = match &call_expr.callee {
+    ExprOrSuper::Expr(expr) => &**expr,
+    ExprOrSuper::Super(_) => return None,
+  };
+
+  if let Some(span) = unwrap_fn(callee) {
+    return Some(span);
+  }
+
+  if !function_prototype_methods {
+    return None;
+  }
+
+  match callee {
+    Expr::Member(member_expr) if !member_expr.computed => {
+      match (&member_expr.obj, &*member_expr.prop) {
+        (ExprOrSuper::Expr(obj), Expr::Ident(prop))
+          if prop.sym == *"call" || prop.sym == *"apply" =>
+        {
+          unwrap_fn(obj)
+        }
+        _ => None,
+      }
+    }
+    _ => None,
+  }
+}
+
+/// Returns byte positions of the parentheses directly wrapping the given span,
+/// if any.
+fn wrapping_parens(text: &str, span: Span) -> Option<(usize, usize)> {
+  let open = text[..span.lo.0 as usize]
+    .char_indices()
+    .rev()
+    .find(|(_, c)| !c.is_whitespace())
+    .filter(|(_, c)| *c == '(')?
+    .0;
+  let close = text[span.hi.0 as usize..]
+    .char_indices()
+    .find(|(_, c)| !c.is_whitespace())
+    .filter(|(_, c)| *c == ')')?
+    .0
+    + span.hi.0 as usize;
+  Some((open, close))
+}
+
+fn span_between(lo: usize, hi: usize) -> Span {
+  Span::new(BytePos(lo as u32), BytePos(hi as u32), Default::default())
+}
+
+struct WrapIifeHandler<'o> {
+  options: &'o WrapIifeOptions,
+}
+
+impl<'o> WrapIifeHandler<'o> {
+  fn new(options: &'o WrapIifeOptions) -> Self {
+    Self { options }
+  }
+}
+
+impl<'o> Handler for WrapIifeHandler<'o> {
+  fn call_expr(&mut self, call_expr: &ast_view::CallExpr, ctx: &mut Context) {
+    let fn_span = match iife_function_span(
+      call_expr.inner,
+      self.options.function_prototype_methods,
+    ) {
+      Some(span) => span,
+      None => return,
+    };
+    let call_span = call_expr.inner.span;
+
+    let text = ctx.source_file().text();
+    let fn_text = ctx.file_text_substring(&fn_span);
+    let call_parens = wrapping_parens(text, call_span);
+    let fn_parens = wrapping_parens(text, fn_span);
+
+    let (message, fix_span, new_text) =
+      match (call_parens, fn_parens, self.options.style) {
+        (None, None, WrapIifeStyle::Inside) => (
+          WrapIifeMessage::WrapInvocation,
+          fn_span,
+          format!("({})", fn_text),
+        ),
+        (None, None, _) => (
+          WrapIifeMessage::WrapInvocation,
+          call_span,
+          format!("({})", ctx.file_text_substring(&call_span)),
+        ),
+        (Some((open, close)), None, WrapIifeStyle::Inside) => {
+          // The parentheses may belong to something else, e.g. arguments of
+          // another call like `foo(function () {}())`, in which case they
+          // have to be left as is.
+          if call_expr.parent().is::<ast_view::ParenExpr>() {
+            let invocation = ctx.file_text_substring(&span_between(
+              fn_span.hi.0 as usize,
+              call_span.hi.0 as usize,
+            ));
+            (
+              WrapIifeMessage::WrapFunctionOnly,
+              span_between(open, close + 1),
+              format!("({}){}", fn_text, invocation),
+            )
+          } else {
+            (
+              WrapIifeMessage::WrapFunctionOnly,
+              fn_span,
+              format!("({})", fn_text),
+            )
+          }
+        }
+        (None, Some((open, close)), WrapIifeStyle::Outside) => {
+          let invocation = ctx.file_text_substring(&span_between(
+            close + 1,
+            call_span.hi.0 as usize,
+          ));
+          (
+            WrapIifeMessage::MoveInvocationIntoParens,
+            span_between(open, call_span.hi.0 as usize),
+            format!("({}{})", fn_text, invocation),
+          )
+        }
+        _ => return,
+      };
+
+    let fix = ctx.create_fix(fix_span, new_text, "Fix the parentheses");
+    ctx.add_diagnostic_with_fix(call_span, CODE, message, fix);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  #[test]
+  fn wrap_iife_valid() {
+    assert_lint_ok! {
+      WrapIife,
+      "(function () {}());",
+      "(function () {}.call(this));",
+      "(function () {}).call(this);",
+      "foo(function () {}());",
+      // The parentheses of the call arguments wrap the invocation
+      "foo((function () {})(a, b));",
+      "(() => {})();",
+      "const foo = function () {};",
+      "foo(function () {});",
+    };
+
+    assert_lint_ok! {
+      WrapIife,
+      options: json!({ "style": "inside" }),
+      "(function () {})();",
+      "((function () {})());",
+      "(function () {}).call(this);",
+    };
+
+    assert_lint_ok! {
+      WrapIife,
+      options: json!({ "style": "any" }),
+      "(function () {}());",
+      "(function () {})();",
+    };
+  }
+
+  #[test]
+  fn wrap_iife_invalid() {
+    assert_lint_err! {
+      WrapIife,
+      "const x = function () { return 1; }();": [
+        {
+          col: 10,
+          message: WrapIifeMessage::WrapInvocation,
+          fix: "const x = (function () { return 1; }());",
+        },
+      ],
+      "(function () {})();": [
+        {
+          col: 0,
+          message: WrapIifeMessage::MoveInvocationIntoParens,
+          fix: "(function () {}());",
+        },
+      ],
+    };
+
+    assert_lint_err! {
+      WrapIife,
+      options: json!({ "style": "inside" }),
+      "const x = function () {}();": [
+        {
+          col: 10,
+          message: WrapIifeMessage::WrapInvocation,
+          fix: "const x = (function () {})();",
+        },
+      ],
+      "(function () {}());": [
+        {
+          col: 1,
+          message: WrapIifeMessage::WrapFunctionOnly,
+          fix: "(function () {})();",
+        },
+      ],
+      "foo(function () {}());": [
+        {
+          col: 4,
+          message: WrapIifeMessage::WrapFunctionOnly,
+          fix: "foo((function () {})());",
+        },
+      ],
+    };
+
+    assert_lint_err! {
+      WrapIife,
+      options: json!({ "functionPrototypeMethods": true }),
+      "const x = function () {}.call(this);": [
+        {
+          col: 10,
+          message: WrapIifeMessage::WrapInvocation,
+          fix: "const x = (function () {}.call(this));",
+        },
+      ],
+      "(function () {}).apply(this, args);": [
+        {
+          col: 0,
+          message: WrapIifeMessage::MoveInvocationIntoParens,
+          fix: "(function () {}.apply(this, args));",
+        },
+      ],
+    };
+  }
+}
@@ -1,13 +1,16 @@
 // Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
 use deno_ast::swc::ast::{
-  ArrowExpr, BlockStmt, BlockStmtOrExpr, CatchClause, ClassDecl, ClassExpr,
-  DoWhileStmt, Expr, FnDecl, FnExpr, ForInStmt, ForOfStmt, ForStmt, Function,
-  Ident, ImportDefaultSpecifier, ImportNamedSpecifier, ImportStarAsSpecifier,
-  Invalid, Param, Pat, SwitchStmt, TsTypeAliasDecl, VarDecl, VarDeclKind,
-  WhileStmt, WithStmt,
+  ArrowExpr, AssignExpr, AssignOp, BlockStmt, BlockStmtOrExpr, CatchClause,
+  ClassDecl, ClassExpr, ClassProp, DoWhileStmt, ExportSpecifier, Expr, FnDecl,
+  FnExpr, ForInStmt, ForOfStmt, ForStmt, Function, Ident,
+  ImportDefaultSpecifier, ImportNamedSpecifier, ImportStarAsSpecifier, Invalid,
+  MemberExpr, NamedExport, Param, Pat, PatOrExpr, Prop, SwitchStmt,
+  TsEntityName, TsEnumDecl, TsExprWithTypeArgs, TsPropertySignature,
+  TsTypeAliasDecl, TsTypeQueryExpr, UpdateExpr, VarDecl, VarDeclKind,
+  VarDeclOrPat, WhileStmt, WithStmt,
 };
 use deno_ast::swc::atoms::JsWord;
-use deno_ast::swc::common::DUMMY_SP;
+use deno_ast::swc::common::{Span, DUMMY_SP};
 use deno_ast::swc::utils::find_ids;
 use deno_ast::swc::utils::ident::IdentLike;
 use deno_ast::swc::utils::Id;
@@ -17,10 +20,23 @@ use deno_ast::swc::visit::VisitWith;
 use deno_ast::view as ast_view;
 use std::collections::HashMap;
 
+pub mod bindings;
+
+/// The bindings of a program along with the references to them.
+///
+/// Identifiers are resolved by their `Id`, which the resolver of swc makes
+/// unique for each binding, so bindings are kept in a flat table rather than
+/// in a tree of scopes. Each binding only records the kinds of the scopes
+/// enclosing its declaration. Rules which need to know the names visible at
+/// some point of the program, like `no-shadow`, collect them per scope with
+/// the helpers in `bindings`.
 #[derive(Debug, Default)]
 pub struct Scope {
   vars: HashMap<Id, Var>,
   symbols: HashMap<JsWord, Vec<Id>>,
+  /// References are collected separately since they may precede the
+  /// declaration of their binding, e.g. calls of hoisted functions.
+  references: HashMap<Id, Vec<Reference>>,
 }
 
 impl Scope {
@@ -49,6 +65,12 @@ impl Scope {
       }
     };
 
+    for (id, references) in std::mem::take(&mut scope.references) {
+      if let Some(var) = scope.vars.get_mut(&id) {
+        var.references = references;
+      }
+    }
+
     scope
   }
 
@@ -61,20 +83,36 @@ impl Scope {
     self.vars.get(id)
   }
 
+  /// Returns the binding which `ident` refers to, or `None` if it refers to
+  /// a global variable.
+  pub fn resolve(&self, ident: &Ident) -> Option<&Var> {
+    self.var(&ident.to_id())
+  }
+
   pub fn is_global(&self, id: &Id) -> bool {
     self.var(id).is_none()
   }
+
+  /// Checks if no binding of the program is named `sym`, so that a reference
+  /// to it anywhere refers to the global variable. This is useful for fixes
+  /// which introduce a new reference, like `Object.hasOwn`.
+  pub fn is_global_symbol(&self, sym: &JsWord) -> bool {
+    self
+      .ids_with_symbol(sym)
+      .map_or(true, |ids| ids.iter().all(|id| self.is_global(id)))
+  }
 }
 
 #[derive(Debug)]
 pub struct Var {
   path: Vec<ScopeKind>,
   kind: BindingKind,
+  declared_at: Span,
+  references: Vec<Reference>,
 }
 
 impl Var {
   /// Empty path means root scope.
-  #[allow(dead_code)]
   pub fn path(&self) -> &[ScopeKind] {
     &self.path
   }
@@ -82,6 +120,47 @@ impl Var {
   pub fn kind(&self) -> BindingKind {
     self.kind
   }
+
+  /// Span of the identifier of the first declaration of the binding.
+  pub fn declared_at(&self) -> Span {
+    self.declared_at
+  }
+
+  /// References to the binding, both reads and writes, in source order.
+  /// Declarations are not included.
+  pub fn references(&self) -> &[Reference] {
+    &self.references
+  }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Reference {
+  span: Span,
+  kind: ReferenceKind,
+}
+
+impl Reference {
+  /// Span of the identifier referring to the binding.
+  pub fn span(&self) -> Span {
+    self.span
+  }
+
+  pub fn is_read(&self) -> bool {
+    matches!(self.kind, ReferenceKind::Read | ReferenceKind::ReadWrite)
+  }
+
+  pub fn is_write(&self) -> bool {
+    matches!(self.kind, ReferenceKind::Write | ReferenceKind::ReadWrite)
+  }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum ReferenceKind {
+  Read,
+  /// Assignment, like `a` of `a = 1` or `[a] = foo`.
+  Write,
+  /// Compound assignment or update, like `a` of `a += 1` or `a++`.
+  ReadWrite,
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
@@ -106,6 +185,7 @@ pub enum BindingKind {
   ValueImport,
 
   TypeAlias,
+  Enum,
 }
 
 impl BindingKind {
@@ -136,26 +216,78 @@ struct Analyzer<'a> {
 }
 
 impl Analyzer<'_> {
-  fn declare_id(&mut self, kind: BindingKind, i: Id) {
-    self.scope.vars.insert(
-      i.clone(),
-      Var {
+  fn declare(&mut self, kind: BindingKind, i: &Ident) {
+    let id = i.to_id();
+    let path = self.path.clone();
+    // Redeclarations update the binding, but keep the first declaration
+    self
+      .scope
+      .vars
+      .entry(id.clone())
+      .and_modify(|var| {
+        var.kind = kind;
+        var.path = path.clone();
+      })
+      .or_insert(Var {
         kind,
-        path: self.path.clone(),
-      },
-    );
-    self.scope.symbols.entry(i.0.clone()).or_default().push(i);
+        path,
+        declared_at: i.span,
+        references: Vec::new(),
+      });
+    self.scope.symbols.entry(id.0.clone()).or_default().push(id);
   }
 
-  fn declare(&mut self, kind: BindingKind, i: &Ident) {
-    self.declare_id(kind, i.to_id());
+  fn declare_pat(&mut self, kind: BindingKind, pat: &Pat) {
+    let idents: Vec<Ident> = find_ids(pat);
+
+    for ident in &idents {
+      self.declare(kind, ident);
+    }
   }
 
-  fn declare_pat(&mut self, kind: BindingKind, pat: &Pat) {
-    let ids: Vec<Id> = find_ids(pat);
+  fn reference(&mut self, i: &Ident, kind: ReferenceKind) {
+    self
+      .scope
+      .references
+      .entry(i.to_id())
+      .or_default()
+      .push(Reference { span: i.span, kind });
+  }
+
+  /// Records the identifiers assigned by a pattern, like `a` and `b` of
+  /// `[a, b] = foo`.
+  fn reference_pat(&mut self, pat: &Pat) {
+    let idents: Vec<Ident> = find_ids(pat);
+
+    for ident in &idents {
+      self.reference(ident, ReferenceKind::Write);
+    }
+  }
 
-    for id in ids {
-      self.declare_id(kind, id);
+  /// Records the target of an assignment which is not a pattern, like `a` of
+  /// `a += 1`.
+  fn reference_assign_target(&mut self, expr: &Expr, op: AssignOp) {
+    match expr {
+      Expr::Ident(i) => {
+        let kind = if op == AssignOp::Assign {
+          ReferenceKind::Write
+        } else {
+          ReferenceKind::ReadWrite
+        };
+        self.reference(i, kind);
+      }
+      _ => expr.visit_with(expr, self),
+    }
+  }
+
+  /// Records the value referred to by an entity name in a type, like `a` of
+  /// `typeof a.b`.
+  fn reference_entity_name(&mut self, name: &TsEntityName) {
+    match name {
+      TsEntityName::TsQualifiedName(qualified) => {
+        self.reference_entity_name(&qualified.left)
+      }
+      TsEntityName::Ident(i) => self.reference(i, ReferenceKind::Read),
     }
   }
 
@@ -220,6 +352,8 @@ impl Visit for Analyzer<'_> {
         }
       }
 
+      // Default values and computed keys of patterns
+      v.name.visit_with(n, self);
       self.declare_pat(
         match n.kind {
           VarDeclKind::Var => BindingKind::Var,
@@ -234,7 +368,9 @@ impl Visit for Analyzer<'_> {
   /// Overriden not to add ScopeKind::Block
   fn visit_function(&mut self, n: &Function, _: &dyn Node) {
     n.decorators.visit_with(n, self);
+    n.type_params.visit_with(n, self);
     n.params.visit_with(n, self);
+    n.return_type.visit_with(n, self);
 
     // Don't add ScopeKind::Block
     match &n.body {
@@ -278,12 +414,14 @@ impl Visit for Analyzer<'_> {
   fn visit_catch_clause(&mut self, n: &CatchClause, _: &dyn Node) {
     if let Some(pat) = &n.param {
       self.declare_pat(BindingKind::CatchClause, pat);
+      pat.visit_with(n, self);
     }
     self.visit_with_path(ScopeKind::Catch, &n.body)
   }
 
   fn visit_param(&mut self, n: &Param, _: &dyn Node) {
     self.declare_pat(BindingKind::Param, &n.pat);
+    n.visit_children_with(self);
   }
 
   fn visit_import_named_specifier(
@@ -324,6 +462,9 @@ impl Visit for Analyzer<'_> {
   }
 
   fn visit_for_of_stmt(&mut self, n: &ForOfStmt, _: &dyn Node) {
+    if let VarDeclOrPat::Pat(pat) = &n.left {
+      self.reference_pat(pat);
+    }
     n.left.visit_with(n, self);
     n.right.visit_with(n, self);
 
@@ -331,6 +472,9 @@ impl Visit for Analyzer<'_> {
   }
 
   fn visit_for_in_stmt(&mut self, n: &ForInStmt, _: &dyn Node) {
+    if let VarDeclOrPat::Pat(pat) = &n.left {
+      self.reference_pat(pat);
+    }
     n.left.visit_with(n, self);
     n.right.visit_with(n, self);
 
@@ -360,6 +504,111 @@ impl Visit for Analyzer<'_> {
     n.type_params.visit_with(n, self);
     n.type_ann.visit_with(n, self);
   }
+
+  fn visit_ts_enum_decl(&mut self, n: &TsEnumDecl, _: &dyn Node) {
+    self.declare(BindingKind::Enum, &n.id);
+    n.members.visit_with(n, self);
+  }
+
+  fn visit_ts_type_query_expr(&mut self, n: &TsTypeQueryExpr, _: &dyn Node) {
+    if let TsTypeQueryExpr::TsEntityName(name) = n {
+      self.reference_entity_name(name);
+    } else {
+      n.visit_children_with(self);
+    }
+  }
+
+  /// Handles the heritage clauses of classes and interfaces, like `Foo` of
+  /// `implements Foo`
+  fn visit_ts_expr_with_type_args(
+    &mut self,
+    n: &TsExprWithTypeArgs,
+    _: &dyn Node,
+  ) {
+    self.reference_entity_name(&n.expr);
+    n.type_args.visit_with(n, self);
+  }
+
+  fn visit_expr(&mut self, n: &Expr, _: &dyn Node) {
+    if let Expr::Ident(i) = n {
+      self.reference(i, ReferenceKind::Read);
+    }
+    n.visit_children_with(self);
+  }
+
+  fn visit_assign_expr(&mut self, n: &AssignExpr, _: &dyn Node) {
+    match &n.left {
+      PatOrExpr::Pat(pat) => match &**pat {
+        Pat::Expr(expr) => self.reference_assign_target(expr, n.op),
+        _ => {
+          self.reference_pat(pat);
+          // Default values and computed keys of patterns
+          pat.visit_with(n, self);
+        }
+      },
+      PatOrExpr::Expr(expr) => self.reference_assign_target(expr, n.op),
+    }
+    n.right.visit_with(n, self);
+  }
+
+  fn visit_update_expr(&mut self, n: &UpdateExpr, _: &dyn Node) {
+    match &*n.arg {
+      Expr::Ident(i) => self.reference(i, ReferenceKind::ReadWrite),
+      _ => n.arg.visit_with(n, self),
+    }
+  }
+
+  /// Overriden not to treat non-computed properties as references
+  fn visit_member_expr(&mut self, n: &MemberExpr, _: &dyn Node) {
+    n.obj.visit_with(n, self);
+    if n.computed {
+      n.prop.visit_with(n, self);
+    }
+  }
+
+  /// Overriden not to treat non-computed keys as references
+  fn visit_class_prop(&mut self, n: &ClassProp, _: &dyn Node) {
+    n.decorators.visit_with(n, self);
+    if n.computed {
+      n.key.visit_with(n, self);
+    }
+    n.value.visit_with(n, self);
+    n.type_ann.visit_with(n, self);
+  }
+
+  /// Overriden not to treat non-computed keys as references
+  fn visit_ts_property_signature(
+    &mut self,
+    n: &TsPropertySignature,
+    _: &dyn Node,
+  ) {
+    if n.computed {
+      n.key.visit_with(n, self);
+    }
+    n.type_params.visit_with(n, self);
+    n.params.visit_with(n, self);
+    n.type_ann.visit_with(n, self);
+    n.init.visit_with(n, self);
+  }
+
+  fn visit_prop(&mut self, n: &Prop, _: &dyn Node) {
+    if let Prop::Shorthand(i) = n {
+      self.reference(i, ReferenceKind::Read);
+    }
+    n.visit_children_with(self);
+  }
+
+  fn visit_named_export(&mut self, n: &NamedExport, _: &dyn Node) {
+    // Re-exports refer to bindings of other modules
+    if n.src.is_some() {
+      return;
+    }
+    for specifier in &n.specifiers {
+      if let ExportSpecifier::Named(named) = specifier {
+        self.reference(&named.orig, ReferenceKind::Read);
+      }
+    }
+  }
 }
 
 #[cfg(test)]
@@ -435,4 +684,91 @@ try {
       assert_eq!(var(&scope, "e").path(), &[]);
     });
   }
+
+  #[test]
+  fn references() {
+    let source_code = r#"
+f(a);
+var a = 1;
+function f(b) {
+  a = b;
+  [a] = [obj.a];
+  return { a, b: obj[b] };
+}
+class Foo {
+  a = 1;
+}
+export { a };
+"#;
+    test_scope(source_code, |scope| {
+      let a = var(&scope, "a");
+      assert_eq!(a.kind(), BindingKind::Var);
+      assert_eq!(a.declared_at().lo.0, 11);
+      let references: Vec<u32> = a
+        .references()
+        .iter()
+        .map(|reference| reference.span().lo.0)
+        .collect();
+      assert_eq!(references, vec![3, 36, 46, 71, 121]);
+      let writes: Vec<u32> = a
+        .references()
+        .iter()
+        .filter(|reference| reference.is_write())
+        .map(|reference| reference.span().lo.0)
+        .collect();
+      assert_eq!(writes, vec![36, 46]);
+
+      let b = var(&scope, "b");
+      assert_eq!(b.references().len(), 2);
+
+      let f = var(&scope, "f");
+      assert_eq!(f.kind(), BindingKind::Function);
+      assert_eq!(f.references().len(), 1);
+
+      assert!(var(&scope, "Foo").references().is_empty());
+    });
+  }
+
+  #[test]
+  fn reference_kinds() {
+    let source_code = r#"
+let a = 1, b = 2;
+a += 1;
+b++;
+const { c = a } = obj;
+function f(d = b) {}
+type T = typeof c;
+enum E { A }
+E.A;
+"#;
+    test_scope(source_code, |scope| {
+      let a = var(&scope, "a");
+      assert_eq!(a.references().len(), 2);
+      assert!(a.references()[0].is_read() && a.references()[0].is_write());
+      assert!(a.references()[1].is_read() && !a.references()[1].is_write());
+
+      let b = var(&scope, "b");
+      assert_eq!(b.references().len(), 2);
+      assert!(b.references()[0].is_read() && b.references()[0].is_write());
+
+      assert_eq!(var(&scope, "c").references().len(), 1);
+
+      let e = var(&scope, "E");
+      assert_eq!(e.kind(), BindingKind::Enum);
+      assert_eq!(e.references().len(), 1);
+    });
+  }
+
+  #[test]
+  fn redeclaration_keeps_first_declaration() {
+    test_scope("var a; var a; function a() {}", |scope| {
+      let a = scope.var(&id_of_first(&scope, "a")).unwrap();
+      assert_eq!(a.kind(), BindingKind::Function);
+      assert_eq!(a.declared_at().lo.0, 4);
+    });
+  }
+
+  fn id_of_first(scope: &Scope, s: &str) -> Id {
+    scope.ids_with_symbol(&s.into()).unwrap()[0].clone()
+  }
 }
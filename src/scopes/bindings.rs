@@ -0,0 +1,188 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+//! Helpers collecting the bindings declared directly in a lexical scope, for
+//! rules which track which names are visible at some point of the program,
+//! like `no-shadow` and `no-label-var`.
+use deno_ast::swc::ast::{
+  ArrowExpr, BlockStmt, Class, Constructor, Decl, DefaultDecl, Function,
+  GetterProp, Ident, ImportSpecifier, Invalid, ModuleDecl, ModuleItem,
+  ObjectPatProp, ParamOrTsParamProp, Pat, SetterProp, Stmt, TsParamPropParam,
+  VarDecl, VarDeclKind,
+};
+use deno_ast::swc::atoms::JsWord;
+use deno_ast::swc::common::{Span, DUMMY_SP};
+use deno_ast::swc::visit::{noop_visit_type, Node, Visit, VisitWith};
+
+#[derive(Clone, Copy)]
+pub struct Binding {
+  /// Span of the identifier declaring the binding.
+  pub span: Span,
+  pub is_function: bool,
+}
+
+pub type Bindings = Vec<(JsWord, Binding)>;
+
+pub fn add_ident(ident: &Ident, is_function: bool, bindings: &mut Bindings) {
+  bindings.push((
+    ident.sym.clone(),
+    Binding {
+      span: ident.span,
+      is_function,
+    },
+  ));
+}
+
+pub fn add_pat(pat: &Pat, bindings: &mut Bindings) {
+  match pat {
+    Pat::Ident(binding_ident) => add_ident(&binding_ident.id, false, bindings),
+    Pat::Array(array_pat) => {
+      for elem in array_pat.elems.iter().flatten() {
+        add_pat(elem, bindings);
+      }
+    }
+    Pat::Object(object_pat) => {
+      for prop in &object_pat.props {
+        match prop {
+          ObjectPatProp::KeyValue(key_value) => {
+            add_pat(&key_value.value, bindings)
+          }
+          ObjectPatProp::Assign(assign) => {
+            add_ident(&assign.key, false, bindings)
+          }
+          ObjectPatProp::Rest(rest) => add_pat(&rest.arg, bindings),
+        }
+      }
+    }
+    Pat::Rest(rest_pat) => add_pat(&rest_pat.arg, bindings),
+    Pat::Assign(assign_pat) => add_pat(&assign_pat.left, bindings),
+    Pat::Expr(_) | Pat::Invalid(_) => {}
+  }
+}
+
+/// Collects the parameters of a constructor, including parameter properties.
+pub fn add_constructor_params(
+  constructor: &Constructor,
+  bindings: &mut Bindings,
+) {
+  for param in &constructor.params {
+    match param {
+      ParamOrTsParamProp::Param(param) => add_pat(&param.pat, bindings),
+      ParamOrTsParamProp::TsParamProp(prop) => match &prop.param {
+        TsParamPropParam::Ident(ident) => add_ident(&ident.id, false, bindings),
+        TsParamPropParam::Assign(assign) => add_pat(&assign.left, bindings),
+      },
+    }
+  }
+}
+
+/// Collects bindings of a block scoped declaration. `var` declarations are
+/// collected separately by `VarCollector`, since they belong to the
+/// enclosing function.
+fn add_decl(decl: &Decl, bindings: &mut Bindings) {
+  match decl {
+    Decl::Var(var_decl) => add_lexical_var_decl(var_decl, bindings),
+    Decl::Fn(fn_decl) => add_ident(&fn_decl.ident, true, bindings),
+    Decl::Class(class_decl) => add_ident(&class_decl.ident, false, bindings),
+    _ => {}
+  }
+}
+
+pub fn add_lexical_var_decl(var_decl: &VarDecl, bindings: &mut Bindings) {
+  if var_decl.kind != VarDeclKind::Var {
+    for declarator in &var_decl.decls {
+      add_pat(&declarator.name, bindings);
+    }
+  }
+}
+
+pub fn add_lexical_stmts<'a>(
+  stmts: impl IntoIterator<Item = &'a Stmt>,
+  bindings: &mut Bindings,
+) {
+  for stmt in stmts {
+    if let Stmt::Decl(decl) = stmt {
+      add_decl(decl, bindings);
+    }
+  }
+}
+
+pub fn add_lexical_module_items(items: &[ModuleItem], bindings: &mut Bindings) {
+  for item in items {
+    match item {
+      ModuleItem::Stmt(Stmt::Decl(decl)) => add_decl(decl, bindings),
+      ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => {
+        add_decl(&export_decl.decl, bindings)
+      }
+      ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(export_default)) => {
+        match &export_default.decl {
+          DefaultDecl::Fn(fn_expr) => {
+            if let Some(ident) = &fn_expr.ident {
+              add_ident(ident, true, bindings);
+            }
+          }
+          DefaultDecl::Class(class_expr) => {
+            if let Some(ident) = &class_expr.ident {
+              add_ident(ident, false, bindings);
+            }
+          }
+          DefaultDecl::TsInterfaceDecl(_) => {}
+        }
+      }
+      ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)) => {
+        for specifier in &import_decl.specifiers {
+          let local = match specifier {
+            ImportSpecifier::Named(named) => &named.local,
+            ImportSpecifier::Default(default) => &default.local,
+            ImportSpecifier::Namespace(namespace) => &namespace.local,
+          };
+          add_ident(local, false, bindings);
+        }
+      }
+      _ => {}
+    }
+  }
+}
+
+/// Collects `var` declarations which are hoisted to the scope being
+/// visited, skipping nested functions.
+#[derive(Default)]
+pub struct VarCollector {
+  bindings: Bindings,
+}
+
+impl VarCollector {
+  pub fn collect<N: VisitWith<Self>>(node: &N, bindings: &mut Bindings) {
+    let mut collector = VarCollector::default();
+    node.visit_with(&Invalid { span: DUMMY_SP }, &mut collector);
+    bindings.append(&mut collector.bindings);
+  }
+}
+
+impl Visit for VarCollector {
+  noop_visit_type!();
+
+  fn visit_var_decl(&mut self, var_decl: &VarDecl, _: &dyn Node) {
+    if var_decl.kind == VarDeclKind::Var {
+      for declarator in &var_decl.decls {
+        add_pat(&declarator.name, &mut self.bindings);
+      }
+    }
+  }
+
+  fn visit_function(&mut self, _: &Function, _: &dyn Node) {}
+
+  fn visit_arrow_expr(&mut self, _: &ArrowExpr, _: &dyn Node) {}
+
+  fn visit_constructor(&mut self, _: &Constructor, _: &dyn Node) {}
+
+  fn visit_getter_prop(&mut self, _: &GetterProp, _: &dyn Node) {}
+
+  fn visit_setter_prop(&mut self, _: &SetterProp, _: &dyn Node) {}
+
+  fn visit_class(&mut self, _: &Class, _: &dyn Node) {}
+}
+
+/// Collects the bindings of a function body.
+pub fn function_body_bindings(body: &BlockStmt, bindings: &mut Bindings) {
+  VarCollector::collect(&body.stmts, bindings);
+  add_lexical_stmts(&body.stmts, bindings);
+}
@@ -3,7 +3,7 @@ use crate::scopes::Scope;
 use deno_ast::swc::ast::{
   BigInt, Bool, ComputedPropName, Expr, ExprOrSpread, Ident, JSXText, Lit,
   MemberExpr, Null, Number, PatOrExpr, PrivateName, Prop, PropName,
-  PropOrSpread, Regex, Str, Tpl,
+  PropOrSpread, Regex, Str, Tpl, UnaryOp,
 };
 use deno_ast::swc::utils::{find_ids, ident::IdentLike};
 use deno_ast::view as ast_view;
@@ -292,3 +292,35 @@ where
     PatOrExpr::Pat(p) => find_ids(p),
   }
 }
+
+/// Builds a human readable name of a function, e.g. "Function `foo`".
+pub(crate) fn describe_function(kind: &str, name: Option<String>) -> String {
+  match name {
+    Some(name) => format!("{} `{}`", kind, name),
+    None => kind.to_string(),
+  }
+}
+
+/// Strips any parentheses around the expression, e.g. `((foo))` gives `foo`.
+pub(crate) fn unwrap_parens(mut expr: &Expr) -> &Expr {
+  while let Expr::Paren(paren) = expr {
+    expr = &paren.expr;
+  }
+  expr
+}
+
+/// Checks if the expression is `null`, the global `undefined` or `void` applied
+/// to a literal, e.g. `void 0`.
+pub(crate) fn is_nullish(expr: &Expr, scope: &Scope) -> bool {
+  match expr {
+    Expr::Lit(Lit::Null(_)) => true,
+    Expr::Ident(ident) => {
+      ident.sym == *"undefined" && scope.is_global(&ident.to_id())
+    }
+    Expr::Unary(unary) => {
+      unary.op == UnaryOp::Void && matches!(&*unary.arg, Expr::Lit(_))
+    }
+    Expr::Paren(paren) => is_nullish(&paren.expr, scope),
+    _ => false,
+  }
+}
@@ -7,11 +7,39 @@ use crate::rules::LintRule;
 use deno_ast::swc::parser::{Syntax, TsConfig};
 use deno_ast::view as ast_view;
 use deno_ast::ParsedSource;
-use std::marker::PhantomData;
 use std::sync::Arc;
 
 #[macro_export]
 macro_rules! assert_lint_ok {
+  (
+    $rule:ty,
+    filename: $filename:literal,
+    options: $options:expr,
+    $($src:literal),+
+    $(,)?
+  ) => {
+    $(
+      $crate::test_util::assert_lint_ok_with_rule(
+        <$rule>::with_options(::serde_json::from_value($options).unwrap()),
+        $src,
+        $filename,
+      );
+    )*
+  };
+  (
+    $rule:ty,
+    options: $options:expr,
+    $($src:literal),+
+    $(,)?
+  ) => {
+    $(
+      $crate::test_util::assert_lint_ok_with_rule(
+        <$rule>::with_options(::serde_json::from_value($options).unwrap()),
+        $src,
+        "deno_lint_ok_test.ts",
+      );
+    )*
+  };
   (
     $rule:ty,
     filename: $filename:literal,
@@ -33,6 +61,41 @@ macro_rules! assert_lint_ok {
 
 #[macro_export]
 macro_rules! assert_lint_err {
+  (
+    $rule:ty,
+    filename: $filename:literal,
+    options: $options:expr,
+    $($src:literal : $test:tt),+
+    $(,)?
+  ) => {
+    $(
+      let errors = parse_err_test!($test);
+      let tester = $crate::test_util::LintErrTester::with_rule(
+        <$rule>::with_options(::serde_json::from_value($options).unwrap()),
+        $src,
+        errors,
+        $filename,
+      );
+      tester.run();
+    )*
+  };
+  (
+    $rule:ty,
+    options: $options:expr,
+    $($src:literal : $test:tt),+
+    $(,)?
+  ) => {
+    $(
+      let errors = parse_err_test!($test);
+      let tester = $crate::test_util::LintErrTester::with_rule(
+        <$rule>::with_options(::serde_json::from_value($options).unwrap()),
+        $src,
+        errors,
+        "deno_lint_err_test.ts",
+      );
+      tester.run();
+    )*
+  };
   (
     $rule:ty,
     filename: $filename:literal,
@@ -170,12 +233,51 @@ macro_rules! parse_err_test {
   }};
 }
 
-#[derive(Default)]
+/// A marker which can be put in the source of an `assert_lint_err!` test right
+/// in front of where a diagnostic is expected, instead of specifying its
+/// `line` and `col` by hand:
+///
+/// ```ignore
+/// assert_lint_err! {
+///   NoDebugger,
+///   "function f() { /*ERR*/debugger; }": [{ message: MESSAGE }],
+/// };
+/// ```
+///
+/// The markers are removed before linting. Expected diagnostics which specify
+/// neither `line` nor `col` take the positions of the markers in order.
+pub const ERR_MARKER: &str = "/*ERR*/";
+
+/// Removes the error markers from the source, and returns the source without
+/// them along with the positions (as line and column) of the markers.
+fn strip_err_markers(source: &str) -> (String, Vec<(usize, usize)>) {
+  let mut stripped = String::with_capacity(source.len());
+  let mut markers = Vec::new();
+  let (mut line, mut col) = (1, 0);
+  let mut rest = source;
+  while let Some(c) = rest.chars().next() {
+    if let Some(after_marker) = rest.strip_prefix(ERR_MARKER) {
+      markers.push((line, col));
+      rest = after_marker;
+      continue;
+    }
+    stripped.push(c);
+    if c == '\n' {
+      line += 1;
+      col = 0;
+    } else {
+      col += 1;
+    }
+    rest = &rest[c.len_utf8()..];
+  }
+  (stripped, markers)
+}
+
 pub struct LintErrTester<T: LintRule + 'static> {
   src: &'static str,
   errors: Vec<LintErr>,
   filename: &'static str,
-  rule: PhantomData<T>,
+  rule: Box<T>,
 }
 
 impl<T: LintRule + 'static> LintErrTester<T> {
@@ -183,26 +285,40 @@ impl<T: LintRule + 'static> LintErrTester<T> {
     src: &'static str,
     errors: Vec<LintErr>,
     filename: &'static str,
+  ) -> Self {
+    Self::with_rule(T::new(), src, errors, filename)
+  }
+
+  /// Creates a tester that runs the given rule instance, which is useful for
+  /// rules configured with options.
+  pub fn with_rule(
+    rule: Box<T>,
+    src: &'static str,
+    errors: Vec<LintErr>,
+    filename: &'static str,
   ) -> Self {
     Self {
       src,
       errors,
       filename,
-      rule: PhantomData,
+      rule,
     }
   }
 
   pub fn run(self) {
-    let rule = T::new();
-    let rule_code = rule.code();
-    let diagnostics = lint(rule, self.src, self.filename);
+    let rule_code = self.rule.code();
+    let marked_src = self.src;
+    let (src, markers) = strip_err_markers(marked_src);
+    let has_markers = !markers.is_empty();
+    let mut markers = markers.into_iter();
+    let diagnostics = lint(self.rule, &src, self.filename);
     assert_eq!(
       self.errors.len(),
       diagnostics.len(),
       "{} diagnostics expected, but got {}.\n\nsource:\n{}\n",
       self.errors.len(),
       diagnostics.len(),
-      self.src,
+      src,
     );
 
     for (error, diagnostic) in self.errors.iter().zip(&diagnostics) {
@@ -211,26 +327,49 @@ impl<T: LintRule + 'static> LintErrTester<T> {
         col,
         message,
         hint,
+        fix,
       } = error;
+      let (line, col) = match (line, col) {
+        (None, None) if has_markers => markers.next().unwrap_or_else(|| {
+          panic!(
+            "More diagnostics without a position than `{}` markers\n\nsource:\n{}\n",
+            ERR_MARKER, marked_src
+          )
+        }),
+        _ => (line.unwrap_or(1), col.unwrap_or(0)),
+      };
       assert_diagnostic_2(
         diagnostic,
         rule_code,
-        *line,
-        *col,
-        self.src,
+        line,
+        col,
+        &src,
         message,
         hint.as_deref(),
       );
+      if let Some(fixed) = fix {
+        assert_fix(diagnostic, &src, fixed);
+      }
     }
+    assert!(
+      markers.next().is_none(),
+      "More `{}` markers than diagnostics without a position\n\nsource:\n{}\n",
+      ERR_MARKER,
+      marked_src
+    );
   }
 }
 
 #[derive(Default)]
 pub struct LintErr {
-  pub line: usize,
-  pub col: usize,
+  /// The expected line, or `None` if the position is given by an
+  /// `ERR_MARKER` in the source
+  pub line: Option<usize>,
+  pub col: Option<usize>,
   pub message: String,
   pub hint: Option<String>,
+  /// The expected source after applying the fix of the diagnostic
+  pub fix: Option<String>,
 }
 
 #[derive(Default)]
@@ -239,6 +378,7 @@ pub struct LintErrBuilder {
   col: Option<usize>,
   message: Option<String>,
   hint: Option<String>,
+  fix: Option<String>,
 }
 
 impl LintErrBuilder {
@@ -268,12 +408,18 @@ impl LintErrBuilder {
     self
   }
 
+  pub fn fix(&mut self, fixed: impl ToString) -> &mut Self {
+    self.fix = Some(fixed.to_string());
+    self
+  }
+
   pub fn build(self) -> LintErr {
     LintErr {
-      line: self.line.unwrap_or(1),
-      col: self.col.unwrap_or(0),
+      line: self.line,
+      col: self.col,
       message: self.message.unwrap_or_else(|| "".to_string()),
       hint: self.hint,
+      fix: self.fix,
     }
   }
 }
@@ -379,11 +525,46 @@ fn assert_diagnostic_2(
   );
 }
 
+fn assert_fix(diagnostic: &LintDiagnostic, source: &str, expected: &str) {
+  let fix = diagnostic.fix.as_ref().unwrap_or_else(|| {
+    panic!(
+      "Diagnostic is expected to have a fix, but doesn't\n\nsource:\n{}\n",
+      source
+    )
+  });
+  // Positions don't account for the byte order mark, which is stripped
+  // before parsing
+  let (bom, text) = match source.strip_prefix('\u{FEFF}') {
+    Some(text) if !fix.removes_bom => ("\u{FEFF}", text),
+    Some(text) => ("", text),
+    None => ("", source),
+  };
+  let fixed = format!(
+    "{}{}{}{}",
+    bom,
+    &text[..fix.range.start.byte_pos],
+    fix.new_text,
+    &text[fix.range.end.byte_pos..]
+  );
+  assert_eq!(
+    expected, fixed,
+    "Fixed source is expected to be \"{}\", but got \"{}\"\n\nsource:\n{}\n",
+    expected, fixed, source
+  );
+}
+
 pub fn assert_lint_ok<T: LintRule + 'static>(
   source: &str,
   filename: &'static str,
 ) {
-  let rule = T::new();
+  assert_lint_ok_with_rule(T::new(), source, filename);
+}
+
+pub fn assert_lint_ok_with_rule(
+  rule: Box<dyn LintRule>,
+  source: &str,
+  filename: &'static str,
+) {
   let diagnostics = lint(rule, source, filename);
   if !diagnostics.is_empty() {
     panic!(
@@ -409,3 +590,28 @@ pub fn parse_and_then(source_code: &str, test: impl Fn(ast_view::Program)) {
     test(pg);
   });
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn strip_err_markers_positions() {
+    assert_eq!(
+      strip_err_markers("a; /*ERR*/b;"),
+      ("a; b;".to_string(), vec![(1, 3)])
+    );
+    assert_eq!(
+      strip_err_markers("/*ERR*/a;\n  /*ERR*/b; /*ERR*/c;"),
+      ("a;\n  b; c;".to_string(), vec![(1, 0), (2, 2), (2, 5)])
+    );
+    assert_eq!(
+      strip_err_markers("'ü'; /*ERR*/a;"),
+      ("'ü'; a;".to_string(), vec![(1, 5)])
+    );
+    assert_eq!(
+      strip_err_markers("/* comment */ a;"),
+      ("/* comment */ a;".to_string(), vec![])
+    );
+  }
+}